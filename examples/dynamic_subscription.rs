@@ -50,6 +50,7 @@ async fn main() -> Result<()> {
         account_include: vec![PUMPFUN_PROGRAM_ID.to_string()],
         account_exclude: vec![],
         account_required: vec![],
+        ..Default::default()
     };
 
     let account_filter = AccountFilter { account: vec![], owner: vec![], filters: vec![] };
@@ -91,6 +92,7 @@ async fn main() -> Result<()> {
         account_include: vec![PUMPFUN_PROGRAM_ID.to_string(), RAYDIUM_CPMM_PROGRAM_ID.to_string()],
         account_exclude: vec![],
         account_required: vec![],
+        ..Default::default()
     };
 
     if let Err(e) = client
@@ -117,6 +119,7 @@ async fn main() -> Result<()> {
         account_include: vec![RAYDIUM_CPMM_PROGRAM_ID.to_string()],
         account_exclude: vec![],
         account_required: vec![],
+        ..Default::default()
     };
 
     if let Err(e) = client
@@ -143,6 +146,7 @@ async fn main() -> Result<()> {
         account_include: vec![PUMPFUN_PROGRAM_ID.to_string()],
         account_exclude: vec![],
         account_required: vec![],
+        ..Default::default()
     };
 
     if let Err(e) = client
@@ -169,6 +173,7 @@ async fn main() -> Result<()> {
         account_include: vec![],
         account_exclude: vec![],
         account_required: vec![],
+        ..Default::default()
     };
 
     if let Err(e) = client
@@ -201,6 +206,7 @@ async fn main() -> Result<()> {
         account_include: vec![],
         account_exclude: vec![],
         account_required: vec![random_pubkey_1.to_string(), random_pubkey_2.to_string()],
+        ..Default::default()
     };
 
     if let Err(e) = client
@@ -266,6 +272,7 @@ async fn main() -> Result<()> {
                 account_include: vec![],
                 account_exclude: vec![],
                 account_required: vec![],
+                ..Default::default()
             }],
             vec![AccountFilter { account: vec![], owner: vec![], filters: vec![] }],
             None,
@@ -337,6 +344,7 @@ async fn main() -> Result<()> {
                 account_include: vec![RAYDIUM_CPMM_PROGRAM_ID.to_string()],
                 account_exclude: vec![],
                 account_required: vec![],
+                ..Default::default()
             }],
             vec![AccountFilter { account: vec![], owner: vec![], filters: vec![] }],
             None,
@@ -370,6 +378,7 @@ async fn main() -> Result<()> {
                 account_include: vec![RAYDIUM_CPMM_PROGRAM_ID.to_string()],
                 account_exclude: vec![],
                 account_required: vec![],
+                ..Default::default()
             }],
             vec![AccountFilter { account: vec![], owner: vec![], filters: vec![] }],
             None,
@@ -404,6 +413,7 @@ async fn main() -> Result<()> {
                 account_include: vec![RAYDIUM_CPMM_PROGRAM_ID.to_string()],
                 account_exclude: vec![],
                 account_required: vec![],
+                ..Default::default()
             }],
             vec![AccountFilter { account: vec![], owner: vec![], filters: vec![] }],
             None,
@@ -422,6 +432,7 @@ async fn main() -> Result<()> {
                         account_include: vec![RAYDIUM_CPMM_PROGRAM_ID.to_string()],
                         account_exclude: vec![],
                         account_required: vec![],
+                        ..Default::default()
                     }],
                     vec![AccountFilter { account: vec![], owner: vec![], filters: vec![] }],
                     None,
@@ -459,6 +470,7 @@ async fn main() -> Result<()> {
                 account_include: vec![RAYDIUM_CPMM_PROGRAM_ID.to_string()],
                 account_exclude: vec![],
                 account_required: vec![],
+                ..Default::default()
             }],
             vec![AccountFilter { account: vec![], owner: vec![], filters: vec![] }],
             None,
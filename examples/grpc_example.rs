@@ -1,14 +1,5 @@
 use solana_streamer_sdk::streaming::{
-    event_parser::{
-        protocols::{
-            bonk::parser::BONK_PROGRAM_ID, meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
-            pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID,
-            raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID,
-            raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
-            raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID,
-        },
-        DexEvent, Protocol,
-    },
+    event_parser::{DexEvent, Protocol},
     grpc::ClientConfig,
     yellowstone_grpc::{AccountFilter, TransactionFilter},
     YellowstoneGrpc,
@@ -47,20 +38,21 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
         Protocol::RaydiumClmm,
         Protocol::RaydiumAmmV4,
         Protocol::MeteoraDammV2,
+        Protocol::OrcaWhirlpool,
+        Protocol::MeteoraDlmm,
+        Protocol::Phoenix,
+        Protocol::Jupiter,
+        Protocol::Moonshot,
+        Protocol::Lifinity,
+        Protocol::OpenBookV2,
+        Protocol::Sanctum,
     ];
 
     println!("Protocols to monitor: {:?}", protocols);
 
-    // Filter accounts
-    let account_include = vec![
-        PUMPFUN_PROGRAM_ID.to_string(),         // Listen to pumpfun program ID
-        PUMPSWAP_PROGRAM_ID.to_string(),        // Listen to pumpswap program ID
-        BONK_PROGRAM_ID.to_string(),            // Listen to bonk program ID
-        RAYDIUM_CPMM_PROGRAM_ID.to_string(),    // Listen to raydium_cpmm program ID
-        RAYDIUM_CLMM_PROGRAM_ID.to_string(),    // Listen to raydium_clmm program ID
-        RAYDIUM_AMM_V4_PROGRAM_ID.to_string(),  // Listen to raydium_amm_v4 program ID
-        METEORA_DAMM_V2_PROGRAM_ID.to_string(), // Listen to meteora_damm_v2 program ID
-    ];
+    // Filter accounts: the programs of the monitored protocols
+    let account_include: Vec<String> =
+        protocols.iter().map(|protocol| protocol.program_id().to_string()).collect();
     let account_exclude = vec![];
     let account_required = vec![];
 
@@ -69,6 +61,7 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
         account_include: account_include.clone(),
         account_exclude,
         account_required,
+        ..Default::default()
     };
 
     // Listen to account data belonging to owner programs -> account event monitoring
@@ -123,7 +116,7 @@ fn create_event_callback() -> impl Fn(DexEvent) {
             }
             // .... other events
             _ => {
-                println!("{:?}", event);
+                println!("{}", event);
             }
         }
     }
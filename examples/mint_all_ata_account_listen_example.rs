@@ -10,11 +10,6 @@ use solana_streamer_sdk::streaming::{
     yellowstone_grpc::{AccountFilter, TransactionFilter},
     YellowstoneGrpc,
 };
-use yellowstone_grpc_proto::geyser::{
-    subscribe_request_filter_accounts_filter::Filter,
-    subscribe_request_filter_accounts_filter_memcmp::Data, SubscribeRequestFilterAccountsFilter,
-    SubscribeRequestFilterAccountsFilterMemcmp,
-};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -45,32 +40,18 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
     let account_required = vec![];
 
     // Listen to transaction data
-    let transaction_filter =
-        TransactionFilter { account_include, account_exclude, account_required };
+    let transaction_filter = TransactionFilter {
+        account_include,
+        account_exclude,
+        account_required,
+        ..Default::default()
+    };
 
     let pump = Pubkey::from_str("pumpCmXqMfrsAkQ5r49WcJnRayYRqmXz6ae8H7H9Dfn").unwrap();
     let usdc = Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
 
-    let all_pump_ata = AccountFilter {
-        account: vec![],
-        owner: vec![],
-        filters: vec![SubscribeRequestFilterAccountsFilter {
-            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
-                offset: 0,
-                data: Some(Data::Bytes(pump.to_bytes().to_vec())),
-            })),
-        }],
-    };
-    let all_usdc_ata = AccountFilter {
-        account: vec![],
-        owner: vec![],
-        filters: vec![SubscribeRequestFilterAccountsFilter {
-            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
-                offset: 0,
-                data: Some(Data::Bytes(usdc.to_bytes().to_vec())),
-            })),
-        }],
-    };
+    let all_pump_ata = AccountFilter::builder().memcmp(0, pump.to_bytes()).build()?;
+    let all_usdc_ata = AccountFilter::builder().memcmp(0, usdc.to_bytes()).build()?;
 
     // Event filtering
     let event_type_filter = Some(EventTypeFilter { include: vec![EventType::TokenAccount] });
@@ -2,6 +2,7 @@ use anyhow::Result;
 use solana_commitment_config::CommitmentConfig;
 use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
 use solana_streamer_sdk::streaming::event_parser::Protocol;
+use solana_streamer_sdk::streaming::event_parser::ParseOptions;
 use solana_streamer_sdk::streaming::event_parser::DexEvent;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -187,6 +188,13 @@ async fn get_single_transaction_details(signature_str: &str) -> Result<()> {
                 Protocol::RaydiumCpmm,
                 Protocol::RaydiumAmmV4,
                 Protocol::MeteoraDammV2,
+                Protocol::OrcaWhirlpool,
+                Protocol::MeteoraDlmm,
+                Protocol::Phoenix,
+                Protocol::Jupiter,
+                Protocol::Moonshot,
+                Protocol::Lifinity,
+                Protocol::Sanctum,
             ];
 
             // Create callback
@@ -196,8 +204,7 @@ async fn get_single_transaction_details(signature_str: &str) -> Result<()> {
 
             // Call parse_instruction_events_from_versioned_transaction
             EventParser::parse_instruction_events_from_versioned_transaction(
-                &protocols,
-                None,
+                ParseOptions::new(&protocols).with_bot_wallet(bot_wallet),
                 &versioned_tx,
                 signature,
                 Some(slot),
@@ -205,7 +212,6 @@ async fn get_single_transaction_details(signature_str: &str) -> Result<()> {
                 recv_us,
                 &accounts,
                 &inner_instructions_vec,
-                bot_wallet,
                 transaction_index,
                 callback,
             )
@@ -10,11 +10,6 @@ use solana_streamer_sdk::streaming::{
     yellowstone_grpc::{AccountFilter, TransactionFilter},
     YellowstoneGrpc,
 };
-use yellowstone_grpc_proto::geyser::{
-    subscribe_request_filter_accounts_filter::Filter,
-    subscribe_request_filter_accounts_filter_memcmp::Data, SubscribeRequestFilterAccountsFilter,
-    SubscribeRequestFilterAccountsFilterMemcmp,
-};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -45,34 +40,22 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
     let account_required = vec![];
 
     // Listen to transaction data
-    let transaction_filter =
-        TransactionFilter { account_include, account_exclude, account_required };
+    let transaction_filter = TransactionFilter {
+        account_include,
+        account_exclude,
+        account_required,
+        ..Default::default()
+    };
 
     // Pump.fun AMM (PUMP-USDC) Market
     let pump_usdc = Pubkey::from_str("2uF4Xh61rDwxnG9woyxsVQP7zuA6kLFpb3NvnRQeoiSd").unwrap();
     let wsol_deepseekai = Pubkey::from_str("BJAjivuMVANjpRWtrRfcxzGhnMSywBN19Sa4jAzWxXDx").unwrap();
 
     // Listen to account data belonging to owner programs -> account event monitoring
-    let pump_usdc_account_filter = AccountFilter {
-        account: vec![],
-        owner: vec![],
-        filters: vec![SubscribeRequestFilterAccountsFilter {
-            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
-                offset: 32,
-                data: Some(Data::Bytes(pump_usdc.to_bytes().to_vec())),
-            })),
-        }],
-    };
-    let wsol_deepseekai_account_filter = AccountFilter {
-        account: vec![],
-        owner: vec![],
-        filters: vec![SubscribeRequestFilterAccountsFilter {
-            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
-                offset: 32,
-                data: Some(Data::Bytes(wsol_deepseekai.to_bytes().to_vec())),
-            })),
-        }],
-    };
+    let pump_usdc_account_filter =
+        AccountFilter::builder().memcmp(32, pump_usdc.to_bytes()).build()?;
+    let wsol_deepseekai_account_filter =
+        AccountFilter::builder().memcmp(32, wsol_deepseekai.to_bytes()).build()?;
 
     // Event filtering
     let event_type_filter = Some(EventTypeFilter { include: vec![EventType::TokenAccount] });
@@ -0,0 +1,156 @@
+use crate::streaming::common::{StreamClientConfig, StreamResult};
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::{DexEvent, Protocol};
+use crate::streaming::yellowstone_grpc::{AccountFilter, TransactionFilter, YellowstoneGrpc};
+use solana_sdk::pubkey::Pubkey;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+use tokio::sync::watch;
+use yellowstone_grpc_proto::geyser::CommitmentLevel;
+
+/// 检查订阅是否自行结束的间隔
+const FINISHED_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// `YellowstoneGrpc` 的同步阻塞封装 (需要开启 `blocking` feature)，类似 `reqwest::blocking`
+///
+/// 内部持有一个多线程 tokio runtime，订阅和回调都在该 runtime 上执行，调用方不需要自己的
+/// runtime。`subscribe_events_blocking` 阻塞当前线程直到 `BlockingStopHandle::stop` 被调用
+/// 或订阅自行结束；客户端被丢弃时关闭 runtime
+///
+/// 不能在异步上下文中调用 (会阻塞 runtime 的工作线程)，异步代码直接使用 `YellowstoneGrpc`
+///
+/// ```rust,ignore
+/// let grpc = BlockingYellowstoneGrpc::new(endpoint, x_token)?;
+/// let stop = grpc.stop_handle();
+/// ctrlc::set_handler(move || stop.stop())?;
+/// grpc.subscribe_events_blocking(protocols, None, transaction_filter, vec![], None, None, |event| {
+///     println!("{:?}", event.metadata().event_type);
+/// })?;
+/// ```
+pub struct BlockingYellowstoneGrpc {
+    client: YellowstoneGrpc,
+    stop_tx: watch::Sender<bool>,
+    /// 只在 drop 时取出
+    runtime: Option<Runtime>,
+}
+
+/// 停止 `BlockingYellowstoneGrpc` 订阅的句柄，可以跨线程传递，也可以在事件回调中使用
+#[derive(Clone)]
+pub struct BlockingStopHandle {
+    stop_tx: watch::Sender<bool>,
+}
+
+impl BlockingStopHandle {
+    /// 让正在运行的 `subscribe_events_blocking` 停止订阅并返回；没有运行中的订阅时，
+    /// 下一次调用会立即返回
+    pub fn stop(&self) {
+        self.stop_tx.send_replace(true);
+    }
+}
+
+impl BlockingYellowstoneGrpc {
+    /// 创建客户端，使用默认配置
+    pub fn new(endpoint: String, x_token: Option<String>) -> StreamResult<Self> {
+        Self::new_with_config(endpoint, x_token, StreamClientConfig::default())
+    }
+
+    /// 创建客户端，使用自定义配置
+    pub fn new_with_config(
+        endpoint: String,
+        x_token: Option<String>,
+        config: StreamClientConfig,
+    ) -> StreamResult<Self> {
+        Self::new_with_endpoints(vec![(endpoint, x_token)], config)
+    }
+
+    /// 创建客户端，连接失败时按顺序切换到下一个 endpoint，见 `YellowstoneGrpc::new_with_endpoints`
+    pub fn new_with_endpoints(
+        endpoints: Vec<(String, Option<String>)>,
+        config: StreamClientConfig,
+    ) -> StreamResult<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build()?;
+        // 开启指标时客户端创建会启动后台任务，需要在 runtime 上下文中进行
+        let client = {
+            let _guard = runtime.enter();
+            YellowstoneGrpc::new_with_endpoints(endpoints, config)?
+        };
+        Ok(Self { client, stop_tx: watch::Sender::new(false), runtime: Some(runtime) })
+    }
+
+    /// 内部的异步客户端，用于设置 mint 过滤、价格源等同步配置
+    pub fn client(&self) -> &YellowstoneGrpc {
+        &self.client
+    }
+
+    /// 获取停止句柄
+    pub fn stop_handle(&self) -> BlockingStopHandle {
+        BlockingStopHandle { stop_tx: self.stop_tx.clone() }
+    }
+
+    /// 阻塞运行 `YellowstoneGrpc::subscribe_events_immediate`，参数相同
+    ///
+    /// 订阅建立失败时返回错误；之后一直阻塞，直到 `BlockingStopHandle::stop` 被调用或订阅
+    /// 自行结束 (如重连次数用尽)，返回前按 `stop_timeout_ms` 等待已收到的消息处理完成
+    #[allow(clippy::too_many_arguments)]
+    pub fn subscribe_events_blocking<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+    ) -> StreamResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        let mut stop_rx = self.stop_tx.subscribe();
+        let result = self.runtime().block_on(async {
+            self.client
+                .subscribe_events_immediate(
+                    protocols,
+                    bot_wallet,
+                    transaction_filter,
+                    account_filter,
+                    event_type_filter,
+                    commitment,
+                    callback,
+                )
+                .await?;
+            loop {
+                tokio::select! {
+                    _ = stop_rx.wait_for(|stop| *stop) => break,
+                    _ = tokio::time::sleep(FINISHED_POLL_INTERVAL) => {
+                        let handle = self.client.subscription_handle.lock().await;
+                        if handle.as_ref().is_none_or(|handle| handle.is_finished()) {
+                            break;
+                        }
+                    }
+                }
+            }
+            self.client.stop().await;
+            Ok(())
+        });
+        self.stop_tx.send_replace(false);
+        result
+    }
+
+    fn runtime(&self) -> &Runtime {
+        self.runtime.as_ref().expect("runtime is only taken on drop")
+    }
+}
+
+impl Drop for BlockingYellowstoneGrpc {
+    fn drop(&mut self) {
+        let Some(runtime) = self.runtime.take() else {
+            return;
+        };
+        if tokio::runtime::Handle::try_current().is_ok() {
+            // 在异步上下文中不能阻塞等待 runtime 关闭
+            runtime.shutdown_background();
+        } else {
+            runtime.shutdown_timeout(Duration::from_millis(self.client.config.stop_timeout_ms));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use super::last_seen::LastSeenCache;
+
+/// 账户 -> 最近一次看到的 lamports 缓存，用于在账户更新中计算 SOL 余额变化
+///
+/// 每个订阅各自创建，由该订阅的 gRPC 账户更新填充；被淘汰账户的下一次更新不会产生
+/// `LamportsChangedEvent`
+pub type AccountLamportsCache = LastSeenCache<u64>;
@@ -0,0 +1,8 @@
+use super::last_seen::LastSeenCache;
+use solana_sdk::pubkey::Pubkey;
+
+/// 账户 -> 最近一次看到的 owner 缓存，用于在账户更新中检测 owner 变化
+///
+/// 每个订阅各自创建，由该订阅的 gRPC 账户更新填充；被淘汰账户的下一次更新不会产生
+/// `AccountOwnerChangedEvent`
+pub type AccountOwnerCache = LastSeenCache<Pubkey>;
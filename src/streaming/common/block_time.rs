@@ -0,0 +1,64 @@
+use parking_lot::RwLock;
+use std::collections::BTreeMap;
+
+/// 最多缓存的 slot 数量
+const BLOCK_TIME_CACHE_SIZE: usize = 1024;
+
+/// slot -> 出块时间（秒）缓存
+///
+/// 由 gRPC block meta 更新填充，用于给同一 slot 的交易/账户事件补充 `block_time`；
+/// 对应 block meta 尚未到达时查询结果为 None
+pub struct BlockTimeCache {
+    times: RwLock<BTreeMap<u64, i64>>,
+}
+
+impl BlockTimeCache {
+    pub fn new() -> Self {
+        Self { times: RwLock::new(BTreeMap::new()) }
+    }
+
+    /// 记录 slot 的出块时间，超出容量时淘汰最旧的 slot
+    pub fn record(&self, slot: u64, block_time: i64) {
+        let mut times = self.times.write();
+        times.insert(slot, block_time);
+        while times.len() > BLOCK_TIME_CACHE_SIZE {
+            times.pop_first();
+        }
+    }
+
+    /// 查询 slot 的出块时间
+    pub fn get(&self, slot: u64) -> Option<i64> {
+        self.times.read().get(&slot).copied()
+    }
+}
+
+impl Default for BlockTimeCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+lazy_static::lazy_static! {
+    pub static ref BLOCK_TIME_CACHE: BlockTimeCache = BlockTimeCache::new();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_slots_are_evicted_beyond_capacity() {
+        let cache = BlockTimeCache::new();
+        let first = 1_000;
+        for slot in first..=first + BLOCK_TIME_CACHE_SIZE as u64 {
+            cache.record(slot, slot as i64 * 2);
+        }
+        assert_eq!(cache.get(first), None);
+        assert_eq!(cache.get(first + 1), Some(2_002));
+        // 晚到的旧 slot 写入后立即被淘汰，不挤掉较新的 slot
+        cache.record(first - 1, 0);
+        assert_eq!(cache.get(first - 1), None);
+        assert_eq!(cache.get(first + 1), Some(2_002));
+        assert_eq!(cache.get(first + BLOCK_TIME_CACHE_SIZE as u64 + 1), None);
+    }
+}
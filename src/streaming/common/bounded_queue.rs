@@ -0,0 +1,162 @@
+use super::config::OverflowPolicy;
+use super::metrics::MetricsManager;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use tokio::sync::Notify;
+
+/// 单生产者单消费者的有界队列，位于 gRPC 读取与解析/回调之间
+///
+/// 队列满时按 `OverflowPolicy` 等待或丢弃，丢弃的消息计入 `dropped_events_count`
+pub struct BoundedQueue<T> {
+    state: Mutex<QueueState<T>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    not_empty: Notify,
+    not_full: Notify,
+}
+
+struct QueueState<T> {
+    items: VecDeque<T>,
+    closed: bool,
+}
+
+impl<T> BoundedQueue<T> {
+    /// 创建队列，容量至少为 1
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            state: Mutex::new(QueueState { items: VecDeque::with_capacity(capacity), closed: false }),
+            capacity,
+            policy,
+            not_empty: Notify::new(),
+            not_full: Notify::new(),
+        }
+    }
+
+    /// 写入一条消息，`Block` 策略下队列满时等待消费者腾出空间
+    pub async fn push(&self, item: T) {
+        loop {
+            {
+                let mut state = self.state.lock();
+                if state.items.len() < self.capacity {
+                    state.items.push_back(item);
+                    drop(state);
+                    self.not_empty.notify_one();
+                    return;
+                }
+                match self.policy {
+                    OverflowPolicy::Block => {}
+                    OverflowPolicy::DropNewest => {
+                        drop(state);
+                        MetricsManager::global().increment_dropped_events();
+                        return;
+                    }
+                    OverflowPolicy::DropOldest => {
+                        state.items.pop_front();
+                        state.items.push_back(item);
+                        drop(state);
+                        MetricsManager::global().increment_dropped_events();
+                        return;
+                    }
+                }
+            }
+            self.not_full.notified().await;
+        }
+    }
+
+    /// 取出最早的一条消息；队列已关闭且为空时返回 None
+    pub async fn pop(&self) -> Option<T> {
+        loop {
+            {
+                let mut state = self.state.lock();
+                if let Some(item) = state.items.pop_front() {
+                    drop(state);
+                    self.not_full.notify_one();
+                    return Some(item);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            self.not_empty.notified().await;
+        }
+    }
+
+    /// 关闭队列，消费者取完剩余消息后结束
+    pub fn close(&self) {
+        self.state.lock().closed = true;
+        self.not_empty.notify_one();
+    }
+
+    /// 当前排队的消息数
+    pub fn len(&self) -> usize {
+        self.state.lock().items.len()
+    }
+
+    /// 队列是否为空
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    async fn drain(queue: &BoundedQueue<u32>) -> Vec<u32> {
+        queue.close();
+        let mut items = Vec::new();
+        while let Some(item) = queue.pop().await {
+            items.push(item);
+        }
+        items
+    }
+
+    #[tokio::test]
+    async fn drop_newest_keeps_the_queued_items() {
+        let queue = BoundedQueue::new(2, OverflowPolicy::DropNewest);
+        for item in 1..=4 {
+            queue.push(item).await;
+        }
+        assert_eq!(drain(&queue).await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_keeps_the_latest_items() {
+        let queue = BoundedQueue::new(2, OverflowPolicy::DropOldest);
+        for item in 1..=4 {
+            queue.push(item).await;
+        }
+        assert_eq!(drain(&queue).await, vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn block_waits_for_the_consumer() {
+        // 容量为 0 时按 1 处理
+        let queue = Arc::new(BoundedQueue::new(0, OverflowPolicy::Block));
+        queue.push(1).await;
+        let producer = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.push(2).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!producer.is_finished());
+        assert_eq!(queue.len(), 1);
+
+        assert_eq!(queue.pop().await, Some(1));
+        producer.await.unwrap();
+        assert_eq!(drain(&queue).await, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn closed_queue_returns_remaining_items_then_none() {
+        let queue = BoundedQueue::new(4, OverflowPolicy::Block);
+        queue.push(1).await;
+        queue.close();
+        assert_eq!(queue.pop().await, Some(1));
+        assert_eq!(queue.pop().await, None);
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::streaming::common::MetricsManager;
+use crate::streaming::event_parser::DexEvent;
+use futures::FutureExt;
+use solana_sdk::signature::Signature;
+use std::any::Any;
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use tracing::error;
+
+/// 包装用户回调：回调 panic 时记录错误并计数，跳过该事件，流继续运行
+pub(crate) fn catch_callback_panics(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    Arc::new(move |event: DexEvent| {
+        let (signature, slot) = (event.metadata().signature, event.metadata().slot);
+        call_catching_panics(&signature, slot, || callback(event));
+    })
+}
+
+/// 调用回调，panic 不向上传播
+pub(crate) fn call_catching_panics(signature: &Signature, slot: u64, call: impl FnOnce()) {
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(call)) {
+        report_panic(signature, slot, payload.as_ref());
+    }
+}
+
+/// 异步回调版本，poll 过程中的 panic 同样被捕获
+pub(crate) async fn await_catching_panics(
+    signature: Signature,
+    slot: u64,
+    call: impl Future<Output = ()>,
+) {
+    if let Err(payload) = AssertUnwindSafe(call).catch_unwind().await {
+        report_panic(&signature, slot, payload.as_ref());
+    }
+}
+
+fn report_panic(signature: &Signature, slot: u64, payload: &(dyn Any + Send)) {
+    MetricsManager::global().increment_callback_panics();
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload");
+    error!(%signature, slot, panic = message, "callback panicked, event skipped");
+}
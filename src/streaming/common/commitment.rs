@@ -0,0 +1,202 @@
+use crate::streaming::common::{BLOCK_TIME_CACHE, MAX_COMMITMENT_TRACKED_SLOTS};
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::event_parser::core::common_event_parser::{
+    CommitmentUpgradeLevel, CommonEventParser,
+};
+use crate::streaming::event_parser::DexEvent;
+use parking_lot::Mutex;
+use solana_sdk::signature::Signature;
+use std::collections::{BTreeMap, HashSet};
+use yellowstone_grpc_proto::geyser::{CommitmentLevel, SlotStatus};
+
+/// 单个 slot 内已推送过事件的交易
+#[derive(Default)]
+struct TrackedSlot {
+    seen: HashSet<Signature>,
+    transactions: Vec<(Signature, Option<u64>)>,
+    confirmed: bool,
+}
+
+/// 确认级别跟踪器
+///
+/// 以较低的确认级别订阅时，记录已推送过事件的交易所在的 slot，
+/// 收到 slot 状态更新后为这些交易生成 `CommitmentUpgradeEvent`
+pub struct CommitmentTracker {
+    track_confirmed: bool,
+    slots: Mutex<BTreeMap<u64, TrackedSlot>>,
+}
+
+impl CommitmentTracker {
+    /// 订阅级别已是 Finalized 时无需跟踪，返回 None
+    pub fn new(commitment: CommitmentLevel) -> Option<Self> {
+        let track_confirmed = match commitment {
+            CommitmentLevel::Processed => true,
+            CommitmentLevel::Confirmed => false,
+            CommitmentLevel::Finalized => return None,
+        };
+        Some(Self { track_confirmed, slots: Mutex::new(BTreeMap::new()) })
+    }
+
+    /// 记录推送给用户的事件对应的交易
+    pub fn observe(&self, event: &DexEvent) {
+        let metadata = event.metadata();
+        if metadata.signature == Signature::default() {
+            return;
+        }
+        let mut slots = self.slots.lock();
+        let tracked = slots.entry(metadata.slot).or_default();
+        if tracked.seen.insert(metadata.signature) {
+            tracked.transactions.push((metadata.signature, metadata.transaction_index));
+        }
+        // 分叉上的 slot 永远不会 finalized，超出容量时淘汰最旧的
+        while slots.len() > MAX_COMMITMENT_TRACKED_SLOTS {
+            slots.pop_first();
+        }
+    }
+
+    /// 处理 slot 状态更新，返回需要推送的升级事件
+    pub fn on_slot_status(&self, slot: u64, status: SlotStatus) -> Vec<DexEvent> {
+        let mut slots = self.slots.lock();
+        let (level, transactions) = match status {
+            SlotStatus::SlotConfirmed if self.track_confirmed => {
+                let Some(tracked) = slots.get_mut(&slot) else { return Vec::new() };
+                if tracked.confirmed {
+                    return Vec::new();
+                }
+                tracked.confirmed = true;
+                (CommitmentUpgradeLevel::Confirmed, tracked.transactions.clone())
+            }
+            SlotStatus::SlotFinalized => {
+                // 更早且未 finalized 的 slot 不会再被确认，一并移除
+                let newer = slots.split_off(&slot.saturating_add(1));
+                let finalized = std::mem::replace(&mut *slots, newer).remove(&slot);
+                let Some(tracked) = finalized else { return Vec::new() };
+                (CommitmentUpgradeLevel::Finalized, tracked.transactions)
+            }
+            SlotStatus::SlotDead => {
+                slots.remove(&slot);
+                return Vec::new();
+            }
+            _ => return Vec::new(),
+        };
+        drop(slots);
+
+        let block_time = BLOCK_TIME_CACHE.get(slot);
+        let recv_us = get_high_perf_clock();
+        transactions
+            .into_iter()
+            .map(|(signature, transaction_index)| {
+                CommonEventParser::generate_commitment_upgrade_event(
+                    signature,
+                    slot,
+                    transaction_index,
+                    block_time,
+                    level,
+                    recv_us,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+
+    fn trade(slot: u64, signature: u8, transaction_index: u64) -> DexEvent {
+        let mut trade = PumpFunTradeEvent::default();
+        trade.metadata.slot = slot;
+        trade.metadata.signature = Signature::from([signature; 64]);
+        trade.metadata.transaction_index = Some(transaction_index);
+        DexEvent::PumpFunTradeEvent(trade)
+    }
+
+    fn upgrades(events: &[DexEvent]) -> Vec<(Signature, u64, CommitmentUpgradeLevel)> {
+        events
+            .iter()
+            .map(|event| {
+                let DexEvent::CommitmentUpgradeEvent(upgrade) = event else {
+                    panic!("unexpected event {event:?}")
+                };
+                (upgrade.metadata.signature, upgrade.metadata.slot, upgrade.level)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finalized_subscriptions_are_not_tracked() {
+        assert!(CommitmentTracker::new(CommitmentLevel::Finalized).is_none());
+    }
+
+    #[test]
+    fn processed_transactions_upgrade_once_per_level() {
+        let tracker = CommitmentTracker::new(CommitmentLevel::Processed).unwrap();
+        // 同一交易的多个事件只升级一次
+        tracker.observe(&trade(10, 1, 0));
+        tracker.observe(&trade(10, 1, 0));
+        tracker.observe(&trade(10, 2, 5));
+
+        let confirmed = tracker.on_slot_status(10, SlotStatus::SlotConfirmed);
+        assert_eq!(
+            upgrades(&confirmed),
+            vec![
+                (Signature::from([1; 64]), 10, CommitmentUpgradeLevel::Confirmed),
+                (Signature::from([2; 64]), 10, CommitmentUpgradeLevel::Confirmed),
+            ]
+        );
+        assert!(tracker.on_slot_status(10, SlotStatus::SlotConfirmed).is_empty());
+
+        let finalized = tracker.on_slot_status(10, SlotStatus::SlotFinalized);
+        assert_eq!(finalized.len(), 2);
+        assert!(tracker.on_slot_status(10, SlotStatus::SlotFinalized).is_empty());
+        // 没有跟踪的 slot 和其他状态不产生事件
+        assert!(tracker.on_slot_status(11, SlotStatus::SlotConfirmed).is_empty());
+        assert!(tracker.on_slot_status(10, SlotStatus::SlotProcessed).is_empty());
+    }
+
+    #[test]
+    fn confirmed_subscriptions_only_upgrade_to_finalized() {
+        let tracker = CommitmentTracker::new(CommitmentLevel::Confirmed).unwrap();
+        tracker.observe(&trade(10, 1, 0));
+        assert!(tracker.on_slot_status(10, SlotStatus::SlotConfirmed).is_empty());
+        assert_eq!(
+            upgrades(&tracker.on_slot_status(10, SlotStatus::SlotFinalized)),
+            vec![(Signature::from([1; 64]), 10, CommitmentUpgradeLevel::Finalized)]
+        );
+    }
+
+    #[test]
+    fn finalizing_a_slot_drops_older_and_dead_slots() {
+        let tracker = CommitmentTracker::new(CommitmentLevel::Processed).unwrap();
+        tracker.observe(&trade(10, 1, 0));
+        tracker.observe(&trade(11, 2, 0));
+        tracker.observe(&trade(12, 3, 0));
+        tracker.observe(&trade(13, 4, 0));
+
+        // 10 在分叉上，11 finalized 后不会再被确认
+        assert_eq!(tracker.on_slot_status(11, SlotStatus::SlotFinalized).len(), 1);
+        assert!(tracker.on_slot_status(10, SlotStatus::SlotFinalized).is_empty());
+        assert!(tracker.on_slot_status(12, SlotStatus::SlotDead).is_empty());
+        assert!(tracker.on_slot_status(12, SlotStatus::SlotConfirmed).is_empty());
+        assert_eq!(tracker.on_slot_status(13, SlotStatus::SlotConfirmed).len(), 1);
+    }
+
+    #[test]
+    fn oldest_slots_are_evicted_beyond_capacity() {
+        let tracker = CommitmentTracker::new(CommitmentLevel::Processed).unwrap();
+        let first = 1_000;
+        for slot in first..=first + MAX_COMMITMENT_TRACKED_SLOTS as u64 {
+            tracker.observe(&trade(slot, 1, 0));
+        }
+        assert!(tracker.on_slot_status(first, SlotStatus::SlotConfirmed).is_empty());
+        assert_eq!(tracker.on_slot_status(first + 1, SlotStatus::SlotConfirmed).len(), 1);
+    }
+
+    #[test]
+    fn events_without_signature_are_ignored() {
+        let tracker = CommitmentTracker::new(CommitmentLevel::Processed).unwrap();
+        tracker.observe(&trade(10, 0, 0));
+        assert!(tracker.on_slot_status(10, SlotStatus::SlotConfirmed).is_empty());
+    }
+}
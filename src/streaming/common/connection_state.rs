@@ -0,0 +1,102 @@
+use parking_lot::{Mutex, RwLock};
+use std::sync::Arc;
+
+/// gRPC 订阅的连接状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// 正在建立首次连接
+    Connecting,
+    /// 订阅已建立 (首次连接或重连成功)
+    Connected,
+    /// 连接断开后正在进行第 `attempt` 次重连 (从 1 开始)
+    Reconnecting { attempt: u32 },
+    /// 连续 `failures` 次认证失败 (x_token 无效、`Unauthenticated` 或 `PermissionDenied`)，
+    /// 订阅已终止且不再重连，需要更换 x_token 后重新订阅
+    AuthFailed { failures: u32 },
+    /// 没有订阅：尚未订阅、已调用 `stop`、连接失败或放弃重连
+    #[default]
+    Stopped,
+}
+
+/// 连接状态回调
+pub type StateChangeCallback = Arc<dyn Fn(ConnectionState) + Send + Sync>;
+
+/// 记录当前连接状态，状态变化时调用回调，克隆之间共享
+#[derive(Clone, Default)]
+pub struct ConnectionStateNotifier {
+    state: Arc<Mutex<ConnectionState>>,
+    callback: Arc<RwLock<Option<StateChangeCallback>>>,
+}
+
+impl ConnectionStateNotifier {
+    /// 当前连接状态
+    pub fn state(&self) -> ConnectionState {
+        *self.state.lock()
+    }
+
+    /// 设置回调，替换之前的回调
+    pub fn set_callback(&self, callback: StateChangeCallback) {
+        *self.callback.write() = Some(callback);
+    }
+
+    /// 移除回调
+    pub fn clear_callback(&self) {
+        *self.callback.write() = None;
+    }
+
+    /// 切换状态，与当前状态相同时不调用回调
+    ///
+    /// 回调在释放状态锁之后同步执行，可以在回调中读取 `state()`
+    pub fn transition(&self, state: ConnectionState) {
+        {
+            let mut current = self.state.lock();
+            if *current == state {
+                return;
+            }
+            *current = state;
+        }
+        let callback = self.callback.read().clone();
+        if let Some(callback) = callback {
+            callback(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callback_runs_only_on_state_changes() {
+        let notifier = ConnectionStateNotifier::default();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        notifier.set_callback({
+            let (seen, notifier) = (seen.clone(), notifier.clone());
+            // 回调中可以读取到新状态
+            Arc::new(move |state| {
+                assert_eq!(notifier.state(), state);
+                seen.lock().push(state);
+            })
+        });
+
+        assert_eq!(notifier.state(), ConnectionState::Stopped);
+        notifier.transition(ConnectionState::Connecting);
+        notifier.transition(ConnectionState::Connected);
+        notifier.transition(ConnectionState::Connected);
+        notifier.transition(ConnectionState::Reconnecting { attempt: 1 });
+        notifier.transition(ConnectionState::Reconnecting { attempt: 2 });
+        notifier.clear_callback();
+        notifier.transition(ConnectionState::Stopped);
+
+        assert_eq!(
+            *seen.lock(),
+            vec![
+                ConnectionState::Connecting,
+                ConnectionState::Connected,
+                ConnectionState::Reconnecting { attempt: 1 },
+                ConnectionState::Reconnecting { attempt: 2 },
+            ]
+        );
+        assert_eq!(notifier.state(), ConnectionState::Stopped);
+    }
+}
@@ -3,13 +3,49 @@
 // 默认配置常量
 pub const DEFAULT_CONNECT_TIMEOUT: u64 = 10;
 pub const DEFAULT_REQUEST_TIMEOUT: u64 = 60;
+pub const DEFAULT_SUBSCRIBE_TIMEOUT: u64 = 10;
 pub const DEFAULT_CHANNEL_SIZE: usize = 1000;
 pub const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 1024 * 1024 * 10;
 
+// 断线重连相关常量
+pub const DEFAULT_RECONNECT_INITIAL_DELAY_MS: u64 = 500;
+pub const DEFAULT_RECONNECT_MAX_DELAY_MS: u64 = 30_000;
+pub const DEFAULT_RECONNECT_MULTIPLIER: f64 = 2.0;
+pub const DEFAULT_RECONNECT_MAX_ATTEMPTS: u32 = 10;
+pub const DEFAULT_RECONNECT_MAX_AUTH_FAILURES: u32 = 3;
+
+// 心跳相关常量
+pub const DEFAULT_HEARTBEAT_INTERVAL_MS: u64 = 30_000;
+pub const DEFAULT_HEARTBEAT_TIMEOUT_MS: u64 = 10_000;
+
+// 优雅停止时等待已接收消息处理完成的最长时间
+pub const DEFAULT_STOP_TIMEOUT_MS: u64 = 5_000;
+
+// 并行解析：默认 worker 数量和乱序窗口
+pub const DEFAULT_PARSE_WORKERS: usize = 1;
+pub const DEFAULT_PARSE_REORDER_WINDOW: usize = 256;
+
+// 事件去重窗口大小
+pub const DEFAULT_DEDUP_WINDOW_SIZE: usize = 10_000;
+
+// 事件排序：事件等待更早事件到达的默认时长，约一个 slot
+pub const DEFAULT_ORDERING_WINDOW_MS: u64 = 400;
+
+// 事件限速：持续限速超过该时长视为超限
+pub const DEFAULT_THROTTLE_MAX_PAUSE_MS: u64 = 10_000;
+
+// 确认级别跟踪最多保留的 slot 数量
+pub const MAX_COMMITMENT_TRACKED_SLOTS: usize = 512;
+
+// slot 延迟：Solana 目标出块间隔和默认告警阈值
+pub const SLOT_DURATION_MS: i64 = 400;
+pub const DEFAULT_SLOT_LAG_THRESHOLD: u64 = 20;
+
 // 性能监控相关常量
 pub const DEFAULT_METRICS_WINDOW_SECONDS: u64 = 5;
 pub const DEFAULT_METRICS_PRINT_INTERVAL_SECONDS: u64 = 10;
 pub const SLOW_PROCESSING_THRESHOLD_US: f64 = 3000.0;
+pub const DEFAULT_SLOW_PARSE_THRESHOLD_US: u64 = 1_000;
 
 // gRPC 延迟监控
 // Solana 不存储毫秒，所以我们用500ms来校准以获得更好的近似值
@@ -0,0 +1,214 @@
+use crate::streaming::event_parser::DexEvent;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// `CsvSink` 的写入选项
+#[derive(Debug, Clone)]
+pub struct CsvSinkOptions {
+    /// 只写入交易事件 (`DexEvent::as_trade` 不为 None)，默认 false
+    pub trades_only: bool,
+    /// 缓存的行数达到该值时写入文件，默认 1000
+    pub flush_rows: usize,
+    /// 距上次写入超过该时间时写入文件，在写入新事件时检查，默认 1s
+    pub flush_interval: Duration,
+}
+
+impl Default for CsvSinkOptions {
+    fn default() -> Self {
+        Self { trades_only: false, flush_rows: 1000, flush_interval: Duration::from_secs(1) }
+    }
+}
+
+/// 事件的扁平化行，`CsvSink` 的列按字段顺序排列
+///
+/// 所有事件都有元数据列；交易事件按 `NormalizedTrade` 填充 `pool` 到 `quote_amount` 列，
+/// 其他事件这些列为空。地址和签名为 base58 字符串
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EventRow {
+    pub slot: u64,
+    pub transaction_index: Option<u64>,
+    pub block_time: Option<i64>,
+    pub recv_us: i64,
+    pub received_us: i64,
+    pub signature: String,
+    pub protocol: String,
+    pub event_type: String,
+    pub program_id: String,
+    pub succeeded: bool,
+    pub outer_index: i64,
+    pub inner_index: Option<i64>,
+    pub pool: Option<String>,
+    pub base_mint: Option<String>,
+    pub quote_mint: Option<String>,
+    pub is_buy: Option<bool>,
+    pub base_amount: Option<u64>,
+    pub quote_amount: Option<u64>,
+    pub priority_fee_lamports: Option<u64>,
+    pub compute_units_consumed: Option<u64>,
+    pub jito_tip_lamports: Option<u64>,
+}
+
+impl From<&DexEvent> for EventRow {
+    fn from(event: &DexEvent) -> Self {
+        let metadata = event.metadata();
+        let mut row = Self {
+            slot: metadata.slot,
+            transaction_index: metadata.transaction_index,
+            block_time: metadata.block_time,
+            recv_us: metadata.recv_us,
+            received_us: metadata.received_us,
+            signature: metadata.signature.to_string(),
+            protocol: format!("{:?}", metadata.protocol),
+            event_type: metadata.event_type.to_string(),
+            program_id: metadata.program_id.to_string(),
+            succeeded: metadata.succeeded,
+            outer_index: metadata.outer_index,
+            inner_index: metadata.inner_index,
+            priority_fee_lamports: metadata.priority_fee_lamports,
+            compute_units_consumed: metadata.compute_units_consumed,
+            jito_tip_lamports: metadata.jito_tip_lamports,
+            ..Default::default()
+        };
+        if let Some(trade) = event.as_trade() {
+            row.pool = Some(trade.pool().to_string());
+            row.base_mint = Some(trade.base_mint().to_string());
+            row.quote_mint = Some(trade.quote_mint().to_string());
+            row.is_buy = Some(trade.is_buy());
+            row.base_amount = Some(trade.base_amount());
+            row.quote_amount = Some(trade.quote_amount());
+        }
+        row
+    }
+}
+
+struct CsvSinkState {
+    writer: csv::Writer<File>,
+    pending_rows: usize,
+    last_flush: Instant,
+}
+
+/// 把事件按 `EventRow` 的扁平格式追加写入 CSV 文件 (需要开启 `csv` feature)
+///
+/// 行先缓存在内存中，达到 `flush_rows` 行或距上次写入超过 `flush_interval` 时写入文件。
+/// 文件已存在且不为空时直接追加，不重复写表头。sink 被丢弃时写入剩余的行
+///
+/// ```rust,ignore
+/// let sink = Arc::new(CsvSink::new("events.csv", CsvSinkOptions::default())?);
+/// let writer = sink.clone();
+/// grpc.subscribe_events_immediate(protocols, None, filter, vec![], None, None, move |event| {
+///     if let Err(e) = writer.write(&event) {
+///         log::error!("failed to write event: {e}");
+///     }
+/// })
+/// .await?;
+/// ```
+pub struct CsvSink {
+    options: CsvSinkOptions,
+    state: Mutex<CsvSinkState>,
+}
+
+impl CsvSink {
+    /// 打开 (或创建) CSV 文件
+    pub fn new(path: impl AsRef<Path>, options: CsvSinkOptions) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let has_headers = file.metadata()?.len() == 0;
+        let writer = csv::WriterBuilder::new().has_headers(has_headers).from_writer(file);
+        Ok(Self {
+            options,
+            state: Mutex::new(CsvSinkState { writer, pending_rows: 0, last_flush: Instant::now() }),
+        })
+    }
+
+    /// 写入一个事件，`trades_only` 时非交易事件直接忽略
+    pub fn write(&self, event: &DexEvent) -> std::io::Result<()> {
+        if self.options.trades_only && event.as_trade().is_none() {
+            return Ok(());
+        }
+        let mut state = self.state.lock();
+        state.writer.serialize(EventRow::from(event)).map_err(std::io::Error::other)?;
+        state.pending_rows += 1;
+        if state.pending_rows >= self.options.flush_rows
+            || state.last_flush.elapsed() >= self.options.flush_interval
+        {
+            Self::flush_state(&mut state)?;
+        }
+        Ok(())
+    }
+
+    /// 立即把缓存的行写入文件
+    pub fn flush(&self) -> std::io::Result<()> {
+        Self::flush_state(&mut self.state.lock())
+    }
+
+    fn flush_state(state: &mut CsvSinkState) -> std::io::Result<()> {
+        state.writer.flush()?;
+        state.pending_rows = 0;
+        state.last_flush = Instant::now();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::block::block_meta_event::BlockMetaEvent;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{name}-{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn trade(slot: u64) -> DexEvent {
+        let mut trade = PumpFunTradeEvent { is_buy: true, sol_amount: 1_000, ..Default::default() };
+        trade.metadata.slot = slot;
+        DexEvent::PumpFunTradeEvent(trade)
+    }
+
+    fn lines(path: &Path) -> Vec<String> {
+        std::fs::read_to_string(path).unwrap().lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn rows_are_buffered_until_flush_rows() {
+        let path = temp_path("csv-sink-flush-rows");
+        let options = CsvSinkOptions {
+            flush_rows: 2,
+            flush_interval: Duration::from_secs(3600),
+            ..Default::default()
+        };
+        let sink = CsvSink::new(&path, options).unwrap();
+        sink.write(&trade(1)).unwrap();
+        assert!(lines(&path).is_empty());
+        sink.write(&trade(2)).unwrap();
+        let written = lines(&path);
+        assert_eq!(written.len(), 3);
+        assert!(written[0].starts_with("slot,transaction_index,"));
+        assert!(written[1].starts_with("1,") && written[2].starts_with("2,"));
+        drop(sink);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn appending_to_an_existing_file_skips_the_header() {
+        let path = temp_path("csv-sink-append");
+        let options = CsvSinkOptions { trades_only: true, ..Default::default() };
+        for slot in [1, 2] {
+            let sink = CsvSink::new(&path, options.clone()).unwrap();
+            sink.write(&trade(slot)).unwrap();
+            // trades_only 时忽略非交易事件
+            sink.write(&DexEvent::BlockMetaEvent(BlockMetaEvent::default())).unwrap();
+            // 丢弃时写入剩余的行
+        }
+        let written = lines(&path);
+        assert_eq!(written.len(), 3);
+        assert_eq!(written.iter().filter(|line| line.starts_with("slot,")).count(), 1);
+        assert!(written[1].starts_with("1,") && written[2].starts_with("2,"));
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::streaming::event_parser::common::types::EventType;
+use crate::streaming::event_parser::DexEvent;
+use parking_lot::Mutex;
+use solana_sdk::signature::Signature;
+use std::collections::{HashSet, VecDeque};
+
+/// 去重键: (signature, outer_index, inner_index, event_type)
+type DedupKey = (Signature, i64, Option<i64>, EventType);
+
+/// 滑动窗口事件去重器
+///
+/// 多个 TransactionFilter 匹配同一笔交易时，Yellowstone 可能重复推送，
+/// 这里按 (signature, 指令下标) 记住最近 `window_size` 个事件，超出后淘汰最旧的键
+pub struct EventDeduplicator {
+    window_size: usize,
+    inner: Mutex<DedupWindow>,
+}
+
+#[derive(Default)]
+struct DedupWindow {
+    seen: HashSet<DedupKey>,
+    order: VecDeque<DedupKey>,
+}
+
+impl EventDeduplicator {
+    pub fn new(window_size: usize) -> Self {
+        Self { window_size: window_size.max(1), inner: Mutex::new(DedupWindow::default()) }
+    }
+
+    /// 检查事件是否已出现过，未出现则记录
+    ///
+    /// 没有签名的事件（账户、区块元数据等）不参与去重
+    pub fn is_duplicate(&self, event: &DexEvent) -> bool {
+        let metadata = event.metadata();
+        if metadata.signature == Signature::default() {
+            return false;
+        }
+        let key = (
+            metadata.signature,
+            metadata.outer_index,
+            metadata.inner_index,
+            metadata.event_type.clone(),
+        );
+
+        let mut window = self.inner.lock();
+        if window.seen.contains(&key) {
+            return true;
+        }
+        if window.order.len() >= self.window_size {
+            if let Some(oldest) = window.order.pop_front() {
+                window.seen.remove(&oldest);
+            }
+        }
+        window.seen.insert(key.clone());
+        window.order.push_back(key);
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+
+    fn trade(signature: u8, outer_index: i64, inner_index: Option<i64>) -> DexEvent {
+        let mut trade = PumpFunTradeEvent::default();
+        trade.metadata.signature = Signature::from([signature; 64]);
+        trade.metadata.outer_index = outer_index;
+        trade.metadata.inner_index = inner_index;
+        trade.metadata.event_type = EventType::PumpFunBuy;
+        DexEvent::PumpFunTradeEvent(trade)
+    }
+
+    #[test]
+    fn repeated_instruction_is_duplicate() {
+        let dedup = EventDeduplicator::new(16);
+        assert!(!dedup.is_duplicate(&trade(1, 0, None)));
+        assert!(dedup.is_duplicate(&trade(1, 0, None)));
+        // 同一交易的其他指令不是重复
+        assert!(!dedup.is_duplicate(&trade(1, 1, None)));
+        assert!(!dedup.is_duplicate(&trade(1, 0, Some(2))));
+        assert!(!dedup.is_duplicate(&trade(2, 0, None)));
+    }
+
+    #[test]
+    fn events_without_signature_are_never_duplicates() {
+        let dedup = EventDeduplicator::new(16);
+        assert!(!dedup.is_duplicate(&trade(0, 0, None)));
+        assert!(!dedup.is_duplicate(&trade(0, 0, None)));
+    }
+
+    #[test]
+    fn oldest_key_is_evicted_when_window_is_full() {
+        let dedup = EventDeduplicator::new(2);
+        assert!(!dedup.is_duplicate(&trade(1, 0, None)));
+        assert!(!dedup.is_duplicate(&trade(2, 0, None)));
+        assert!(!dedup.is_duplicate(&trade(3, 0, None)));
+        // 1 已被淘汰，重新记录后淘汰 2
+        assert!(!dedup.is_duplicate(&trade(1, 0, None)));
+        assert!(dedup.is_duplicate(&trade(3, 0, None)));
+        assert!(!dedup.is_duplicate(&trade(2, 0, None)));
+    }
+
+    #[test]
+    fn zero_window_keeps_the_latest_key() {
+        let dedup = EventDeduplicator::new(0);
+        assert!(!dedup.is_duplicate(&trade(1, 0, None)));
+        assert!(dedup.is_duplicate(&trade(1, 0, None)));
+        assert!(!dedup.is_duplicate(&trade(2, 0, None)));
+        assert!(!dedup.is_duplicate(&trade(1, 0, None)));
+    }
+}
@@ -0,0 +1,181 @@
+use super::metrics::MetricsManager;
+use parking_lot::Mutex;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+
+/// 仍在使用的客户端的 endpoint 统计，`get_endpoint_metrics` 从这里汇总
+static TRACKERS: Mutex<Vec<Weak<EndpointHealthTracker>>> = Mutex::new(Vec::new());
+
+struct EndpointState {
+    endpoint: String,
+    connections: u64,
+    failures: u64,
+    /// 之前作为当前 endpoint 的累计时长
+    active_time: Duration,
+    /// 成为当前 endpoint 的时间，不是当前 endpoint 时为 None
+    active_since: Option<Instant>,
+}
+
+/// 单个 gRPC endpoint 的连接统计
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EndpointHealth {
+    /// endpoint 的脱敏标识，只保留 scheme、主机和端口，不包含路径和查询参数中可能带有的 token
+    pub label: String,
+    /// 建立成功的连接数
+    pub connections: u64,
+    /// 连接失败的次数
+    pub failures: u64,
+    /// 作为当前 endpoint 的累计时长，从连接成功开始，到同一订阅切换到其他 endpoint 或该
+    /// endpoint 连接失败为止；多个订阅使用同一 endpoint 时为各订阅时长之和
+    pub active_time: Duration,
+    /// 是否为某个订阅的当前 endpoint
+    pub active: bool,
+}
+
+/// 一个订阅连接 (`SubscriptionManager` 及其克隆) 的各 endpoint 统计
+///
+/// 当前 endpoint 只在同一订阅内切换，不同客户端和多确认级别订阅的各条连接互不影响
+pub(crate) struct EndpointHealthTracker {
+    endpoints: Mutex<Vec<EndpointState>>,
+}
+
+impl EndpointHealthTracker {
+    /// 创建统计并登记到全局汇总，`endpoints` 按下标与 `record` 对应
+    pub(crate) fn new<'a>(endpoints: impl IntoIterator<Item = &'a str>) -> Arc<Self> {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|endpoint| EndpointState {
+                endpoint: endpoint.to_string(),
+                connections: 0,
+                failures: 0,
+                active_time: Duration::ZERO,
+                active_since: None,
+            })
+            .collect();
+        let tracker = Arc::new(Self { endpoints: Mutex::new(endpoints) });
+        let mut trackers = TRACKERS.lock();
+        trackers.retain(|tracker| tracker.strong_count() > 0);
+        trackers.push(Arc::downgrade(&tracker));
+        tracker
+    }
+
+    /// 记录第 `index` 个 endpoint 的一次连接结果
+    ///
+    /// 连接成功的 endpoint 成为当前 endpoint，同一订阅的其他 endpoint 停止累计活跃时长
+    pub(crate) fn record(&self, index: usize, success: bool) {
+        let now = Instant::now();
+        let mut endpoints = self.endpoints.lock();
+        if index >= endpoints.len() {
+            return;
+        }
+        if success {
+            endpoints[index].connections += 1;
+            for (i, entry) in endpoints.iter_mut().enumerate() {
+                if i == index {
+                    entry.active_since.get_or_insert(now);
+                } else if let Some(since) = entry.active_since.take() {
+                    entry.active_time += now - since;
+                }
+            }
+        } else {
+            let entry = &mut endpoints[index];
+            entry.failures += 1;
+            if let Some(since) = entry.active_since.take() {
+                entry.active_time += now - since;
+            }
+        }
+    }
+
+    /// 各 endpoint 的统计，按配置顺序排列
+    pub(crate) fn snapshot(&self) -> Vec<EndpointHealth> {
+        let now = Instant::now();
+        self.endpoints
+            .lock()
+            .iter()
+            .map(|entry| EndpointHealth {
+                label: endpoint_label(&entry.endpoint),
+                connections: entry.connections,
+                failures: entry.failures,
+                active_time: entry.active_time
+                    + entry.active_since.map_or(Duration::ZERO, |since| now - since),
+                active: entry.active_since.is_some(),
+            })
+            .collect()
+    }
+}
+
+impl MetricsManager {
+    /// 获取每个 endpoint 的连接统计，汇总进程内所有仍存在的客户端
+    ///
+    /// 同一 endpoint 的连接次数和活跃时长相加，任一订阅正在使用时 `active` 为 true；
+    /// 与 `enable_metrics` 无关，始终记录。单个客户端的统计见 `YellowstoneGrpc::endpoint_health`
+    pub fn get_endpoint_metrics(&self) -> Vec<EndpointHealth> {
+        let trackers: Vec<Arc<EndpointHealthTracker>> = {
+            let mut trackers = TRACKERS.lock();
+            trackers.retain(|tracker| tracker.strong_count() > 0);
+            trackers.iter().filter_map(Weak::upgrade).collect()
+        };
+        let mut merged: Vec<EndpointHealth> = Vec::new();
+        for health in trackers.iter().flat_map(|tracker| tracker.snapshot()) {
+            match merged.iter_mut().find(|entry| entry.label == health.label) {
+                Some(entry) => {
+                    entry.connections += health.connections;
+                    entry.failures += health.failures;
+                    entry.active_time += health.active_time;
+                    entry.active |= health.active;
+                }
+                None => merged.push(health),
+            }
+        }
+        merged
+    }
+}
+
+/// endpoint 的脱敏标识，去掉路径和查询参数
+fn endpoint_label(endpoint: &str) -> String {
+    let authority_start = endpoint.find("://").map_or(0, |i| i + 3);
+    let end = endpoint[authority_start..]
+        .find(['/', '?'])
+        .map_or(endpoint.len(), |i| authority_start + i);
+    endpoint[..end].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trackers_switch_active_endpoint_independently() {
+        let first =
+            EndpointHealthTracker::new(["https://a.example:443/token", "https://b.example"]);
+        let second = EndpointHealthTracker::new(["https://b.example", "https://a.example:443"]);
+        first.record(0, true);
+        second.record(0, true);
+        second.record(1, false);
+
+        let labels = |tracker: &EndpointHealthTracker| -> Vec<(String, bool)> {
+            tracker.snapshot().into_iter().map(|health| (health.label, health.active)).collect()
+        };
+        assert_eq!(
+            labels(&first),
+            [("https://a.example:443".to_string(), true), ("https://b.example".to_string(), false)]
+        );
+        assert_eq!(
+            labels(&second),
+            [("https://b.example".to_string(), true), ("https://a.example:443".to_string(), false)]
+        );
+
+        let merged = MetricsManager::global().get_endpoint_metrics();
+        let a = merged.iter().find(|health| health.label == "https://a.example:443").unwrap();
+        let b = merged.iter().find(|health| health.label == "https://b.example").unwrap();
+        assert!(a.active && b.active);
+        assert_eq!((a.connections, a.failures), (1, 1));
+        assert_eq!((b.connections, b.failures), (1, 0));
+
+        // 连接失败的 endpoint 停止累计活跃时长
+        first.record(0, false);
+        let a = first.snapshot().remove(0);
+        assert!(!a.active);
+        assert_eq!(a.active_time, first.snapshot()[0].active_time);
+    }
+}
@@ -0,0 +1,120 @@
+use futures::channel::mpsc;
+use tonic::{metadata::errors::InvalidMetadataValue, transport, Code, Status};
+use yellowstone_grpc_client::{GeyserGrpcBuilderError, GeyserGrpcClientError};
+
+/// 流式客户端公共 API 的错误类型
+///
+/// 调用方可以按变体区分可重试的传输错误与需要中止的认证/配置错误
+#[derive(Debug, thiserror::Error)]
+pub enum StreamError {
+    /// 建立连接失败（网络不可达、超时等）
+    #[error("connection error: {0}")]
+    Connection(#[source] transport::Error),
+    /// TLS 配置失败
+    #[error("TLS error: {0}")]
+    Tls(#[source] transport::Error),
+    /// endpoint 地址无效
+    #[error("invalid endpoint: {0}")]
+    InvalidEndpoint(#[source] transport::Error),
+    /// x_token 无法作为 gRPC 元数据发送
+    #[error("invalid x_token: {0}")]
+    InvalidToken(#[from] InvalidMetadataValue),
+    /// 服务端返回的 gRPC 状态错误
+    #[error("subscribe failed: {0}")]
+    Subscribe(#[from] Status),
+    /// 数据解码失败
+    #[error("decode error: {0}")]
+    Decode(String),
+    /// 文件读写失败（录制与回放）
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// 订阅请求通道已关闭
+    #[error("subscription channel closed")]
+    ChannelClosed,
+    /// 已存在活跃订阅
+    #[error("already subscribed, use update_subscription() to modify filters")]
+    AlreadySubscribed,
+    /// 没有可更新的活跃订阅
+    #[error("no active subscription")]
+    NoActiveSubscription,
+    /// 客户端配置错误
+    #[error("invalid configuration: {0}")]
+    Config(String),
+    /// 操作超时（打开订阅流、`ping` 等）
+    #[error("{0} timed out")]
+    Timeout(&'static str),
+}
+
+/// 流式客户端公共 API 的返回类型
+pub type StreamResult<T> = Result<T, StreamError>;
+
+impl StreamError {
+    /// 是否为可重试的瞬时错误（传输层错误、服务端暂时不可用等）
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StreamError::Connection(_) | StreamError::ChannelClosed | StreamError::Timeout(_) => {
+                true
+            }
+            StreamError::Subscribe(status) => matches!(
+                status.code(),
+                Code::Unavailable
+                    | Code::DeadlineExceeded
+                    | Code::ResourceExhausted
+                    | Code::Aborted
+                    | Code::Internal
+                    | Code::Unknown
+            ),
+            _ => false,
+        }
+    }
+
+    /// 更新超过 `max_decoding_message_size` 时返回其大小（字节）
+    ///
+    /// tonic 在解码前按长度拒绝该消息，之后流即结束
+    pub fn oversized_message_len(&self) -> Option<usize> {
+        let StreamError::Subscribe(status) = self else {
+            return None;
+        };
+        if status.code() != Code::OutOfRange {
+            return None;
+        }
+        let found =
+            status.message().strip_prefix("Error, decoded message length too large: found ")?;
+        found.split_once(" bytes")?.0.parse().ok()
+    }
+
+    /// 是否为认证错误，重试无意义
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            StreamError::InvalidToken(_) => true,
+            StreamError::Subscribe(status) => {
+                matches!(status.code(), Code::Unauthenticated | Code::PermissionDenied)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<GeyserGrpcBuilderError> for StreamError {
+    fn from(error: GeyserGrpcBuilderError) -> Self {
+        match error {
+            GeyserGrpcBuilderError::MetadataValueError(e) => StreamError::InvalidToken(e),
+            GeyserGrpcBuilderError::TonicError(e) => StreamError::Connection(e),
+        }
+    }
+}
+
+impl From<GeyserGrpcClientError> for StreamError {
+    fn from(error: GeyserGrpcClientError) -> Self {
+        match error {
+            GeyserGrpcClientError::TonicStatus(status) => StreamError::Subscribe(status),
+            GeyserGrpcClientError::SubscribeSendError(_) => StreamError::ChannelClosed,
+        }
+    }
+}
+
+impl From<mpsc::SendError> for StreamError {
+    fn from(_: mpsc::SendError) -> Self {
+        StreamError::ChannelClosed
+    }
+}
@@ -0,0 +1,222 @@
+use crate::streaming::event_parser::DexEvent;
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// 排序键: (slot, transaction_index, outer_index, inner_index, 到达序号)
+///
+/// 没有交易位置的事件 (账户、区块元数据) 排在同一 slot 的交易之后；外层指令排在其 inner
+/// instructions 之前；键相同时保持到达顺序
+type OrderKey = (u64, u64, i64, i64, u64);
+
+/// 在有限时间窗口内按 `(slot, transaction_index, instruction index)` 重排事件
+///
+/// 每个事件最多等待 `window` 让更早的事件到达，到期时连同所有排在它之前的事件一起按序释放。
+/// 排在已释放事件之前的迟到事件无法再排序，立即释放并记录警告
+pub struct EventReorderBuffer {
+    window: Duration,
+    pending: BTreeMap<OrderKey, DexEvent>,
+    /// 按到达顺序排列的 (到达时间, 键)，已释放的键在到达队首时跳过
+    arrivals: VecDeque<(Instant, OrderKey)>,
+    last_released: Option<OrderKey>,
+    next_seq: u64,
+}
+
+impl EventReorderBuffer {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: BTreeMap::new(),
+            arrivals: VecDeque::new(),
+            last_released: None,
+            next_seq: 0,
+        }
+    }
+
+    /// 缓存事件，迟到的事件直接返回
+    pub fn push(&mut self, event: DexEvent, now: Instant) -> Option<DexEvent> {
+        let metadata = event.metadata();
+        let key = (
+            metadata.slot,
+            metadata.transaction_index.unwrap_or(u64::MAX),
+            metadata.outer_index,
+            metadata.inner_index.unwrap_or(-1),
+            self.next_seq,
+        );
+        self.next_seq += 1;
+        if self.last_released.is_some_and(|last| key < last) {
+            log::warn!(
+                "{:?} at slot {} arrived after the reorder window, delivering out of order",
+                metadata.event_type,
+                metadata.slot
+            );
+            return Some(event);
+        }
+        self.pending.insert(key, event);
+        self.arrivals.push_back((now, key));
+        None
+    }
+
+    /// 按序取出到期的事件，以及排在它们之前的所有事件
+    pub fn pop_ready(&mut self, now: Instant) -> Vec<DexEvent> {
+        let mut ready = Vec::new();
+        while let Some(&(arrived, key)) = self.arrivals.front() {
+            if !self.pending.contains_key(&key) {
+                self.arrivals.pop_front();
+                continue;
+            }
+            if now.duration_since(arrived) < self.window {
+                break;
+            }
+            let later = self.pending.split_off(&key);
+            ready.extend(std::mem::replace(&mut self.pending, later).into_values());
+            if let Some((key, event)) = self.pending.pop_first() {
+                ready.push(event);
+                self.last_released = Some(key);
+            }
+            self.arrivals.pop_front();
+        }
+        ready
+    }
+
+    /// 按序取出全部事件，订阅结束时调用
+    pub fn drain(&mut self) -> Vec<DexEvent> {
+        self.arrivals.clear();
+        if let Some((&key, _)) = self.pending.last_key_value() {
+            self.last_released = Some(key);
+        }
+        std::mem::take(&mut self.pending).into_values().collect()
+    }
+
+    /// 下一个事件到期的时间，没有缓存事件时为 None
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.arrivals
+            .iter()
+            .find(|(_, key)| self.pending.contains_key(key))
+            .map(|(arrived, _)| *arrived + self.window)
+    }
+
+    /// 缓存中的事件数
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+
+    const WINDOW: Duration = Duration::from_millis(100);
+
+    fn trade(
+        slot: u64,
+        transaction_index: Option<u64>,
+        outer: i64,
+        inner: Option<i64>,
+    ) -> DexEvent {
+        let mut trade = PumpFunTradeEvent::default();
+        trade.metadata.slot = slot;
+        trade.metadata.transaction_index = transaction_index;
+        trade.metadata.outer_index = outer;
+        trade.metadata.inner_index = inner;
+        DexEvent::PumpFunTradeEvent(trade)
+    }
+
+    fn positions(events: &[DexEvent]) -> Vec<(u64, Option<u64>, i64, Option<i64>)> {
+        events
+            .iter()
+            .map(|event| {
+                let metadata = event.metadata();
+                (
+                    metadata.slot,
+                    metadata.transaction_index,
+                    metadata.outer_index,
+                    metadata.inner_index,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn out_of_order_events_are_released_in_order_after_the_window() {
+        let start = Instant::now();
+        let mut buffer = EventReorderBuffer::new(WINDOW);
+        assert!(buffer.push(trade(11, Some(0), 0, None), start).is_none());
+        assert!(buffer
+            .push(trade(10, Some(3), 1, Some(0)), start + Duration::from_millis(10))
+            .is_none());
+        assert!(buffer
+            .push(trade(10, Some(3), 1, None), start + Duration::from_millis(20))
+            .is_none());
+        assert!(buffer.push(trade(10, None, 0, None), start + Duration::from_millis(30)).is_none());
+        assert!(buffer
+            .push(trade(10, Some(1), 0, None), start + Duration::from_millis(40))
+            .is_none());
+        assert_eq!(buffer.next_deadline(), Some(start + WINDOW));
+
+        assert!(buffer.pop_ready(start + WINDOW - Duration::from_millis(1)).is_empty());
+        // 最早到达的 slot 11 到期，排在它之前的事件一起释放
+        let ready = buffer.pop_ready(start + WINDOW);
+        assert_eq!(
+            positions(&ready),
+            vec![
+                (10, Some(1), 0, None),
+                (10, Some(3), 1, None),
+                (10, Some(3), 1, Some(0)),
+                (10, None, 0, None),
+                (11, Some(0), 0, None),
+            ]
+        );
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.next_deadline(), None);
+    }
+
+    #[test]
+    fn only_expired_events_and_their_predecessors_are_released() {
+        let start = Instant::now();
+        let mut buffer = EventReorderBuffer::new(WINDOW);
+        buffer.push(trade(10, Some(0), 0, None), start);
+        buffer.push(trade(12, Some(0), 0, None), start + Duration::from_millis(50));
+        buffer.push(trade(11, Some(0), 0, None), start + Duration::from_millis(60));
+
+        let ready = buffer.pop_ready(start + WINDOW);
+        assert_eq!(positions(&ready), vec![(10, Some(0), 0, None)]);
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.next_deadline(), Some(start + Duration::from_millis(50) + WINDOW));
+
+        let ready = buffer.pop_ready(start + Duration::from_millis(50) + WINDOW);
+        assert_eq!(positions(&ready), vec![(11, Some(0), 0, None), (12, Some(0), 0, None)]);
+    }
+
+    #[test]
+    fn late_events_are_delivered_immediately() {
+        let start = Instant::now();
+        let mut buffer = EventReorderBuffer::new(WINDOW);
+        buffer.push(trade(11, Some(0), 0, None), start);
+        assert_eq!(buffer.pop_ready(start + WINDOW).len(), 1);
+
+        let late = buffer.push(trade(10, Some(0), 0, None), start + WINDOW);
+        assert_eq!(positions(late.as_slice()), vec![(10, Some(0), 0, None)]);
+        // 排在已释放事件之后的事件照常缓存
+        assert!(buffer.push(trade(11, Some(1), 0, None), start + WINDOW).is_none());
+        assert_eq!(buffer.len(), 1);
+    }
+
+    #[test]
+    fn drain_releases_everything_in_order() {
+        let start = Instant::now();
+        let mut buffer = EventReorderBuffer::new(WINDOW);
+        buffer.push(trade(12, Some(0), 0, None), start);
+        buffer.push(trade(11, Some(0), 0, None), start);
+        assert_eq!(
+            positions(&buffer.drain()),
+            vec![(11, Some(0), 0, None), (12, Some(0), 0, None)]
+        );
+        assert_eq!(buffer.next_deadline(), None);
+        assert!(buffer.push(trade(11, Some(5), 0, None), start).is_some());
+    }
+}
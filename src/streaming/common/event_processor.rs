@@ -1,23 +1,30 @@
 use crate::common::AnyResult;
-use crate::streaming::common::MetricsEventType;
-use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::common::{MetricsEventType, BLOCK_TIME_CACHE};
+use crate::streaming::event_parser::common::timestamp_millis;
+use crate::streaming::event_parser::common::unknown_tx::{
+    has_unknown_tx_callback, invoked_program_ids, report_unknown_tx, UnknownTx,
+};
 use crate::streaming::event_parser::core::account_event_parser::AccountEventParser;
 use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
 use crate::streaming::event_parser::core::event_parser::EventParser;
-use crate::streaming::event_parser::{core::traits::DexEvent, Protocol};
+use crate::streaming::event_parser::core::parse_options::ParseOptions;
+use crate::streaming::event_parser::core::traits::DexEvent;
 use crate::streaming::grpc::{EventPretty, MetricsManager};
 use crate::streaming::shred::TransactionWithSlot;
-use solana_sdk::pubkey::Pubkey;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 /// 创建带 metrics 统计的 callback 包装器
 ///
-/// 用于 Transaction 事件处理，在调用原始 callback 的同时更新 metrics
+/// 用于 Transaction 事件处理，在调用原始 callback 的同时更新 metrics，并写入接收时间
 #[inline]
 fn create_metrics_callback(
     callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    received_us: i64,
 ) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
-    Arc::new(move |event: DexEvent| {
+    Arc::new(move |mut event: DexEvent| {
+        event.metadata_mut().received_us = received_us;
         let metadata = event.metadata();
         let processing_time_us = metadata.handle_us as f64;
         let recv_us = metadata.recv_us;
@@ -38,22 +45,46 @@ fn create_metrics_callback(
 /// Process GRPC transaction events
 pub async fn process_grpc_transaction(
     event_pretty: EventPretty,
-    protocols: &[Protocol],
-    event_type_filter: Option<&EventTypeFilter>,
+    options: ParseOptions<'_>,
     callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
-    bot_wallet: Option<Pubkey>,
 ) -> AnyResult<()> {
     match event_pretty {
         EventPretty::Account(account_pretty) => {
             MetricsManager::global().add_account_process_count();
+            let received_us = account_pretty.received_us;
+
+            // owner 和 lamports 变化在账户自身的事件之前推送
+            let owner_changed = options.owner_cache.and_then(|cache| {
+                AccountEventParser::parse_owner_changed_event(
+                    &account_pretty,
+                    cache,
+                    options.event_type_filter,
+                )
+            });
+            let lamports_changed = options.lamports_cache.and_then(|cache| {
+                AccountEventParser::parse_lamports_changed_event(
+                    &account_pretty,
+                    cache,
+                    options.event_type_filter,
+                )
+            });
+            for mut event in [owner_changed, lamports_changed].into_iter().flatten() {
+                event.metadata_mut().block_time = BLOCK_TIME_CACHE.get(event.metadata().slot);
+                event.metadata_mut().received_us = received_us;
+                let processing_time_us = event.metadata().handle_us as f64;
+                callback(event);
+                update_metrics(MetricsEventType::Account, 1, processing_time_us);
+            }
 
             let account_event = AccountEventParser::parse_account_event(
-                protocols,
+                options.protocols,
                 account_pretty,
-                event_type_filter,
+                options.event_type_filter,
             );
 
-            if let Some(event) = account_event {
+            if let Some(mut event) = account_event {
+                event.metadata_mut().block_time = BLOCK_TIME_CACHE.get(event.metadata().slot);
+                event.metadata_mut().received_us = received_us;
                 let processing_time_us = event.metadata().handle_us as f64;
                 callback(event);
                 update_metrics(MetricsEventType::Account, 1, processing_time_us);
@@ -66,44 +97,115 @@ pub async fn process_grpc_transaction(
             let signature = transaction_pretty.signature;
             let block_time = transaction_pretty.block_time;
             let recv_us = transaction_pretty.recv_us;
+            let received_us = transaction_pretty.received_us;
             let transaction_index = transaction_pretty.transaction_index;
             let grpc_tx = transaction_pretty.grpc_tx;
+            // 开启 enable_metrics 时记录整笔交易的解析耗时
+            let parse_timing = MetricsManager::global().is_enabled().then(|| {
+                let outer = grpc_tx
+                    .transaction
+                    .as_ref()
+                    .and_then(|tx| tx.message.as_ref())
+                    .map_or(0, |message| message.instructions.len());
+                let inner: usize = grpc_tx.meta.as_ref().map_or(0, |meta| {
+                    meta.inner_instructions.iter().map(|inner| inner.instructions.len()).sum()
+                });
+                (Instant::now(), outer + inner)
+            });
 
-            let adapter_callback = create_metrics_callback(callback.clone());
+            // 设置了未解析交易回调时，记录交易调用的程序并统计解析出的事件数
+            let unknown_tx = has_unknown_tx_callback()
+                .then(|| (invoked_program_ids(&grpc_tx), Arc::new(AtomicUsize::new(0))));
+            let event_count = unknown_tx.as_ref().map(|(_, count)| count.clone());
+
+            // gRPC 的 block_time 参数是消息创建时间，真实出块时间取自已收到的 block meta
+            let chain_block_time = BLOCK_TIME_CACHE.get(slot);
+            let callback = callback.clone();
+            let adapter_callback = create_metrics_callback(
+                Arc::new(move |mut event: DexEvent| {
+                    event.metadata_mut().block_time = chain_block_time;
+                    if let Some(count) = &event_count {
+                        count.fetch_add(1, Ordering::Relaxed);
+                    }
+                    callback(event);
+                }),
+                received_us,
+            );
 
             EventParser::parse_grpc_transaction(
-                protocols,
-                event_type_filter,
+                options,
                 grpc_tx,
                 signature,
                 Some(slot),
                 block_time,
                 recv_us,
-                bot_wallet,
                 transaction_index,
                 adapter_callback,
             )
             .await?;
+            if let Some((program_ids, count)) = unknown_tx {
+                if count.load(Ordering::Relaxed) == 0 {
+                    report_unknown_tx(UnknownTx { signature, slot, program_ids });
+                }
+            }
+            if let Some((started, instruction_count)) = parse_timing {
+                MetricsManager::global().record_transaction_parse(
+                    &signature,
+                    instruction_count,
+                    started.elapsed(),
+                );
+            }
         }
         EventPretty::BlockMeta(block_meta_pretty) => {
             MetricsManager::global().add_block_meta_process_count();
 
             let block_time_ms = block_meta_pretty
                 .block_time
-                .map(|ts| ts.seconds * 1000 + ts.nanos as i64 / 1_000_000)
+                .map(|ts| timestamp_millis(ts.seconds, ts.nanos))
                 .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
 
-            let block_meta_event = CommonEventParser::generate_block_meta_event(
+            if let Some(block_time) = block_meta_pretty.unix_block_time {
+                BLOCK_TIME_CACHE.record(block_meta_pretty.slot, block_time);
+            }
+
+            let mut block_meta_event = CommonEventParser::generate_block_meta_event(
                 block_meta_pretty.slot,
                 block_meta_pretty.block_hash,
+                block_meta_pretty.parent_slot,
+                block_meta_pretty.parent_block_hash,
+                block_meta_pretty.block_height,
+                block_meta_pretty.executed_transaction_count,
+                block_meta_pretty.unix_block_time,
                 block_time_ms,
                 block_meta_pretty.recv_us,
             );
+            block_meta_event.metadata_mut().received_us = block_meta_pretty.received_us;
 
             let processing_time_us = block_meta_event.metadata().handle_us as f64;
             callback(block_meta_event);
             update_metrics(MetricsEventType::BlockMeta, 1, processing_time_us);
         }
+        EventPretty::Entry(entry_pretty) => {
+            // entry 先于 block meta 到达，出块时间通常还不可用
+            let block_time = BLOCK_TIME_CACHE.get(entry_pretty.slot);
+            let block_time_ms = block_time
+                .map(|seconds| seconds * 1000)
+                .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+
+            let mut entry_event = CommonEventParser::generate_entry_event(
+                entry_pretty.slot,
+                entry_pretty.index,
+                entry_pretty.num_hashes,
+                entry_pretty.hash,
+                entry_pretty.executed_transaction_count,
+                entry_pretty.starting_transaction_index,
+                block_time,
+                block_time_ms,
+                entry_pretty.recv_us,
+            );
+            entry_event.metadata_mut().received_us = entry_pretty.received_us;
+            callback(entry_event);
+        }
     }
 
     Ok(())
@@ -112,10 +214,8 @@ pub async fn process_grpc_transaction(
 /// Process Shred transaction events
 pub async fn process_shred_transaction(
     transaction_with_slot: TransactionWithSlot,
-    protocols: &[Protocol],
-    event_type_filter: Option<&EventTypeFilter>,
+    options: ParseOptions<'_>,
     callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
-    bot_wallet: Option<Pubkey>,
 ) -> AnyResult<()> {
     MetricsManager::global().add_tx_process_count();
 
@@ -129,12 +229,11 @@ pub async fn process_shred_transaction(
     let signature = tx.signatures[0];
     let recv_us = transaction_with_slot.recv_us;
 
-    let adapter_callback = create_metrics_callback(callback);
+    let adapter_callback = create_metrics_callback(callback, recv_us);
     let accounts = tx.message.static_account_keys();
 
     EventParser::parse_instruction_events_from_versioned_transaction(
-        protocols,
-        event_type_filter,
+        options,
         &tx,
         signature,
         Some(slot),
@@ -142,7 +241,6 @@ pub async fn process_shred_transaction(
         recv_us,
         accounts,
         &[],
-        bot_wallet,
         None,
         adapter_callback,
     )
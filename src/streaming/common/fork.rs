@@ -0,0 +1,177 @@
+use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
+use crate::streaming::event_parser::protocols::block::block_meta_event::BlockMetaEvent;
+use crate::streaming::event_parser::DexEvent;
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashSet};
+
+/// 最多记录的区块数量
+const FORK_DETECTOR_SIZE: usize = 1024;
+
+/// 已收到的区块
+struct SeenBlock {
+    block_hash: String,
+    parent_slot: u64,
+}
+
+/// 根据 block meta 的父 slot 链检测分叉
+///
+/// 新区块的父 slot 低于已收到的区块时，`(parent_slot, slot]` 之间已收到、但不是该区块本身的
+/// slot 位于另一条分叉上，连同以它们为祖先的后续区块一起视为被回滚；同一 slot 收到不同
+/// block hash 时旧区块被替换。每个被回滚的 slot 产生一个 `SlotRollbackEvent`
+///
+/// 只在 `Processed` 确认级别下有意义，`Confirmed` 及以上的区块不会被回滚
+pub struct ForkDetector {
+    blocks: Mutex<BTreeMap<u64, SeenBlock>>,
+}
+
+impl ForkDetector {
+    pub fn new() -> Self {
+        Self { blocks: Mutex::new(BTreeMap::new()) }
+    }
+
+    /// 记录区块，返回被该区块回滚的 slot 对应的事件，按 slot 升序
+    pub fn observe(&self, block: &BlockMetaEvent) -> Vec<DexEvent> {
+        let mut blocks = self.blocks.lock();
+        // 父 slot 不小于 slot 的异常区块只检查同一 slot 的替换
+        let first_slot = block.parent_slot.saturating_add(1).min(block.slot);
+        let mut orphaned: Vec<u64> = blocks
+            .range(first_slot..=block.slot)
+            .filter(|(&slot, seen)| slot != block.slot || seen.block_hash != block.block_hash)
+            .map(|(&slot, _)| slot)
+            .collect();
+        if block.slot < blocks.last_key_value().map_or(0, |(&slot, _)| slot) {
+            // 建立在被回滚区块之上的后续区块同样被回滚
+            let mut orphaned_set: HashSet<u64> = orphaned.iter().copied().collect();
+            for (&slot, seen) in blocks.range(block.slot + 1..) {
+                if orphaned_set.contains(&seen.parent_slot) {
+                    orphaned_set.insert(slot);
+                    orphaned.push(slot);
+                }
+            }
+        }
+
+        let recv_us = block.metadata.recv_us;
+        let events = orphaned
+            .into_iter()
+            .filter_map(|slot| blocks.remove(&slot).map(|seen| (slot, seen)))
+            .map(|(slot, seen)| {
+                CommonEventParser::generate_slot_rollback_event(
+                    slot,
+                    seen.block_hash,
+                    block.slot,
+                    block.parent_slot,
+                    recv_us,
+                )
+            })
+            .collect();
+
+        blocks.insert(
+            block.slot,
+            SeenBlock { block_hash: block.block_hash.clone(), parent_slot: block.parent_slot },
+        );
+        while blocks.len() > FORK_DETECTOR_SIZE {
+            blocks.pop_first();
+        }
+        events
+    }
+}
+
+impl Default for ForkDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(slot: u64, parent_slot: u64, block_hash: &str) -> BlockMetaEvent {
+        BlockMetaEvent {
+            slot,
+            parent_slot,
+            block_hash: block_hash.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn rolled_back_slots(events: &[DexEvent]) -> Vec<u64> {
+        events
+            .iter()
+            .map(|event| match event {
+                DexEvent::SlotRollbackEvent(rollback) => rollback.slot,
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parent_slot_equal_to_slot_only_checks_replacement() {
+        let detector = ForkDetector::new();
+        assert!(detector.observe(&block(9, 8, "a")).is_empty());
+        assert!(detector.observe(&block(10, 9, "b")).is_empty());
+
+        assert!(detector.observe(&block(10, 10, "b")).is_empty());
+        assert_eq!(rolled_back_slots(&detector.observe(&block(10, 10, "c"))), vec![10]);
+    }
+
+    #[test]
+    fn parent_slot_above_slot_does_not_panic() {
+        let detector = ForkDetector::new();
+        assert!(detector.observe(&block(10, 9, "a")).is_empty());
+        assert!(detector.observe(&block(10, u64::MAX, "a")).is_empty());
+        assert_eq!(rolled_back_slots(&detector.observe(&block(10, 12, "b"))), vec![10]);
+    }
+
+    #[test]
+    fn linear_chain_and_skipped_slots_roll_back_nothing() {
+        let detector = ForkDetector::new();
+        assert!(detector.observe(&block(10, 9, "a")).is_empty());
+        assert!(detector.observe(&block(11, 10, "b")).is_empty());
+        // 跳过的 slot 没有收到过，不产生事件
+        assert!(detector.observe(&block(14, 11, "c")).is_empty());
+    }
+
+    #[test]
+    fn fork_rolls_back_skipped_slots_and_their_descendants() {
+        let detector = ForkDetector::new();
+        detector.observe(&block(10, 9, "a"));
+        detector.observe(&block(11, 10, "b"));
+        detector.observe(&block(12, 11, "c"));
+        detector.observe(&block(14, 12, "d"));
+
+        // 12 建立在 10 之上，11 位于另一条分叉，12 和 14 以它为祖先一起被回滚
+        let events = detector.observe(&block(12, 10, "e"));
+        assert_eq!(rolled_back_slots(&events), vec![11, 12, 14]);
+        let DexEvent::SlotRollbackEvent(rollback) = &events[0] else { unreachable!() };
+        assert_eq!((rollback.fork_slot, rollback.fork_parent_slot), (12, 10));
+        assert_eq!(rollback.block_hash, "b");
+
+        // 回滚过的 slot 不会重复产生事件
+        assert!(detector.observe(&block(13, 12, "f")).is_empty());
+    }
+
+    #[test]
+    fn late_block_on_the_same_chain_rolls_back_nothing() {
+        let detector = ForkDetector::new();
+        detector.observe(&block(10, 9, "a"));
+        detector.observe(&block(12, 11, "c"));
+        // 11 晚于其子区块到达，仍在同一条链上
+        assert!(detector.observe(&block(11, 10, "b")).is_empty());
+        assert!(detector.observe(&block(13, 12, "d")).is_empty());
+    }
+
+    #[test]
+    fn oldest_blocks_are_evicted_beyond_capacity() {
+        let detector = ForkDetector::new();
+        let first = 1_000;
+        for slot in first..=first + FORK_DETECTOR_SIZE as u64 {
+            detector.observe(&block(slot, slot - 1, "a"));
+        }
+        // 最早的区块已被移出，替换它不再产生事件
+        assert!(detector.observe(&block(first, first - 1, "b")).is_empty());
+        let rolled_back = rolled_back_slots(&detector.observe(&block(first + 1, first, "b")));
+        assert_eq!(rolled_back.len(), FORK_DETECTOR_SIZE);
+        assert_eq!(rolled_back[0], first + 1);
+    }
+}
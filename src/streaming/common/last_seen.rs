@@ -0,0 +1,124 @@
+use parking_lot::Mutex;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, VecDeque};
+
+/// 默认最多缓存的账户数量
+const LAST_SEEN_CACHE_SIZE: usize = 100_000;
+
+struct LastSeenState<V> {
+    /// 账户 -> (记录时的 slot, 值)
+    values: HashMap<Pubkey, (u64, V)>,
+    /// 账户首次记录的先后顺序，超出容量时从队首淘汰
+    order: VecDeque<Pubkey>,
+}
+
+/// 账户 -> 最近一次看到的值的有界缓存，用于在账户更新之间检测某个字段的变化
+///
+/// 超出容量时淘汰最早记录的账户，被淘汰账户的下一次更新只重新记录，不报告变化。
+/// 缓存属于单个订阅，不同订阅 (或确认级别) 各自检测变化
+pub struct LastSeenCache<V> {
+    capacity: usize,
+    state: Mutex<LastSeenState<V>>,
+}
+
+impl<V: PartialEq + Copy> LastSeenCache<V> {
+    pub fn new() -> Self {
+        Self::with_capacity(LAST_SEEN_CACHE_SIZE)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(LastSeenState { values: HashMap::new(), order: VecDeque::new() }),
+        }
+    }
+
+    /// 记录账户在 `slot` 的值，与上次记录不同时返回上次的值；账户第一次出现时返回 None
+    ///
+    /// slot 低于上次记录的更新 (如并行解析时晚到的旧更新) 忽略，不覆盖较新的值
+    pub fn observe(&self, account: Pubkey, slot: u64, value: V) -> Option<V> {
+        let mut state = self.state.lock();
+        if state.values.get(&account).is_some_and(|(last_slot, _)| slot < *last_slot) {
+            return None;
+        }
+        match state.values.insert(account, (slot, value)) {
+            Some((_, previous)) => (previous != value).then_some(previous),
+            None => {
+                state.order.push_back(account);
+                while state.order.len() > self.capacity {
+                    if let Some(evicted) = state.order.pop_front() {
+                        state.values.remove(&evicted);
+                    }
+                }
+                None
+            }
+        }
+    }
+
+    /// 查询账户最近一次看到的值
+    pub fn get(&self, account: &Pubkey) -> Option<V> {
+        self.state.lock().values.get(account).map(|(_, value)| *value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().values.is_empty()
+    }
+}
+
+impl<V: PartialEq + Copy> Default for LastSeenCache<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_changes_and_evicts_oldest_account() {
+        let cache = LastSeenCache::<u64>::with_capacity(2);
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(cache.observe(a, 10, 1), None);
+        assert_eq!(cache.observe(a, 10, 1), None);
+        assert_eq!(cache.observe(a, 11, 5), Some(1));
+        assert_eq!(cache.observe(b, 11, 2), None);
+        assert_eq!(cache.observe(c, 12, 3), None);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&a), None);
+        // 被淘汰的账户重新记录，不报告变化
+        assert_eq!(cache.observe(a, 13, 9), None);
+        assert_eq!(cache.get(&b), None);
+    }
+
+    #[test]
+    fn ignores_updates_older_than_the_last_seen_slot() {
+        let cache = LastSeenCache::<u64>::new();
+        let account = Pubkey::new_unique();
+        assert_eq!(cache.observe(account, 100, 7), None);
+        assert_eq!(cache.observe(account, 102, 9), Some(7));
+        // 晚到的旧更新既不报告反向变化，也不覆盖较新的值
+        assert_eq!(cache.observe(account, 101, 8), None);
+        assert_eq!(cache.get(&account), Some(9));
+        // 同一 slot 内的多次写入依次比较
+        assert_eq!(cache.observe(account, 102, 4), Some(9));
+        assert_eq!(cache.observe(account, 103, 4), None);
+    }
+
+    #[test]
+    fn caches_are_independent() {
+        let (first, second) = (LastSeenCache::<u64>::new(), LastSeenCache::<u64>::new());
+        let account = Pubkey::new_unique();
+        for cache in [&first, &second] {
+            assert_eq!(cache.observe(account, 1, 5), None);
+        }
+        // 每个缓存都报告同一次变化，先看到更新的一方不会吞掉另一方的变化
+        assert_eq!(first.observe(account, 2, 6), Some(5));
+        assert_eq!(second.observe(account, 2, 6), Some(5));
+    }
+}
@@ -171,6 +171,11 @@ pub struct PerformanceMetrics {
     pub block_meta_metrics: EventMetricsSnapshot,
     pub processing_stats: ProcessingTimeStats,
     pub dropped_events_count: u64,
+    pub duplicate_events_count: u64,
+    pub oversized_messages_count: u64,
+    pub dropped_parse_errors_count: u64,
+    pub callback_panics_count: u64,
+    pub reconnect_count: u64,
 }
 
 impl PerformanceMetrics {
@@ -190,6 +195,11 @@ impl PerformanceMetrics {
             block_meta_metrics: default_metrics,
             processing_stats: default_stats,
             dropped_events_count: 0,
+            duplicate_events_count: 0,
+            oversized_messages_count: 0,
+            dropped_parse_errors_count: 0,
+            callback_panics_count: 0,
+            reconnect_count: 0,
         }
     }
 }
@@ -202,6 +212,16 @@ pub struct HighPerformanceMetrics {
     processing_stats: AtomicProcessingTimeStats,
     // 丢弃事件指标
     dropped_events_count: AtomicU64,
+    // 去重丢弃的重复事件
+    duplicate_events_count: AtomicU64,
+    // 超过 max_decoding_message_size 被丢弃的更新
+    oversized_messages_count: AtomicU64,
+    // 错误流已满被丢弃的解析错误
+    dropped_parse_errors_count: AtomicU64,
+    // 回调 panic 次数
+    callback_panics_count: AtomicU64,
+    // 成功重连次数
+    reconnect_count: AtomicU64,
 }
 
 impl HighPerformanceMetrics {
@@ -216,6 +236,11 @@ impl HighPerformanceMetrics {
             ],
             processing_stats: AtomicProcessingTimeStats::new_const(),
             dropped_events_count: AtomicU64::new(0),
+            duplicate_events_count: AtomicU64::new(0),
+            oversized_messages_count: AtomicU64::new(0),
+            dropped_parse_errors_count: AtomicU64::new(0),
+            callback_panics_count: AtomicU64::new(0),
+            reconnect_count: AtomicU64::new(0),
         }
     }
 
@@ -266,6 +291,36 @@ impl HighPerformanceMetrics {
         self.dropped_events_count.load(Ordering::Relaxed)
     }
 
+    /// 获取重复事件计数
+    #[inline]
+    pub fn get_duplicate_events_count(&self) -> u64 {
+        self.duplicate_events_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取超长消息计数
+    #[inline]
+    pub fn get_oversized_messages_count(&self) -> u64 {
+        self.oversized_messages_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取被丢弃的解析错误计数
+    #[inline]
+    pub fn get_dropped_parse_errors_count(&self) -> u64 {
+        self.dropped_parse_errors_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取回调 panic 次数
+    #[inline]
+    pub fn get_callback_panics_count(&self) -> u64 {
+        self.callback_panics_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取重连次数
+    #[inline]
+    pub fn get_reconnect_count(&self) -> u64 {
+        self.reconnect_count.load(Ordering::Relaxed)
+    }
+
     /// 更新窗口指标（后台任务调用）
     fn update_window_metrics(&self, event_type: EventType, window_duration_nanos: u64) {
         let now_nanos =
@@ -330,7 +385,7 @@ impl MetricsManager {
     }
 
     #[inline]
-    fn is_enabled(&self) -> bool {
+    pub(crate) fn is_enabled(&self) -> bool {
         METRICS_ENABLED.load(Ordering::Relaxed)
     }
 
@@ -375,7 +430,8 @@ impl MetricsManager {
     pub fn check_and_warn_high_latency(&self, recv_us: i64, block_time_ms: i64) {
         let recv_ms = recv_us / 1000;
         // 校准延迟: recv_time - (block_time + 500ms)
-        let adjusted_latency_ms = recv_ms - (block_time_ms + SOLANA_BLOCK_TIME_ADJUSTMENT_MS);
+        let adjusted_latency_ms =
+            recv_ms.saturating_sub(block_time_ms.saturating_add(SOLANA_BLOCK_TIME_ADJUSTMENT_MS));
 
         if adjusted_latency_ms > MAX_LATENCY_THRESHOLD_MS {
             log::warn!(
@@ -408,6 +464,31 @@ impl MetricsManager {
         GLOBAL_METRICS.get_dropped_events_count()
     }
 
+    /// 获取重复事件计数
+    pub fn get_duplicate_events_count(&self) -> u64 {
+        GLOBAL_METRICS.get_duplicate_events_count()
+    }
+
+    /// 获取超过 `max_decoding_message_size` 被丢弃的更新数
+    pub fn get_oversized_messages_count(&self) -> u64 {
+        GLOBAL_METRICS.get_oversized_messages_count()
+    }
+
+    /// 获取 `subscribe_events_stream_with_errors` 因通道已满丢弃的解析错误数
+    pub fn get_dropped_parse_errors_count(&self) -> u64 {
+        GLOBAL_METRICS.get_dropped_parse_errors_count()
+    }
+
+    /// 获取回调 panic 次数，panic 的事件被跳过，流继续运行
+    pub fn get_callback_panics_count(&self) -> u64 {
+        GLOBAL_METRICS.get_callback_panics_count()
+    }
+
+    /// 获取成功重连次数
+    pub fn get_reconnect_count(&self) -> u64 {
+        GLOBAL_METRICS.get_reconnect_count()
+    }
+
     /// 打印性能指标（非阻塞）
     pub fn print_metrics(&self) {
         println!("\n📊 Performance Metrics");
@@ -418,6 +499,38 @@ impl MetricsManager {
         if dropped_count > 0 {
             println!("\n⚠️  Dropped Events: {}", dropped_count);
         }
+        let duplicate_count = self.get_duplicate_events_count();
+        if duplicate_count > 0 {
+            println!("   Duplicate Events: {}", duplicate_count);
+        }
+        let oversized_count = self.get_oversized_messages_count();
+        if oversized_count > 0 {
+            println!("   Oversized Messages: {}", oversized_count);
+        }
+        let dropped_parse_errors = self.get_dropped_parse_errors_count();
+        if dropped_parse_errors > 0 {
+            println!("   Dropped Parse Errors: {}", dropped_parse_errors);
+        }
+        let callback_panics = self.get_callback_panics_count();
+        if callback_panics > 0 {
+            println!("   Callback Panics: {}", callback_panics);
+        }
+        let slot_lag = self.get_slot_lag();
+        if slot_lag.processed_slot > 0 {
+            println!(
+                "   Slot Lag: {} (processed {}, tip {})",
+                slot_lag.lag_slots,
+                slot_lag.processed_slot,
+                slot_lag.chain_tip_slot()
+            );
+        }
+        let throttle = self.get_throttle_metrics();
+        if throttle.events_per_second > 0 || throttle.throttled_ms > 0 {
+            println!(
+                "   Event Rate: {}/s (throttled {}ms, breaches {})",
+                throttle.events_per_second, throttle.throttled_ms, throttle.breaches
+            );
+        }
 
         // 打印事件指标表格（包含处理时间统计）
         println!("┌─────────────┬──────────────┬──────────────────┬─────────────┬─────────────┐");
@@ -437,6 +550,31 @@ impl MetricsManager {
         }
 
         println!("└─────────────┴──────────────┴──────────────────┴─────────────┴─────────────┘");
+
+        let transactions = self.get_transaction_parse_metrics();
+        if transactions.transactions > 0 {
+            println!(
+                "   Transaction parse: avg {:.2}μs, max {:.2}μs, {} slow",
+                transactions.avg_parse_us, transactions.max_parse_us, transactions.slow_transactions
+            );
+        }
+
+        // 按累计解析耗时列出最慢的解析器
+        let parsers = self.get_parser_metrics();
+        if !parsers.is_empty() {
+            println!("   Slowest parsers (by total parse time):");
+            for parser in parsers.iter().take(5) {
+                println!(
+                    "   {:?}/{:?}: {} parsed, {} errors, avg {:.2}μs, max {:.2}μs",
+                    parser.protocol,
+                    parser.event_type,
+                    parser.events_parsed,
+                    parser.parse_errors,
+                    parser.avg_parse_us,
+                    parser.max_parse_us
+                );
+            }
+        }
         println!();
     }
 
@@ -467,6 +605,11 @@ impl MetricsManager {
             block_meta_metrics: self.get_event_metrics(EventType::BlockMeta),
             processing_stats: self.get_processing_stats(),
             dropped_events_count: self.get_dropped_events_count(),
+            duplicate_events_count: self.get_duplicate_events_count(),
+            oversized_messages_count: self.get_oversized_messages_count(),
+            dropped_parse_errors_count: self.get_dropped_parse_errors_count(),
+            callback_panics_count: self.get_callback_panics_count(),
+            reconnect_count: self.get_reconnect_count(),
         }
     }
 
@@ -515,12 +658,10 @@ impl MetricsManager {
     }
 
     /// 增加丢弃事件计数
+    ///
+    /// 丢弃由显式配置的 `OverflowPolicy` 触发，因此不受 enable_metrics 限制
     #[inline]
     pub fn increment_dropped_events(&self) {
-        if !self.is_enabled() {
-            return;
-        }
-
         // 原子地增加丢弃事件计数
         let new_count = GLOBAL_METRICS.dropped_events_count.fetch_add(1, Ordering::Relaxed) + 1;
 
@@ -530,6 +671,38 @@ impl MetricsManager {
         }
     }
 
+    /// 增加重复事件计数
+    ///
+    /// 去重是显式开启的功能，因此不受 enable_metrics 限制
+    #[inline]
+    pub fn increment_duplicate_events(&self) {
+        GLOBAL_METRICS.duplicate_events_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加超长消息计数，不受 enable_metrics 限制
+    #[inline]
+    pub fn increment_oversized_messages(&self) {
+        GLOBAL_METRICS.oversized_messages_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加被丢弃的解析错误计数，不受 enable_metrics 限制
+    #[inline]
+    pub fn increment_dropped_parse_errors(&self) {
+        GLOBAL_METRICS.dropped_parse_errors_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加回调 panic 计数，不受 enable_metrics 限制
+    #[inline]
+    pub fn increment_callback_panics(&self) {
+        GLOBAL_METRICS.callback_panics_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加重连计数，不受 enable_metrics 限制
+    #[inline]
+    pub fn increment_reconnects(&self) {
+        GLOBAL_METRICS.reconnect_count.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// 批量增加丢弃事件计数
     #[inline]
     pub fn increment_dropped_events_by(&self, count: u64) {
@@ -1,15 +1,66 @@
 // 公用模块 - 包含流处理相关的通用功能
+pub mod account_lamports;
+pub mod account_owner;
+pub mod block_time;
+pub mod bounded_queue;
+pub(crate) mod callback_guard;
+pub mod commitment;
 pub mod config;
+pub mod connection_state;
+pub mod dedup;
+pub mod endpoint_health;
+pub mod error;
+pub mod event_order;
+pub mod fork;
+pub mod last_seen;
 pub mod metrics;
 pub mod constants;
+#[cfg(feature = "csv")]
+pub mod csv_sink;
+pub mod parse_metrics;
+pub mod price_feed;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+pub mod replay;
+pub mod sampling;
+pub mod slot_lag;
 pub mod subscription;
+pub mod throttle;
+pub mod token_registry;
+pub mod transfer_fee;
+pub mod x_token_pool;
 pub mod event_processor;
 pub mod simd_utils;
 
 // 重新导出主要类型
+pub use account_lamports::*;
+pub use account_owner::*;
+pub use block_time::*;
+pub use bounded_queue::*;
+pub use commitment::*;
 pub use config::*;
+pub use connection_state::*;
+pub use dedup::*;
+pub use endpoint_health::*;
+pub use error::*;
+pub use event_order::*;
+pub use fork::*;
+pub use last_seen::*;
 pub use metrics::*;
 pub use constants::*;
+#[cfg(feature = "csv")]
+pub use csv_sink::*;
+pub use parse_metrics::*;
+pub use price_feed::*;
+#[cfg(feature = "prometheus")]
+pub use prometheus::*;
+pub use replay::*;
+pub use sampling::*;
+pub use slot_lag::*;
 pub use subscription::*;
+pub use throttle::*;
+pub use token_registry::*;
+pub use transfer_fee::*;
+pub use x_token_pool::*;
 pub use event_processor::*;
 pub use simd_utils::*;
\ No newline at end of file
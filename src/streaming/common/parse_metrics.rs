@@ -0,0 +1,257 @@
+use super::constants::DEFAULT_SLOW_PARSE_THRESHOLD_US;
+use super::endpoint_health::EndpointHealth;
+use super::metrics::{MetricsManager, PerformanceMetrics};
+use super::slot_lag::SlotLagSnapshot;
+use super::throttle::ThrottleSnapshot;
+use super::x_token_pool::XTokenUsage;
+use crate::streaming::event_parser::common::EventType;
+use crate::streaming::event_parser::Protocol;
+use parking_lot::RwLock;
+use solana_sdk::signature::Signature;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+/// 解析耗时直方图各桶的上界（微秒），超过最后一个上界的计入额外的 +Inf 桶
+pub const PARSE_DURATION_BUCKETS_US: [u64; 10] = [1, 2, 5, 10, 20, 50, 100, 200, 500, 1000];
+
+const BUCKET_COUNT: usize = PARSE_DURATION_BUCKETS_US.len() + 1;
+
+/// 单笔交易解析耗时直方图各桶的上界（微秒），超过最后一个上界的计入额外的 +Inf 桶
+pub const TRANSACTION_PARSE_DURATION_BUCKETS_US: [u64; 10] =
+    [10, 20, 50, 100, 200, 500, 1000, 2000, 5000, 10000];
+
+const TRANSACTION_BUCKET_COUNT: usize = TRANSACTION_PARSE_DURATION_BUCKETS_US.len() + 1;
+
+/// 单笔交易解析耗时超过该值（微秒）时输出告警，0 表示不告警
+static SLOW_PARSE_THRESHOLD_US: AtomicU64 = AtomicU64::new(DEFAULT_SLOW_PARSE_THRESHOLD_US);
+
+/// 单个 (协议, 事件类型) 的原子统计
+#[derive(Debug, Default)]
+struct AtomicParseStats {
+    events_parsed: AtomicU64,
+    parse_errors: AtomicU64,
+    total_parse_ns: AtomicU64,
+    max_parse_ns: AtomicU64,
+    duration_buckets: [AtomicU64; BUCKET_COUNT],
+}
+
+impl AtomicParseStats {
+    #[inline]
+    fn record(&self, elapsed: Duration, success: bool) {
+        if success {
+            self.events_parsed.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.parse_errors.fetch_add(1, Ordering::Relaxed);
+        }
+        let elapsed_ns = elapsed.as_nanos().min(u64::MAX as u128) as u64;
+        self.total_parse_ns.fetch_add(elapsed_ns, Ordering::Relaxed);
+        self.max_parse_ns.fetch_max(elapsed_ns, Ordering::Relaxed);
+        let elapsed_us = elapsed_ns / 1_000;
+        let bucket = PARSE_DURATION_BUCKETS_US
+            .iter()
+            .position(|&upper_us| elapsed_us <= upper_us)
+            .unwrap_or(PARSE_DURATION_BUCKETS_US.len());
+        self.duration_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// 单笔交易解析的原子统计
+#[derive(Debug, Default)]
+struct AtomicTransactionParseStats {
+    transactions: AtomicU64,
+    slow_transactions: AtomicU64,
+    total_parse_ns: AtomicU64,
+    max_parse_ns: AtomicU64,
+    duration_buckets: [AtomicU64; TRANSACTION_BUCKET_COUNT],
+}
+
+static TRANSACTION_PARSE_METRICS: LazyLock<AtomicTransactionParseStats> =
+    LazyLock::new(Default::default);
+
+type ParseStatsMap = HashMap<(Protocol, EventType), Arc<AtomicParseStats>>;
+
+/// 按 (协议, 事件类型) 保存的统计，只在首次出现时获取写锁
+static PARSE_METRICS: LazyLock<RwLock<ParseStatsMap>> = LazyLock::new(Default::default);
+
+/// 单个协议、事件类型的解析统计快照
+#[derive(Debug, Clone)]
+pub struct ParserMetricsSnapshot {
+    pub protocol: Protocol,
+    pub event_type: EventType,
+    /// 成功解析的事件数
+    pub events_parsed: u64,
+    /// 判别器已识别但解码失败的次数
+    pub parse_errors: u64,
+    /// 累计解析耗时（微秒）
+    pub total_parse_us: f64,
+    /// 平均每次解析耗时（微秒），包括失败的解析
+    pub avg_parse_us: f64,
+    /// 最大单次解析耗时（微秒）
+    pub max_parse_us: f64,
+    /// 各耗时桶内的解析次数（非累计），上界见 `PARSE_DURATION_BUCKETS_US`，最后一项为 +Inf 桶
+    pub duration_buckets: Vec<u64>,
+}
+
+/// 单笔交易解析耗时的统计快照
+#[derive(Debug, Clone, Default)]
+pub struct TransactionParseSnapshot {
+    /// 解析的交易数
+    pub transactions: u64,
+    /// 解析耗时超过慢解析阈值的交易数
+    pub slow_transactions: u64,
+    /// 累计解析耗时（微秒）
+    pub total_parse_us: f64,
+    /// 平均每笔交易解析耗时（微秒）
+    pub avg_parse_us: f64,
+    /// 最大单笔交易解析耗时（微秒）
+    pub max_parse_us: f64,
+    /// 各耗时桶内的交易数（非累计），上界见 `TRANSACTION_PARSE_DURATION_BUCKETS_US`，
+    /// 最后一项为 +Inf 桶
+    pub duration_buckets: Vec<u64>,
+}
+
+/// `metrics_snapshot()` 返回的完整指标快照
+#[derive(Debug, Clone)]
+pub struct MetricsSnapshot {
+    /// 整体处理指标
+    pub performance: PerformanceMetrics,
+    /// 按协议和事件类型的解析指标，按累计解析耗时从高到低排列
+    pub parsers: Vec<ParserMetricsSnapshot>,
+    /// 单笔交易的解析耗时
+    pub transactions: TransactionParseSnapshot,
+    /// 处理进度相对链上最新 slot 的延迟
+    pub slot_lag: SlotLagSnapshot,
+    /// 推送给回调的事件速率和限速统计
+    pub throttle: ThrottleSnapshot,
+    /// `x_tokens` 中每个 token 的连接次数
+    pub x_tokens: Vec<XTokenUsage>,
+    /// 每个 gRPC endpoint 的连接次数和作为当前 endpoint 的时长
+    pub endpoints: Vec<EndpointHealth>,
+}
+
+impl MetricsManager {
+    /// 记录一次指令或账户解析，未开启 enable_metrics 时不记录
+    #[inline]
+    pub fn record_parse(
+        &self,
+        protocol: &Protocol,
+        event_type: &EventType,
+        elapsed: Duration,
+        success: bool,
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+        let key = (protocol.clone(), event_type.clone());
+        let stats = PARSE_METRICS.read().get(&key).cloned();
+        let stats = match stats {
+            Some(stats) => stats,
+            None => PARSE_METRICS.write().entry(key).or_default().clone(),
+        };
+        stats.record(elapsed, success);
+    }
+
+    /// 设置慢解析告警阈值（微秒），0 表示不告警
+    pub fn set_slow_parse_threshold_us(&self, threshold_us: u64) {
+        SLOW_PARSE_THRESHOLD_US.store(threshold_us, Ordering::Relaxed);
+    }
+
+    /// 记录一笔交易的解析耗时，超过慢解析阈值时输出告警；未开启 enable_metrics 时不记录
+    pub fn record_transaction_parse(
+        &self,
+        signature: &Signature,
+        instruction_count: usize,
+        elapsed: Duration,
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+        let stats = &*TRANSACTION_PARSE_METRICS;
+        let elapsed_ns = elapsed.as_nanos().min(u64::MAX as u128) as u64;
+        stats.transactions.fetch_add(1, Ordering::Relaxed);
+        stats.total_parse_ns.fetch_add(elapsed_ns, Ordering::Relaxed);
+        stats.max_parse_ns.fetch_max(elapsed_ns, Ordering::Relaxed);
+        let elapsed_us = elapsed_ns / 1_000;
+        let bucket = TRANSACTION_PARSE_DURATION_BUCKETS_US
+            .iter()
+            .position(|&upper_us| elapsed_us <= upper_us)
+            .unwrap_or(TRANSACTION_PARSE_DURATION_BUCKETS_US.len());
+        stats.duration_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+
+        let threshold_us = SLOW_PARSE_THRESHOLD_US.load(Ordering::Relaxed);
+        if threshold_us > 0 && elapsed_us > threshold_us {
+            stats.slow_transactions.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                %signature,
+                instruction_count,
+                elapsed_us,
+                threshold_us,
+                "slow transaction parse"
+            );
+        }
+    }
+
+    /// 获取单笔交易解析耗时的统计
+    pub fn get_transaction_parse_metrics(&self) -> TransactionParseSnapshot {
+        let stats = &*TRANSACTION_PARSE_METRICS;
+        let transactions = stats.transactions.load(Ordering::Relaxed);
+        let total_parse_us = stats.total_parse_ns.load(Ordering::Relaxed) as f64 / 1_000.0;
+        TransactionParseSnapshot {
+            transactions,
+            slow_transactions: stats.slow_transactions.load(Ordering::Relaxed),
+            total_parse_us,
+            avg_parse_us: if transactions > 0 { total_parse_us / transactions as f64 } else { 0.0 },
+            max_parse_us: stats.max_parse_ns.load(Ordering::Relaxed) as f64 / 1_000.0,
+            duration_buckets: stats
+                .duration_buckets
+                .iter()
+                .map(|bucket| bucket.load(Ordering::Relaxed))
+                .collect(),
+        }
+    }
+
+    /// 获取按协议和事件类型的解析指标，按累计解析耗时从高到低排列
+    pub fn get_parser_metrics(&self) -> Vec<ParserMetricsSnapshot> {
+        let mut snapshots: Vec<ParserMetricsSnapshot> = PARSE_METRICS
+            .read()
+            .iter()
+            .map(|((protocol, event_type), stats)| {
+                let events_parsed = stats.events_parsed.load(Ordering::Relaxed);
+                let parse_errors = stats.parse_errors.load(Ordering::Relaxed);
+                let total_parse_us = stats.total_parse_ns.load(Ordering::Relaxed) as f64 / 1_000.0;
+                let parses = events_parsed + parse_errors;
+                ParserMetricsSnapshot {
+                    protocol: protocol.clone(),
+                    event_type: event_type.clone(),
+                    events_parsed,
+                    parse_errors,
+                    total_parse_us,
+                    avg_parse_us: if parses > 0 { total_parse_us / parses as f64 } else { 0.0 },
+                    max_parse_us: stats.max_parse_ns.load(Ordering::Relaxed) as f64 / 1_000.0,
+                    duration_buckets: stats
+                        .duration_buckets
+                        .iter()
+                        .map(|bucket| bucket.load(Ordering::Relaxed))
+                        .collect(),
+                }
+            })
+            .collect();
+        snapshots.sort_by(|a, b| b.total_parse_us.total_cmp(&a.total_parse_us));
+        snapshots
+    }
+
+    /// 获取完整指标快照，便于记录日志或导出
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            performance: self.get_metrics(),
+            parsers: self.get_parser_metrics(),
+            transactions: self.get_transaction_parse_metrics(),
+            slot_lag: self.get_slot_lag(),
+            throttle: self.get_throttle_metrics(),
+            x_tokens: self.get_x_token_metrics(),
+            endpoints: self.get_endpoint_metrics(),
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::streaming::event_parser::DexEvent;
+use parking_lot::Mutex;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// 过期交易对的清理间隔
+const SWEEP_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 交易对的价格快照
+///
+/// 价格为 quote/base 的最小单位数量之比，换算为界面价格需要乘以
+/// `10^(base_decimals - quote_decimals)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceQuote {
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    /// 最近一笔交易的成交价
+    pub last_price: f64,
+    /// VWAP 窗口内的成交量加权均价
+    pub vwap: f64,
+    /// VWAP 窗口内的 base 成交量，最小单位
+    pub base_volume: u128,
+    /// VWAP 窗口内的 quote 成交量，最小单位
+    pub quote_volume: u128,
+    /// VWAP 窗口内的交易笔数
+    pub trade_count: usize,
+    /// 最近一笔交易的 slot
+    pub last_slot: u64,
+    /// 最近一笔交易的收到时间
+    pub updated_at: Instant,
+}
+
+/// 单个交易对的滚动状态
+struct PairState {
+    last_price: f64,
+    last_slot: u64,
+    updated_at: Instant,
+    /// VWAP 窗口内的交易: (收到时间, base 数量, quote 数量)
+    trades: VecDeque<(Instant, u64, u64)>,
+    base_volume: u128,
+    quote_volume: u128,
+}
+
+impl PairState {
+    /// 移出 VWAP 窗口之外的交易
+    fn evict(&mut self, now: Instant, window: Duration) {
+        while let Some(&(at, base, quote)) = self.trades.front() {
+            if now.duration_since(at) <= window {
+                break;
+            }
+            self.trades.pop_front();
+            self.base_volume -= base as u128;
+            self.quote_volume -= quote as u128;
+        }
+    }
+
+    fn quote(&self, base_mint: Pubkey, quote_mint: Pubkey) -> PriceQuote {
+        let vwap = if self.base_volume > 0 {
+            self.quote_volume as f64 / self.base_volume as f64
+        } else {
+            self.last_price
+        };
+        PriceQuote {
+            base_mint,
+            quote_mint,
+            last_price: self.last_price,
+            vwap,
+            base_volume: self.base_volume,
+            quote_volume: self.quote_volume,
+            trade_count: self.trades.len(),
+            last_slot: self.last_slot,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+struct PriceFeedState {
+    pairs: HashMap<(Pubkey, Pubkey), PairState>,
+    last_sweep: Instant,
+}
+
+/// 按 (base_mint, quote_mint) 聚合交易事件的价格源
+///
+/// 每个交易事件 (`DexEvent::as_trade`) 更新最新成交价和滚动 VWAP，
+/// 超过 `ttl` 没有交易的交易对被移除。可以通过 `YellowstoneGrpc::set_price_feed`
+/// 挂到订阅上，也可以在自己的回调中调用 `observe`
+///
+/// 失败交易和缺少 mint 或数量的事件 (如只有 lot 的订单簿成交) 不参与统计
+pub struct PriceFeed {
+    ttl: Duration,
+    vwap_window: Duration,
+    state: Mutex<PriceFeedState>,
+}
+
+impl PriceFeed {
+    /// 创建价格源，VWAP 窗口默认与 `ttl` 相同
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            vwap_window: ttl,
+            state: Mutex::new(PriceFeedState { pairs: HashMap::new(), last_sweep: Instant::now() }),
+        }
+    }
+
+    /// 设置 VWAP 的滚动窗口
+    pub fn with_vwap_window(mut self, window: Duration) -> Self {
+        self.vwap_window = window;
+        self
+    }
+
+    /// 用事件更新价格，非交易事件直接忽略
+    pub fn observe(&self, event: &DexEvent) {
+        self.observe_at(event, Instant::now());
+    }
+
+    fn observe_at(&self, event: &DexEvent, now: Instant) {
+        let Some(trade) = event.as_trade() else {
+            return;
+        };
+        let metadata = event.metadata();
+        if !metadata.succeeded {
+            return;
+        }
+        let (base_mint, quote_mint) = (trade.base_mint(), trade.quote_mint());
+        let (base_amount, quote_amount) = (trade.base_amount(), trade.quote_amount());
+        if base_mint == Pubkey::default()
+            || quote_mint == Pubkey::default()
+            || base_amount == 0
+            || quote_amount == 0
+        {
+            return;
+        }
+
+        let mut state = self.state.lock();
+        if now.duration_since(state.last_sweep) >= SWEEP_INTERVAL {
+            state.pairs.retain(|_, pair| now.duration_since(pair.updated_at) <= self.ttl);
+            state.last_sweep = now;
+        }
+        let pair = state.pairs.entry((base_mint, quote_mint)).or_insert_with(|| PairState {
+            last_price: 0.0,
+            last_slot: 0,
+            updated_at: now,
+            trades: VecDeque::new(),
+            base_volume: 0,
+            quote_volume: 0,
+        });
+        pair.last_price = quote_amount as f64 / base_amount as f64;
+        pair.last_slot = metadata.slot;
+        pair.updated_at = now;
+        pair.trades.push_back((now, base_amount, quote_amount));
+        pair.base_volume += base_amount as u128;
+        pair.quote_volume += quote_amount as u128;
+        pair.evict(now, self.vwap_window);
+    }
+
+    /// 获取交易对的价格，没有交易或已过期时返回 None
+    pub fn get_price(&self, base_mint: &Pubkey, quote_mint: &Pubkey) -> Option<PriceQuote> {
+        self.get_price_at(base_mint, quote_mint, Instant::now())
+    }
+
+    fn get_price_at(
+        &self,
+        base_mint: &Pubkey,
+        quote_mint: &Pubkey,
+        now: Instant,
+    ) -> Option<PriceQuote> {
+        let mut state = self.state.lock();
+        let key = (*base_mint, *quote_mint);
+        let pair = state.pairs.get_mut(&key)?;
+        if now.duration_since(pair.updated_at) > self.ttl {
+            state.pairs.remove(&key);
+            return None;
+        }
+        pair.evict(now, self.vwap_window);
+        Some(pair.quote(*base_mint, *quote_mint))
+    }
+
+    /// 所有未过期交易对的价格
+    pub fn prices(&self) -> Vec<PriceQuote> {
+        let now = Instant::now();
+        let mut state = self.state.lock();
+        state.pairs.retain(|_, pair| now.duration_since(pair.updated_at) <= self.ttl);
+        state
+            .pairs
+            .iter_mut()
+            .map(|(&(base_mint, quote_mint), pair)| {
+                pair.evict(now, self.vwap_window);
+                pair.quote(base_mint, quote_mint)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::filter::WSOL_MINT;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+
+    const MINT: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    fn trade(slot: u64, token_amount: u64, sol_amount: u64) -> DexEvent {
+        let mut trade = PumpFunTradeEvent::default();
+        trade.metadata.slot = slot;
+        trade.metadata.succeeded = true;
+        trade.mint = MINT;
+        trade.token_amount = token_amount;
+        trade.sol_amount = sol_amount;
+        DexEvent::PumpFunTradeEvent(trade)
+    }
+
+    #[test]
+    fn vwap_weights_trades_by_volume() {
+        let feed = PriceFeed::new(Duration::from_secs(60));
+        let now = Instant::now();
+        feed.observe_at(&trade(10, 100, 200), now);
+        feed.observe_at(&trade(11, 300, 300), now);
+
+        let quote = feed.get_price_at(&MINT, &WSOL_MINT, now).unwrap();
+        assert_eq!(quote.last_price, 1.0);
+        assert_eq!(quote.vwap, 500.0 / 400.0);
+        assert_eq!((quote.base_volume, quote.quote_volume, quote.trade_count), (400, 500, 2));
+        assert_eq!(quote.last_slot, 11);
+        // 交易对有方向，反向查询没有价格
+        assert!(feed.get_price_at(&WSOL_MINT, &MINT, now).is_none());
+    }
+
+    #[test]
+    fn trades_roll_out_of_the_vwap_window() {
+        let feed = PriceFeed::new(Duration::from_secs(60)).with_vwap_window(Duration::from_secs(5));
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+        feed.observe_at(&trade(10, 100, 400), start);
+        feed.observe_at(&trade(11, 100, 200), at(3));
+
+        let quote = feed.get_price_at(&MINT, &WSOL_MINT, at(5)).unwrap();
+        assert_eq!((quote.vwap, quote.trade_count), (3.0, 2));
+        // 第一笔交易恰好在窗口边界上仍然计入，超过后移出
+        let quote = feed.get_price_at(&MINT, &WSOL_MINT, at(6)).unwrap();
+        assert_eq!((quote.vwap, quote.trade_count), (2.0, 1));
+
+        // 窗口清空后 VWAP 退回最近成交价
+        let quote = feed.get_price_at(&MINT, &WSOL_MINT, at(9)).unwrap();
+        assert_eq!((quote.vwap, quote.trade_count, quote.base_volume), (2.0, 0, 0));
+        assert_eq!(quote.last_price, 2.0);
+    }
+
+    #[test]
+    fn idle_pairs_expire_after_ttl() {
+        let feed = PriceFeed::new(Duration::from_secs(10));
+        let start = Instant::now();
+        feed.observe_at(&trade(10, 100, 100), start);
+        assert!(feed.get_price_at(&MINT, &WSOL_MINT, start + Duration::from_secs(10)).is_some());
+        assert!(feed.get_price_at(&MINT, &WSOL_MINT, start + Duration::from_secs(11)).is_none());
+        assert!(feed.prices().is_empty());
+    }
+
+    #[test]
+    fn failed_and_incomplete_trades_are_ignored() {
+        let feed = PriceFeed::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        let mut failed = trade(10, 100, 100);
+        if let DexEvent::PumpFunTradeEvent(trade) = &mut failed {
+            trade.metadata.succeeded = false;
+        }
+        feed.observe_at(&failed, now);
+        feed.observe_at(&trade(10, 0, 100), now);
+        feed.observe_at(&trade(10, 100, 0), now);
+        let mut no_mint = trade(10, 100, 100);
+        if let DexEvent::PumpFunTradeEvent(trade) = &mut no_mint {
+            trade.mint = Pubkey::default();
+        }
+        feed.observe_at(&no_mint, now);
+
+        assert!(feed.get_price_at(&MINT, &WSOL_MINT, now).is_none());
+        assert!(feed.prices().is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+use super::metrics::{EventMetricsSnapshot, MetricsManager};
+use super::parse_metrics::{
+    MetricsSnapshot, PARSE_DURATION_BUCKETS_US, TRANSACTION_PARSE_DURATION_BUCKETS_US,
+};
+use std::fmt::Write;
+
+/// Prometheus 文本格式的 Content-Type
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+const PREFIX: &str = "solana_streamer";
+
+impl MetricsManager {
+    /// 以 Prometheus 文本格式 (0.0.4) 编码当前指标，可直接作为 `/metrics` 的响应体
+    pub fn encode_prometheus(&self) -> String {
+        encode_prometheus(&self.metrics_snapshot())
+    }
+}
+
+/// 以 Prometheus 文本格式 (0.0.4) 编码指标快照
+pub fn encode_prometheus(snapshot: &MetricsSnapshot) -> String {
+    let mut out = String::with_capacity(4096);
+    let performance = &snapshot.performance;
+    let kinds: [(&str, &EventMetricsSnapshot); 3] = [
+        ("transaction", &performance.tx_metrics),
+        ("account", &performance.account_metrics),
+        ("block_meta", &performance.block_meta_metrics),
+    ];
+
+    header(&mut out, "uptime_seconds", "gauge", "Time since metrics were initialized");
+    let _ = writeln!(out, "{PREFIX}_uptime_seconds {}", performance.uptime.as_secs_f64());
+
+    header(&mut out, "updates_received_total", "counter", "gRPC updates received by kind");
+    for (kind, metrics) in kinds {
+        let _ = writeln!(
+            out,
+            "{PREFIX}_updates_received_total{{kind=\"{kind}\"}} {}",
+            metrics.process_count
+        );
+    }
+
+    header(&mut out, "events_total", "counter", "Events delivered to the callback by kind");
+    for (kind, metrics) in kinds {
+        let _ =
+            writeln!(out, "{PREFIX}_events_total{{kind=\"{kind}\"}} {}", metrics.events_processed);
+    }
+
+    header(&mut out, "dropped_events_total", "counter", "Updates dropped by the overflow policy");
+    let _ = writeln!(out, "{PREFIX}_dropped_events_total {}", performance.dropped_events_count);
+
+    header(&mut out, "duplicate_events_total", "counter", "Events dropped by deduplication");
+    let _ = writeln!(out, "{PREFIX}_duplicate_events_total {}", performance.duplicate_events_count);
+
+    header(
+        &mut out,
+        "oversized_messages_total",
+        "counter",
+        "Updates dropped for exceeding max_decoding_message_size",
+    );
+    let _ =
+        writeln!(out, "{PREFIX}_oversized_messages_total {}", performance.oversized_messages_count);
+
+    header(
+        &mut out,
+        "dropped_parse_errors_total",
+        "counter",
+        "Parse errors dropped because the error stream was full",
+    );
+    let _ = writeln!(
+        out,
+        "{PREFIX}_dropped_parse_errors_total {}",
+        performance.dropped_parse_errors_count
+    );
+
+    header(&mut out, "callback_panics_total", "counter", "Events whose callback panicked");
+    let _ = writeln!(out, "{PREFIX}_callback_panics_total {}", performance.callback_panics_count);
+
+    header(&mut out, "reconnects_total", "counter", "Successful gRPC reconnections");
+    let _ = writeln!(out, "{PREFIX}_reconnects_total {}", performance.reconnect_count);
+
+    header(&mut out, "x_token_connections_total", "counter", "gRPC connections by pooled x_token");
+    for usage in &snapshot.x_tokens {
+        let _ = writeln!(
+            out,
+            "{PREFIX}_x_token_connections_total{{token=\"{}\"}} {}",
+            usage.label, usage.connections
+        );
+    }
+
+    header(
+        &mut out,
+        "x_token_connection_failures_total",
+        "counter",
+        "Failed gRPC connections by pooled x_token",
+    );
+    for usage in &snapshot.x_tokens {
+        let _ = writeln!(
+            out,
+            "{PREFIX}_x_token_connection_failures_total{{token=\"{}\"}} {}",
+            usage.label, usage.failures
+        );
+    }
+
+    header(&mut out, "endpoint_connections_total", "counter", "gRPC connections by endpoint");
+    for health in &snapshot.endpoints {
+        let _ = writeln!(
+            out,
+            "{PREFIX}_endpoint_connections_total{{endpoint=\"{}\"}} {}",
+            health.label, health.connections
+        );
+    }
+
+    header(
+        &mut out,
+        "endpoint_connection_failures_total",
+        "counter",
+        "Failed gRPC connections by endpoint",
+    );
+    for health in &snapshot.endpoints {
+        let _ = writeln!(
+            out,
+            "{PREFIX}_endpoint_connection_failures_total{{endpoint=\"{}\"}} {}",
+            health.label, health.failures
+        );
+    }
+
+    header(
+        &mut out,
+        "endpoint_active_seconds_total",
+        "counter",
+        "Time each gRPC endpoint has been the active one",
+    );
+    for health in &snapshot.endpoints {
+        let _ = writeln!(
+            out,
+            "{PREFIX}_endpoint_active_seconds_total{{endpoint=\"{}\"}} {}",
+            health.label,
+            health.active_time.as_secs_f64()
+        );
+    }
+
+    header(&mut out, "endpoint_active", "gauge", "Whether the gRPC endpoint is the active one");
+    for health in &snapshot.endpoints {
+        let _ = writeln!(
+            out,
+            "{PREFIX}_endpoint_active{{endpoint=\"{}\"}} {}",
+            health.label,
+            u8::from(health.active)
+        );
+    }
+
+    let slot_lag = &snapshot.slot_lag;
+    header(&mut out, "processed_slot", "gauge", "Highest slot delivered to the callback");
+    let _ = writeln!(out, "{PREFIX}_processed_slot {}", slot_lag.processed_slot);
+
+    header(&mut out, "chain_tip_slot", "gauge", "Estimated current slot of the chain");
+    let _ = writeln!(out, "{PREFIX}_chain_tip_slot {}", slot_lag.chain_tip_slot());
+
+    header(&mut out, "slot_lag", "gauge", "Slots between the chain tip and the processed slot");
+    let _ = writeln!(out, "{PREFIX}_slot_lag {}", slot_lag.lag_slots);
+
+    let throttle = &snapshot.throttle;
+    header(&mut out, "events_per_second", "gauge", "Events delivered to the callback per second");
+    let _ = writeln!(out, "{PREFIX}_events_per_second {}", throttle.events_per_second);
+
+    header(&mut out, "throttled_ms_total", "counter", "Time spent paused by the event throttle");
+    let _ = writeln!(out, "{PREFIX}_throttled_ms_total {}", throttle.throttled_ms);
+
+    header(&mut out, "throttle_breaches_total", "counter", "Unsustainable event rate breaches");
+    let _ = writeln!(out, "{PREFIX}_throttle_breaches_total {}", throttle.breaches);
+
+    header(&mut out, "events_parsed_total", "counter", "Events parsed by protocol and event type");
+    for parser in &snapshot.parsers {
+        let labels = parser_labels(&parser.protocol, &parser.event_type);
+        let _ = writeln!(out, "{PREFIX}_events_parsed_total{{{labels}}} {}", parser.events_parsed);
+    }
+
+    header(&mut out, "parse_errors_total", "counter", "Recognized instructions that failed to decode");
+    for parser in &snapshot.parsers {
+        let labels = parser_labels(&parser.protocol, &parser.event_type);
+        let _ = writeln!(out, "{PREFIX}_parse_errors_total{{{labels}}} {}", parser.parse_errors);
+    }
+
+    header(&mut out, "parse_duration_seconds", "histogram", "Instruction and account parse time");
+    for parser in &snapshot.parsers {
+        let labels = parser_labels(&parser.protocol, &parser.event_type);
+        let mut cumulative = 0;
+        for (upper_us, count) in PARSE_DURATION_BUCKETS_US.iter().zip(&parser.duration_buckets) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{PREFIX}_parse_duration_seconds_bucket{{{labels},le=\"{}\"}} {cumulative}",
+                *upper_us as f64 / 1_000_000.0
+            );
+        }
+        let total: u64 = parser.duration_buckets.iter().sum();
+        let _ = writeln!(
+            out,
+            "{PREFIX}_parse_duration_seconds_bucket{{{labels},le=\"+Inf\"}} {total}"
+        );
+        let _ = writeln!(
+            out,
+            "{PREFIX}_parse_duration_seconds_sum{{{labels}}} {}",
+            parser.total_parse_us / 1_000_000.0
+        );
+        let _ = writeln!(out, "{PREFIX}_parse_duration_seconds_count{{{labels}}} {total}");
+    }
+
+    let transactions = &snapshot.transactions;
+    header(
+        &mut out,
+        "transaction_parse_duration_seconds",
+        "histogram",
+        "Parse time per transaction",
+    );
+    let mut cumulative = 0;
+    for (upper_us, count) in
+        TRANSACTION_PARSE_DURATION_BUCKETS_US.iter().zip(&transactions.duration_buckets)
+    {
+        cumulative += count;
+        let _ = writeln!(
+            out,
+            "{PREFIX}_transaction_parse_duration_seconds_bucket{{le=\"{}\"}} {cumulative}",
+            *upper_us as f64 / 1_000_000.0
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{PREFIX}_transaction_parse_duration_seconds_bucket{{le=\"+Inf\"}} {}",
+        transactions.transactions
+    );
+    let _ = writeln!(
+        out,
+        "{PREFIX}_transaction_parse_duration_seconds_sum {}",
+        transactions.total_parse_us / 1_000_000.0
+    );
+    let _ = writeln!(
+        out,
+        "{PREFIX}_transaction_parse_duration_seconds_count {}",
+        transactions.transactions
+    );
+
+    header(
+        &mut out,
+        "slow_transaction_parses_total",
+        "counter",
+        "Transactions that took longer than the slow parse threshold",
+    );
+    let _ =
+        writeln!(out, "{PREFIX}_slow_transaction_parses_total {}", transactions.slow_transactions);
+
+    out
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {PREFIX}_{name} {help}");
+    let _ = writeln!(out, "# TYPE {PREFIX}_{name} {kind}");
+}
+
+fn parser_labels(protocol: &impl std::fmt::Debug, event_type: &impl std::fmt::Debug) -> String {
+    format!("protocol=\"{protocol:?}\",event_type=\"{event_type:?}\"")
+}
@@ -0,0 +1,47 @@
+use parking_lot::Mutex;
+use prost::Message;
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::Path;
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+
+/// 原始 `SubscribeUpdate` 录制器
+///
+/// 每条消息以 protobuf length-delimited 格式追加写入文件，可通过
+/// `YellowstoneGrpc::subscribe_events_from_file` 离线回放
+pub struct UpdateRecorder {
+    writer: Mutex<BufWriter<File>>,
+}
+
+impl UpdateRecorder {
+    /// 创建录制文件，已存在时覆盖
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self { writer: Mutex::new(BufWriter::new(File::create(path)?)) })
+    }
+
+    /// 写入一条消息
+    pub fn record(&self, update: &SubscribeUpdate) -> std::io::Result<()> {
+        let buf = update.encode_length_delimited_to_vec();
+        self.writer.lock().write_all(&buf)
+    }
+
+    /// 将缓冲区写入文件；录制器被丢弃时也会自动写入
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.writer.lock().flush()
+    }
+}
+
+/// 读取录制文件中的全部消息，顺序与录制时一致
+pub fn read_recorded_updates(path: impl AsRef<Path>) -> std::io::Result<Vec<SubscribeUpdate>> {
+    let mut bytes = Vec::new();
+    File::open(path)?.read_to_end(&mut bytes)?;
+
+    let mut buf = bytes.as_slice();
+    let mut updates = Vec::new();
+    while !buf.is_empty() {
+        let update = SubscribeUpdate::decode_length_delimited(&mut buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        updates.push(update);
+    }
+    Ok(updates)
+}
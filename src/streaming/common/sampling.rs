@@ -0,0 +1,93 @@
+use super::config::SamplingConfig;
+use crate::streaming::event_parser::common::EventType;
+use crate::streaming::event_parser::DexEvent;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// 按事件类型 1/N 采样
+///
+/// 计数模式下每种类型保留第 1、N+1、2N+1... 个事件；确定性模式下按交易签名取模，
+/// 同一笔交易的结果在每次运行中都相同，且同一交易中同类型的事件一起保留或丢弃
+pub struct EventSampler {
+    /// 事件类型 -> (N, 计数器)
+    rates: HashMap<EventType, (u64, AtomicU64)>,
+    deterministic: bool,
+}
+
+impl EventSampler {
+    /// 没有需要采样的类型时返回 None
+    pub fn new(config: &SamplingConfig) -> Option<Self> {
+        let rates: HashMap<_, _> = config
+            .rates
+            .iter()
+            .filter(|(_, &rate)| rate > 1)
+            .map(|(event_type, &rate)| (event_type.clone(), (rate, AtomicU64::new(0))))
+            .collect();
+        (!rates.is_empty()).then_some(Self { rates, deterministic: config.deterministic })
+    }
+
+    /// 事件是否保留，未配置采样的类型始终保留
+    pub fn keep(&self, event: &DexEvent) -> bool {
+        let metadata = event.metadata();
+        let Some((rate, counter)) = self.rates.get(&metadata.event_type) else {
+            return true;
+        };
+        let n = if self.deterministic {
+            // 签名本身是均匀分布的，直接取前 8 字节，不依赖带随机种子的哈希
+            let bytes = metadata.signature.as_ref();
+            u64::from_le_bytes(bytes[..8].try_into().unwrap_or_default())
+        } else {
+            counter.fetch_add(1, Ordering::Relaxed)
+        };
+        n % rate == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+    use solana_sdk::signature::Signature;
+
+    fn trade(event_type: EventType, signature: u8) -> DexEvent {
+        let mut trade = PumpFunTradeEvent::default();
+        trade.metadata.event_type = event_type;
+        trade.metadata.signature = Signature::from([signature; 64]);
+        DexEvent::PumpFunTradeEvent(trade)
+    }
+
+    fn config(rates: &[(EventType, u64)], deterministic: bool) -> SamplingConfig {
+        SamplingConfig { rates: rates.iter().cloned().collect(), deterministic }
+    }
+
+    #[test]
+    fn rates_of_zero_or_one_disable_sampling() {
+        assert!(EventSampler::new(&SamplingConfig::default()).is_none());
+        let config = config(&[(EventType::PumpFunBuy, 0), (EventType::PumpFunSell, 1)], false);
+        assert!(EventSampler::new(&config).is_none());
+    }
+
+    #[test]
+    fn counter_mode_keeps_one_in_n_per_type() {
+        let sampler = EventSampler::new(&config(&[(EventType::PumpFunBuy, 3)], false)).unwrap();
+        let kept: Vec<bool> =
+            (0..7).map(|i| sampler.keep(&trade(EventType::PumpFunBuy, i))).collect();
+        assert_eq!(kept, vec![true, false, false, true, false, false, true]);
+        // 未配置的类型不占用计数器，始终保留
+        assert!((0..5).all(|i| sampler.keep(&trade(EventType::PumpFunSell, i))));
+        assert!(!sampler.keep(&trade(EventType::PumpFunBuy, 0)));
+    }
+
+    #[test]
+    fn deterministic_mode_decides_by_signature() {
+        let sampler = EventSampler::new(&config(&[(EventType::PumpFunBuy, 2)], true)).unwrap();
+        // 签名前 8 字节按小端序为 0x0202..02 (偶数) 和 0x0303..03 (奇数)
+        let even = trade(EventType::PumpFunBuy, 2);
+        let odd = trade(EventType::PumpFunBuy, 3);
+        for _ in 0..3 {
+            assert!(sampler.keep(&even));
+            assert!(!sampler.keep(&odd));
+        }
+        assert!(sampler.keep(&trade(EventType::PumpFunSell, 3)));
+    }
+}
@@ -0,0 +1,195 @@
+use super::config::SlotLagConfig;
+use super::constants::SLOT_DURATION_MS;
+use super::metrics::MetricsManager;
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
+use crate::streaming::event_parser::DexEvent;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
+
+/// slot 延迟的全局状态
+struct SlotLagState {
+    /// 读取任务收到的最大 slot
+    latest_seen_slot: AtomicU64,
+    /// 解析任务处理完成的最大 slot
+    processed_slot: AtomicU64,
+    /// 最近一条 block meta 的 (slot, 出块时间秒)，用于按墙上时间估算当前 slot
+    block_time_anchor: Mutex<Option<(u64, i64)>>,
+}
+
+static SLOT_LAG: SlotLagState = SlotLagState {
+    latest_seen_slot: AtomicU64::new(0),
+    processed_slot: AtomicU64::new(0),
+    block_time_anchor: Mutex::new(None),
+};
+
+/// slot 延迟快照
+#[derive(Debug, Clone, Default)]
+pub struct SlotLagSnapshot {
+    /// 最近处理完成（已推送给回调）的最大 slot，尚未处理任何消息时为 0
+    pub processed_slot: u64,
+    /// gRPC 流上收到的最大 slot
+    pub latest_seen_slot: u64,
+    /// 按最近 block meta 的出块时间和墙上时间估算的当前 slot，未订阅 block meta 时为 None
+    pub expected_slot: Option<u64>,
+    /// `max(latest_seen_slot, expected_slot) - processed_slot`
+    pub lag_slots: u64,
+}
+
+impl SlotLagSnapshot {
+    /// 估算的链上最新 slot
+    pub fn chain_tip_slot(&self) -> u64 {
+        self.expected_slot.unwrap_or(0).max(self.latest_seen_slot)
+    }
+}
+
+/// 消息对应的 slot
+pub(crate) fn update_slot(update: &UpdateOneof) -> Option<u64> {
+    match update {
+        UpdateOneof::Account(account) => Some(account.slot),
+        UpdateOneof::Transaction(transaction) => Some(transaction.slot),
+        UpdateOneof::BlockMeta(block_meta) => Some(block_meta.slot),
+        UpdateOneof::Block(block) => Some(block.slot),
+        UpdateOneof::Slot(slot) => Some(slot.slot),
+        UpdateOneof::Entry(entry) => Some(entry.slot),
+        _ => None,
+    }
+}
+
+impl MetricsManager {
+    /// 读取任务收到消息时记录链上进度，block meta 同时更新出块时间锚点
+    #[inline]
+    pub fn observe_seen_update(&self, update: &UpdateOneof) {
+        let Some(slot) = update_slot(update) else {
+            return;
+        };
+        SLOT_LAG.latest_seen_slot.fetch_max(slot, Ordering::Relaxed);
+        if let UpdateOneof::BlockMeta(block_meta) = update {
+            if let Some(block_time) = &block_meta.block_time {
+                let mut anchor = SLOT_LAG.block_time_anchor.lock();
+                if anchor.is_none_or(|(anchor_slot, _)| slot >= anchor_slot) {
+                    *anchor = Some((slot, block_time.timestamp));
+                }
+            }
+        }
+    }
+
+    /// 解析任务处理完一条消息后记录处理进度
+    #[inline]
+    pub fn observe_processed_slot(&self, slot: u64) {
+        SLOT_LAG.processed_slot.fetch_max(slot, Ordering::Relaxed);
+    }
+
+    /// 获取当前 slot 延迟
+    ///
+    /// 出块时间只精确到秒且由验证者投票估算，`expected_slot` 可能有数个 slot 的误差
+    pub fn get_slot_lag(&self) -> SlotLagSnapshot {
+        let processed_slot = SLOT_LAG.processed_slot.load(Ordering::Relaxed);
+        let latest_seen_slot = SLOT_LAG.latest_seen_slot.load(Ordering::Relaxed);
+        let expected_slot = (*SLOT_LAG.block_time_anchor.lock()).map(|(slot, block_time)| {
+            let elapsed_ms =
+                (get_high_perf_clock() / 1_000).saturating_sub(block_time.saturating_mul(1_000));
+            slot.saturating_add((elapsed_ms.max(0) / SLOT_DURATION_MS) as u64)
+        });
+        let mut snapshot =
+            SlotLagSnapshot { processed_slot, latest_seen_slot, expected_slot, lag_slots: 0 };
+        if processed_slot > 0 {
+            snapshot.lag_slots = snapshot.chain_tip_slot().saturating_sub(processed_slot);
+        }
+        snapshot
+    }
+}
+
+/// slot 延迟告警
+///
+/// 延迟超过阈值时生成一次 `SlotLagEvent`，回落到阈值一半以下后重新开始检测，避免在阈值附近反复告警
+pub struct SlotLagMonitor {
+    threshold_slots: u64,
+    lagging: AtomicBool,
+}
+
+impl SlotLagMonitor {
+    /// 未开启 `warn` 时返回 None
+    pub fn new(config: &SlotLagConfig) -> Option<Self> {
+        config.warn.then(|| Self {
+            threshold_slots: config.threshold_slots,
+            lagging: AtomicBool::new(false),
+        })
+    }
+
+    /// 检查当前延迟，刚超过阈值时返回告警事件
+    pub fn check(&self) -> Option<DexEvent> {
+        self.check_lag(&MetricsManager::global().get_slot_lag())
+    }
+
+    fn check_lag(&self, lag: &SlotLagSnapshot) -> Option<DexEvent> {
+        if lag.lag_slots > self.threshold_slots {
+            if self.lagging.swap(true, Ordering::Relaxed) {
+                return None;
+            }
+            log::warn!(
+                "Stream is {} slots behind the chain tip (processed {}, tip {})",
+                lag.lag_slots,
+                lag.processed_slot,
+                lag.chain_tip_slot()
+            );
+            return Some(CommonEventParser::generate_slot_lag_event(
+                lag.processed_slot,
+                lag.chain_tip_slot(),
+                lag.lag_slots,
+                self.threshold_slots,
+                get_high_perf_clock(),
+            ));
+        }
+        if lag.lag_slots <= self.threshold_slots / 2 {
+            self.lagging.store(false, Ordering::Relaxed);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lag(processed_slot: u64, latest_seen_slot: u64) -> SlotLagSnapshot {
+        SlotLagSnapshot {
+            processed_slot,
+            latest_seen_slot,
+            expected_slot: None,
+            lag_slots: latest_seen_slot - processed_slot,
+        }
+    }
+
+    #[test]
+    fn chain_tip_is_the_later_of_seen_and_expected_slots() {
+        let mut snapshot = lag(100, 110);
+        assert_eq!(snapshot.chain_tip_slot(), 110);
+        snapshot.expected_slot = Some(120);
+        assert_eq!(snapshot.chain_tip_slot(), 120);
+        snapshot.expected_slot = Some(105);
+        assert_eq!(snapshot.chain_tip_slot(), 110);
+    }
+
+    #[test]
+    fn warns_once_until_lag_drops_below_half_the_threshold() {
+        assert!(SlotLagMonitor::new(&SlotLagConfig { warn: false, threshold_slots: 10 }).is_none());
+        let monitor =
+            SlotLagMonitor::new(&SlotLagConfig { warn: true, threshold_slots: 10 }).unwrap();
+        assert!(monitor.check_lag(&lag(100, 110)).is_none());
+
+        let Some(DexEvent::SlotLagEvent(event)) = monitor.check_lag(&lag(100, 111)) else {
+            panic!("expected a slot lag event")
+        };
+        assert_eq!((event.processed_slot, event.chain_tip_slot), (100, 111));
+        assert_eq!((event.lag_slots, event.threshold_slots), (11, 10));
+
+        // 仍在阈值之上或只回落到一半以上时不重复告警
+        assert!(monitor.check_lag(&lag(100, 130)).is_none());
+        assert!(monitor.check_lag(&lag(100, 106)).is_none());
+        assert!(monitor.check_lag(&lag(100, 111)).is_none());
+        assert!(monitor.check_lag(&lag(100, 105)).is_none());
+        assert!(monitor.check_lag(&lag(100, 111)).is_some());
+    }
+}
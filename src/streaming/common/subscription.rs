@@ -1,3 +1,6 @@
+use log::warn;
+use std::time::Duration;
+use tokio::sync::watch;
 use tokio::task::JoinHandle;
 
 /// Subscription handle for managing and stopping subscriptions
@@ -5,6 +8,7 @@ pub struct SubscriptionHandle {
     stream_handle: JoinHandle<()>,
     event_handle: Option<JoinHandle<()>>,
     metrics_handle: Option<JoinHandle<()>>,
+    shutdown_tx: Option<watch::Sender<bool>>,
 }
 
 impl SubscriptionHandle {
@@ -14,7 +18,13 @@ impl SubscriptionHandle {
         event_handle: Option<JoinHandle<()>>,
         metrics_handle: Option<JoinHandle<()>>,
     ) -> Self {
-        Self { stream_handle, event_handle, metrics_handle }
+        Self { stream_handle, event_handle, metrics_handle, shutdown_tx: None }
+    }
+
+    /// Attach a shutdown signal watched by the stream task, enabling graceful `shutdown`
+    pub fn with_shutdown(mut self, shutdown_tx: watch::Sender<bool>) -> Self {
+        self.shutdown_tx = Some(shutdown_tx);
+        self
     }
 
     /// Stop subscription and abort all related tasks
@@ -28,6 +38,43 @@ impl SubscriptionHandle {
         }
     }
 
+    /// Stop subscription gracefully
+    ///
+    /// Signals the stream task to stop reading new updates and waits for it and the event task
+    /// to finish processing the updates already received. Both tasks are aborted if they do not
+    /// finish within `timeout`. Without a shutdown signal this is the same as `stop`.
+    pub async fn shutdown(self, timeout: Duration) {
+        let mut stream_handle = self.stream_handle;
+        let mut event_handle = self.event_handle;
+        match self.shutdown_tx {
+            Some(shutdown_tx) if shutdown_tx.send(true).is_ok() => {
+                let drained = tokio::time::timeout(timeout, async {
+                    let _ = (&mut stream_handle).await;
+                    if let Some(handle) = event_handle.as_mut() {
+                        let _ = handle.await;
+                    }
+                })
+                .await;
+                if drained.is_err() {
+                    warn!("Subscription did not drain within {timeout:?}, aborting");
+                    stream_handle.abort();
+                }
+            }
+            _ => stream_handle.abort(),
+        }
+        if let Some(handle) = event_handle {
+            handle.abort();
+        }
+        if let Some(handle) = self.metrics_handle {
+            handle.abort();
+        }
+    }
+
+    /// Whether the stream task has finished (stopped, or the stream ended on its own)
+    pub fn is_finished(&self) -> bool {
+        self.stream_handle.is_finished()
+    }
+
     /// Asynchronously wait for all tasks to complete
     pub async fn join(self) -> Result<(), tokio::task::JoinError> {
         let _ = self.stream_handle.await;
@@ -0,0 +1,231 @@
+use super::config::ThrottleConfig;
+use super::metrics::MetricsManager;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// 允许的突发量：最多提前消耗一秒的额度
+const THROTTLE_BURST: Duration = Duration::from_secs(1);
+
+/// 事件速率的全局状态
+struct ThrottleMetricsState {
+    /// 当前一秒窗口的 (起始时间, 事件数)
+    window: Mutex<Option<(Instant, u64)>>,
+    /// 上一个完整窗口的事件速率
+    events_per_second: AtomicU64,
+    /// 限速累计暂停时长（毫秒）
+    throttled_ms: AtomicU64,
+    /// 持续限速超过 `max_pause_ms` 的次数
+    breaches: AtomicU64,
+}
+
+static THROTTLE_METRICS: ThrottleMetricsState = ThrottleMetricsState {
+    window: Mutex::new(None),
+    events_per_second: AtomicU64::new(0),
+    throttled_ms: AtomicU64::new(0),
+    breaches: AtomicU64::new(0),
+};
+
+/// 事件速率和限速快照
+#[derive(Debug, Clone, Default)]
+pub struct ThrottleSnapshot {
+    /// 最近一秒推送给回调的事件数，只在开启 enable_metrics 或限速时统计
+    pub events_per_second: u64,
+    /// 限速累计暂停的时长（毫秒）
+    pub throttled_ms: u64,
+    /// 持续限速超过 `ThrottleConfig::max_pause_ms` 的次数
+    pub breaches: u64,
+}
+
+impl MetricsManager {
+    /// 记录推送给回调的事件，用于统计事件速率
+    #[inline]
+    pub fn record_delivered_events(&self, count: u64) {
+        let now = Instant::now();
+        let mut window = THROTTLE_METRICS.window.lock();
+        if let Some((start, events)) = window.as_mut() {
+            let elapsed = now.duration_since(*start);
+            if elapsed < Duration::from_secs(1) {
+                *events += count;
+                return;
+            }
+            let rate = (*events as f64 / elapsed.as_secs_f64()).round() as u64;
+            THROTTLE_METRICS.events_per_second.store(rate, Ordering::Relaxed);
+        }
+        *window = Some((now, count));
+    }
+
+    /// 获取事件速率和限速统计
+    pub fn get_throttle_metrics(&self) -> ThrottleSnapshot {
+        // 长时间没有事件时，上一个窗口的速率已经过时
+        let stale = (*THROTTLE_METRICS.window.lock())
+            .is_none_or(|(start, _)| start.elapsed() >= Duration::from_secs(2));
+        ThrottleSnapshot {
+            events_per_second: if stale {
+                0
+            } else {
+                THROTTLE_METRICS.events_per_second.load(Ordering::Relaxed)
+            },
+            throttled_ms: THROTTLE_METRICS.throttled_ms.load(Ordering::Relaxed),
+            breaches: THROTTLE_METRICS.breaches.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// `EventThrottle::check` 的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleAction {
+    /// 未超过速率上限
+    Continue,
+    /// 处理下一条消息前暂停指定时长
+    Pause(Duration),
+    /// 持续限速刚超过 `max_pause_ms`，同样需要暂停
+    Breach(Duration),
+}
+
+struct ThrottleState {
+    /// 已推送事件的额度用完的时间点
+    next_free: Instant,
+    /// 本轮持续限速的开始时间，未限速时为 None
+    throttled_since: Option<Instant>,
+    /// 本轮持续限速是否已报告超限
+    breached: bool,
+}
+
+/// 事件速率上限
+///
+/// 按 GCRA 计算：每个推送给回调的事件占用 `1 / max_events_per_sec` 秒的额度，最多提前消耗
+/// 一秒。超出时解析任务在处理下一条消息前暂停，队列写满后读取任务随之暂停读取 gRPC 流。
+/// 限速持续 `max_pause_ms` 仍未追上 (剩余额度回到半秒以上) 时报告超限，追上后才会再次报告
+pub struct EventThrottle {
+    /// 每个事件占用的额度
+    interval: Duration,
+    max_pause: Duration,
+    stop_on_breach: bool,
+    state: Mutex<ThrottleState>,
+}
+
+impl EventThrottle {
+    /// 未设置 `max_events_per_sec` 时返回 None
+    pub fn new(config: &ThrottleConfig) -> Option<Self> {
+        (config.max_events_per_sec > 0).then(|| Self {
+            interval: Duration::from_nanos(1_000_000_000 / config.max_events_per_sec),
+            max_pause: Duration::from_millis(config.max_pause_ms),
+            stop_on_breach: config.stop_on_breach,
+            state: Mutex::new(ThrottleState {
+                next_free: Instant::now(),
+                throttled_since: None,
+                breached: false,
+            }),
+        })
+    }
+
+    /// 超限时是否停止订阅
+    pub fn stop_on_breach(&self) -> bool {
+        self.stop_on_breach
+    }
+
+    /// 记录推送给回调的事件
+    pub fn record(&self, count: u64) {
+        self.record_at(count, Instant::now());
+    }
+
+    fn record_at(&self, count: u64, now: Instant) {
+        let cost = self.interval.saturating_mul(u32::try_from(count).unwrap_or(u32::MAX));
+        let mut state = self.state.lock();
+        state.next_free = state.next_free.max(now) + cost;
+    }
+
+    /// 检查是否需要在处理下一条消息前暂停
+    pub fn check(&self) -> ThrottleAction {
+        self.check_at(Instant::now())
+    }
+
+    fn check_at(&self, now: Instant) -> ThrottleAction {
+        let mut state = self.state.lock();
+        let pause = state.next_free.saturating_duration_since(now + THROTTLE_BURST);
+        if pause.is_zero() {
+            // 刚暂停完时额度恰好用完，剩余额度超过一半才视为追上
+            if state.next_free <= now + THROTTLE_BURST / 2 {
+                state.throttled_since = None;
+                state.breached = false;
+            }
+            return ThrottleAction::Continue;
+        }
+        let throttled_since = *state.throttled_since.get_or_insert(now);
+        if !state.breached && now.duration_since(throttled_since) >= self.max_pause {
+            state.breached = true;
+            THROTTLE_METRICS.breaches.fetch_add(1, Ordering::Relaxed);
+            return ThrottleAction::Breach(pause);
+        }
+        ThrottleAction::Pause(pause)
+    }
+
+    /// 暂停指定时长并计入统计
+    pub async fn pause(&self, pause: Duration) {
+        tokio::time::sleep(pause).await;
+        THROTTLE_METRICS.throttled_ms.fetch_add(pause.as_millis() as u64, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn throttle(max_events_per_sec: u64, max_pause_ms: u64) -> EventThrottle {
+        let config = ThrottleConfig { max_events_per_sec, max_pause_ms, stop_on_breach: false };
+        EventThrottle::new(&config).unwrap()
+    }
+
+    #[test]
+    fn disabled_without_a_rate() {
+        let config =
+            ThrottleConfig { max_events_per_sec: 0, max_pause_ms: 0, stop_on_breach: true };
+        assert!(EventThrottle::new(&config).is_none());
+    }
+
+    #[test]
+    fn one_second_burst_is_allowed_then_pauses() {
+        let throttle = throttle(1_000, 60_000);
+        let start = Instant::now();
+        throttle.record_at(1_000, start);
+        assert_eq!(throttle.check_at(start), ThrottleAction::Continue);
+
+        throttle.record_at(100, start);
+        assert_eq!(throttle.check_at(start), ThrottleAction::Pause(Duration::from_millis(100)));
+        // 额度随时间恢复
+        let later = start + Duration::from_millis(100);
+        assert_eq!(throttle.check_at(later), ThrottleAction::Continue);
+    }
+
+    #[test]
+    fn idle_time_does_not_accumulate_more_than_the_burst() {
+        let throttle = throttle(1_000, 60_000);
+        let start = Instant::now() + Duration::from_secs(10);
+        throttle.record_at(1_500, start);
+        assert_eq!(throttle.check_at(start), ThrottleAction::Pause(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn breach_is_reported_once_per_throttled_period() {
+        let throttle = throttle(1_000, 50);
+        let start = Instant::now();
+        throttle.record_at(3_000, start);
+        assert!(matches!(throttle.check_at(start), ThrottleAction::Pause(_)));
+        let at = |ms| start + Duration::from_millis(ms);
+        assert!(matches!(throttle.check_at(at(49)), ThrottleAction::Pause(_)));
+        assert_eq!(throttle.check_at(at(50)), ThrottleAction::Breach(Duration::from_millis(1_950)));
+        assert!(matches!(throttle.check_at(at(60)), ThrottleAction::Pause(_)));
+
+        // 额度刚好用完还不算追上，再次限速时不重复报告
+        assert_eq!(throttle.check_at(at(2_000)), ThrottleAction::Continue);
+        throttle.record_at(100, at(2_000));
+        assert_eq!(throttle.check_at(at(2_000)), ThrottleAction::Pause(Duration::from_millis(100)));
+
+        // 剩余额度回到一半以上后重新计时
+        assert_eq!(throttle.check_at(at(2_600)), ThrottleAction::Continue);
+        throttle.record_at(2_000, at(2_600));
+        assert!(matches!(throttle.check_at(at(2_600)), ThrottleAction::Pause(_)));
+        assert!(matches!(throttle.check_at(at(2_650)), ThrottleAction::Breach(_)));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::streaming::event_parser::{common::TradeTokenInfo, DexEvent};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// 代币的符号和精度
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// mint 到代币符号和精度的查询，用于为交易事件填充 `metadata.token_info`
+///
+/// 可以通过 `YellowstoneGrpc::set_token_registry` 挂到订阅上，也可以在自己的回调中调用
+/// `enrich_event`；`HashMap<Pubkey, TokenInfo>` 可以直接作为注册表使用
+pub trait TokenRegistry: Send + Sync {
+    /// 查询 mint，不在注册表中时返回 None
+    fn lookup(&self, mint: &Pubkey) -> Option<TokenInfo>;
+}
+
+impl TokenRegistry for HashMap<Pubkey, TokenInfo> {
+    fn lookup(&self, mint: &Pubkey) -> Option<TokenInfo> {
+        self.get(mint).cloned()
+    }
+}
+
+/// 用注册表为交易事件填充 `metadata.token_info`，其他事件不做处理
+pub fn enrich_event(registry: &dyn TokenRegistry, event: &mut DexEvent) {
+    let Some(trade) = event.as_trade() else {
+        return;
+    };
+    let base = registry.lookup(&trade.base_mint());
+    let quote = registry.lookup(&trade.quote_mint());
+    let (base_amount, quote_amount) = (trade.base_amount(), trade.quote_amount());
+    let info = TradeTokenInfo {
+        base_decimals: base.as_ref().map(|token| token.decimals),
+        base_ui_amount: base.as_ref().map(|token| format_ui_amount(base_amount, token.decimals)),
+        base_symbol: base.map(|token| token.symbol),
+        quote_decimals: quote.as_ref().map(|token| token.decimals),
+        quote_ui_amount: quote.as_ref().map(|token| format_ui_amount(quote_amount, token.decimals)),
+        quote_symbol: quote.map(|token| token.symbol),
+    };
+    event.metadata_mut().token_info = Some(info);
+}
+
+/// 按精度把最小单位数量换算为十进制字符串，去掉小数部分末尾的 0
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let digits = amount.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+    let padded = format!("{digits:0>width$}", width = decimals + 1);
+    let (integer, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::filter::WSOL_MINT;
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+
+    #[test]
+    fn ui_amounts_are_scaled_and_trimmed() {
+        assert_eq!(format_ui_amount(1_500_000, 6), "1.5");
+        assert_eq!(format_ui_amount(2_000_000, 6), "2");
+        assert_eq!(format_ui_amount(1, 9), "0.000000001");
+        assert_eq!(format_ui_amount(0, 9), "0");
+        assert_eq!(format_ui_amount(123, 0), "123");
+        assert_eq!(format_ui_amount(u64::MAX, 9), "18446744073.709551615");
+    }
+
+    #[test]
+    fn trades_are_enriched_with_known_mints_only() {
+        let registry: HashMap<Pubkey, TokenInfo> =
+            [(WSOL_MINT, TokenInfo { symbol: "SOL".to_string(), decimals: 9 })].into();
+        let trade = PumpFunTradeEvent {
+            mint: Pubkey::new_from_array([7; 32]),
+            token_amount: 1_000_000,
+            sol_amount: 250_000_000,
+            ..Default::default()
+        };
+        let mut event = DexEvent::PumpFunTradeEvent(trade);
+
+        enrich_event(&registry, &mut event);
+        assert_eq!(
+            event.metadata().token_info,
+            Some(TradeTokenInfo {
+                base_symbol: None,
+                base_decimals: None,
+                base_ui_amount: None,
+                quote_symbol: Some("SOL".to_string()),
+                quote_decimals: Some(9),
+                quote_ui_amount: Some("0.25".to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::streaming::event_parser::core::account_event_parser::TransferFeeConfig;
+use crate::streaming::event_parser::{common::TransferFeeAmounts, DexEvent};
+use parking_lot::RwLock;
+use solana_sdk::clock::DEFAULT_SLOTS_PER_EPOCH;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+
+/// Token-2022 mint 转账手续费配置的缓存，用于计算交易事件扣除手续费后的实际数量
+///
+/// 配置来自 mint 账户：订阅 Token-2022 mint 账户时 `observe` 从 `TokenInfoEvent` 中读取，
+/// 也可以用 `insert` 手动写入 (如启动时通过 RPC 查询)。可以通过
+/// `YellowstoneGrpc::set_transfer_fee_cache` 挂到订阅上，为交易事件填充
+/// `metadata.transfer_fee_amounts`；没有配置的 mint 按没有转账手续费处理，净额等于总额
+///
+/// 新旧手续费按事件 slot 所在的 epoch 选择，默认使用主网的每 epoch slot 数
+pub struct TransferFeeCache {
+    slots_per_epoch: u64,
+    mints: RwLock<HashMap<Pubkey, TransferFeeConfig>>,
+}
+
+impl Default for TransferFeeCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransferFeeCache {
+    pub fn new() -> Self {
+        Self { slots_per_epoch: DEFAULT_SLOTS_PER_EPOCH, mints: RwLock::default() }
+    }
+
+    /// 设置每个 epoch 的 slot 数，用于 devnet 等 epoch 长度不同的集群
+    pub fn with_slots_per_epoch(mut self, slots_per_epoch: u64) -> Self {
+        self.slots_per_epoch = slots_per_epoch.max(1);
+        self
+    }
+
+    /// 写入 mint 的转账手续费配置
+    pub fn insert(&self, mint: Pubkey, config: TransferFeeConfig) {
+        self.mints.write().insert(mint, config);
+    }
+
+    /// 移除 mint 的配置，之后按没有转账手续费处理
+    pub fn remove(&self, mint: &Pubkey) {
+        self.mints.write().remove(mint);
+    }
+
+    /// mint 的转账手续费配置，未知或没有转账手续费时返回 None
+    pub fn get(&self, mint: &Pubkey) -> Option<TransferFeeConfig> {
+        self.mints.read().get(mint).copied()
+    }
+
+    /// 已缓存配置的 mint 数
+    pub fn len(&self) -> usize {
+        self.mints.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mints.read().is_empty()
+    }
+
+    /// 从 mint 账户事件更新配置，其他事件直接忽略
+    pub fn observe(&self, event: &DexEvent) {
+        let DexEvent::TokenInfoEvent(info) = event else {
+            return;
+        };
+        match info.transfer_fee {
+            Some(config) => self.insert(info.pubkey, config),
+            None => self.remove(&info.pubkey),
+        }
+    }
+
+    /// 在 `slot` 转账 `amount` 个 `mint` 后实际到账的数量，未知配置时返回 `amount`
+    pub fn net_amount(&self, mint: &Pubkey, amount: u64, slot: u64) -> u64 {
+        let Some(config) = self.get(mint) else {
+            return amount;
+        };
+        // slot 未知时按最新的手续费计算
+        let fee = if slot == 0 {
+            &config.newer_transfer_fee
+        } else {
+            config.epoch_fee(slot / self.slots_per_epoch)
+        };
+        amount.saturating_sub(fee.calculate_fee(amount))
+    }
+
+    /// 为交易事件填充 `metadata.transfer_fee_amounts`，非交易事件直接忽略
+    ///
+    /// 事件中的数量视为转账的总额
+    pub fn apply(&self, event: &mut DexEvent) {
+        let Some(trade) = event.as_trade() else {
+            return;
+        };
+        let slot = event.metadata().slot;
+        let (base_amount, quote_amount) = (trade.base_amount(), trade.quote_amount());
+        let amounts = TransferFeeAmounts {
+            base_gross_amount: base_amount,
+            base_net_amount: self.net_amount(&trade.base_mint(), base_amount, slot),
+            quote_gross_amount: quote_amount,
+            quote_net_amount: self.net_amount(&trade.quote_mint(), quote_amount, slot),
+        };
+        event.metadata_mut().transfer_fee_amounts = Some(amounts);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::filter::WSOL_MINT;
+    use crate::streaming::event_parser::core::account_event_parser::{TokenInfoEvent, TransferFee};
+    use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+
+    const MINT: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    /// epoch 10 之前 1%，之后 2% 且最多 100
+    fn config() -> TransferFeeConfig {
+        TransferFeeConfig {
+            older_transfer_fee: TransferFee {
+                epoch: 0,
+                maximum_fee: u64::MAX,
+                transfer_fee_basis_points: 100,
+            },
+            newer_transfer_fee: TransferFee {
+                epoch: 10,
+                maximum_fee: 100,
+                transfer_fee_basis_points: 200,
+            },
+            withheld_amount: 0,
+        }
+    }
+
+    #[test]
+    fn net_amount_uses_the_fee_of_the_slot_epoch() {
+        let cache = TransferFeeCache::new().with_slots_per_epoch(100);
+        cache.insert(MINT, config());
+        assert_eq!(cache.net_amount(&MINT, 1_000, 999), 990);
+        assert_eq!(cache.net_amount(&MINT, 1_000, 1_000), 980);
+        // 新手续费封顶，slot 未知时按新手续费计算
+        assert_eq!(cache.net_amount(&MINT, 100_000, 1_000), 99_900);
+        assert_eq!(cache.net_amount(&MINT, 1_000, 0), 980);
+        // 未知 mint 没有手续费
+        assert_eq!(cache.net_amount(&WSOL_MINT, 1_000, 999), 1_000);
+    }
+
+    #[test]
+    fn mint_events_insert_and_remove_configs() {
+        let cache = TransferFeeCache::new();
+        let mut info =
+            TokenInfoEvent { pubkey: MINT, transfer_fee: Some(config()), ..Default::default() };
+        cache.observe(&DexEvent::TokenInfoEvent(info.clone()));
+        assert_eq!(cache.get(&MINT), Some(config()));
+
+        // 手续费扩展被移除 (或 mint 没有该扩展) 时删除缓存
+        info.transfer_fee = None;
+        cache.observe(&DexEvent::TokenInfoEvent(info));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn apply_fills_gross_and_net_amounts_of_trades() {
+        let cache = TransferFeeCache::new().with_slots_per_epoch(100);
+        cache.insert(MINT, config());
+        let mut trade = PumpFunTradeEvent::default();
+        trade.metadata.slot = 500;
+        trade.mint = MINT;
+        trade.token_amount = 1_000;
+        trade.sol_amount = 50;
+        let mut event = DexEvent::PumpFunTradeEvent(trade);
+
+        cache.apply(&mut event);
+        assert_eq!(
+            event.metadata().transfer_fee_amounts,
+            Some(TransferFeeAmounts {
+                base_gross_amount: 1_000,
+                base_net_amount: 990,
+                quote_gross_amount: 50,
+                quote_net_amount: 50,
+            })
+        );
+
+        let mut info = DexEvent::TokenInfoEvent(TokenInfoEvent::default());
+        cache.apply(&mut info);
+        assert_eq!(info.metadata().transfer_fee_amounts, None);
+    }
+}
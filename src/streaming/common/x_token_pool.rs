@@ -0,0 +1,115 @@
+use super::metrics::MetricsManager;
+use parking_lot::Mutex;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// 每个 x_token 的连接统计，按 token 首次使用的顺序排列
+///
+/// 以完整 token 的哈希区分，末尾 4 个字符相同的 token 不会合并
+static X_TOKEN_USAGE: Mutex<Vec<(u64, XTokenUsage)>> = Mutex::new(Vec::new());
+
+/// 单个 x_token 的连接统计
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XTokenUsage {
+    /// token 的脱敏标识，只保留最后 4 个字符，如 `…a1b2`；末尾相同的不同 token 各占一条记录，
+    /// 按首次使用的顺序加上 `#2`、`#3` 等后缀区分
+    pub label: String,
+    /// 使用该 token 建立成功的连接数
+    pub connections: u64,
+    /// 使用该 token 连接失败的次数
+    pub failures: u64,
+}
+
+impl MetricsManager {
+    /// 记录一次使用 x_token 的连接结果
+    pub fn record_x_token_connection(&self, x_token: &str, success: bool) {
+        let key = x_token_key(x_token);
+        let mut usage = X_TOKEN_USAGE.lock();
+        let index = match usage.iter().position(|(entry_key, _)| *entry_key == key) {
+            Some(index) => index,
+            None => {
+                let suffix = x_token_label(x_token);
+                let same_suffix =
+                    usage.iter().filter(|(_, entry)| entry.label.starts_with(&suffix)).count();
+                let label = match same_suffix {
+                    0 => suffix,
+                    n => format!("{suffix}#{}", n + 1),
+                };
+                usage.push((key, XTokenUsage { label, ..Default::default() }));
+                usage.len() - 1
+            }
+        };
+        let entry = &mut usage[index].1;
+        if success {
+            entry.connections += 1;
+        } else {
+            entry.failures += 1;
+        }
+    }
+
+    /// 获取每个 x_token 的连接统计，只统计 `StreamClientConfig::x_tokens` 中的 token
+    pub fn get_x_token_metrics(&self) -> Vec<XTokenUsage> {
+        X_TOKEN_USAGE.lock().iter().map(|(_, usage)| usage.clone()).collect()
+    }
+}
+
+/// 区分 token 的键，统计中不保存完整 token
+fn x_token_key(x_token: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x_token.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// token 的脱敏标识，指标中不出现完整 token
+fn x_token_label(x_token: &str) -> String {
+    let chars: Vec<char> = x_token.chars().collect();
+    let suffix: String = chars[chars.len().saturating_sub(4)..].iter().collect();
+    format!("…{suffix}")
+}
+
+/// 轮流分配给每次连接的 x_token，克隆之间共享分配位置
+#[derive(Debug, Clone, Default)]
+pub struct XTokenPool {
+    tokens: Arc<Vec<String>>,
+    next: Arc<AtomicUsize>,
+}
+
+impl XTokenPool {
+    pub fn new(tokens: Vec<String>) -> Self {
+        Self { tokens: Arc::new(tokens), next: Arc::default() }
+    }
+
+    /// 下一个 token，池为空时返回 None
+    pub fn next_token(&self) -> Option<&str> {
+        if self.tokens.is_empty() {
+            return None;
+        }
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.tokens.len();
+        Some(&self.tokens[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_with_the_same_suffix_are_counted_separately() {
+        let metrics = MetricsManager::global();
+        metrics.record_x_token_connection("first-token-zq9w", true);
+        metrics.record_x_token_connection("second-token-zq9w", false);
+        metrics.record_x_token_connection("second-token-zq9w", true);
+
+        let usage: Vec<XTokenUsage> = metrics
+            .get_x_token_metrics()
+            .into_iter()
+            .filter(|usage| usage.label.starts_with("…zq9w"))
+            .collect();
+        assert_eq!(usage.len(), 2);
+        assert_eq!((usage[0].label.as_str(), usage[1].label.as_str()), ("…zq9w", "…zq9w#2"));
+        assert_eq!((usage[0].connections, usage[0].failures), (1, 0));
+        assert_eq!((usage[1].connections, usage[1].failures), (1, 1));
+    }
+}
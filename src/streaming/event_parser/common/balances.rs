@@ -0,0 +1,153 @@
+use crate::streaming::event_parser::common::serde_base58;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use yellowstone_grpc_proto::prelude::{TokenBalance, TransactionStatusMeta};
+
+/// 交易前后单个 token 账户的余额变化
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBalanceChange {
+    #[serde(with = "serde_base58::pubkey")]
+    pub account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub owner: Pubkey,
+    pub decimals: u8,
+    pub pre_amount: u64,
+    pub post_amount: u64,
+}
+
+impl TokenBalanceChange {
+    /// 余额变化量 (post - pre)，收到为正，转出为负
+    pub fn delta(&self) -> i128 {
+        self.post_amount as i128 - self.pre_amount as i128
+    }
+}
+
+/// 交易 meta 中的余额信息 (借用 meta，不做拷贝)
+#[derive(Debug, Clone, Copy)]
+pub struct TransactionBalances<'a> {
+    pre_balances: &'a [u64],
+    post_balances: &'a [u64],
+    pre_token_balances: &'a [TokenBalance],
+    post_token_balances: &'a [TokenBalance],
+}
+
+impl<'a> TransactionBalances<'a> {
+    pub fn from_grpc_meta(meta: &'a TransactionStatusMeta) -> Self {
+        Self {
+            pre_balances: &meta.pre_balances,
+            post_balances: &meta.post_balances,
+            pre_token_balances: &meta.pre_token_balances,
+            post_token_balances: &meta.post_token_balances,
+        }
+    }
+
+    /// 账户交易前后的 lamports
+    pub fn lamports(&self, account_index: usize) -> Option<(u64, u64)> {
+        Some((*self.pre_balances.get(account_index)?, *self.post_balances.get(account_index)?))
+    }
+
+    /// token 账户交易前后的余额
+    ///
+    /// 交易中新建或关闭的账户只会出现在 pre / post 其中一边，缺失的一边视为 0；
+    /// 不是 token 账户时返回 `None`
+    pub fn token_balance_change(
+        &self,
+        account_index: usize,
+        accounts: &[Pubkey],
+    ) -> Option<TokenBalanceChange> {
+        let find = |balances: &'a [TokenBalance]| {
+            balances.iter().find(|balance| balance.account_index as usize == account_index)
+        };
+        let pre = find(self.pre_token_balances);
+        let post = find(self.post_token_balances);
+        let info = post.or(pre)?;
+        let amount = |balance: Option<&TokenBalance>| {
+            balance
+                .and_then(|balance| balance.ui_token_amount.as_ref())
+                .and_then(|amount| amount.amount.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        Some(TokenBalanceChange {
+            account: accounts.get(account_index).copied().unwrap_or_default(),
+            mint: Pubkey::from_str(&info.mint).unwrap_or_default(),
+            owner: Pubkey::from_str(&info.owner).unwrap_or_default(),
+            decimals: info.ui_token_amount.as_ref().map(|amount| amount.decimals as u8).unwrap_or(0),
+            pre_amount: amount(pre),
+            post_amount: amount(post),
+        })
+    }
+
+    /// 指令涉及的所有 token 账户的余额变化 (按账户在指令中的顺序，去重)
+    pub fn token_balance_changes(
+        &self,
+        account_indexes: &[u8],
+        accounts: &[Pubkey],
+    ) -> Vec<TokenBalanceChange> {
+        let mut changes: Vec<TokenBalanceChange> = Vec::new();
+        for (i, &account_index) in account_indexes.iter().enumerate() {
+            if account_indexes[..i].contains(&account_index) {
+                continue;
+            }
+            if let Some(change) = self.token_balance_change(account_index as usize, accounts) {
+                changes.push(change);
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::UiTokenAmount;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    fn token_balance(account_index: u32, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index,
+            mint: key(7).to_string(),
+            owner: key(8).to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals: 6,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn token_balance_changes_cover_created_and_closed_accounts() {
+        let accounts: Vec<Pubkey> = (1..=4).map(key).collect();
+        let meta = TransactionStatusMeta {
+            pre_balances: vec![5_000, 2_039_280, 0, 0],
+            post_balances: vec![2_044_280, 0, 2_039_280, 0],
+            // 1 被关闭，2 在交易中新建，3 余额减少
+            pre_token_balances: vec![token_balance(1, 500), token_balance(3, 1_000)],
+            post_token_balances: vec![token_balance(2, 300), token_balance(3, 400)],
+            ..Default::default()
+        };
+        let balances = TransactionBalances::from_grpc_meta(&meta);
+        assert_eq!(balances.lamports(0), Some((5_000, 2_044_280)));
+        assert_eq!(balances.lamports(4), None);
+
+        let changes = balances.token_balance_changes(&[3, 1, 0, 3, 2], &accounts);
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|change| (change.account, change.pre_amount, change.post_amount, change.delta()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(key(4), 1_000, 400, -600), (key(2), 500, 0, -500), (key(3), 0, 300, 300)]
+        );
+        assert_eq!((changes[0].mint, changes[0].owner, changes[0].decimals), (key(7), key(8), 6));
+        // 非 token 账户没有余额变化
+        assert_eq!(balances.token_balance_change(0, &accounts), None);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::streaming::event_parser::core::common_event_parser::COMPUTE_BUDGET_PROGRAM_ID;
+use solana_sdk::pubkey::Pubkey;
+
+/// 每个签名的基础费用 (lamports)
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+/// 未设置 SetComputeUnitLimit 时每条指令的默认计算单元上限
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+/// 单笔交易的计算单元上限
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// 交易级别的计算单元消耗和优先费，同一交易的所有事件共用
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionFees {
+    /// 实际消耗的计算单元 (来自交易 meta，无 meta 时为 None)
+    pub compute_units_consumed: Option<u64>,
+    /// 优先费 (lamports)
+    pub priority_fee_lamports: Option<u64>,
+    /// 转给 Jito tip 账户的 lamports，没有 tip 转账时为 None
+    pub jito_tip_lamports: Option<u64>,
+}
+
+impl TransactionFees {
+    /// 由交易的顶层指令和 meta 中的费用计算
+    ///
+    /// 优先费 = SetComputeUnitPrice × 计算单元上限 (向上取整)。上限取 SetComputeUnitLimit，
+    /// 未设置时按每条非 Compute Budget 指令 200k 估算。已知 `fee` 时结果不超过扣除签名基础费用后的部分
+    ///
+    /// # 参数
+    /// - `instructions`: 顶层指令的 (program_id_index, data)
+    /// - `accounts`: 交易账户列表
+    /// - `signature_count`: 交易签名数量
+    /// - `fee`: meta 中的总费用
+    /// - `compute_units_consumed`: meta 中实际消耗的计算单元
+    pub fn new<'a>(
+        instructions: impl IntoIterator<Item = (usize, &'a [u8])>,
+        accounts: &[Pubkey],
+        signature_count: usize,
+        fee: Option<u64>,
+        compute_units_consumed: Option<u64>,
+    ) -> Self {
+        let mut unit_limit: Option<u64> = None;
+        let mut unit_price: Option<u64> = None;
+        let mut other_instructions: u64 = 0;
+        for (program_id_index, data) in instructions {
+            if accounts.get(program_id_index) != Some(&COMPUTE_BUDGET_PROGRAM_ID) {
+                other_instructions += 1;
+                continue;
+            }
+            match data.first() {
+                // SetComputeUnitLimit
+                Some(2) if data.len() >= 5 => {
+                    unit_limit = data[1..5].try_into().ok().map(u32::from_le_bytes).map(u64::from);
+                }
+                // SetComputeUnitPrice
+                Some(3) if data.len() >= 9 => {
+                    unit_price = data[1..9].try_into().ok().map(u64::from_le_bytes);
+                }
+                _ => {}
+            }
+        }
+
+        let unit_limit = unit_limit
+            .unwrap_or(other_instructions * DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT)
+            .min(MAX_COMPUTE_UNIT_LIMIT);
+        let micro_lamports = unit_price.unwrap_or(0) as u128 * unit_limit as u128;
+        let mut priority_fee =
+            micro_lamports.div_ceil(MICRO_LAMPORTS_PER_LAMPORT).min(u64::MAX as u128) as u64;
+        if let Some(fee) = fee {
+            let base_fee = LAMPORTS_PER_SIGNATURE.saturating_mul(signature_count as u64);
+            priority_fee = priority_fee.min(fee.saturating_sub(base_fee));
+        }
+
+        Self {
+            compute_units_consumed,
+            priority_fee_lamports: Some(priority_fee),
+            jito_tip_lamports: None,
+        }
+    }
+
+    /// 设置交易的 Jito tip，见 `jito_tip_lamports`
+    pub fn with_jito_tip(mut self, jito_tip_lamports: Option<u64>) -> Self {
+        self.jito_tip_lamports = jito_tip_lamports;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accounts() -> Vec<Pubkey> {
+        vec![Pubkey::new_from_array([1; 32]), COMPUTE_BUDGET_PROGRAM_ID]
+    }
+
+    fn set_unit_limit(limit: u32) -> Vec<u8> {
+        [&[2][..], &limit.to_le_bytes()].concat()
+    }
+
+    fn set_unit_price(micro_lamports: u64) -> Vec<u8> {
+        [&[3][..], &micro_lamports.to_le_bytes()].concat()
+    }
+
+    #[test]
+    fn priority_fee_uses_the_requested_unit_limit() {
+        let (limit, price) = (set_unit_limit(300_000), set_unit_price(1_000_001));
+        let instructions = [(1, &limit[..]), (1, &price[..]), (0, &[9][..])];
+        let fees = TransactionFees::new(instructions, &accounts(), 1, None, Some(120_000));
+        // 300_000 × 1_000_001 / 10^6 向上取整
+        assert_eq!(fees.priority_fee_lamports, Some(300_001));
+        assert_eq!(fees.compute_units_consumed, Some(120_000));
+        assert_eq!(fees.jito_tip_lamports, None);
+    }
+
+    #[test]
+    fn unit_limit_defaults_per_instruction_and_is_capped() {
+        let price = set_unit_price(1_000_000);
+        let instructions = [(1, &price[..]), (0, &[9][..]), (0, &[9][..])];
+        let fees = TransactionFees::new(instructions, &accounts(), 1, None, None);
+        assert_eq!(fees.priority_fee_lamports, Some(400_000));
+
+        let limit = set_unit_limit(u32::MAX);
+        let instructions = [(1, &limit[..]), (1, &price[..])];
+        let fees = TransactionFees::new(instructions, &accounts(), 1, None, None);
+        assert_eq!(fees.priority_fee_lamports, Some(1_400_000));
+    }
+
+    #[test]
+    fn priority_fee_is_bounded_by_the_paid_fee() {
+        let (limit, price) = (set_unit_limit(200_000), set_unit_price(1_000_000));
+        let instructions = [(1, &limit[..]), (1, &price[..])];
+        // 两个签名的基础费用为 10_000，实际只付了 50_000 的优先费
+        let fees = TransactionFees::new(instructions, &accounts(), 2, Some(60_000), None);
+        assert_eq!(fees.priority_fee_lamports, Some(50_000));
+
+        let fees = TransactionFees::new([(0, &[9][..])], &accounts(), 1, Some(5_000), None);
+        assert_eq!(fees.priority_fee_lamports, Some(0));
+    }
+}
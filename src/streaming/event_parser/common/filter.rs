@@ -1,6 +1,16 @@
 use crate::streaming::event_parser::common::{
     types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES,
 };
+use crate::streaming::event_parser::DexEvent;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+
+/// 原生 SOL（swap_data 中 SOL 一侧使用）
+pub const NATIVE_SOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111111");
+/// Wrapped SOL
+pub const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+/// USDC
+pub const USDC_MINT: Pubkey = solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct EventTypeFilter {
@@ -8,6 +18,11 @@ pub struct EventTypeFilter {
 }
 
 impl EventTypeFilter {
+    /// 是否包含该事件类型
+    pub fn includes(&self, event_type: &EventType) -> bool {
+        self.include.contains(event_type)
+    }
+
     pub fn include_transaction_event(&self) -> bool {
         self.include
             .iter()
@@ -22,3 +37,125 @@ impl EventTypeFilter {
         self.include.iter().any(|event| BLOCK_EVENT_TYPES.contains(event))
     }
 }
+
+/// 按代币 mint 过滤事件（解析后执行，对所有协议一致）
+///
+/// 列表为空时不过滤；区块元数据、重连、确认级别升级等流状态事件始终保留
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MintFilter {
+    pub mints: HashSet<Pubkey>,
+}
+
+impl MintFilter {
+    pub fn new(mints: Vec<Pubkey>) -> Self {
+        Self { mints: mints.into_iter().collect() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mints.is_empty()
+    }
+
+    /// 事件涉及的 mint 与过滤集合有交集时返回 true
+    pub fn matches(&self, event: &DexEvent) -> bool {
+        if self.mints.is_empty() {
+            return true;
+        }
+        if matches!(
+            event.metadata().event_type,
+            EventType::BlockMeta
+                | EventType::Reconnect
+                | EventType::CommitmentUpgrade
+                | EventType::SlotLag
+                | EventType::SlotRollback
+                | EventType::Entry
+        ) {
+            return true;
+        }
+        event.token_mints().iter().any(|mint| self.mints.contains(mint))
+    }
+}
+
+/// 最小成交额过滤器，按报价币种设置阈值（最小单位，如 SOL 为 lamports，USDC 为 micro）
+///
+/// 只作用于交易类事件 (`DexEvent::is_trade`)，在解析循环内、交给回调前执行；
+/// 建池、迁移等事件以及报价币种不在阈值表中或没有 swap_data 的交易直接通过
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MinQuoteAmountFilter {
+    pub thresholds: HashMap<Pubkey, u64>,
+}
+
+impl MinQuoteAmountFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置某个报价币种的最小成交额
+    pub fn with_min_amount(mut self, quote_mint: Pubkey, min_amount: u64) -> Self {
+        self.thresholds.insert(quote_mint, min_amount);
+        self
+    }
+
+    /// 设置 SOL 报价的最小成交额（lamports），同时作用于原生 SOL 和 WSOL
+    pub fn with_min_sol(self, lamports: u64) -> Self {
+        self.with_min_amount(NATIVE_SOL_MINT, lamports).with_min_amount(WSOL_MINT, lamports)
+    }
+
+    /// 设置 USDC 报价的最小成交额（micro USDC）
+    pub fn with_min_usdc(self, micro_usdc: u64) -> Self {
+        self.with_min_amount(USDC_MINT, micro_usdc)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.thresholds.is_empty()
+    }
+
+    /// 事件成交额不低于阈值（或不适用）时返回 true
+    pub fn passes(&self, event: &DexEvent) -> bool {
+        if self.thresholds.is_empty() || !event.is_trade() {
+            return true;
+        }
+        let Some(swap_data) = &event.metadata().swap_data else {
+            return true;
+        };
+        if let Some(min_amount) = self.thresholds.get(&swap_data.from_mint) {
+            swap_data.from_amount >= *min_amount
+        } else if let Some(min_amount) = self.thresholds.get(&swap_data.to_mint) {
+            swap_data.to_amount >= *min_amount
+        } else {
+            true
+        }
+    }
+}
+
+/// 按交易执行结果和优先费过滤事件（解析后、交给回调前执行），字段默认不过滤
+///
+/// 优先费取 `metadata.priority_fee_lamports`；账户事件等没有优先费的事件不受费用限制，
+/// 区块元数据、重连等流状态事件始终保留
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionMetaFilter {
+    /// 只保留执行成功的交易的事件
+    pub only_succeeded: bool,
+    /// 优先费下限 (lamports)，低于该值的交易事件被丢弃
+    pub min_fee_lamports: Option<u64>,
+    /// 优先费上限 (lamports)，高于该值的交易事件被丢弃
+    pub max_fee_lamports: Option<u64>,
+}
+
+impl TransactionMetaFilter {
+    pub fn is_empty(&self) -> bool {
+        !self.only_succeeded && self.min_fee_lamports.is_none() && self.max_fee_lamports.is_none()
+    }
+
+    /// 事件满足全部条件（或不适用）时返回 true
+    pub fn passes(&self, event: &DexEvent) -> bool {
+        let metadata = event.metadata();
+        if self.only_succeeded && !metadata.succeeded {
+            return false;
+        }
+        let Some(fee) = metadata.priority_fee_lamports else {
+            return true;
+        };
+        self.min_fee_lamports.is_none_or(|min| fee >= min)
+            && self.max_fee_lamports.is_none_or(|max| fee <= max)
+    }
+}
@@ -0,0 +1,129 @@
+use parking_lot::RwLock;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+
+/// System Program
+const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+/// System Program Transfer 指令的序号
+const SYSTEM_TRANSFER_INSTRUCTION: u32 = 2;
+
+/// 主网 Jito tip 账户
+pub const DEFAULT_JITO_TIP_ACCOUNTS: [Pubkey; 8] = [
+    solana_sdk::pubkey!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5"),
+    solana_sdk::pubkey!("HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe"),
+    solana_sdk::pubkey!("Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY"),
+    solana_sdk::pubkey!("ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49"),
+    solana_sdk::pubkey!("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh"),
+    solana_sdk::pubkey!("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt"),
+    solana_sdk::pubkey!("DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL"),
+    solana_sdk::pubkey!("3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT"),
+];
+
+lazy_static::lazy_static! {
+    static ref JITO_TIP_ACCOUNTS: RwLock<HashSet<Pubkey>> =
+        RwLock::new(DEFAULT_JITO_TIP_ACCOUNTS.into_iter().collect());
+}
+
+/// 当前识别的 Jito tip 账户
+pub fn jito_tip_accounts() -> Vec<Pubkey> {
+    JITO_TIP_ACCOUNTS.read().iter().copied().collect()
+}
+
+/// 替换识别的 Jito tip 账户 (如 Jito 更换 tip 账户或使用测试网)，对之后解析的交易生效
+pub fn set_jito_tip_accounts(accounts: impl IntoIterator<Item = Pubkey>) {
+    *JITO_TIP_ACCOUNTS.write() = accounts.into_iter().collect();
+}
+
+/// 追加一个 Jito tip 账户
+pub fn add_jito_tip_account(account: Pubkey) {
+    JITO_TIP_ACCOUNTS.write().insert(account);
+}
+
+/// 账户是否为 Jito tip 账户
+pub fn is_jito_tip_account(account: &Pubkey) -> bool {
+    JITO_TIP_ACCOUNTS.read().contains(account)
+}
+
+/// 统计交易中转给 Jito tip 账户的 lamports，没有 tip 转账时返回 None
+///
+/// 只识别 System Program 的 Transfer 指令，外层指令和 inner instruction (如通过合约 CPI 支付的
+/// tip) 都应传入
+///
+/// # 参数
+/// - `instructions`: 指令的 (program_id_index, 账户索引, data)
+/// - `accounts`: 交易账户列表
+pub fn jito_tip_lamports<'a>(
+    instructions: impl IntoIterator<Item = (usize, &'a [u8], &'a [u8])>,
+    accounts: &[Pubkey],
+) -> Option<u64> {
+    let tip_accounts = JITO_TIP_ACCOUNTS.read();
+    if tip_accounts.is_empty() {
+        return None;
+    }
+    let mut tip: Option<u64> = None;
+    for (program_id_index, account_indexes, data) in instructions {
+        if accounts.get(program_id_index) != Some(&SYSTEM_PROGRAM_ID) || data.len() < 12 {
+            continue;
+        }
+        let instruction = data[..4].try_into().ok().map(u32::from_le_bytes);
+        if instruction != Some(SYSTEM_TRANSFER_INSTRUCTION) {
+            continue;
+        }
+        // Transfer 的账户为 [from, to]
+        let Some(to) = account_indexes.get(1).and_then(|&index| accounts.get(index as usize))
+        else {
+            continue;
+        };
+        if !tip_accounts.contains(to) {
+            continue;
+        }
+        let lamports = data[4..12].try_into().map(u64::from_le_bytes).unwrap_or_default();
+        tip = Some(tip.unwrap_or(0).saturating_add(lamports));
+    }
+    tip
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer(lamports: u64) -> Vec<u8> {
+        [&SYSTEM_TRANSFER_INSTRUCTION.to_le_bytes()[..], &lamports.to_le_bytes()].concat()
+    }
+
+    #[test]
+    fn tips_to_every_tip_account_are_summed() {
+        let accounts = [
+            Pubkey::new_from_array([1; 32]),
+            DEFAULT_JITO_TIP_ACCOUNTS[0],
+            DEFAULT_JITO_TIP_ACCOUNTS[5],
+            Pubkey::new_from_array([2; 32]),
+            SYSTEM_PROGRAM_ID,
+        ];
+        let (tip, other_tip, payment) = (transfer(10_000), transfer(2_500), transfer(1_000_000));
+        let instructions = [
+            (4, &[0, 1][..], &tip[..]),
+            (4, &[0, 2][..], &other_tip[..]),
+            // 转给普通账户的不计入
+            (4, &[0, 3][..], &payment[..]),
+        ];
+        assert_eq!(jito_tip_lamports(instructions, &accounts), Some(12_500));
+    }
+
+    #[test]
+    fn non_transfer_instructions_are_not_tips() {
+        let accounts =
+            [Pubkey::new_from_array([1; 32]), DEFAULT_JITO_TIP_ACCOUNTS[0], SYSTEM_PROGRAM_ID];
+        let tip = transfer(10_000);
+        // 其他程序、其他 System 指令和缺少账户的 Transfer
+        let mut create_account = tip.clone();
+        create_account[0] = 0;
+        let instructions = [
+            (0, &[0, 1][..], &tip[..]),
+            (2, &[0, 1][..], &create_account[..]),
+            (2, &[0][..], &tip[..]),
+            (2, &[0, 1][..], &tip[..8]),
+        ];
+        assert_eq!(jito_tip_lamports(instructions, &accounts), None);
+    }
+}
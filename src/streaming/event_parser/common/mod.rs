@@ -1,6 +1,25 @@
+pub mod balances;
+pub mod fees;
 pub mod filter;
 pub mod high_performance_clock;
+pub mod jito;
+pub mod parse_error;
+pub mod program_logs;
+pub mod serde_base58;
+pub mod sqrt_price;
 pub mod types;
+pub mod unknown_tx;
 pub mod utils;
+pub use balances::TokenBalanceChange;
+pub use fees::TransactionFees;
+pub use jito::{
+    add_jito_tip_account, is_jito_tip_account, jito_tip_accounts, jito_tip_lamports,
+    set_jito_tip_accounts, DEFAULT_JITO_TIP_ACCOUNTS,
+};
+pub use parse_error::{ParseError, ParseErrorCallback};
+pub use sqrt_price::sqrt_price_to_price;
 pub use types::*;
+pub use unknown_tx::{
+    clear_unknown_tx_callback, set_unknown_tx_callback, UnknownTx, UnknownTxCallback,
+};
 pub use utils::*;
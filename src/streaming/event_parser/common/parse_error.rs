@@ -0,0 +1,28 @@
+use crate::streaming::event_parser::common::EventType;
+use crate::streaming::event_parser::Protocol;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::sync::Arc;
+
+/// 指令解析失败：判别器已登记为某个事件，但数据或账户无法解码，通常意味着程序升级改变了布局
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "failed to decode {protocol:?} {event_type:?} instruction {outer_index}/{inner_index:?} of program {program_id} in {signature} (slot {slot})"
+)]
+pub struct ParseError {
+    pub signature: Signature,
+    pub slot: u64,
+    pub protocol: Protocol,
+    /// 判别器对应的事件类型
+    pub event_type: EventType,
+    pub program_id: Pubkey,
+    pub outer_index: i64,
+    pub inner_index: Option<i64>,
+    /// 指令原始数据，包括判别器
+    pub data: Vec<u8>,
+}
+
+/// 解析错误回调
+///
+/// 按订阅设置 (`YellowstoneGrpc::set_parse_error_callback`)，经 `ParseOptions` 传给解析器，
+/// 在解析任务中同步执行，应尽快返回
+pub type ParseErrorCallback = Arc<dyn Fn(&ParseError) + Send + Sync>;
@@ -0,0 +1,102 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+use crate::streaming::event_parser::common::extract_program_data;
+
+/// 程序通过 `sol_log_data` 输出的一条 "Program data:" 日志
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramDataLog {
+    /// 输出日志的程序
+    pub program_id: Pubkey,
+    /// 发出日志的外层指令位置
+    pub outer_index: i64,
+    /// 发出日志的 inner instruction 位置，外层指令本身输出时为 `None`
+    pub inner_index: Option<i64>,
+    /// 输出日志时的 CPI 深度，外层指令本身输出时为 0
+    pub cpi_depth: u8,
+    /// inner instruction 输出时为所属外层指令的程序
+    pub outer_program: Option<Pubkey>,
+    /// base64 解码后的日志数据
+    pub data: Vec<u8>,
+}
+
+/// 从交易日志中提取 "Program data:" 日志，并定位到输出它的指令
+///
+/// 运行时为每次程序调用输出 `Program <id> invoke [<depth>]`，结束时输出
+/// `Program <id> success` 或 `Program <id> failed: ...`，因此可以用一个调用栈还原日志所属的程序：
+/// - depth 为 1 的调用对应外层指令，按程序ID与 `instruction_program_ids` 依次匹配
+///   (预编译程序不输出调用日志，不能简单计数)
+/// - 更深的调用按出现顺序对应该外层指令的 inner instructions
+///
+/// 只保留 `include` 返回 true 的程序输出的日志。日志被截断 (`Log truncated`) 时停止解析
+pub fn extract_program_data_logs(
+    logs: &[String],
+    instruction_program_ids: &[Pubkey],
+    include: impl Fn(&Pubkey) -> bool,
+) -> Vec<ProgramDataLog> {
+    let mut result = Vec::new();
+    // 调用栈: (程序ID, inner instruction 位置)
+    let mut stack: Vec<(Pubkey, Option<i64>)> = Vec::new();
+    let mut outer_index: Option<usize> = None;
+    let mut next_inner_index: i64 = 0;
+
+    for log in logs {
+        if let Some(data) = extract_program_data(log) {
+            let Some(&(program_id, inner_index)) = stack.last() else {
+                continue;
+            };
+            let Some(outer_index) = outer_index else {
+                continue;
+            };
+            if !include(&program_id) {
+                continue;
+            }
+            if let Ok(data) = STANDARD.decode(data) {
+                let cpi_depth = (stack.len() - 1).min(u8::MAX as usize) as u8;
+                let outer_program = inner_index.and(stack.first().map(|&(id, _)| id));
+                result.push(ProgramDataLog {
+                    program_id,
+                    outer_index: outer_index as i64,
+                    inner_index,
+                    cpi_depth,
+                    outer_program,
+                    data,
+                });
+            }
+            continue;
+        }
+        if log == "Log truncated" {
+            break;
+        }
+        let Some((program, rest)) = log.strip_prefix("Program ").and_then(|l| l.split_once(' '))
+        else {
+            continue;
+        };
+        if rest.starts_with("invoke [") {
+            let Ok(program_id) = Pubkey::from_str(program) else {
+                continue;
+            };
+            if rest == "invoke [1]" {
+                let start = outer_index.map_or(0, |index| index + 1);
+                let Some(offset) = instruction_program_ids
+                    .get(start..)
+                    .and_then(|ids| ids.iter().position(|id| id == &program_id))
+                else {
+                    // 日志与指令对不上，后面的位置都不可信
+                    break;
+                };
+                outer_index = Some(start + offset);
+                next_inner_index = 0;
+                stack.clear();
+                stack.push((program_id, None));
+            } else {
+                stack.push((program_id, Some(next_inner_index)));
+                next_inner_index += 1;
+            }
+        } else if rest == "success" || rest.starts_with("failed") {
+            stack.pop();
+        }
+    }
+    result
+}
@@ -0,0 +1,247 @@
+//! 以 base58 字符串序列化 `Pubkey` / `Signature` 的 serde 辅助模块
+//!
+//! solana 自带的 serde 实现把 `Pubkey` 序列化为 32 字节数组，转发到 JSON 后不便阅读和关联；
+//! 事件结构体的字段通过 `#[serde(with = "serde_base58::pubkey")]` 使用这里的实现。
+//! bincode 等非 human-readable 格式仍按原始字节编码，保持紧凑
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::str::FromStr;
+
+fn parse<'de, T, D>(s: &str) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+    D: Deserializer<'de>,
+{
+    T::from_str(s).map_err(D::Error::custom)
+}
+
+pub mod pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(pubkey)
+        } else {
+            pubkey.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        if deserializer.is_human_readable() {
+            parse::<Pubkey, D>(&String::deserialize(deserializer)?)
+        } else {
+            Pubkey::deserialize(deserializer)
+        }
+    }
+}
+
+pub mod option_pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        pubkey: &Option<Pubkey>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            pubkey.map(|pubkey| pubkey.to_string()).serialize(serializer)
+        } else {
+            pubkey.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Pubkey>, D::Error> {
+        if deserializer.is_human_readable() {
+            Option::<String>::deserialize(deserializer)?.map(|s| parse::<Pubkey, D>(&s)).transpose()
+        } else {
+            Option::<Pubkey>::deserialize(deserializer)
+        }
+    }
+}
+
+pub mod vec_pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(pubkeys: &[Pubkey], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_seq(pubkeys.iter().map(|pubkey| pubkey.to_string()))
+        } else {
+            serializer.collect_seq(pubkeys)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Pubkey>, D::Error> {
+        if deserializer.is_human_readable() {
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|s| parse::<Pubkey, D>(s))
+                .collect()
+        } else {
+            Vec::<Pubkey>::deserialize(deserializer)
+        }
+    }
+}
+
+pub mod pubkey_array {
+    use super::*;
+    use serde::de::{SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use std::fmt;
+
+    /// 非 human-readable 格式与 serde 对定长数组的编码相同 (元组，无长度前缀)
+    pub fn serialize<S: Serializer, const N: usize>(
+        pubkeys: &[Pubkey; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return super::vec_pubkey::serialize(pubkeys, serializer);
+        }
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for pubkey in pubkeys {
+            tuple.serialize_element(pubkey)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[Pubkey; N], D::Error> {
+        let pubkeys = if deserializer.is_human_readable() {
+            super::vec_pubkey::deserialize(deserializer)?
+        } else {
+            deserializer.deserialize_tuple(N, PubkeyTupleVisitor(N))?
+        };
+        let len = pubkeys.len();
+        pubkeys.try_into().map_err(|_| D::Error::invalid_length(len, &N.to_string().as_str()))
+    }
+
+    struct PubkeyTupleVisitor(usize);
+
+    impl<'de> Visitor<'de> for PubkeyTupleVisitor {
+        type Value = Vec<Pubkey>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of {} pubkeys", self.0)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut pubkeys = Vec::with_capacity(self.0);
+            while let Some(pubkey) = seq.next_element()? {
+                pubkeys.push(pubkey);
+            }
+            Ok(pubkeys)
+        }
+    }
+}
+
+pub mod signature {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        signature: &Signature,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(signature)
+        } else {
+            signature.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Signature, D::Error> {
+        if deserializer.is_human_readable() {
+            parse::<Signature, D>(&String::deserialize(deserializer)?)
+        } else {
+            Signature::deserialize(deserializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Base58Fields {
+        #[serde(with = "super::pubkey")]
+        pubkey: Pubkey,
+        #[serde(with = "super::option_pubkey")]
+        option_pubkey: Option<Pubkey>,
+        #[serde(with = "super::vec_pubkey")]
+        vec_pubkey: Vec<Pubkey>,
+        #[serde(with = "super::pubkey_array")]
+        pubkey_array: [Pubkey; 2],
+        #[serde(with = "super::signature")]
+        signature: Signature,
+    }
+
+    /// 同样的字段使用 solana 自带的 serde 实现
+    #[derive(Serialize)]
+    struct RawFields {
+        pubkey: Pubkey,
+        option_pubkey: Option<Pubkey>,
+        vec_pubkey: Vec<Pubkey>,
+        pubkey_array: [Pubkey; 2],
+        signature: Signature,
+    }
+
+    fn sample() -> Base58Fields {
+        Base58Fields {
+            pubkey: Pubkey::new_unique(),
+            option_pubkey: Some(Pubkey::new_unique()),
+            vec_pubkey: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            pubkey_array: [Pubkey::new_unique(), Pubkey::new_unique()],
+            signature: Signature::from([7; 64]),
+        }
+    }
+
+    #[test]
+    fn json_round_trips_through_base58_strings() {
+        let fields = sample();
+        let json = serde_json::to_value(&fields).unwrap();
+        assert_eq!(json["pubkey"], fields.pubkey.to_string());
+        assert_eq!(json["option_pubkey"], fields.option_pubkey.unwrap().to_string());
+        assert_eq!(json["vec_pubkey"][1], fields.vec_pubkey[1].to_string());
+        assert_eq!(json["pubkey_array"][0], fields.pubkey_array[0].to_string());
+        assert_eq!(json["signature"], fields.signature.to_string());
+        assert_eq!(serde_json::from_value::<Base58Fields>(json).unwrap(), fields);
+
+        let none = Base58Fields { option_pubkey: None, ..sample() };
+        let json = serde_json::to_string(&none).unwrap();
+        assert_eq!(serde_json::from_str::<Base58Fields>(&json).unwrap(), none);
+    }
+
+    #[test]
+    fn json_rejects_invalid_base58_and_wrong_array_length() {
+        let mut json = serde_json::to_value(sample()).unwrap();
+        json["pubkey"] = "not base58!".into();
+        assert!(serde_json::from_value::<Base58Fields>(json).is_err());
+
+        let mut json = serde_json::to_value(sample()).unwrap();
+        json["pubkey_array"] = serde_json::json!([Pubkey::new_unique().to_string()]);
+        assert!(serde_json::from_value::<Base58Fields>(json).is_err());
+    }
+
+    #[test]
+    fn bincode_keeps_raw_bytes() {
+        let fields = sample();
+        let bytes = bincode::serialize(&fields).unwrap();
+        let raw = RawFields {
+            pubkey: fields.pubkey,
+            option_pubkey: fields.option_pubkey,
+            vec_pubkey: fields.vec_pubkey.clone(),
+            pubkey_array: fields.pubkey_array,
+            signature: fields.signature,
+        };
+        assert_eq!(bytes, bincode::serialize(&raw).unwrap());
+        assert_eq!(&bytes[..32], fields.pubkey.as_ref());
+        assert_eq!(bincode::deserialize::<Base58Fields>(&bytes).unwrap(), fields);
+    }
+}
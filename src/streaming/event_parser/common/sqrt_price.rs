@@ -0,0 +1,51 @@
+/// Q64.64 定点数的 1.0
+const Q64: f64 = 18_446_744_073_709_551_616.0;
+
+/// 把集中流动性池的 Q64.64 平方根价格换算为按精度调整后的价格
+///
+/// 返回每 1 个 token a 可换得的 token b 数量 (均为界面单位)，a/b 沿用池子自身的顺序
+/// (Whirlpool 的 token a/b、Raydium CLMM 的 token0/token1)。例如 SOL (9 位) / USDC (6 位) 池
+/// 的 `sqrt_price_x64` 为 `7_378_697_629_483_820_646` (0.4 × 2^64) 时价格为 0.16 × 10^3 = 160。
+///
+/// 使用 f64 计算，结果约有 15 位有效数字，适合展示和监控，不适合链上金额的精确计算
+pub fn sqrt_price_to_price(sqrt_price_x64: u128, decimals_a: u8, decimals_b: u8) -> f64 {
+    let sqrt_price = sqrt_price_x64 as f64 / Q64;
+    sqrt_price * sqrt_price * 10f64.powi(decimals_a as i32 - decimals_b as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        let tolerance = expected.abs() * 1e-12;
+        assert!((actual - expected).abs() <= tolerance, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn one_with_equal_decimals() {
+        assert_close(sqrt_price_to_price(1 << 64, 6, 6), 1.0);
+        assert_close(sqrt_price_to_price(1 << 64, 9, 9), 1.0);
+    }
+
+    #[test]
+    fn sol_usdc_documented_example() {
+        assert_close(sqrt_price_to_price(7_378_697_629_483_820_646, 9, 6), 160.0);
+    }
+
+    #[test]
+    fn decimals_a_below_decimals_b() {
+        // 原始价格 1，按精度调整后为 10^(6 - 9)
+        assert_close(sqrt_price_to_price(1 << 64, 6, 9), 0.001);
+        // 原始价格 4 (sqrt 为 2)
+        assert_close(sqrt_price_to_price(2 << 64, 6, 9), 0.004);
+    }
+
+    #[test]
+    fn u128_max_is_finite() {
+        // sqrt 约为 2^64，价格约为 2^128
+        let price = sqrt_price_to_price(u128::MAX, 0, 0);
+        assert!(price.is_finite());
+        assert_close(price, 2f64.powi(128));
+    }
+}
@@ -1,10 +1,23 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::{BorshDeserialize, BorshSerialize};
 use crossbeam_queue::ArrayQueue;
 use serde::{Deserialize, Serialize};
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
-use std::{borrow::Cow, fmt, str::FromStr, sync::Arc};
+use std::{
+    borrow::Cow,
+    fmt,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use crate::streaming::{common::SimdUtils, event_parser::DexEvent};
+use crate::streaming::{
+    common::SimdUtils,
+    event_parser::{
+        common::{read_u64_le, TokenBalanceChange, TransactionFees},
+        DexEvent,
+    },
+};
 
 // Object pool size configuration
 const EVENT_METADATA_POOL_SIZE: usize = 1000;
@@ -52,7 +65,18 @@ pub enum ProtocolType {
     RaydiumClmm,
     RaydiumAmmV4,
     MeteoraDammV2,
+    OrcaWhirlpool,
+    MeteoraDlmm,
+    Phoenix,
+    Jupiter,
+    Moonshot,
     Common,
+
+    // 以下变体在二进制编码引入后追加，新变体只加在末尾
+    Lifinity,
+    OpenBookV2,
+    Sanctum,
+    Custom,
 }
 
 /// Event type enumeration
@@ -118,6 +142,44 @@ pub enum EventType {
     MeteoraDammV2InitializeCustomizablePool,
     MeteoraDammV2InitializePoolWithDynamicConfig,
 
+    // Orca Whirlpool events
+    OrcaWhirlpoolSwap,
+    OrcaWhirlpoolSwapV2,
+    OrcaWhirlpoolIncreaseLiquidity,
+    OrcaWhirlpoolIncreaseLiquidityV2,
+    OrcaWhirlpoolDecreaseLiquidity,
+    OrcaWhirlpoolDecreaseLiquidityV2,
+    OrcaWhirlpoolInitializePool,
+    OrcaWhirlpoolInitializePoolV2,
+
+    // Meteora DLMM events
+    MeteoraDlmmSwap,
+    MeteoraDlmmSwap2,
+    MeteoraDlmmSwapExactOut,
+    MeteoraDlmmSwapExactOut2,
+    MeteoraDlmmAddLiquidityByStrategy,
+    MeteoraDlmmRemoveLiquidity,
+    MeteoraDlmmGoToABin,
+
+    // Phoenix events
+    PhoenixFill,
+    PhoenixPlace,
+    PhoenixReduce,
+    PhoenixCancel,
+
+    // Jupiter events
+    JupiterRoute,
+    JupiterRouteWithTokenLedger,
+    JupiterExactOutRoute,
+    JupiterSharedAccountsRoute,
+    JupiterSharedAccountsRouteWithTokenLedger,
+    JupiterSharedAccountsExactOutRoute,
+
+    // Moonshot events
+    MoonshotBuy,
+    MoonshotSell,
+    MoonshotMigration,
+
     // Account events
     AccountRaydiumAmmV4AmmInfo,
     AccountPumpSwapGlobalConfig,
@@ -141,7 +203,45 @@ pub enum EventType {
     BlockMeta,
     SetComputeUnitLimit,
     SetComputeUnitPrice,
+    Reconnect,
+    CommitmentUpgrade,
     Unknown,
+
+    // 以下变体在二进制编码 (`encode_event`) 引入后追加。bincode 按变体下标编码，
+    // 新变体只加在末尾，保持已有变体的下标不变
+    SlotLag,
+    AccountMeteoraDammV2Pool,
+
+    // Lifinity events
+    LifinitySwap,
+
+    // OpenBook v2 events
+    OpenBookV2Fill,
+
+    // Raydium CLMM position and liquidity events
+    RaydiumClmmOpenPosition,
+    RaydiumClmmIncreaseLiquidity,
+    RaydiumClmmDecreaseLiquidity,
+    RaydiumClmmIncreaseLiquidityLog,
+    RaydiumClmmDecreaseLiquidityLog,
+    RaydiumClmmCollectPersonalFee,
+    RaydiumClmmLiquidityChange,
+
+    AccountOwnerChanged,
+    SlotRollback,
+    RawAccount,
+
+    // Sanctum events
+    SanctumSwapExactIn,
+    SanctumSwapExactOut,
+
+    // Program log events
+    RaydiumClmmSwapLog,
+    OrcaWhirlpoolTraded,
+
+    Entry,
+    LamportsChanged,
+    Custom,
 }
 
 pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
@@ -159,10 +259,14 @@ pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
     EventType::AccountRaydiumClmmTickArrayState,
     EventType::AccountRaydiumCpmmAmmConfig,
     EventType::AccountRaydiumCpmmPoolState,
+    EventType::AccountMeteoraDammV2Pool,
     EventType::TokenAccount,
     EventType::NonceAccount,
+    EventType::AccountOwnerChanged,
+    EventType::LamportsChanged,
+    EventType::RawAccount,
 ];
-pub const BLOCK_EVENT_TYPES: &[EventType] = &[EventType::BlockMeta];
+pub const BLOCK_EVENT_TYPES: &[EventType] = &[EventType::BlockMeta, EventType::Entry];
 
 impl fmt::Display for EventType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -205,6 +309,18 @@ impl fmt::Display for EventType {
                 write!(f, "RaydiumClmmOpenPositionWithToken22Nft")
             }
             EventType::RaydiumClmmOpenPositionV2 => write!(f, "RaydiumClmmOpenPositionV2"),
+            EventType::RaydiumClmmOpenPosition => write!(f, "RaydiumClmmOpenPosition"),
+            EventType::RaydiumClmmIncreaseLiquidity => write!(f, "RaydiumClmmIncreaseLiquidity"),
+            EventType::RaydiumClmmDecreaseLiquidity => write!(f, "RaydiumClmmDecreaseLiquidity"),
+            EventType::RaydiumClmmIncreaseLiquidityLog => {
+                write!(f, "RaydiumClmmIncreaseLiquidityLog")
+            }
+            EventType::RaydiumClmmDecreaseLiquidityLog => {
+                write!(f, "RaydiumClmmDecreaseLiquidityLog")
+            }
+            EventType::RaydiumClmmCollectPersonalFee => write!(f, "RaydiumClmmCollectPersonalFee"),
+            EventType::RaydiumClmmLiquidityChange => write!(f, "RaydiumClmmLiquidityChange"),
+            EventType::RaydiumClmmSwapLog => write!(f, "RaydiumClmmSwapLog"),
             EventType::RaydiumAmmV4SwapBaseIn => write!(f, "RaydiumAmmV4SwapBaseIn"),
             EventType::RaydiumAmmV4SwapBaseOut => write!(f, "RaydiumAmmV4SwapBaseOut"),
             EventType::RaydiumAmmV4Deposit => write!(f, "RaydiumAmmV4Deposit"),
@@ -216,6 +332,49 @@ impl fmt::Display for EventType {
             EventType::MeteoraDammV2InitializePool => write!(f, "MeteoraDammV2InitializePool"),
             EventType::MeteoraDammV2InitializeCustomizablePool => write!(f, "MeteoraDammV2InitializeCustomizablePool"),
             EventType::MeteoraDammV2InitializePoolWithDynamicConfig => write!(f, "MeteoraDammV2InitializePoolWithDynamicConfig"),
+            EventType::OrcaWhirlpoolSwap => write!(f, "OrcaWhirlpoolSwap"),
+            EventType::OrcaWhirlpoolSwapV2 => write!(f, "OrcaWhirlpoolSwapV2"),
+            EventType::OrcaWhirlpoolIncreaseLiquidity => write!(f, "OrcaWhirlpoolIncreaseLiquidity"),
+            EventType::OrcaWhirlpoolIncreaseLiquidityV2 => {
+                write!(f, "OrcaWhirlpoolIncreaseLiquidityV2")
+            }
+            EventType::OrcaWhirlpoolDecreaseLiquidity => write!(f, "OrcaWhirlpoolDecreaseLiquidity"),
+            EventType::OrcaWhirlpoolDecreaseLiquidityV2 => {
+                write!(f, "OrcaWhirlpoolDecreaseLiquidityV2")
+            }
+            EventType::OrcaWhirlpoolInitializePool => write!(f, "OrcaWhirlpoolInitializePool"),
+            EventType::OrcaWhirlpoolInitializePoolV2 => write!(f, "OrcaWhirlpoolInitializePoolV2"),
+            EventType::OrcaWhirlpoolTraded => write!(f, "OrcaWhirlpoolTraded"),
+            EventType::MeteoraDlmmSwap => write!(f, "MeteoraDlmmSwap"),
+            EventType::MeteoraDlmmSwap2 => write!(f, "MeteoraDlmmSwap2"),
+            EventType::MeteoraDlmmSwapExactOut => write!(f, "MeteoraDlmmSwapExactOut"),
+            EventType::MeteoraDlmmSwapExactOut2 => write!(f, "MeteoraDlmmSwapExactOut2"),
+            EventType::MeteoraDlmmAddLiquidityByStrategy => {
+                write!(f, "MeteoraDlmmAddLiquidityByStrategy")
+            }
+            EventType::MeteoraDlmmRemoveLiquidity => write!(f, "MeteoraDlmmRemoveLiquidity"),
+            EventType::MeteoraDlmmGoToABin => write!(f, "MeteoraDlmmGoToABin"),
+            EventType::PhoenixFill => write!(f, "PhoenixFill"),
+            EventType::PhoenixPlace => write!(f, "PhoenixPlace"),
+            EventType::PhoenixReduce => write!(f, "PhoenixReduce"),
+            EventType::PhoenixCancel => write!(f, "PhoenixCancel"),
+            EventType::JupiterRoute => write!(f, "JupiterRoute"),
+            EventType::JupiterRouteWithTokenLedger => write!(f, "JupiterRouteWithTokenLedger"),
+            EventType::JupiterExactOutRoute => write!(f, "JupiterExactOutRoute"),
+            EventType::JupiterSharedAccountsRoute => write!(f, "JupiterSharedAccountsRoute"),
+            EventType::JupiterSharedAccountsRouteWithTokenLedger => {
+                write!(f, "JupiterSharedAccountsRouteWithTokenLedger")
+            }
+            EventType::JupiterSharedAccountsExactOutRoute => {
+                write!(f, "JupiterSharedAccountsExactOutRoute")
+            }
+            EventType::MoonshotBuy => write!(f, "MoonshotBuy"),
+            EventType::MoonshotSell => write!(f, "MoonshotSell"),
+            EventType::MoonshotMigration => write!(f, "MoonshotMigration"),
+            EventType::LifinitySwap => write!(f, "LifinitySwap"),
+            EventType::OpenBookV2Fill => write!(f, "OpenBookV2Fill"),
+            EventType::SanctumSwapExactIn => write!(f, "SanctumSwapExactIn"),
+            EventType::SanctumSwapExactOut => write!(f, "SanctumSwapExactOut"),
             EventType::AccountRaydiumAmmV4AmmInfo => write!(f, "AccountRaydiumAmmV4AmmInfo"),
             EventType::AccountPumpSwapGlobalConfig => write!(f, "AccountPumpSwapGlobalConfig"),
             EventType::AccountPumpSwapPool => write!(f, "AccountPumpSwapPool"),
@@ -232,11 +391,21 @@ impl fmt::Display for EventType {
             }
             EventType::AccountRaydiumCpmmAmmConfig => write!(f, "AccountRaydiumCpmmAmmConfig"),
             EventType::AccountRaydiumCpmmPoolState => write!(f, "AccountRaydiumCpmmPoolState"),
+            EventType::AccountMeteoraDammV2Pool => write!(f, "AccountMeteoraDammV2Pool"),
             EventType::TokenAccount => write!(f, "TokenAccount"),
             EventType::NonceAccount => write!(f, "NonceAccount"),
+            EventType::AccountOwnerChanged => write!(f, "AccountOwnerChanged"),
+            EventType::LamportsChanged => write!(f, "LamportsChanged"),
+            EventType::RawAccount => write!(f, "RawAccount"),
             EventType::BlockMeta => write!(f, "BlockMeta"),
             EventType::SetComputeUnitLimit => write!(f, "SetComputeUnitLimit"),
             EventType::SetComputeUnitPrice => write!(f, "SetComputeUnitPrice"),
+            EventType::Reconnect => write!(f, "Reconnect"),
+            EventType::CommitmentUpgrade => write!(f, "CommitmentUpgrade"),
+            EventType::SlotLag => write!(f, "SlotLag"),
+            EventType::SlotRollback => write!(f, "SlotRollback"),
+            EventType::Entry => write!(f, "Entry"),
+            EventType::Custom => write!(f, "Custom"),
             EventType::Unknown => write!(f, "Unknown"),
         }
     }
@@ -272,6 +441,7 @@ impl<T> ParseResult<T> {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProtocolInfo {
     pub name: String,
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub program_ids: Vec<Pubkey>,
 }
 
@@ -289,29 +459,137 @@ impl ProtocolInfo {
     Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
 )]
 pub struct SwapData {
+    #[serde(with = "serde_base58::pubkey")]
     pub from_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub to_mint: Pubkey,
     pub from_amount: u64,
     pub to_amount: u64,
     pub description: Option<Cow<'static, str>>,
 }
 
+/// 事件来源订阅的确认级别
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EventCommitment {
+    #[default]
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl From<yellowstone_grpc_proto::geyser::CommitmentLevel> for EventCommitment {
+    fn from(level: yellowstone_grpc_proto::geyser::CommitmentLevel) -> Self {
+        use yellowstone_grpc_proto::geyser::CommitmentLevel;
+        match level {
+            CommitmentLevel::Processed => EventCommitment::Processed,
+            CommitmentLevel::Confirmed => EventCommitment::Confirmed,
+            CommitmentLevel::Finalized => EventCommitment::Finalized,
+        }
+    }
+}
+
+/// 交易事件扣除 Token-2022 转账手续费前后的数量，最小单位
+///
+/// gross 为转出的数量，net 为实际到账的数量；没有转账手续费的 mint 两者相等
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferFeeAmounts {
+    pub base_gross_amount: u64,
+    pub base_net_amount: u64,
+    pub quote_gross_amount: u64,
+    pub quote_net_amount: u64,
+}
+
+/// 交易事件 base/quote 代币的符号和界面数量，mint 不在 `TokenRegistry` 中时对应字段为 None
+///
+/// 界面数量为按精度换算后的十进制字符串 (去掉末尾的 0)，如 `1500000` 和 6 位精度为 `"1.5"`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeTokenInfo {
+    pub base_symbol: Option<String>,
+    pub base_decimals: Option<u8>,
+    pub base_ui_amount: Option<String>,
+    pub quote_symbol: Option<String>,
+    pub quote_decimals: Option<u8>,
+    pub quote_ui_amount: Option<String>,
+}
+
 /// Event metadata
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EventMetadata {
+    /// 交易的第一个签名；账户事件取写入该账户的交易签名，gRPC 未提供时为 `Signature::default()`
+    #[serde(with = "serde_base58::signature")]
     pub signature: Signature,
     pub slot: u64,
     pub transaction_index: Option<u64>, // 新增：交易在slot中的索引
-    pub block_time: i64,
+    /// 出块时间（秒），gRPC 订阅中对应 slot 的 block meta 尚未到达时为 None
+    pub block_time: Option<i64>,
     pub block_time_ms: i64,
     pub recv_us: i64,
     pub handle_us: i64,
+    /// gRPC 流读出该更新时的时间 (UNIX 微秒)，早于 `recv_us` (进入解析的时间)；
+    /// ShredStream 取收到交易的时间，RPC 解析为 0
+    pub received_us: i64,
     pub protocol: ProtocolType,
     pub event_type: EventType,
+    #[serde(with = "serde_base58::pubkey")]
     pub program_id: Pubkey,
     pub swap_data: Option<SwapData>,
     pub outer_index: i64,
     pub inner_index: Option<i64>,
+    /// 指令涉及的 token 账户在交易前后的余额变化 (来自 gRPC 交易 meta，无 meta 时为空)
+    pub balance_changes: Vec<TokenBalanceChange>,
+    /// 交易实际消耗的计算单元 (来自交易 meta，无 meta 时为 None)
+    pub compute_units_consumed: Option<u64>,
+    /// 交易的优先费 (lamports)，由 Compute Budget 指令和 meta 中的 fee 计算，账户事件为 None
+    pub priority_fee_lamports: Option<u64>,
+    /// 交易转给 Jito tip 账户的 lamports (包括 inner instruction 中的转账)，没有 tip 时为 None；
+    /// tip 账户列表可通过 `set_jito_tip_accounts` 更新
+    pub jito_tip_lamports: Option<u64>,
+    /// 交易是否执行成功，只有 `TransactionFilter::include_failed` 订阅的失败交易为 false；
+    /// 没有交易 meta 时 (如 ShredStream) 无法判断，视为成功
+    pub succeeded: bool,
+    /// 产出事件的 gRPC 订阅的确认级别，ShredStream、文件回放和 RPC 解析为 None
+    pub commitment: Option<EventCommitment>,
+    /// 指令的 CPI 深度：外层指令为 0，被外层指令直接调用的 inner instruction 为 1，依此类推；
+    /// 交易 meta 没有 stack height 时 inner instruction 按 1 计
+    pub cpi_depth: u8,
+    /// inner instruction 所属外层指令的程序 (如路由交易的聚合器)，外层指令为 None
+    #[serde(with = "serde_base58::option_pubkey")]
+    pub outer_program: Option<Pubkey>,
+    /// 交易事件扣除转账手续费前后的 base/quote 数量，挂载 `TransferFeeCache` 时填充，否则为 None
+    pub transfer_fee_amounts: Option<TransferFeeAmounts>,
+    /// 交易事件 base/quote 代币的符号和界面数量，挂载 `TokenRegistry` 时填充，否则为 None
+    pub token_info: Option<TradeTokenInfo>,
+}
+
+impl Default for EventMetadata {
+    fn default() -> Self {
+        Self {
+            signature: Signature::default(),
+            slot: 0,
+            transaction_index: None,
+            block_time: None,
+            block_time_ms: 0,
+            recv_us: 0,
+            handle_us: 0,
+            received_us: 0,
+            protocol: ProtocolType::default(),
+            event_type: EventType::default(),
+            program_id: Pubkey::default(),
+            swap_data: None,
+            outer_index: 0,
+            inner_index: None,
+            balance_changes: Vec::new(),
+            compute_units_consumed: None,
+            priority_fee_lamports: None,
+            jito_tip_lamports: None,
+            succeeded: true,
+            commitment: None,
+            cpi_depth: 0,
+            outer_program: None,
+            transfer_fee_amounts: None,
+            token_info: None,
+        }
+    }
 }
 
 impl EventMetadata {
@@ -319,7 +597,7 @@ impl EventMetadata {
     pub fn new(
         signature: Signature,
         slot: u64,
-        block_time: i64,
+        block_time: Option<i64>,
         block_time_ms: i64,
         protocol: ProtocolType,
         event_type: EventType,
@@ -336,6 +614,7 @@ impl EventMetadata {
             block_time_ms,
             recv_us,
             handle_us: 0,
+            received_us: 0,
             protocol,
             event_type,
             program_id,
@@ -343,6 +622,16 @@ impl EventMetadata {
             outer_index,
             inner_index,
             transaction_index,
+            balance_changes: Vec::new(),
+            compute_units_consumed: None,
+            priority_fee_lamports: None,
+            jito_tip_lamports: None,
+            succeeded: true,
+            commitment: None,
+            cpi_depth: 0,
+            outer_program: None,
+            transfer_fee_amounts: None,
+            token_info: None,
         }
     }
 
@@ -350,6 +639,35 @@ impl EventMetadata {
         self.swap_data = Some(swap_data);
     }
 
+    /// gRPC 流读出该更新的时间，未记录时 (如 RPC 解析) 返回 None
+    pub fn received_at(&self) -> Option<SystemTime> {
+        (self.received_us > 0).then(|| UNIX_EPOCH + Duration::from_micros(self.received_us as u64))
+    }
+
+    /// 从出块到读出该更新的传播延迟 (毫秒)，出块时间或接收时间未知时返回 None
+    pub fn propagation_delay_ms(&self) -> Option<i64> {
+        let block_time = self.block_time?;
+        (self.received_us > 0).then(|| self.received_us / 1000 - block_time * 1000)
+    }
+
+    /// base58 编码的交易签名，没有交易签名的事件 (如部分账户更新) 返回 None
+    pub fn signature_base58(&self) -> Option<String> {
+        (self.signature != Signature::default()).then(|| self.signature.to_string())
+    }
+
+    /// 设置交易级别的计算单元消耗、优先费和 Jito tip
+    pub fn set_transaction_fees(&mut self, fees: TransactionFees) {
+        self.compute_units_consumed = fees.compute_units_consumed;
+        self.priority_fee_lamports = fees.priority_fee_lamports;
+        self.jito_tip_lamports = fees.jito_tip_lamports;
+    }
+
+    /// 设置 inner instruction 的 CPI 深度和所属外层指令的程序
+    pub fn set_cpi_context(&mut self, cpi_depth: u8, outer_program: Pubkey) {
+        self.cpi_depth = cpi_depth;
+        self.outer_program = Some(outer_program);
+    }
+
     /// Recycle EventMetadata to object pool
     pub fn recycle(self) {
         EVENT_METADATA_POOL.release(self);
@@ -369,7 +687,7 @@ lazy_static::lazy_static! {
 pub fn parse_swap_data_from_next_instructions(
     event: &DexEvent,
     inner_instruction: &solana_transaction_status::InnerInstructions,
-    current_index: i8,
+    current_index: i64,
     accounts: &[Pubkey],
 ) -> Option<SwapData> {
     let mut swap_data = SwapData {
@@ -411,6 +729,10 @@ pub fn parse_swap_data_from_next_instructions(
             swap_data.from_mint = e.base_mint;
             swap_data.to_mint = e.quote_mint;
         }
+        DexEvent::MoonshotTradeEvent(e) => {
+            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
+            swap_data.to_mint = if e.is_buy { e.mint } else { *SOL_MINT };
+        }
         DexEvent::RaydiumCpmmSwapEvent(e) => {
             // user = Some(e.payer);
             from_mint = Some(e.input_token_mint);
@@ -447,6 +769,53 @@ pub fn parse_swap_data_from_next_instructions(
             from_vault = Some(e.pool_pc_token_account);
             to_vault = Some(e.pool_coin_token_account);
         }
+        DexEvent::OrcaWhirlpoolSwapEvent(e) => {
+            // swap 指令没有 mint 账户，只有 swap_v2 可以直接拿到 mint
+            if e.token_mint_a == Pubkey::default() {
+                swap_data.description =
+                    Some("Unable to get from_mint and to_mint from OrcaWhirlpoolSwapEvent".into());
+            }
+            let (mint_in, mint_out) = if e.a_to_b {
+                (e.token_mint_a, e.token_mint_b)
+            } else {
+                (e.token_mint_b, e.token_mint_a)
+            };
+            from_mint = Some(mint_in);
+            to_mint = Some(mint_out);
+            if e.a_to_b {
+                user_from_token = Some(e.token_owner_account_a);
+                user_to_token = Some(e.token_owner_account_b);
+                from_vault = Some(e.token_vault_a);
+                to_vault = Some(e.token_vault_b);
+            } else {
+                user_from_token = Some(e.token_owner_account_b);
+                user_to_token = Some(e.token_owner_account_a);
+                from_vault = Some(e.token_vault_b);
+                to_vault = Some(e.token_vault_a);
+            }
+        }
+        DexEvent::MeteoraDlmmSwapEvent(e) => {
+            // 指令阶段无法确定方向，mint 与数量由 process_event 根据 swap_for_y 修正
+            user_from_token = Some(e.user_token_in);
+            user_to_token = Some(e.user_token_out);
+            from_vault = Some(e.reserve_x);
+            to_vault = Some(e.reserve_y);
+        }
+        DexEvent::LifinitySwapEvent(e) => {
+            // 指令账户中没有 mint，由交易 meta 中的余额变化补全
+            user_from_token = Some(e.source_info);
+            user_to_token = Some(e.destination_info);
+            from_vault = Some(e.swap_source);
+            to_vault = Some(e.swap_destination);
+        }
+        DexEvent::SanctumSwapEvent(e) => {
+            from_mint = Some(e.src_lst_mint);
+            to_mint = Some(e.dst_lst_mint);
+            user_from_token = Some(e.src_lst_acc);
+            user_to_token = Some(e.dst_lst_acc);
+            from_vault = Some(e.src_pool_reserves);
+            to_vault = Some(e.dst_pool_reserves);
+        }
         _ => {}
     }
 
@@ -458,9 +827,13 @@ pub fn parse_swap_data_from_next_instructions(
     let from_mint = from_mint.unwrap_or_default();
 
     // 单次循环完成提取和判断
-    for instruction in inner_instruction.instructions.iter().skip((current_index + 1) as usize) {
+    // current_index 为 -1 表示外层指令，从第一条 inner instruction 开始查找
+    let skip = current_index.saturating_add(1).max(0) as usize;
+    for instruction in inner_instruction.instructions.iter().skip(skip) {
         let compiled = &instruction.instruction;
-        let program_id = accounts[compiled.program_id_index as usize];
+        let Some(&program_id) = accounts.get(compiled.program_id_index as usize) else {
+            break;
+        };
         if !SYSTEM_PROGRAMS.contains(&program_id) {
             break;
         }
@@ -471,22 +844,19 @@ pub fn parse_swap_data_from_next_instructions(
             continue;
         }
 
-        let get_pubkey = |i: usize| accounts[compiled.accounts[i] as usize];
+        let get_pubkey =
+            |i: usize| compiled.accounts.get(i).and_then(|&index| accounts.get(index as usize));
+        // 数据过短或账户索引越界的指令直接跳过
         let (source, destination, amount) = match data[0] {
-            12 if compiled.accounts.len() >= 4 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(2), amt)
-            }
-            3 if compiled.accounts.len() >= 3 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
-            2 if compiled.accounts.len() >= 2 => {
-                let amt = u64::from_le_bytes(data[4..12].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
+            12 => (get_pubkey(0), get_pubkey(2), read_u64_le(data, 1)),
+            3 => (get_pubkey(0), get_pubkey(1), read_u64_le(data, 1)),
+            2 => (get_pubkey(0), get_pubkey(1), read_u64_le(data, 4)),
             _ => continue,
         };
+        let (Some(&source), Some(&destination), Some(amount)) = (source, destination, amount)
+        else {
+            continue;
+        };
 
         match (source, destination) {
             (s, d) if s == user_to_token && d == to_vault => {
@@ -539,7 +909,7 @@ pub fn parse_swap_data_from_next_instructions(
 pub fn parse_swap_data_from_next_grpc_instructions(
     event: &DexEvent,
     inner_instruction: &yellowstone_grpc_proto::prelude::InnerInstructions,
-    current_index: i8,
+    current_index: i64,
     accounts: &[Pubkey],
 ) -> Option<SwapData> {
     let mut swap_data = SwapData {
@@ -581,6 +951,10 @@ pub fn parse_swap_data_from_next_grpc_instructions(
             swap_data.from_mint = e.base_mint;
             swap_data.to_mint = e.quote_mint;
         }
+        DexEvent::MoonshotTradeEvent(e) => {
+            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
+            swap_data.to_mint = if e.is_buy { e.mint } else { *SOL_MINT };
+        }
         DexEvent::RaydiumCpmmSwapEvent(e) => {
             // user = Some(e.payer);
             from_mint = Some(e.input_token_mint);
@@ -617,6 +991,53 @@ pub fn parse_swap_data_from_next_grpc_instructions(
             from_vault = Some(e.pool_pc_token_account);
             to_vault = Some(e.pool_coin_token_account);
         }
+        DexEvent::OrcaWhirlpoolSwapEvent(e) => {
+            // swap 指令没有 mint 账户，只有 swap_v2 可以直接拿到 mint
+            if e.token_mint_a == Pubkey::default() {
+                swap_data.description =
+                    Some("Unable to get from_mint and to_mint from OrcaWhirlpoolSwapEvent".into());
+            }
+            let (mint_in, mint_out) = if e.a_to_b {
+                (e.token_mint_a, e.token_mint_b)
+            } else {
+                (e.token_mint_b, e.token_mint_a)
+            };
+            from_mint = Some(mint_in);
+            to_mint = Some(mint_out);
+            if e.a_to_b {
+                user_from_token = Some(e.token_owner_account_a);
+                user_to_token = Some(e.token_owner_account_b);
+                from_vault = Some(e.token_vault_a);
+                to_vault = Some(e.token_vault_b);
+            } else {
+                user_from_token = Some(e.token_owner_account_b);
+                user_to_token = Some(e.token_owner_account_a);
+                from_vault = Some(e.token_vault_b);
+                to_vault = Some(e.token_vault_a);
+            }
+        }
+        DexEvent::MeteoraDlmmSwapEvent(e) => {
+            // 指令阶段无法确定方向，mint 与数量由 process_event 根据 swap_for_y 修正
+            user_from_token = Some(e.user_token_in);
+            user_to_token = Some(e.user_token_out);
+            from_vault = Some(e.reserve_x);
+            to_vault = Some(e.reserve_y);
+        }
+        DexEvent::LifinitySwapEvent(e) => {
+            // 指令账户中没有 mint，由交易 meta 中的余额变化补全
+            user_from_token = Some(e.source_info);
+            user_to_token = Some(e.destination_info);
+            from_vault = Some(e.swap_source);
+            to_vault = Some(e.swap_destination);
+        }
+        DexEvent::SanctumSwapEvent(e) => {
+            from_mint = Some(e.src_lst_mint);
+            to_mint = Some(e.dst_lst_mint);
+            user_from_token = Some(e.src_lst_acc);
+            user_to_token = Some(e.dst_lst_acc);
+            from_vault = Some(e.src_pool_reserves);
+            to_vault = Some(e.dst_pool_reserves);
+        }
         _ => {}
     }
 
@@ -628,9 +1049,13 @@ pub fn parse_swap_data_from_next_grpc_instructions(
     let from_mint = from_mint.unwrap_or_default();
 
     // 单次循环完成提取和判断
-    for instruction in inner_instruction.instructions.iter().skip((current_index + 1) as usize) {
+    // current_index 为 -1 表示外层指令，从第一条 inner instruction 开始查找
+    let skip = current_index.saturating_add(1).max(0) as usize;
+    for instruction in inner_instruction.instructions.iter().skip(skip) {
         let compiled = &instruction;
-        let program_id = accounts[compiled.program_id_index as usize];
+        let Some(&program_id) = accounts.get(compiled.program_id_index as usize) else {
+            break;
+        };
         if !SYSTEM_PROGRAMS.contains(&program_id) {
             break;
         }
@@ -641,22 +1066,19 @@ pub fn parse_swap_data_from_next_grpc_instructions(
             continue;
         }
 
-        let get_pubkey = |i: usize| accounts[compiled.accounts[i] as usize];
+        let get_pubkey =
+            |i: usize| compiled.accounts.get(i).and_then(|&index| accounts.get(index as usize));
+        // 数据过短或账户索引越界的指令直接跳过
         let (source, destination, amount) = match data[0] {
-            12 if compiled.accounts.len() >= 4 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(2), amt)
-            }
-            3 if compiled.accounts.len() >= 3 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
-            2 if compiled.accounts.len() >= 2 => {
-                let amt = u64::from_le_bytes(data[4..12].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
+            12 => (get_pubkey(0), get_pubkey(2), read_u64_le(data, 1)),
+            3 => (get_pubkey(0), get_pubkey(1), read_u64_le(data, 1)),
+            2 => (get_pubkey(0), get_pubkey(1), read_u64_le(data, 4)),
             _ => continue,
         };
+        let (Some(&source), Some(&destination), Some(amount)) = (source, destination, amount)
+        else {
+            continue;
+        };
 
         match (source, destination) {
             (s, d) if s == user_to_token && d == to_vault => {
@@ -703,3 +1125,90 @@ pub fn parse_swap_data_from_next_grpc_instructions(
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::bonk::BonkTradeEvent;
+    use solana_sdk::message::compiled_instruction::CompiledInstruction;
+    use solana_transaction_status::{InnerInstruction, InnerInstructions};
+
+    const TOKEN_PROGRAM: usize = 0;
+    const USER_BASE: usize = 1;
+    const BASE_VAULT: usize = 2;
+    const AUTHORITY: usize = 3;
+
+    fn accounts() -> Vec<Pubkey> {
+        let mut accounts = vec![SYSTEM_PROGRAMS[0]];
+        accounts.extend((0..3).map(|_| Pubkey::new_unique()));
+        accounts
+    }
+
+    fn bonk_trade(accounts: &[Pubkey]) -> DexEvent {
+        DexEvent::BonkTradeEvent(BonkTradeEvent {
+            base_token_mint: Pubkey::new_unique(),
+            quote_token_mint: Pubkey::new_unique(),
+            user_base_token: accounts[USER_BASE],
+            user_quote_token: Pubkey::new_unique(),
+            base_vault: accounts[BASE_VAULT],
+            quote_vault: Pubkey::new_unique(),
+            ..Default::default()
+        })
+    }
+
+    fn token_transfer(source: u8, destination: u8, amount: u64) -> InnerInstruction {
+        let mut data = vec![3];
+        data.extend_from_slice(&amount.to_le_bytes());
+        inner(vec![source, destination, AUTHORITY as u8], data)
+    }
+
+    fn inner(accounts: Vec<u8>, data: Vec<u8>) -> InnerInstruction {
+        InnerInstruction {
+            instruction: CompiledInstruction {
+                program_id_index: TOKEN_PROGRAM as u8,
+                accounts,
+                data,
+            },
+            stack_height: None,
+        }
+    }
+
+    #[test]
+    fn swap_data_skips_short_data_and_out_of_range_accounts() {
+        let accounts = accounts();
+        let event = bonk_trade(&accounts);
+        // 数据不足 8 字节
+        let too_short = inner(vec![USER_BASE as u8, BASE_VAULT as u8], vec![3, 1]);
+        // 通过长度检查但不足以读取 u64 数量
+        let truncated_amount = inner(vec![USER_BASE as u8, BASE_VAULT as u8], vec![3; 8]);
+        // 账户下标超出交易账户列表
+        let out_of_range = token_transfer(200, BASE_VAULT as u8, 7);
+        let valid = token_transfer(USER_BASE as u8, BASE_VAULT as u8, 500);
+        let inner_instructions = InnerInstructions {
+            index: 0,
+            instructions: vec![too_short, truncated_amount, out_of_range, valid],
+        };
+
+        let swap_data =
+            parse_swap_data_from_next_instructions(&event, &inner_instructions, -1, &accounts)
+                .unwrap();
+        let DexEvent::BonkTradeEvent(trade) = &event else { unreachable!() };
+        assert_eq!(swap_data.from_mint, trade.base_token_mint);
+        assert_eq!(swap_data.from_amount, 500);
+    }
+
+    #[test]
+    fn swap_data_ignores_transfers_before_inner_index_past_i8_range() {
+        let accounts = accounts();
+        let event = bonk_trade(&accounts);
+        // 第 0 条是更早指令的转账，当前指令位于第 130 条 inner instruction
+        let mut instructions = vec![token_transfer(USER_BASE as u8, BASE_VAULT as u8, 500)];
+        instructions.extend((0..130).map(|_| inner(vec![], vec![0; 8])));
+        let inner_instructions = InnerInstructions { index: 0, instructions };
+
+        assert_eq!(
+            parse_swap_data_from_next_instructions(&event, &inner_instructions, 130, &accounts),
+            None
+        );
+    }
+}
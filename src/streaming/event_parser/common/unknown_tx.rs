@@ -0,0 +1,133 @@
+use parking_lot::RwLock;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::sync::Arc;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/// 匹配了订阅过滤条件、但没有解析出任何事件的交易
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTx {
+    pub signature: Signature,
+    pub slot: u64,
+    /// 交易调用的全部程序 (外层和 inner instructions)，按首次调用的顺序去重
+    pub program_ids: Vec<Pubkey>,
+}
+
+/// 未解析交易回调
+pub type UnknownTxCallback = Arc<dyn Fn(UnknownTx) + Send + Sync>;
+
+/// 与解析错误回调一样在进程内全局生效
+static UNKNOWN_TX_CALLBACK: RwLock<Option<UnknownTxCallback>> = RwLock::new(None);
+
+/// 设置未解析交易回调，替换之前的回调
+///
+/// 回调在解析任务中同步执行，应尽快返回
+pub fn set_unknown_tx_callback<F>(callback: F)
+where
+    F: Fn(UnknownTx) + Send + Sync + 'static,
+{
+    *UNKNOWN_TX_CALLBACK.write() = Some(Arc::new(callback));
+}
+
+/// 移除未解析交易回调
+pub fn clear_unknown_tx_callback() {
+    *UNKNOWN_TX_CALLBACK.write() = None;
+}
+
+/// 是否设置了未解析交易回调，未设置时不收集交易调用的程序
+#[inline]
+pub(crate) fn has_unknown_tx_callback() -> bool {
+    UNKNOWN_TX_CALLBACK.read().is_some()
+}
+
+pub(crate) fn report_unknown_tx(tx: UnknownTx) {
+    let callback = UNKNOWN_TX_CALLBACK.read().clone();
+    if let Some(callback) = callback {
+        callback(tx);
+    }
+}
+
+/// 交易调用的全部程序ID，按首次调用的顺序去重
+pub(crate) fn invoked_program_ids(grpc_tx: &SubscribeUpdateTransactionInfo) -> Vec<Pubkey> {
+    let Some(message) = grpc_tx.transaction.as_ref().and_then(|tx| tx.message.as_ref()) else {
+        return Vec::new();
+    };
+    let meta = grpc_tx.meta.as_ref();
+    let loaded = meta.into_iter().flat_map(|meta| {
+        meta.loaded_writable_addresses.iter().chain(&meta.loaded_readonly_addresses)
+    });
+    let accounts: Vec<&Vec<u8>> = message.account_keys.iter().chain(loaded).collect();
+
+    let outer = message.instructions.iter().map(|instruction| instruction.program_id_index);
+    let inner = meta.into_iter().flat_map(|meta| {
+        meta.inner_instructions
+            .iter()
+            .flat_map(|inner| inner.instructions.iter().map(|ix| ix.program_id_index))
+    });
+    let mut program_ids: Vec<Pubkey> = Vec::new();
+    for index in outer.chain(inner) {
+        let Some(program_id) =
+            accounts.get(index as usize).and_then(|key| Pubkey::try_from(key.as_slice()).ok())
+        else {
+            continue;
+        };
+        if !program_ids.contains(&program_id) {
+            program_ids.push(program_id);
+        }
+    }
+    program_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message, Transaction,
+        TransactionStatusMeta,
+    };
+
+    fn key(n: u8) -> Vec<u8> {
+        vec![n; 32]
+    }
+
+    #[test]
+    fn invoked_programs_include_inner_and_loaded_accounts() {
+        let instruction =
+            |program_id_index| CompiledInstruction { program_id_index, ..Default::default() };
+        let inner_instruction =
+            |program_id_index| InnerInstruction { program_id_index, ..Default::default() };
+        let grpc_tx = SubscribeUpdateTransactionInfo {
+            transaction: Some(Transaction {
+                message: Some(Message {
+                    account_keys: vec![key(1), key(2), key(3)],
+                    // 下标 9 超出账户列表，忽略
+                    instructions: vec![
+                        instruction(1),
+                        instruction(2),
+                        instruction(1),
+                        instruction(9),
+                    ],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            meta: Some(TransactionStatusMeta {
+                loaded_writable_addresses: vec![key(4)],
+                loaded_readonly_addresses: vec![key(5)],
+                inner_instructions: vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![
+                        inner_instruction(4),
+                        inner_instruction(2),
+                        inner_instruction(3),
+                    ],
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let program_ids: Vec<Pubkey> =
+            [2, 3, 5, 4].into_iter().map(|n| Pubkey::new_from_array([n; 32])).collect();
+        assert_eq!(invoked_program_ids(&grpc_tx), program_ids);
+        assert!(invoked_program_ids(&SubscribeUpdateTransactionInfo::default()).is_empty());
+    }
+}
@@ -5,6 +5,11 @@ pub fn current_timestamp() -> i64 {
     SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs() as i64
 }
 
+/// 把时间戳转换为毫秒，超出 i64 范围时取边界值
+pub fn timestamp_millis(seconds: i64, nanos: i32) -> i64 {
+    seconds.saturating_mul(1000).saturating_add(i64::from(nanos) / 1_000_000)
+}
+
 /// 从字节数组中提取鉴别器和剩余数据
 pub fn extract_discriminator(length: usize, data: &[u8]) -> Option<(&[u8], &[u8])> {
     if data.len() < length {
@@ -108,3 +113,22 @@ pub fn format_pubkey_short(pubkey: &solana_sdk::pubkey::Pubkey) -> String {
         format!("{}...{}", &s[..4], &s[s.len() - 4..])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_millis_converts_seconds_and_nanos() {
+        assert_eq!(timestamp_millis(1_700_000_000, 123_456_789), 1_700_000_000_123);
+        assert_eq!(timestamp_millis(0, 999_999), 0);
+    }
+
+    #[test]
+    fn timestamp_millis_saturates_at_i64_bounds() {
+        assert_eq!(timestamp_millis(i64::MAX, 999_999_999), i64::MAX);
+        assert_eq!(timestamp_millis(i64::MAX / 1000, 999_999_999), i64::MAX);
+        assert_eq!(timestamp_millis(i64::MIN, 0), i64::MIN);
+        assert_eq!(timestamp_millis(i64::MIN, -999_999_999), i64::MIN);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::streaming::common::{AccountLamportsCache, AccountOwnerCache, MetricsManager};
+use crate::streaming::event_parser::common::serde_base58;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::high_performance_clock::elapsed_micros_since;
 use crate::streaming::event_parser::common::{EventMetadata, EventType, ProtocolType};
@@ -9,21 +11,34 @@ use solana_account_decoder::parse_nonce::parse_nonce;
 use solana_sdk::pubkey::Pubkey;
 use spl_token::solana_program::program_pack::Pack;
 use spl_token::state::{Account, Mint};
+use spl_token::solana_program::program_option::COption;
+use spl_token::solana_program::pubkey::Pubkey as SplPubkey;
 use spl_token_2022::{
-    extension::StateWithExtensions,
+    extension::{
+        transfer_fee::{TransferFee as SplTransferFee, TransferFeeConfig as SplTransferFeeConfig},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
     state::{Account as Account2022, Mint as Mint2022},
 };
+use std::time::Instant;
+
+fn to_option_pubkey(key: COption<SplPubkey>) -> Option<Pubkey> {
+    Option::from(key).map(|key: SplPubkey| Pubkey::new_from_array(key.to_bytes()))
+}
 
 /// 通用账户事件
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub amount: Option<u64>,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_owner: Pubkey,
 }
 
@@ -31,26 +46,156 @@ pub struct TokenAccountEvent {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NonceAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub nonce: String,
     pub authority: String,
 }
 
-/// Nonce account event
+/// 账户 owner 变化事件，如账户创建后被分配给其他程序、或被关闭后归还系统程序
+///
+/// 与缓存中该账户上次更新的 owner 比较得出，账户第一次出现时不产生
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountOwnerChangedEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub old_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub new_owner: Pubkey,
+    pub lamports: u64,
+}
+
+/// 账户 SOL 余额 (lamports) 变化事件，用于跟踪钱包的 SOL 流入流出
+///
+/// 与缓存中该账户上次更新的 lamports 比较得出，账户第一次出现时不产生
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LamportsChangedEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub owner: Pubkey,
+    pub pre_lamports: u64,
+    pub post_lamports: u64,
+    /// 变化量 (post - pre)，收到为正，转出为负
+    pub delta: i64,
+}
+
+/// 没有解码器可以解析的账户更新，保留原始数据
+///
+/// owner 不是已支持协议的程序 (或该协议未在订阅的协议列表中)、也不是 Token / Nonce 账户，
+/// 或 owner 是已支持协议但 discriminator 未知时产出
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RawAccountEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pubkey: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub owner: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub rent_epoch: u64,
+    pub data: Vec<u8>,
+}
+
+impl RawAccountEvent {
+    /// 账户数据的前 8 字节 (Anchor 账户 discriminator)，数据不足 8 字节时为 None
+    pub fn discriminator(&self) -> Option<&[u8]> {
+        self.data.get(..8)
+    }
+}
+
+/// Token-2022 转账手续费，从 `epoch` 开始生效
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferFee {
+    pub epoch: u64,
+    pub maximum_fee: u64,
+    pub transfer_fee_basis_points: u16,
+}
+
+impl TransferFee {
+    /// 转账 `amount` 时扣除的手续费，与链上一致向上取整并以 `maximum_fee` 封顶
+    pub fn calculate_fee(&self, amount: u64) -> u64 {
+        if self.transfer_fee_basis_points == 0 || amount == 0 {
+            return 0;
+        }
+        let fee = (amount as u128 * self.transfer_fee_basis_points as u128).div_ceil(10_000);
+        fee.min(self.maximum_fee as u128) as u64
+    }
+}
+
+/// Token-2022 TransferFeeConfig 扩展
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferFeeConfig {
+    /// 当前 epoch 小于 `newer_transfer_fee.epoch` 时生效
+    pub older_transfer_fee: TransferFee,
+    /// 当前 epoch 大于等于其 `epoch` 时生效
+    pub newer_transfer_fee: TransferFee,
+    /// 已归集到 mint 账户、等待提取的手续费
+    pub withheld_amount: u64,
+}
+
+impl TransferFeeConfig {
+    /// 指定 epoch 生效的手续费
+    pub fn epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+}
+
+impl From<&SplTransferFee> for TransferFee {
+    fn from(fee: &SplTransferFee) -> Self {
+        Self {
+            epoch: u64::from(fee.epoch),
+            maximum_fee: u64::from(fee.maximum_fee),
+            transfer_fee_basis_points: u16::from(fee.transfer_fee_basis_points),
+        }
+    }
+}
+
+impl From<&SplTransferFeeConfig> for TransferFeeConfig {
+    fn from(config: &SplTransferFeeConfig) -> Self {
+        Self {
+            older_transfer_fee: (&config.older_transfer_fee).into(),
+            newer_transfer_fee: (&config.newer_transfer_fee).into(),
+            withheld_amount: u64::from(config.withheld_amount),
+        }
+    }
+}
+
+/// SPL Token / Token-2022 mint 账户事件
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenInfoEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub supply: u64,
     pub decimals: u8,
+    /// 铸币权限，None 表示已放弃
+    #[serde(with = "serde_base58::option_pubkey")]
+    pub mint_authority: Option<Pubkey>,
+    /// 冻结权限，None 表示未设置
+    #[serde(with = "serde_base58::option_pubkey")]
+    pub freeze_authority: Option<Pubkey>,
+    /// Token-2022 扩展类型名称，如 `"TransferFeeConfig"`；SPL Token mint 为空
+    pub extensions: Vec<String>,
+    /// Token-2022 转账手续费配置，计算实际到账数量时使用
+    pub transfer_fee: Option<TransferFeeConfig>,
 }
 
 pub struct AccountEventParser {}
@@ -70,7 +215,7 @@ impl AccountEventParser {
             // 尝试识别协议类型
             if let Some(protocol) = EventDispatcher::match_protocol_by_program_id(&account.owner) {
                 // 检查是否在请求的协议列表中
-                if protocols.contains(&protocol) {
+                if EventDispatcher::is_protocol_selected(protocols, &protocol) {
                     // 构建临时元数据（protocol会被dispatcher设置，event_type会在parser中设置）
                     let metadata = EventMetadata {
                         slot: account.slot,
@@ -83,22 +228,25 @@ impl AccountEventParser {
                         ..Default::default()
                     };
 
-                    // 使用 dispatcher 解析
-                    if let Some(event) = EventDispatcher::dispatch_account(
-                        protocol,
+                    // 使用 dispatcher 解析，开启 enable_metrics 时记录解析耗时
+                    let started = MetricsManager::global().is_enabled().then(Instant::now);
+                    let event = EventDispatcher::dispatch_account(
+                        protocol.clone(),
                         discriminator,
                         &account,
                         metadata,
-                    ) {
-                        // 应用事件类型过滤
-                        if let Some(filter) = event_type_filter {
-                            if filter.include.contains(&event.metadata().event_type) {
-                                return Some(event);
-                            }
-                            // 不匹配过滤器，继续尝试其他解析方式
-                        } else {
-                            return Some(event);
-                        }
+                    );
+                    if let (Some(started), Some(event)) = (started, &event) {
+                        MetricsManager::global().record_parse(
+                            &protocol,
+                            &event.metadata().event_type,
+                            started.elapsed(),
+                            true,
+                        );
+                    }
+                    if let Some(event) = event {
+                        // 应用事件类型过滤，已解码但被过滤的账户不再作为原始账户推送
+                        return Self::apply_filter(event, event_type_filter);
                     }
                 }
             }
@@ -119,27 +267,120 @@ impl AccountEventParser {
 
         // 尝试解析 Nonce 账户
         if let Some(event) = Self::parse_nonce_account_event(&account, metadata.clone()) {
-            if let Some(filter) = event_type_filter {
-                if filter.include.contains(&event.metadata().event_type) {
-                    return Some(event);
-                }
-            } else {
-                return Some(event);
-            }
+            return Self::apply_filter(event, event_type_filter);
         }
 
         // 尝试解析 Token 账户
-        if let Some(event) = Self::parse_token_account_event(&account, metadata) {
-            if let Some(filter) = event_type_filter {
-                if filter.include.contains(&event.metadata().event_type) {
-                    return Some(event);
-                }
-            } else {
-                return Some(event);
+        if Self::is_token_program(&account.owner) {
+            if let Some(event) = Self::parse_token_account_event(&account, metadata.clone()) {
+                return Self::apply_filter(event, event_type_filter);
             }
         }
 
-        None
+        // 3. 没有解码器匹配时推送原始账户数据
+        Self::apply_filter(Self::parse_raw_account_event(account, metadata), event_type_filter)
+    }
+
+    fn apply_filter(
+        event: DexEvent,
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> Option<DexEvent> {
+        match event_type_filter {
+            Some(filter) if !filter.include.contains(&event.metadata().event_type) => None,
+            _ => Some(event),
+        }
+    }
+
+    /// 账户是否由 SPL Token 或 Token-2022 程序拥有
+    fn is_token_program(owner: &Pubkey) -> bool {
+        let owner = owner.to_bytes();
+        owner == spl_token::ID.to_bytes() || owner == spl_token_2022::ID.to_bytes()
+    }
+
+    /// 保留原始数据的账户事件
+    pub fn parse_raw_account_event(
+        account: AccountPretty,
+        mut metadata: EventMetadata,
+    ) -> DexEvent {
+        metadata.event_type = EventType::RawAccount;
+        metadata.handle_us = elapsed_micros_since(account.recv_us);
+        DexEvent::RawAccountEvent(RawAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            owner: account.owner,
+            executable: account.executable,
+            lamports: account.lamports,
+            rent_epoch: account.rent_epoch,
+            data: account.data,
+        })
+    }
+
+    /// 账户 owner 与订阅上次看到的不同时生成 `AccountOwnerChangedEvent`
+    ///
+    /// 过滤器不包含 `AccountOwnerChanged` 时不记录 owner
+    pub fn parse_owner_changed_event(
+        account: &AccountPretty,
+        owner_cache: &AccountOwnerCache,
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> Option<DexEvent> {
+        if event_type_filter
+            .is_some_and(|filter| !filter.include.contains(&EventType::AccountOwnerChanged))
+        {
+            return None;
+        }
+        let old_owner = owner_cache.observe(account.pubkey, account.slot, account.owner)?;
+        let metadata = EventMetadata {
+            slot: account.slot,
+            signature: account.signature,
+            protocol: ProtocolType::Common,
+            event_type: EventType::AccountOwnerChanged,
+            program_id: account.owner,
+            recv_us: account.recv_us,
+            handle_us: elapsed_micros_since(account.recv_us),
+            ..Default::default()
+        };
+        Some(DexEvent::AccountOwnerChangedEvent(AccountOwnerChangedEvent {
+            metadata,
+            account: account.pubkey,
+            old_owner,
+            new_owner: account.owner,
+            lamports: account.lamports,
+        }))
+    }
+
+    /// 账户 lamports 与订阅上次看到的不同时生成 `LamportsChangedEvent`
+    ///
+    /// 过滤器不包含 `LamportsChanged` 时不记录 lamports
+    pub fn parse_lamports_changed_event(
+        account: &AccountPretty,
+        lamports_cache: &AccountLamportsCache,
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> Option<DexEvent> {
+        if event_type_filter
+            .is_some_and(|filter| !filter.include.contains(&EventType::LamportsChanged))
+        {
+            return None;
+        }
+        let pre_lamports =
+            lamports_cache.observe(account.pubkey, account.slot, account.lamports)?;
+        let metadata = EventMetadata {
+            slot: account.slot,
+            signature: account.signature,
+            protocol: ProtocolType::Common,
+            event_type: EventType::LamportsChanged,
+            program_id: account.owner,
+            recv_us: account.recv_us,
+            handle_us: elapsed_micros_since(account.recv_us),
+            ..Default::default()
+        };
+        Some(DexEvent::LamportsChangedEvent(LamportsChangedEvent {
+            metadata,
+            account: account.pubkey,
+            owner: account.owner,
+            pre_lamports,
+            post_lamports: account.lamports,
+            delta: account.lamports as i64 - pre_lamports as i64,
+        }))
     }
 
     pub fn parse_token_account_event(
@@ -153,41 +394,9 @@ impl AccountEventParser {
         let lamports = account.lamports;
         let owner = account.owner;
         let rent_epoch = account.rent_epoch;
-        // Spl Token Mint
-        if account.data.len() >= Mint::LEN {
-            if let Ok(mint) = Mint::unpack_from_slice(&account.data) {
-                let mut event = TokenInfoEvent {
-                    metadata,
-                    pubkey,
-                    executable,
-                    lamports,
-                    owner,
-                    rent_epoch,
-                    supply: mint.supply,
-                    decimals: mint.decimals,
-                };
-                let recv_delta = elapsed_micros_since(account.recv_us);
-                event.metadata.handle_us = recv_delta;
-                return Some(DexEvent::TokenInfoEvent(event));
-            }
-        }
-        // Spl Token2022 Mint
-        if account.data.len() >= Account2022::LEN {
-            if let Ok(mint) = StateWithExtensions::<Mint2022>::unpack(&account.data) {
-                let mut event = TokenInfoEvent {
-                    metadata,
-                    pubkey,
-                    executable,
-                    lamports,
-                    owner,
-                    rent_epoch,
-                    supply: mint.base.supply,
-                    decimals: mint.base.decimals,
-                };
-                let recv_delta = elapsed_micros_since(account.recv_us);
-                event.metadata.handle_us = recv_delta;
-                return Some(DexEvent::TokenInfoEvent(event));
-            }
+        // Spl Token / Token2022 Mint
+        if let Some(event) = Self::parse_mint_event(account, metadata.clone()) {
+            return Some(event);
         }
         let amount = if account.owner.to_bytes() == spl_token_2022::ID.to_bytes() {
             StateWithExtensions::<Account2022>::unpack(&account.data)
@@ -212,6 +421,45 @@ impl AccountEventParser {
         Some(DexEvent::TokenAccountEvent(event))
     }
 
+    /// 解析 Token / Token-2022 程序拥有的 mint 账户，Token-2022 额外解析扩展和转账手续费
+    pub fn parse_mint_event(account: &AccountPretty, metadata: EventMetadata) -> Option<DexEvent> {
+        let mut event = TokenInfoEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            ..Default::default()
+        };
+        let owner = account.owner.to_bytes();
+        if owner == spl_token_2022::ID.to_bytes() {
+            let mint = StateWithExtensions::<Mint2022>::unpack(&account.data).ok()?;
+            event.supply = mint.base.supply;
+            event.decimals = mint.base.decimals;
+            event.mint_authority = to_option_pubkey(mint.base.mint_authority);
+            event.freeze_authority = to_option_pubkey(mint.base.freeze_authority);
+            event.extensions = mint
+                .get_extension_types()
+                .unwrap_or_default()
+                .iter()
+                .map(|extension| format!("{extension:?}"))
+                .collect();
+            event.transfer_fee =
+                mint.get_extension::<SplTransferFeeConfig>().ok().map(TransferFeeConfig::from);
+        } else if owner == spl_token::ID.to_bytes() {
+            let mint = Mint::unpack(&account.data).ok()?;
+            event.supply = mint.supply;
+            event.decimals = mint.decimals;
+            event.mint_authority = to_option_pubkey(mint.mint_authority);
+            event.freeze_authority = to_option_pubkey(mint.freeze_authority);
+        } else {
+            return None;
+        }
+        event.metadata.handle_us = elapsed_micros_since(account.recv_us);
+        Some(DexEvent::TokenInfoEvent(event))
+    }
+
     pub fn parse_nonce_account_event(
         account: &AccountPretty,
         mut metadata: EventMetadata,
@@ -240,3 +488,200 @@ impl AccountEventParser {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account_update(pubkey: Pubkey, slot: u64, lamports: u64) -> AccountPretty {
+        AccountPretty { pubkey, slot, lamports, ..Default::default() }
+    }
+
+    fn delta(event: Option<DexEvent>) -> Option<(u64, i64)> {
+        match event? {
+            DexEvent::LamportsChangedEvent(event) => Some((event.pre_lamports, event.delta)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn lamports_changes_are_tracked_per_subscription() {
+        let (processed, confirmed) = (AccountLamportsCache::new(), AccountLamportsCache::new());
+        let wallet = Pubkey::new_unique();
+        let updates = [account_update(wallet, 10, 1_000), account_update(wallet, 11, 400)];
+        for (update, expected) in updates.iter().zip([None, Some((1_000, -600))]) {
+            // 两个订阅都收到同一次变化，先解析的一方不会吞掉另一方的事件
+            for cache in [&processed, &confirmed] {
+                let event = AccountEventParser::parse_lamports_changed_event(update, cache, None);
+                assert_eq!(delta(event), expected);
+            }
+        }
+
+        // 较低 slot 的更新晚到时不产生反向变化
+        let late = account_update(wallet, 10, 1_000);
+        assert!(AccountEventParser::parse_lamports_changed_event(&late, &processed, None).is_none());
+        let next = account_update(wallet, 12, 900);
+        assert_eq!(
+            delta(AccountEventParser::parse_lamports_changed_event(&next, &processed, None)),
+            Some((400, 500))
+        );
+    }
+
+    #[test]
+    fn excluded_lamports_changes_are_not_recorded() {
+        let cache = AccountLamportsCache::new();
+        let filter = EventTypeFilter { include: vec![EventType::AccountOwnerChanged] };
+        let update = account_update(Pubkey::new_unique(), 1, 5);
+        assert!(
+            AccountEventParser::parse_lamports_changed_event(&update, &cache, Some(&filter))
+                .is_none()
+        );
+        assert!(cache.is_empty());
+    }
+
+    fn mint_account(owner: Pubkey, data: Vec<u8>) -> AccountPretty {
+        AccountPretty { pubkey: Pubkey::new_unique(), owner, data, ..Default::default() }
+    }
+
+    fn token_info(event: Option<DexEvent>) -> TokenInfoEvent {
+        match event {
+            Some(DexEvent::TokenInfoEvent(info)) => info,
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+
+    #[test]
+    fn spl_token_mint_decodes_authorities() {
+        let authority = SplPubkey::new_from_array([1; 32]);
+        let mint = Mint {
+            mint_authority: COption::Some(authority),
+            supply: 1_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+        let owner = Pubkey::new_from_array(spl_token::ID.to_bytes());
+
+        let account = mint_account(owner, data);
+        let info = token_info(AccountEventParser::parse_account_event(&[], account, None));
+        assert_eq!(info.metadata.event_type, EventType::TokenAccount);
+        assert_eq!((info.supply, info.decimals), (1_000_000, 6));
+        assert_eq!(info.mint_authority, Some(Pubkey::new_from_array([1; 32])));
+        assert_eq!(info.freeze_authority, None);
+        assert!(info.extensions.is_empty());
+        assert_eq!(info.transfer_fee, None);
+    }
+
+    #[test]
+    fn token_2022_mint_decodes_transfer_fee_extension() {
+        use spl_token_2022::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
+
+        let len = ExtensionType::try_calculate_account_len::<Mint2022>(&[
+            ExtensionType::TransferFeeConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0; len];
+        let mut state =
+            StateWithExtensionsMut::<Mint2022>::unpack_uninitialized(&mut data).unwrap();
+        let config = state.init_extension::<SplTransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = SplTransferFee {
+            epoch: 0.into(),
+            maximum_fee: 0.into(),
+            transfer_fee_basis_points: 0.into(),
+        };
+        config.newer_transfer_fee = SplTransferFee {
+            epoch: 500.into(),
+            maximum_fee: 5_000.into(),
+            transfer_fee_basis_points: 100.into(),
+        };
+        config.withheld_amount = 42.into();
+        state.base = Mint2022 {
+            mint_authority: COption::None,
+            supply: 10,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: COption::Some(SplPubkey::new_from_array([2; 32])),
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        let owner = Pubkey::new_from_array(spl_token_2022::ID.to_bytes());
+
+        let info = token_info(AccountEventParser::parse_mint_event(
+            &mint_account(owner, data),
+            EventMetadata::default(),
+        ));
+        assert_eq!((info.supply, info.decimals), (10, 9));
+        assert_eq!(info.mint_authority, None);
+        assert_eq!(info.freeze_authority, Some(Pubkey::new_from_array([2; 32])));
+        assert_eq!(info.extensions, vec!["TransferFeeConfig".to_string()]);
+
+        let config = info.transfer_fee.unwrap();
+        assert_eq!(config.withheld_amount, 42);
+        assert_eq!(config.epoch_fee(499), &config.older_transfer_fee);
+        assert_eq!(config.epoch_fee(500), &config.newer_transfer_fee);
+    }
+
+    #[test]
+    fn non_mint_accounts_are_not_decoded_as_mints() {
+        let owner = Pubkey::new_from_array(spl_token::ID.to_bytes());
+        let account = mint_account(owner, vec![0; 10]);
+        assert!(AccountEventParser::parse_mint_event(&account, EventMetadata::default()).is_none());
+        let account = mint_account(Pubkey::new_unique(), vec![0; Mint::LEN]);
+        assert!(AccountEventParser::parse_mint_event(&account, EventMetadata::default()).is_none());
+    }
+
+    #[test]
+    fn transfer_fee_rounds_up_and_is_capped() {
+        let fee = TransferFee { epoch: 0, maximum_fee: 50, transfer_fee_basis_points: 100 };
+        assert_eq!(fee.calculate_fee(0), 0);
+        assert_eq!(fee.calculate_fee(1), 1);
+        assert_eq!(fee.calculate_fee(100), 1);
+        assert_eq!(fee.calculate_fee(101), 2);
+        assert_eq!(fee.calculate_fee(u64::MAX), 50);
+        let free = TransferFee { transfer_fee_basis_points: 0, ..fee };
+        assert_eq!(free.calculate_fee(1_000), 0);
+    }
+
+    #[test]
+    fn owner_changes_are_reported_with_the_previous_owner() {
+        let cache = AccountOwnerCache::new();
+        let (account, system, program) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let update = |slot, owner| AccountPretty {
+            pubkey: account,
+            slot,
+            owner,
+            lamports: 1_000,
+            ..Default::default()
+        };
+
+        // 第一次出现和 owner 未变时不产生事件
+        assert!(AccountEventParser::parse_owner_changed_event(&update(10, system), &cache, None)
+            .is_none());
+        assert!(AccountEventParser::parse_owner_changed_event(&update(11, system), &cache, None)
+            .is_none());
+        let event =
+            AccountEventParser::parse_owner_changed_event(&update(12, program), &cache, None);
+        let Some(DexEvent::AccountOwnerChangedEvent(changed)) = event else {
+            panic!("unexpected event {event:?}")
+        };
+        assert_eq!(changed.metadata.event_type, EventType::AccountOwnerChanged);
+        assert_eq!(
+            (changed.account, changed.old_owner, changed.new_owner),
+            (account, system, program)
+        );
+        assert_eq!((changed.metadata.slot, changed.lamports), (12, 1_000));
+
+        // 过滤器不包含该事件类型时不记录
+        let filter = EventTypeFilter { include: vec![EventType::LamportsChanged] };
+        let update = update(13, system);
+        assert!(
+            AccountEventParser::parse_owner_changed_event(&update, &cache, Some(&filter)).is_none()
+        );
+        assert_eq!(cache.get(&account), Some(program));
+    }
+}
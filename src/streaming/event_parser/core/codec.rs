@@ -0,0 +1,467 @@
+use crate::streaming::event_parser::core::traits::DexEvent;
+
+/// 二进制编码头部的魔数
+pub const EVENT_CODEC_MAGIC: [u8; 4] = *b"SSEV";
+/// 二进制编码格式版本
+///
+/// bincode 按字段顺序编码且不带字段名，枚举按变体下标编码。`EventMetadata` 或任一事件结构体
+/// 增删、调整字段，以及 `DexEvent`、`ProtocolType`、`EventType` 等枚举的变体顺序变化都会改变布局，
+/// 每次这类改动都要递增，旧版本的接收端才能得到 `UnsupportedVersion` 而不是解码错误。
+/// 新增变体只追加在枚举末尾
+pub const EVENT_CODEC_VERSION: u16 = 24;
+/// 头部长度：魔数 + 小端 u16 版本号
+pub const EVENT_CODEC_HEADER_LEN: usize = EVENT_CODEC_MAGIC.len() + 2;
+
+/// 二进制事件解码错误
+#[derive(Debug, thiserror::Error)]
+pub enum EventCodecError {
+    /// 数据长度不足一个头部
+    #[error("buffer too short: {0} bytes")]
+    Truncated(usize),
+    /// 魔数不匹配，数据不是本库编码的事件
+    #[error("invalid magic: {0:?}")]
+    InvalidMagic([u8; 4]),
+    /// 编码版本与当前库不一致
+    #[error("unsupported codec version {found}, expected {expected}")]
+    UnsupportedVersion { found: u16, expected: u16 },
+    /// 事件体反序列化失败
+    #[error("decode error: {0}")]
+    Decode(#[from] bincode::Error),
+}
+
+/// 将事件编码为带版本头的二进制格式，用于进程间传输
+///
+/// 格式为 `"SSEV"` 魔数 + 小端 u16 版本号 + bincode 编码的 `DexEvent`
+pub fn encode_event(event: &DexEvent) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(EVENT_CODEC_HEADER_LEN + 256);
+    buf.extend_from_slice(&EVENT_CODEC_MAGIC);
+    buf.extend_from_slice(&EVENT_CODEC_VERSION.to_le_bytes());
+    // 写入 Vec 且事件不含无长度序列，bincode 序列化不会失败
+    bincode::serialize_into(&mut buf, event).expect("DexEvent serialization is infallible");
+    buf
+}
+
+/// 解码 `encode_event` 生成的数据，校验魔数和版本号
+pub fn decode_event(bytes: &[u8]) -> Result<DexEvent, EventCodecError> {
+    if bytes.len() < EVENT_CODEC_HEADER_LEN {
+        return Err(EventCodecError::Truncated(bytes.len()));
+    }
+    let (header, body) = bytes.split_at(EVENT_CODEC_HEADER_LEN);
+    let magic: [u8; 4] = header[..4].try_into().unwrap();
+    if magic != EVENT_CODEC_MAGIC {
+        return Err(EventCodecError::InvalidMagic(magic));
+    }
+    let version = u16::from_le_bytes([header[4], header[5]]);
+    if version != EVENT_CODEC_VERSION {
+        return Err(EventCodecError::UnsupportedVersion {
+            found: version,
+            expected: EVENT_CODEC_VERSION,
+        });
+    }
+    Ok(bincode::deserialize(body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::{
+        EventCommitment, EventMetadata, EventType, ProtocolType, SwapData, TokenBalanceChange,
+        TradeTokenInfo, TransferFeeAmounts,
+    };
+    use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+    /// 当前编码布局的指纹：`layout_fingerprint` 变化说明布局变了，确认已递增
+    /// `EVENT_CODEC_VERSION` 后把两个值一起更新
+    const LAYOUT_FINGERPRINT: (u16, u64) = (24, 0x1036_efee_8ce8_4794);
+
+    /// 列出枚举的全部变体；match 不带通配分支，新增变体时编译失败，提醒补充到列表中
+    macro_rules! every_variant {
+        ($enum:ident; $($variant:ident),* $(,)?) => {{
+            fn covered(value: &$enum) {
+                match value {
+                    $($enum::$variant => {})*
+                }
+            }
+            let values = vec![$((stringify!($variant), $enum::$variant)),*];
+            values.iter().for_each(|(_, value)| covered(value));
+            values
+        }};
+        ($enum:ident($($variant:ident),* $(,)?)) => {{
+            fn covered(value: &$enum) {
+                match value {
+                    $($enum::$variant(_) => {})*
+                }
+            }
+            // 逐个构造，避免调试构建中一次性在栈上放下所有事件
+            let constructors: Vec<(&'static str, fn() -> $enum)> =
+                vec![$((stringify!($variant), || $enum::$variant(Default::default()))),*];
+            constructors
+                .into_iter()
+                .map(|(name, construct)| {
+                    let value = construct();
+                    covered(&value);
+                    (name, value)
+                })
+                .collect()
+        }};
+    }
+
+    /// 每个 `DexEvent` 变体各一个默认事件
+    fn every_event() -> Vec<(&'static str, DexEvent)> {
+        every_variant!(DexEvent(
+            BonkTradeEvent,
+            BonkPoolCreateEvent,
+            BonkMigrateToAmmEvent,
+            BonkMigrateToCpswapEvent,
+            BonkPoolStateAccountEvent,
+            BonkGlobalConfigAccountEvent,
+            BonkPlatformConfigAccountEvent,
+            PumpFunCreateTokenEvent,
+            PumpFunCreateV2TokenEvent,
+            PumpFunTradeEvent,
+            PumpFunMigrateEvent,
+            PumpFunBondingCurveAccountEvent,
+            PumpFunGlobalAccountEvent,
+            PumpSwapBuyEvent,
+            PumpSwapSellEvent,
+            PumpSwapCreatePoolEvent,
+            PumpSwapDepositEvent,
+            PumpSwapWithdrawEvent,
+            PumpSwapGlobalConfigAccountEvent,
+            PumpSwapPoolAccountEvent,
+            RaydiumAmmV4SwapEvent,
+            RaydiumAmmV4DepositEvent,
+            RaydiumAmmV4WithdrawEvent,
+            RaydiumAmmV4WithdrawPnlEvent,
+            RaydiumAmmV4Initialize2Event,
+            RaydiumAmmV4AmmInfoAccountEvent,
+            RaydiumClmmSwapEvent,
+            RaydiumClmmSwapV2Event,
+            RaydiumClmmClosePositionEvent,
+            RaydiumClmmIncreaseLiquidityV2Event,
+            RaydiumClmmDecreaseLiquidityV2Event,
+            RaydiumClmmCreatePoolEvent,
+            RaydiumClmmOpenPositionWithToken22NftEvent,
+            RaydiumClmmOpenPositionV2Event,
+            RaydiumClmmAmmConfigAccountEvent,
+            RaydiumClmmPoolStateAccountEvent,
+            RaydiumClmmTickArrayStateAccountEvent,
+            RaydiumCpmmSwapEvent,
+            RaydiumCpmmDepositEvent,
+            RaydiumCpmmWithdrawEvent,
+            RaydiumCpmmInitializeEvent,
+            RaydiumCpmmAmmConfigAccountEvent,
+            RaydiumCpmmPoolStateAccountEvent,
+            MeteoraDammV2SwapEvent,
+            MeteoraDammV2Swap2Event,
+            MeteoraDammV2InitializePoolEvent,
+            MeteoraDammV2InitializeCustomizablePoolEvent,
+            MeteoraDammV2InitializePoolWithDynamicConfigEvent,
+            OrcaWhirlpoolSwapEvent,
+            OrcaWhirlpoolIncreaseLiquidityEvent,
+            OrcaWhirlpoolDecreaseLiquidityEvent,
+            OrcaWhirlpoolInitializePoolEvent,
+            MeteoraDlmmSwapEvent,
+            MeteoraDlmmAddLiquidityEvent,
+            MeteoraDlmmRemoveLiquidityEvent,
+            MeteoraDlmmGoToABinEvent,
+            PhoenixFillEvent,
+            PhoenixPlaceEvent,
+            PhoenixReduceEvent,
+            JupiterRoute,
+            MoonshotTradeEvent,
+            MoonshotMigrationEvent,
+            TokenAccountEvent,
+            NonceAccountEvent,
+            TokenInfoEvent,
+            BlockMetaEvent,
+            SetComputeUnitLimitEvent,
+            SetComputeUnitPriceEvent,
+            ReconnectEvent,
+            CommitmentUpgradeEvent,
+            SlotLagEvent,
+            MeteoraDammV2PoolAccountEvent,
+            LifinitySwapEvent,
+            OpenBookV2FillEvent,
+            RaydiumClmmOpenPositionEvent,
+            RaydiumClmmIncreaseLiquidityEvent,
+            RaydiumClmmDecreaseLiquidityEvent,
+            RaydiumClmmIncreaseLiquidityLogEvent,
+            RaydiumClmmDecreaseLiquidityLogEvent,
+            RaydiumClmmCollectPersonalFeeEvent,
+            RaydiumClmmLiquidityChangeEvent,
+            AccountOwnerChangedEvent,
+            SlotRollbackEvent,
+            RawAccountEvent,
+            SanctumSwapEvent,
+            RaydiumClmmSwapLogEvent,
+            OrcaWhirlpoolTradedEvent,
+            EntryEvent,
+            LamportsChangedEvent,
+            CustomEvent,
+        ))
+    }
+
+    fn every_protocol_type() -> Vec<(&'static str, ProtocolType)> {
+        every_variant!(ProtocolType;
+            PumpSwap,
+            PumpFun,
+            Bonk,
+            RaydiumCpmm,
+            RaydiumClmm,
+            RaydiumAmmV4,
+            MeteoraDammV2,
+            OrcaWhirlpool,
+            MeteoraDlmm,
+            Phoenix,
+            Jupiter,
+            Moonshot,
+            Lifinity,
+            OpenBookV2,
+            Sanctum,
+            Common,
+            Custom,
+        )
+    }
+
+    fn every_event_type() -> Vec<(&'static str, EventType)> {
+        every_variant!(EventType;
+            PumpSwapBuy,
+            PumpSwapSell,
+            PumpSwapCreatePool,
+            PumpSwapDeposit,
+            PumpSwapWithdraw,
+            PumpFunCreateToken,
+            PumpFunCreateV2Token,
+            PumpFunBuy,
+            PumpFunSell,
+            PumpFunMigrate,
+            BonkBuyExactIn,
+            BonkBuyExactOut,
+            BonkSellExactIn,
+            BonkSellExactOut,
+            BonkInitialize,
+            BonkInitializeV2,
+            BonkInitializeWithToken2022,
+            BonkMigrateToAmm,
+            BonkMigrateToCpswap,
+            RaydiumCpmmSwapBaseInput,
+            RaydiumCpmmSwapBaseOutput,
+            RaydiumCpmmDeposit,
+            RaydiumCpmmInitialize,
+            RaydiumCpmmWithdraw,
+            RaydiumClmmSwap,
+            RaydiumClmmSwapV2,
+            RaydiumClmmClosePosition,
+            RaydiumClmmIncreaseLiquidityV2,
+            RaydiumClmmDecreaseLiquidityV2,
+            RaydiumClmmCreatePool,
+            RaydiumClmmOpenPositionWithToken22Nft,
+            RaydiumClmmOpenPositionV2,
+            RaydiumClmmOpenPosition,
+            RaydiumClmmIncreaseLiquidity,
+            RaydiumClmmDecreaseLiquidity,
+            RaydiumClmmIncreaseLiquidityLog,
+            RaydiumClmmDecreaseLiquidityLog,
+            RaydiumClmmCollectPersonalFee,
+            RaydiumClmmLiquidityChange,
+            RaydiumClmmSwapLog,
+            RaydiumAmmV4SwapBaseIn,
+            RaydiumAmmV4SwapBaseOut,
+            RaydiumAmmV4Deposit,
+            RaydiumAmmV4Initialize2,
+            RaydiumAmmV4Withdraw,
+            RaydiumAmmV4WithdrawPnl,
+            MeteoraDammV2Swap,
+            MeteoraDammV2Swap2,
+            MeteoraDammV2InitializePool,
+            MeteoraDammV2InitializeCustomizablePool,
+            MeteoraDammV2InitializePoolWithDynamicConfig,
+            OrcaWhirlpoolSwap,
+            OrcaWhirlpoolSwapV2,
+            OrcaWhirlpoolIncreaseLiquidity,
+            OrcaWhirlpoolIncreaseLiquidityV2,
+            OrcaWhirlpoolDecreaseLiquidity,
+            OrcaWhirlpoolDecreaseLiquidityV2,
+            OrcaWhirlpoolInitializePool,
+            OrcaWhirlpoolInitializePoolV2,
+            OrcaWhirlpoolTraded,
+            MeteoraDlmmSwap,
+            MeteoraDlmmSwap2,
+            MeteoraDlmmSwapExactOut,
+            MeteoraDlmmSwapExactOut2,
+            MeteoraDlmmAddLiquidityByStrategy,
+            MeteoraDlmmRemoveLiquidity,
+            MeteoraDlmmGoToABin,
+            PhoenixFill,
+            PhoenixPlace,
+            PhoenixReduce,
+            PhoenixCancel,
+            JupiterRoute,
+            JupiterRouteWithTokenLedger,
+            JupiterExactOutRoute,
+            JupiterSharedAccountsRoute,
+            JupiterSharedAccountsRouteWithTokenLedger,
+            JupiterSharedAccountsExactOutRoute,
+            MoonshotBuy,
+            MoonshotSell,
+            MoonshotMigration,
+            LifinitySwap,
+            OpenBookV2Fill,
+            SanctumSwapExactIn,
+            SanctumSwapExactOut,
+            AccountRaydiumAmmV4AmmInfo,
+            AccountPumpSwapGlobalConfig,
+            AccountPumpSwapPool,
+            AccountBonkPoolState,
+            AccountBonkGlobalConfig,
+            AccountBonkPlatformConfig,
+            AccountBonkVestingRecord,
+            AccountPumpFunBondingCurve,
+            AccountPumpFunGlobal,
+            AccountRaydiumClmmAmmConfig,
+            AccountRaydiumClmmPoolState,
+            AccountRaydiumClmmTickArrayState,
+            AccountRaydiumCpmmAmmConfig,
+            AccountRaydiumCpmmPoolState,
+            AccountMeteoraDammV2Pool,
+            NonceAccount,
+            TokenAccount,
+            AccountOwnerChanged,
+            LamportsChanged,
+            RawAccount,
+            BlockMeta,
+            SetComputeUnitLimit,
+            SetComputeUnitPrice,
+            Reconnect,
+            CommitmentUpgrade,
+            SlotLag,
+            SlotRollback,
+            Entry,
+            Custom,
+            Unknown,
+        )
+    }
+
+    /// 所有可选字段都有值的元数据，覆盖默认值测不到的编码分支
+    fn populated_metadata() -> EventMetadata {
+        let mut metadata = EventMetadata::new(
+            Signature::from([7; 64]),
+            312_000_000,
+            Some(1_733_000_000),
+            1_733_000_000_123,
+            ProtocolType::RaydiumCpmm,
+            EventType::RaydiumCpmmSwapBaseInput,
+            Pubkey::new_from_array([1; 32]),
+            3,
+            Some(2),
+            1_733_000_000_456_789,
+            Some(42),
+        );
+        metadata.handle_us = 12;
+        metadata.received_us = 1_733_000_000_400_000;
+        metadata.swap_data = Some(SwapData {
+            from_mint: Pubkey::new_from_array([2; 32]),
+            to_mint: Pubkey::new_from_array([3; 32]),
+            from_amount: 1_000,
+            to_amount: 2_000,
+            description: Some("route".into()),
+        });
+        metadata.balance_changes = vec![TokenBalanceChange {
+            account: Pubkey::new_from_array([4; 32]),
+            mint: Pubkey::new_from_array([2; 32]),
+            owner: Pubkey::new_from_array([5; 32]),
+            decimals: 6,
+            pre_amount: 10,
+            post_amount: 1_010,
+        }];
+        metadata.compute_units_consumed = Some(42_000);
+        metadata.priority_fee_lamports = Some(5_000);
+        metadata.jito_tip_lamports = Some(10_000);
+        metadata.succeeded = false;
+        metadata.commitment = Some(EventCommitment::Confirmed);
+        metadata.cpi_depth = 1;
+        metadata.outer_program = Some(Pubkey::new_from_array([6; 32]));
+        metadata.transfer_fee_amounts = Some(TransferFeeAmounts {
+            base_gross_amount: 1_000,
+            base_net_amount: 990,
+            quote_gross_amount: 2_000,
+            quote_net_amount: 2_000,
+        });
+        metadata.token_info = Some(TradeTokenInfo {
+            base_symbol: Some("BASE".to_string()),
+            base_decimals: Some(6),
+            base_ui_amount: Some("0.00099".to_string()),
+            ..Default::default()
+        });
+        metadata
+    }
+
+    /// 按名称排序后对每个变体的编码做 FNV-1a，变体下标或任一结构体的字段变化都会改变结果
+    fn layout_fingerprint() -> u64 {
+        let mut entries: Vec<(&str, Vec<u8>)> = Vec::new();
+        for (name, mut event) in every_event() {
+            *event.metadata_mut() = populated_metadata();
+            entries.push((name, encode_event(&event)));
+        }
+        for (name, protocol) in every_protocol_type() {
+            entries.push((name, bincode::serialize(&protocol).unwrap()));
+        }
+        for (name, event_type) in every_event_type() {
+            entries.push((name, bincode::serialize(&event_type).unwrap()));
+        }
+        entries.sort();
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for (name, bytes) in &entries {
+            for byte in name.bytes().chain(bytes.iter().copied()) {
+                hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        hash
+    }
+
+    #[test]
+    fn every_variant_round_trips() {
+        for (_, mut event) in every_event() {
+            for metadata in [EventMetadata::default(), populated_metadata()] {
+                *event.metadata_mut() = metadata;
+                let encoded = encode_event(&event);
+                let decoded = decode_event(&encoded)
+                    .unwrap_or_else(|err| panic!("{err} while decoding {event:?}"));
+                assert_eq!(decoded, event);
+                assert_eq!(encode_event(&decoded), encoded);
+            }
+        }
+    }
+
+    #[test]
+    fn layout_changes_bump_the_version() {
+        assert_eq!(
+            (EVENT_CODEC_VERSION, layout_fingerprint()),
+            LAYOUT_FINGERPRINT,
+            "the encoded layout changed: bump EVENT_CODEC_VERSION and update LAYOUT_FINGERPRINT"
+        );
+    }
+
+    #[test]
+    fn rejects_other_versions_and_bad_headers() {
+        let mut encoded = encode_event(&every_event().remove(0).1);
+        assert!(matches!(
+            decode_event(&encoded[..EVENT_CODEC_HEADER_LEN - 1]),
+            Err(EventCodecError::Truncated(5))
+        ));
+
+        encoded[4..6].copy_from_slice(&(EVENT_CODEC_VERSION - 1).to_le_bytes());
+        assert!(matches!(
+            decode_event(&encoded),
+            Err(EventCodecError::UnsupportedVersion { found, expected })
+                if found == EVENT_CODEC_VERSION - 1 && expected == EVENT_CODEC_VERSION
+        ));
+
+        encoded[..4].copy_from_slice(b"XXXX");
+        assert!(matches!(
+            decode_event(&encoded),
+            Err(EventCodecError::InvalidMagic(magic)) if &magic == b"XXXX"
+        ));
+    }
+}
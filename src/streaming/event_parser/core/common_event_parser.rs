@@ -3,9 +3,11 @@ use crate::streaming::event_parser::common::types::{EventType, ProtocolType};
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::streaming::event_parser::core::traits::DexEvent;
 use crate::streaming::event_parser::protocols::block::block_meta_event::BlockMetaEvent;
+use crate::streaming::event_parser::protocols::block::entry_event::EntryEvent;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 
 // Compute Budget Program ID
 pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
@@ -29,20 +31,236 @@ pub struct SetComputeUnitPriceEvent {
     pub micro_lamports: u64,
 }
 
+/// gRPC 断线重连事件
+///
+/// 重连成功后发出，断开期间的事件可能已丢失
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ReconnectEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    /// 本次重连成功前尝试的次数
+    pub attempts: u32,
+    /// 断开原因
+    pub reason: String,
+    /// 断开时间 (微秒)
+    pub disconnected_us: i64,
+}
+
+/// 交易升级到的确认级别
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshDeserialize,
+)]
+pub enum CommitmentUpgradeLevel {
+    #[default]
+    Confirmed,
+    Finalized,
+}
+
+/// 已推送过事件的交易达到更高确认级别
+///
+/// 开启 `track_commitment_upgrades` 后，根据 slot 状态更新发出
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CommitmentUpgradeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub level: CommitmentUpgradeLevel,
+}
+
+/// 处理进度落后于链上最新 slot
+///
+/// 开启 `slot_lag.warn` 后，延迟超过阈值时发出一次，延迟回落到阈值一半以下后才会再次发出
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SlotLagEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    /// 最近处理完成的 slot
+    pub processed_slot: u64,
+    /// 估算的链上最新 slot
+    pub chain_tip_slot: u64,
+    /// 落后的 slot 数
+    pub lag_slots: u64,
+    /// 触发告警的阈值
+    pub threshold_slots: u64,
+}
+
+/// slot 被分叉回滚，已推送的该 slot 的事件应当撤销
+///
+/// 开启 `detect_forks` 后，根据 block meta 的父 slot 链发出，`metadata.slot` 为被回滚的 slot
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SlotRollbackEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    /// 被回滚的 slot
+    pub slot: u64,
+    /// 被回滚区块的 block hash
+    pub block_hash: String,
+    /// 触发回滚的新区块的 slot
+    pub fork_slot: u64,
+    /// 新区块的父 slot，分叉点
+    pub fork_parent_slot: u64,
+}
+
 pub struct CommonEventParser {}
 
 impl CommonEventParser {
+    #[allow(clippy::too_many_arguments)]
     pub fn generate_block_meta_event(
         slot: u64,
         block_hash: String,
+        parent_slot: u64,
+        parent_block_hash: String,
+        block_height: Option<u64>,
+        executed_transaction_count: u64,
+        block_time: Option<i64>,
         block_time_ms: i64,
         recv_us: i64,
     ) -> DexEvent {
-        let mut block_meta_event = BlockMetaEvent::new(slot, block_hash, block_time_ms, recv_us);
+        let mut block_meta_event = BlockMetaEvent::new(
+            slot,
+            block_hash,
+            parent_slot,
+            parent_block_hash,
+            block_height,
+            executed_transaction_count,
+            block_time,
+            block_time_ms,
+            recv_us,
+        );
         block_meta_event.metadata.handle_us = elapsed_micros_since(recv_us);
         DexEvent::BlockMetaEvent(block_meta_event)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_entry_event(
+        slot: u64,
+        index: u64,
+        num_hashes: u64,
+        hash: String,
+        num_transactions: u64,
+        starting_transaction_index: u64,
+        block_time: Option<i64>,
+        block_time_ms: i64,
+        recv_us: i64,
+    ) -> DexEvent {
+        let mut entry_event = EntryEvent::new(
+            slot,
+            index,
+            num_hashes,
+            hash,
+            num_transactions,
+            starting_transaction_index,
+            block_time,
+            block_time_ms,
+            recv_us,
+        );
+        entry_event.metadata.handle_us = elapsed_micros_since(recv_us);
+        DexEvent::EntryEvent(entry_event)
+    }
+
+    pub fn generate_reconnect_event(
+        attempts: u32,
+        reason: String,
+        disconnected_us: i64,
+        recv_us: i64,
+    ) -> DexEvent {
+        let metadata = EventMetadata::new(
+            Signature::default(),
+            0,
+            None,
+            recv_us / 1_000,
+            ProtocolType::Common,
+            EventType::Reconnect,
+            Pubkey::default(),
+            0,
+            None,
+            recv_us,
+            None,
+        );
+        DexEvent::ReconnectEvent(ReconnectEvent { metadata, attempts, reason, disconnected_us })
+    }
+
+    pub fn generate_commitment_upgrade_event(
+        signature: Signature,
+        slot: u64,
+        transaction_index: Option<u64>,
+        block_time: Option<i64>,
+        level: CommitmentUpgradeLevel,
+        recv_us: i64,
+    ) -> DexEvent {
+        let metadata = EventMetadata::new(
+            signature,
+            slot,
+            block_time,
+            block_time.map(|t| t.saturating_mul(1000)).unwrap_or_default(),
+            ProtocolType::Common,
+            EventType::CommitmentUpgrade,
+            Pubkey::default(),
+            0,
+            None,
+            recv_us,
+            transaction_index,
+        );
+        DexEvent::CommitmentUpgradeEvent(CommitmentUpgradeEvent { metadata, level })
+    }
+
+    pub fn generate_slot_lag_event(
+        processed_slot: u64,
+        chain_tip_slot: u64,
+        lag_slots: u64,
+        threshold_slots: u64,
+        recv_us: i64,
+    ) -> DexEvent {
+        let metadata = EventMetadata::new(
+            Signature::default(),
+            processed_slot,
+            None,
+            recv_us / 1_000,
+            ProtocolType::Common,
+            EventType::SlotLag,
+            Pubkey::default(),
+            0,
+            None,
+            recv_us,
+            None,
+        );
+        DexEvent::SlotLagEvent(SlotLagEvent {
+            metadata,
+            processed_slot,
+            chain_tip_slot,
+            lag_slots,
+            threshold_slots,
+        })
+    }
+
+    pub fn generate_slot_rollback_event(
+        slot: u64,
+        block_hash: String,
+        fork_slot: u64,
+        fork_parent_slot: u64,
+        recv_us: i64,
+    ) -> DexEvent {
+        let metadata = EventMetadata::new(
+            Signature::default(),
+            slot,
+            None,
+            recv_us / 1_000,
+            ProtocolType::Common,
+            EventType::SlotRollback,
+            Pubkey::default(),
+            0,
+            None,
+            recv_us,
+            None,
+        );
+        DexEvent::SlotRollbackEvent(SlotRollbackEvent {
+            metadata,
+            slot,
+            block_hash,
+            fork_slot,
+            fork_parent_slot,
+        })
+    }
+
     /// 解析 Compute Budget 指令
     pub fn parse_compute_budget_instruction(
         instruction_data: &[u8],
@@ -8,12 +8,16 @@
 //! - **可测试性**: 每个函数都可以独立测试
 
 use crate::streaming::event_parser::{
-    common::EventMetadata,
+    common::{balances::TransactionBalances, EventMetadata, EventType},
     core::common_event_parser::{CommonEventParser, COMPUTE_BUDGET_PROGRAM_ID},
     protocols::{
         bonk::parser as bonk, meteora_damm_v2::parser as meteora_damm_v2, pumpfun::parser as pumpfun,
         pumpswap::parser as pumpswap, raydium_amm_v4::parser as raydium_amm_v4,
         raydium_clmm::parser as raydium_clmm, raydium_cpmm::parser as raydium_cpmm,
+        orca_whirlpool::parser as orca_whirlpool, meteora_dlmm::parser as meteora_dlmm,
+        phoenix::parser as phoenix, raydium_launchpad::parser as raydium_launchpad,
+        jupiter::parser as jupiter, moonshot::parser as moonshot, lifinity::parser as lifinity,
+        openbook_v2::parser as openbook_v2, sanctum::parser as sanctum,
     },
     DexEvent, Protocol,
 };
@@ -54,6 +58,15 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
             Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
             Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+            Protocol::OrcaWhirlpool => ProtocolType::OrcaWhirlpool,
+            Protocol::MeteoraDlmm => ProtocolType::MeteoraDlmm,
+            Protocol::Phoenix => ProtocolType::Phoenix,
+            Protocol::RaydiumLaunchpad => ProtocolType::Bonk,
+            Protocol::Jupiter => ProtocolType::Jupiter,
+            Protocol::Moonshot => ProtocolType::Moonshot,
+            Protocol::Lifinity => ProtocolType::Lifinity,
+            Protocol::OpenBookV2 => ProtocolType::OpenBookV2,
+            Protocol::Sanctum => ProtocolType::Sanctum,
         };
 
         match protocol {
@@ -99,6 +112,62 @@ impl EventDispatcher {
                 accounts,
                 metadata,
             ),
+            Protocol::OrcaWhirlpool => orca_whirlpool::parse_orca_whirlpool_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::MeteoraDlmm => meteora_dlmm::parse_meteora_dlmm_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::Phoenix => phoenix::parse_phoenix_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::RaydiumLaunchpad => {
+                raydium_launchpad::parse_raydium_launchpad_instruction_data(
+                    instruction_discriminator,
+                    instruction_data,
+                    accounts,
+                    metadata,
+                )
+            }
+            Protocol::Jupiter => jupiter::parse_jupiter_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::Moonshot => moonshot::parse_moonshot_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::Lifinity => lifinity::parse_lifinity_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::OpenBookV2 => openbook_v2::parse_openbook_v2_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::Sanctum => sanctum::parse_sanctum_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
         }
     }
 
@@ -129,6 +198,15 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
             Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
             Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+            Protocol::OrcaWhirlpool => ProtocolType::OrcaWhirlpool,
+            Protocol::MeteoraDlmm => ProtocolType::MeteoraDlmm,
+            Protocol::Phoenix => ProtocolType::Phoenix,
+            Protocol::RaydiumLaunchpad => ProtocolType::Bonk,
+            Protocol::Jupiter => ProtocolType::Jupiter,
+            Protocol::Moonshot => ProtocolType::Moonshot,
+            Protocol::Lifinity => ProtocolType::Lifinity,
+            Protocol::OpenBookV2 => ProtocolType::OpenBookV2,
+            Protocol::Sanctum => ProtocolType::Sanctum,
         };
 
         match protocol {
@@ -167,9 +245,149 @@ impl EventDispatcher {
                 inner_instruction_data,
                 metadata,
             ),
+            Protocol::OrcaWhirlpool => orca_whirlpool::parse_orca_whirlpool_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::MeteoraDlmm => meteora_dlmm::parse_meteora_dlmm_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::Phoenix => phoenix::parse_phoenix_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::RaydiumLaunchpad => {
+                raydium_launchpad::parse_raydium_launchpad_inner_instruction_data(
+                    inner_instruction_discriminator,
+                    inner_instruction_data,
+                    metadata,
+                )
+            }
+            Protocol::Jupiter => jupiter::parse_jupiter_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::Moonshot => moonshot::parse_moonshot_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::Lifinity => lifinity::parse_lifinity_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::OpenBookV2 => openbook_v2::parse_openbook_v2_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::Sanctum => sanctum::parse_sanctum_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+        }
+    }
+
+    /// 是否需要收集 inner instructions 中的全部 CPI 事件
+    ///
+    /// 大部分协议一条指令只 emit 一个 CPI 事件，取第一个匹配即可；
+    /// Jupiter 路由每一跳都会 emit 一个 SwapEvent，需要全部收集后依次合并
+    #[inline]
+    pub fn collects_all_inner_events(protocol: &Protocol) -> bool {
+        matches!(protocol, Protocol::Jupiter)
+    }
+
+    /// 解析通过程序日志指令批量输出的事件
+    ///
+    /// 部分协议 (如 Phoenix) 不使用 Anchor CPI 事件，而是自调用一条 Log 指令，
+    /// 一条指令中包含多个事件，因此返回 `Vec<DexEvent>`。
+    /// 在 `EventParser` 中，该函数在 inner instruction 遍历到协议的 Log 指令时被调用，
+    /// 位于 `dispatch_instruction` 之前。
+    ///
+    /// # 返回
+    /// 协议没有 Log 指令事件时返回 `None`，调用方应继续走 `dispatch_instruction`
+    #[inline]
+    pub fn dispatch_log_instruction(
+        protocol: &Protocol,
+        instruction_discriminator: &[u8],
+        instruction_data: &[u8],
+        mut metadata: EventMetadata,
+    ) -> Option<Vec<DexEvent>> {
+        use crate::streaming::event_parser::common::ProtocolType;
+        match protocol {
+            Protocol::Phoenix => {
+                metadata.protocol = ProtocolType::Phoenix;
+                Some(phoenix::parse_phoenix_log_events(
+                    instruction_discriminator,
+                    instruction_data,
+                    metadata,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// 协议是否通过 "Program data:" 交易日志输出事件
+    ///
+    /// 这类协议需要 `EventParser` 在解析指令的同时解析交易日志
+    #[inline]
+    pub fn uses_program_data_logs(protocol: &Protocol) -> bool {
+        matches!(protocol, Protocol::OpenBookV2 | Protocol::RaydiumClmm | Protocol::OrcaWhirlpool)
+    }
+
+    /// 解析协议通过 `sol_log_data` 输出的一条 "Program data:" 日志
+    ///
+    /// `data` 为 base64 解码后的日志数据，`metadata` 的指令位置为发出日志的指令
+    #[inline]
+    pub fn dispatch_program_data_log(
+        protocol: &Protocol,
+        data: &[u8],
+        mut metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        use crate::streaming::event_parser::common::ProtocolType;
+        match protocol {
+            Protocol::OpenBookV2 => {
+                metadata.protocol = ProtocolType::OpenBookV2;
+                openbook_v2::parse_openbook_v2_program_data(data, metadata)
+            }
+            Protocol::RaydiumClmm => {
+                metadata.protocol = ProtocolType::RaydiumClmm;
+                raydium_clmm::parse_raydium_clmm_program_data(data, metadata)
+            }
+            Protocol::OrcaWhirlpool => {
+                metadata.protocol = ProtocolType::OrcaWhirlpool;
+                orca_whirlpool::parse_orca_whirlpool_program_data(data, metadata)
+            }
+            _ => None,
         }
     }
 
+    /// 用交易 meta 中的余额信息补全事件
+    ///
+    /// 填充 `metadata.balance_changes` (指令涉及的 token 账户)，
+    /// 并补全依赖余额变化的协议事件 (如 Moonshot 的真实数量和曲线进度、Lifinity 的 mint 和数量、Sanctum 的实际数量、
+    /// Raydium CPMM 的手续费拆分)
+    pub fn apply_transaction_balances(
+        event: &mut DexEvent,
+        balances: &TransactionBalances<'_>,
+        instruction_accounts: &[u8],
+        accounts: &[Pubkey],
+    ) {
+        event.metadata_mut().balance_changes =
+            balances.token_balance_changes(instruction_accounts, accounts);
+        moonshot::fill_moonshot_event_from_balances(event, balances, accounts);
+        lifinity::fill_lifinity_event_from_balances(event);
+        sanctum::fill_sanctum_event_from_balances(event);
+        raydium_cpmm::fill_raydium_cpmm_fees_from_balances(event);
+    }
+
     /// 通过 program_id 匹配协议类型
     #[inline]
     pub fn match_protocol_by_program_id(program_id: &Pubkey) -> Option<Protocol> {
@@ -187,11 +405,68 @@ impl EventDispatcher {
             Some(Protocol::RaydiumAmmV4)
         } else if program_id == &meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID {
             Some(Protocol::MeteoraDammV2)
+        } else if program_id == &orca_whirlpool::WHIRLPOOL_PROGRAM_ID {
+            Some(Protocol::OrcaWhirlpool)
+        } else if program_id == &meteora_dlmm::METEORA_DLMM_PROGRAM_ID {
+            Some(Protocol::MeteoraDlmm)
+        } else if program_id == &phoenix::PHOENIX_PROGRAM_ID {
+            Some(Protocol::Phoenix)
+        } else if program_id == &jupiter::JUPITER_V6_PROGRAM_ID {
+            Some(Protocol::Jupiter)
+        } else if program_id == &moonshot::MOONSHOT_PROGRAM_ID {
+            Some(Protocol::Moonshot)
+        } else if program_id == &lifinity::LIFINITY_V2_PROGRAM_ID {
+            Some(Protocol::Lifinity)
+        } else if program_id == &openbook_v2::OPENBOOK_V2_PROGRAM_ID {
+            Some(Protocol::OpenBookV2)
+        } else if program_id == &sanctum::SANCTUM_INFINITY_PROGRAM_ID {
+            Some(Protocol::Sanctum)
         } else {
             None
         }
     }
 
+    /// 检查协议是否在订阅的协议列表中
+    ///
+    /// Raydium Launchpad 与 Bonk 共用一个程序，`match_protocol_by_program_id` 统一返回 `Bonk`，
+    /// 因此选择了任意一个都视为选中
+    #[inline]
+    pub fn is_protocol_selected(protocols: &[Protocol], protocol: &Protocol) -> bool {
+        match protocol {
+            Protocol::Bonk | Protocol::RaydiumLaunchpad => {
+                protocols.contains(&Protocol::Bonk) || protocols.contains(&Protocol::RaydiumLaunchpad)
+            }
+            _ => protocols.contains(protocol),
+        }
+    }
+
+    /// 获取指令判别器对应的事件类型
+    ///
+    /// 判别器未登记时 (如 Phoenix 日志指令、OpenBook v2 日志事件) 返回 `None`，
+    /// 这类指令只能在解析后按事件类型过滤
+    pub fn instruction_event_type(protocol: &Protocol, discriminator: &[u8]) -> Option<EventType> {
+        let table = match protocol {
+            Protocol::PumpFun => pumpfun::INSTRUCTION_EVENT_TYPES,
+            Protocol::PumpSwap => pumpswap::INSTRUCTION_EVENT_TYPES,
+            Protocol::Bonk | Protocol::RaydiumLaunchpad => bonk::INSTRUCTION_EVENT_TYPES,
+            Protocol::RaydiumCpmm => raydium_cpmm::INSTRUCTION_EVENT_TYPES,
+            Protocol::RaydiumClmm => raydium_clmm::INSTRUCTION_EVENT_TYPES,
+            Protocol::RaydiumAmmV4 => raydium_amm_v4::INSTRUCTION_EVENT_TYPES,
+            Protocol::MeteoraDammV2 => meteora_damm_v2::INSTRUCTION_EVENT_TYPES,
+            Protocol::OrcaWhirlpool => orca_whirlpool::INSTRUCTION_EVENT_TYPES,
+            Protocol::MeteoraDlmm => meteora_dlmm::INSTRUCTION_EVENT_TYPES,
+            Protocol::Jupiter => jupiter::INSTRUCTION_EVENT_TYPES,
+            Protocol::Moonshot => moonshot::INSTRUCTION_EVENT_TYPES,
+            Protocol::Lifinity => lifinity::INSTRUCTION_EVENT_TYPES,
+            Protocol::Sanctum => sanctum::INSTRUCTION_EVENT_TYPES,
+            Protocol::Phoenix | Protocol::OpenBookV2 => return None,
+        };
+        table
+            .iter()
+            .find(|(instruction_discriminator, _)| *instruction_discriminator == discriminator)
+            .map(|(_, event_type)| event_type.clone())
+    }
+
     /// 检查是否为 Compute Budget Program
     #[inline]
     pub fn is_compute_budget_program(program_id: &Pubkey) -> bool {
@@ -225,12 +500,27 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => raydium_clmm::RAYDIUM_CLMM_PROGRAM_ID,
             Protocol::RaydiumAmmV4 => raydium_amm_v4::RAYDIUM_AMM_V4_PROGRAM_ID,
             Protocol::MeteoraDammV2 => meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID,
+            Protocol::OrcaWhirlpool => orca_whirlpool::WHIRLPOOL_PROGRAM_ID,
+            Protocol::MeteoraDlmm => meteora_dlmm::METEORA_DLMM_PROGRAM_ID,
+            Protocol::Phoenix => phoenix::PHOENIX_PROGRAM_ID,
+            Protocol::RaydiumLaunchpad => raydium_launchpad::RAYDIUM_LAUNCHPAD_PROGRAM_ID,
+            Protocol::Jupiter => jupiter::JUPITER_V6_PROGRAM_ID,
+            Protocol::Moonshot => moonshot::MOONSHOT_PROGRAM_ID,
+            Protocol::Lifinity => lifinity::LIFINITY_V2_PROGRAM_ID,
+            Protocol::OpenBookV2 => openbook_v2::OPENBOOK_V2_PROGRAM_ID,
+            Protocol::Sanctum => sanctum::SANCTUM_INFINITY_PROGRAM_ID,
         }
     }
 
-    /// 批量获取 program_ids
+    /// 批量获取 program_ids (去重，Bonk 与 Raydium Launchpad 共用一个程序)
     pub fn get_program_ids(protocols: &[Protocol]) -> Vec<Pubkey> {
-        protocols.iter().map(|p| Self::get_program_id(p.clone())).collect()
+        let mut program_ids: Vec<Pubkey> = Vec::with_capacity(protocols.len());
+        for program_id in protocols.iter().map(|p| Self::get_program_id(p.clone())) {
+            if !program_ids.contains(&program_id) {
+                program_ids.push(program_id);
+            }
+        }
+        program_ids
     }
 
     /// 解析账户数据
@@ -261,6 +551,15 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
             Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
             Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+            Protocol::OrcaWhirlpool => ProtocolType::OrcaWhirlpool,
+            Protocol::MeteoraDlmm => ProtocolType::MeteoraDlmm,
+            Protocol::Phoenix => ProtocolType::Phoenix,
+            Protocol::RaydiumLaunchpad => ProtocolType::Bonk,
+            Protocol::Jupiter => ProtocolType::Jupiter,
+            Protocol::Moonshot => ProtocolType::Moonshot,
+            Protocol::Lifinity => ProtocolType::Lifinity,
+            Protocol::OpenBookV2 => ProtocolType::OpenBookV2,
+            Protocol::Sanctum => ProtocolType::Sanctum,
         };
 
         match protocol {
@@ -280,8 +579,46 @@ impl EventDispatcher {
             Protocol::RaydiumAmmV4 => {
                 raydium_amm_v4::parse_raydium_amm_v4_account_data(discriminator, account, metadata)
             }
-            Protocol::MeteoraDammV2 => {
-                // Meteora DAMM 目前不需要解析账户数据，返回 None
+            Protocol::MeteoraDammV2 => meteora_damm_v2::parse_meteora_damm_v2_account_data(
+                discriminator,
+                account,
+                metadata,
+            ),
+            Protocol::OrcaWhirlpool => {
+                // Orca Whirlpool 目前不需要解析账户数据，返回 None
+                None
+            }
+            Protocol::MeteoraDlmm => {
+                // Meteora DLMM 目前不需要解析账户数据，返回 None
+                None
+            }
+            Protocol::Phoenix => {
+                // Phoenix 目前不需要解析账户数据，返回 None
+                None
+            }
+            Protocol::RaydiumLaunchpad => raydium_launchpad::parse_raydium_launchpad_account_data(
+                discriminator,
+                account,
+                metadata,
+            ),
+            Protocol::Jupiter => {
+                // Jupiter 目前不需要解析账户数据，返回 None
+                None
+            }
+            Protocol::Moonshot => {
+                // Moonshot 目前不需要解析账户数据，返回 None
+                None
+            }
+            Protocol::Lifinity => {
+                // Lifinity 目前不需要解析账户数据，返回 None
+                None
+            }
+            Protocol::OpenBookV2 => {
+                // OpenBook v2 目前不需要解析账户数据，返回 None
+                None
+            }
+            Protocol::Sanctum => {
+                // Sanctum 目前不需要解析账户数据，返回 None
                 None
             }
         }
@@ -1,6 +1,10 @@
+use crate::streaming::common::MetricsManager;
 use crate::streaming::event_parser::{
     DexEvent, Protocol, common::{
-        EventMetadata, filter::EventTypeFilter, high_performance_clock::elapsed_micros_since, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions
+        EventMetadata, high_performance_clock::elapsed_micros_since,
+        parse_error::{ParseError, ParseErrorCallback}, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions, SwapData,
+        balances::TransactionBalances, jito_tip_lamports, timestamp_millis, TransactionFees,
+        program_logs::{extract_program_data_logs, ProgramDataLog},
     }, core::{
         dispatcher::EventDispatcher,
         global_state::{
@@ -8,15 +12,21 @@ use crate::streaming::event_parser::{
             is_dev_address_in_signature,
         },
         merger_event::merge,
-    }, protocols::raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID
+        parse_options::ParseOptions,
+        protocol_parser::{protocol_parser_for, ParseContext},
+    }, protocols::{phoenix::parser::PHOENIX_PROGRAM_ID, pumpfun::discriminators as pumpfun_discriminators, raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID, sanctum::parser::SANCTUM_INFINITY_PROGRAM_ID}
 };
 use prost_types::Timestamp;
 use solana_sdk::{
     message::compiled_instruction::CompiledInstruction, pubkey::Pubkey, signature::Signature,
     transaction::VersionedTransaction,
 };
-use solana_transaction_status::InnerInstructions;
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+    InnerInstruction, InnerInstructions, UiInstruction,
+};
 use std::sync::Arc;
+use std::time::Instant;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
 pub struct EventParser {}
@@ -30,21 +40,24 @@ impl EventParser {
     ///
     /// This is the main entry point for parsing transactions received from gRPC streams.
     /// It extracts account keys, inner instructions, and delegates to instruction parsing.
+    #[allow(clippy::too_many_arguments)]
     pub async fn parse_grpc_transaction(
-        protocols: &[Protocol],
-        event_type_filter: Option<&EventTypeFilter>,
+        options: ParseOptions<'_>,
         grpc_tx: SubscribeUpdateTransactionInfo,
         signature: Signature,
         slot: Option<u64>,
         block_time: Option<Timestamp>,
         recv_us: i64,
-        bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
+        // 订阅了失败交易时由 meta.err 标记事件，没有 meta 视为成功
+        let succeeded = grpc_tx.meta.as_ref().is_none_or(|meta| meta.err.is_none());
         // 创建适配器回调，将所有权回调转换为引用回调
         let adapter_callback = Arc::new(move |event: &DexEvent| {
-            callback(event.clone());
+            let mut event = event.clone();
+            event.metadata_mut().succeeded = succeeded;
+            callback(event);
         });
         if let Some(transition) = grpc_tx.transaction {
             if let Some(message) = &transition.message {
@@ -53,13 +66,17 @@ impl EventParser {
                     yellowstone_grpc_proto::solana::storage::confirmed_block::InnerInstructions,
                 > = vec![];
 
-                if let Some(meta) = grpc_tx.meta {
-                    inner_instructions = meta.inner_instructions;
+                let mut log_messages: Vec<String> = vec![];
+
+                let mut meta = grpc_tx.meta;
+                if let Some(meta) = meta.as_mut() {
+                    inner_instructions = std::mem::take(&mut meta.inner_instructions);
+                    log_messages = std::mem::take(&mut meta.log_messages);
                     address_table_lookups.reserve(
                         meta.loaded_writable_addresses.len() + meta.loaded_readonly_addresses.len(),
                     );
-                    let loaded_writable_addresses = meta.loaded_writable_addresses;
-                    let loaded_readonly_addresses = meta.loaded_readonly_addresses;
+                    let loaded_writable_addresses = std::mem::take(&mut meta.loaded_writable_addresses);
+                    let loaded_readonly_addresses = std::mem::take(&mut meta.loaded_readonly_addresses);
                     address_table_lookups.extend(
                         loaded_writable_addresses.into_iter().chain(loaded_readonly_addresses),
                     );
@@ -69,22 +86,64 @@ impl EventParser {
                     Vec::with_capacity(message.account_keys.len() + address_table_lookups.len());
                 accounts_bytes.extend_from_slice(&message.account_keys);
                 accounts_bytes.extend(address_table_lookups);
-                // 转换为 Pubkey
+                // 转换为 Pubkey：静态账户之后依次是地址查找表加载的可写和只读账户，与 v0 交易的
+                // 账户下标一致；格式错误的账户用默认公钥占位，不能跳过，否则之后的下标会错位
                 let accounts: Vec<Pubkey> = accounts_bytes
                     .iter()
-                    .filter_map(|account| {
-                        if account.len() == 32 {
-                            Some(Pubkey::try_from(account.as_slice()).unwrap_or_default())
-                        } else {
-                            None
-                        }
-                    })
+                    .map(|account| Pubkey::try_from(account.as_slice()).unwrap_or_default())
                     .collect();
-                // 解析指令事件
+
+                // 交易前后的余额，用于填充事件的 balance_changes
+                let balances = meta.as_ref().map(TransactionBalances::from_grpc_meta);
+
+                // 交易级别的计算单元消耗、优先费和 Jito tip，同一交易的所有事件共用
                 let instructions = &message.instructions;
+                let jito_tip = jito_tip_lamports(
+                    instructions
+                        .iter()
+                        .map(|instruction| {
+                            (instruction.program_id_index, &instruction.accounts, &instruction.data)
+                        })
+                        .chain(inner_instructions.iter().flat_map(|inner| {
+                            inner.instructions.iter().map(|instruction| {
+                                (
+                                    instruction.program_id_index,
+                                    &instruction.accounts,
+                                    &instruction.data,
+                                )
+                            })
+                        }))
+                        .map(|(program_id_index, accounts, data)| {
+                            (program_id_index as usize, accounts.as_slice(), data.as_slice())
+                        }),
+                    &accounts,
+                );
+                let fees = TransactionFees::new(
+                    instructions.iter().map(|instruction| {
+                        (instruction.program_id_index as usize, instruction.data.as_slice())
+                    }),
+                    &accounts,
+                    transition.signatures.len(),
+                    meta.as_ref().map(|meta| meta.fee),
+                    meta.as_ref().and_then(|meta| meta.compute_units_consumed),
+                )
+                .with_jito_tip(jito_tip);
+
+                // 通过交易日志输出的事件 (如 OpenBook v2 成交)
+                let program_logs = Self::program_data_logs(
+                    options.protocols,
+                    &log_messages,
+                    instructions.iter().map(|instruction| {
+                        accounts
+                            .get(instruction.program_id_index as usize)
+                            .copied()
+                            .unwrap_or_default()
+                    }),
+                );
+
+                // 解析指令事件
                 Self::parse_instruction_events_from_grpc_transaction(
-                    protocols,
-                    event_type_filter,
+                    options,
                     &instructions,
                     signature,
                     slot,
@@ -92,7 +151,9 @@ impl EventParser {
                     recv_us,
                     &accounts,
                     &inner_instructions,
-                    bot_wallet,
+                    &program_logs,
+                    balances.as_ref(),
+                    fees,
                     transaction_index,
                     adapter_callback,
                 )
@@ -103,14 +164,195 @@ impl EventParser {
         Ok(())
     }
 
+    /// Parse transaction fetched from RPC (`getTransaction`)
+    ///
+    /// Used for one-off lookups and backfill. Converts the RPC response's inner instructions and
+    /// loaded addresses, then delegates to the same parsing as the streaming entry points.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn parse_encoded_confirmed_transaction(
+        options: ParseOptions<'_>,
+        transaction: EncodedConfirmedTransactionWithStatusMeta,
+        signature: Signature,
+        recv_us: i64,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    ) -> anyhow::Result<()> {
+        let versioned_tx = transaction
+            .transaction
+            .transaction
+            .decode()
+            .ok_or_else(|| anyhow::anyhow!("Failed to decode transaction {signature}"))?;
+
+        let mut inner_instructions: Vec<InnerInstructions> = Vec::new();
+        let mut loaded_addresses: Vec<Pubkey> = Vec::new();
+        let mut fee: Option<u64> = None;
+        let mut compute_units_consumed: Option<u64> = None;
+        let mut succeeded = true;
+        let mut log_messages: &[String] = &[];
+        if let Some(meta) = &transaction.transaction.meta {
+            fee = Some(meta.fee);
+            succeeded = meta.err.is_none();
+            if let OptionSerializer::Some(logs) = &meta.log_messages {
+                log_messages = logs;
+            }
+            if let OptionSerializer::Some(units) = meta.compute_units_consumed {
+                compute_units_consumed = Some(units);
+            }
+            if let OptionSerializer::Some(ui_inner_instructions) = &meta.inner_instructions {
+                for ui_inner in ui_inner_instructions {
+                    let instructions = ui_inner
+                        .instructions
+                        .iter()
+                        .filter_map(|ui_instruction| match ui_instruction {
+                            UiInstruction::Compiled(compiled) => {
+                                let data = solana_sdk::bs58::decode(&compiled.data).into_vec().ok()?;
+                                Some(InnerInstruction {
+                                    instruction: CompiledInstruction {
+                                        program_id_index: compiled.program_id_index,
+                                        accounts: compiled.accounts.clone(),
+                                        data,
+                                    },
+                                    stack_height: compiled.stack_height,
+                                })
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    inner_instructions.push(InnerInstructions { index: ui_inner.index, instructions });
+                }
+            }
+            if let OptionSerializer::Some(addresses) = &meta.loaded_addresses {
+                loaded_addresses.extend(
+                    addresses
+                        .writable
+                        .iter()
+                        .chain(addresses.readonly.iter())
+                        .map(|address| address.parse::<Pubkey>().unwrap_or_default()),
+                );
+            }
+        }
+
+        let mut accounts = Vec::with_capacity(
+            versioned_tx.message.static_account_keys().len() + loaded_addresses.len(),
+        );
+        accounts.extend_from_slice(versioned_tx.message.static_account_keys());
+        accounts.extend(loaded_addresses);
+
+        let fees = Self::versioned_transaction_fees(
+            &versioned_tx,
+            &accounts,
+            &inner_instructions,
+            fee,
+            compute_units_consumed,
+        );
+        let program_logs = Self::program_data_logs(
+            options.protocols,
+            log_messages,
+            versioned_tx.message.instructions().iter().map(|instruction| {
+                accounts.get(instruction.program_id_index as usize).copied().unwrap_or_default()
+            }),
+        );
+        let block_time = transaction.block_time.map(|seconds| Timestamp { seconds, nanos: 0 });
+        Self::parse_versioned_transaction_with_fees(
+            options,
+            &versioned_tx,
+            signature,
+            Some(transaction.slot),
+            block_time,
+            recv_us,
+            &accounts,
+            &inner_instructions,
+            &program_logs,
+            fees,
+            None,
+            Arc::new(move |mut event: DexEvent| {
+                event.metadata_mut().succeeded = succeeded;
+                callback(event);
+            }),
+        )
+        .await
+    }
+
     /// Parse transaction from VersionedTransaction
     ///
     /// This is the entry point for parsing VersionedTransaction objects.
     /// It's used when working with RPC responses or historical data.
     #[allow(clippy::too_many_arguments)]
     pub async fn parse_instruction_events_from_versioned_transaction(
-        protocols: &[Protocol],
-        event_type_filter: Option<&EventTypeFilter>,
+        options: ParseOptions<'_>,
+        transaction: &VersionedTransaction,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        accounts: &[Pubkey],
+        inner_instructions: &[InnerInstructions],
+        transaction_index: Option<u64>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    ) -> anyhow::Result<()> {
+        // 没有交易 meta，优先费只能由 Compute Budget 指令计算
+        let fees =
+            Self::versioned_transaction_fees(transaction, accounts, inner_instructions, None, None);
+        Self::parse_versioned_transaction_with_fees(
+            options,
+            transaction,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            accounts,
+            inner_instructions,
+            &[],
+            fees,
+            transaction_index,
+            callback,
+        )
+        .await
+    }
+
+    // ================================================================================================
+    // VersionedTransaction Processing
+    // ================================================================================================
+
+    /// Compute transaction-level fees and Jito tip from a VersionedTransaction and optional meta values
+    fn versioned_transaction_fees(
+        transaction: &VersionedTransaction,
+        accounts: &[Pubkey],
+        inner_instructions: &[InnerInstructions],
+        fee: Option<u64>,
+        compute_units_consumed: Option<u64>,
+    ) -> TransactionFees {
+        let instructions = transaction.message.instructions();
+        let jito_tip = jito_tip_lamports(
+            instructions
+                .iter()
+                .chain(inner_instructions.iter().flat_map(|inner| {
+                    inner.instructions.iter().map(|inner| &inner.instruction)
+                }))
+                .map(|instruction| {
+                    (
+                        instruction.program_id_index as usize,
+                        instruction.accounts.as_slice(),
+                        instruction.data.as_slice(),
+                    )
+                }),
+            accounts,
+        );
+        TransactionFees::new(
+            instructions.iter().map(|instruction| {
+                (instruction.program_id_index as usize, instruction.data.as_slice())
+            }),
+            transaction.message.static_account_keys(),
+            transaction.signatures.len(),
+            fee,
+            compute_units_consumed,
+        )
+        .with_jito_tip(jito_tip)
+    }
+
+    /// Parse instruction events from VersionedTransaction with precomputed transaction fees
+    #[allow(clippy::too_many_arguments)]
+    async fn parse_versioned_transaction_with_fees(
+        options: ParseOptions<'_>,
         transaction: &VersionedTransaction,
         signature: Signature,
         slot: Option<u64>,
@@ -118,7 +360,8 @@ impl EventParser {
         recv_us: i64,
         accounts: &[Pubkey],
         inner_instructions: &[InnerInstructions],
-        bot_wallet: Option<Pubkey>,
+        mut program_logs: &[ProgramDataLog],
+        fees: TransactionFees,
         transaction_index: Option<u64>,
         callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
@@ -130,9 +373,7 @@ impl EventParser {
         let compiled_instructions = transaction.message.instructions();
         let mut accounts: Vec<Pubkey> = accounts.to_vec();
         // 检查交易中是否包含程序
-        let has_program = accounts
-            .iter()
-            .any(|account| Self::should_handle(protocols, event_type_filter, account));
+        let has_program = accounts.iter().any(|account| Self::should_handle(options, account));
         if has_program {
             // 解析每个指令
             for (index, instruction) in compiled_instructions.iter().enumerate() {
@@ -141,15 +382,14 @@ impl EventParser {
                     let inner_instructions = inner_instructions
                         .iter()
                         .find(|inner_instruction| inner_instruction.index == index as u8);
-                    if Self::should_handle(protocols, event_type_filter, &program_id) {
+                    if Self::should_handle(options, &program_id) {
                         let max_idx = instruction.accounts.iter().max().unwrap_or(&0);
                         // 补齐accounts(使用Pubkey::default())
                         if *max_idx as usize >= accounts.len() {
                             accounts.resize(*max_idx as usize + 1, Pubkey::default());
                         }
                         Self::parse_events_from_instruction(
-                            protocols,
-                            event_type_filter,
+                            options,
                             instruction,
                             &accounts,
                             signature,
@@ -158,7 +398,8 @@ impl EventParser {
                             recv_us,
                             index as i64,
                             None,
-                            bot_wallet,
+                            None,
+                            fees,
                             transaction_index,
                             inner_instructions,
                             adapter_callback.clone(),
@@ -170,8 +411,7 @@ impl EventParser {
                             inner_instructions.instructions.iter().enumerate()
                         {
                             Self::parse_events_from_instruction(
-                                protocols,
-                                event_type_filter,
+                                options,
                                 &inner_instruction.instruction,
                                 &accounts,
                                 signature,
@@ -180,13 +420,26 @@ impl EventParser {
                                 recv_us,
                                 index as i64,
                                 Some(inner_index as i64),
-                                bot_wallet,
+                                Some(program_id),
+                                fees,
                                 transaction_index,
                                 Some(&inner_instructions),
                                 adapter_callback.clone(),
                             )?;
                         }
                     }
+                    program_logs = Self::parse_events_from_program_logs(
+                        options,
+                        program_logs,
+                        index as i64,
+                        signature,
+                        slot.unwrap_or(0),
+                        block_time,
+                        recv_us,
+                        fees,
+                        transaction_index,
+                        adapter_callback.clone(),
+                    );
                 }
             }
         }
@@ -203,8 +456,7 @@ impl EventParser {
     /// and delegates to instruction-level parsing for both outer and inner instructions.
     #[allow(clippy::too_many_arguments)]
     async fn parse_instruction_events_from_grpc_transaction(
-        protocols: &[Protocol],
-        event_type_filter: Option<&EventTypeFilter>,
+        options: ParseOptions<'_>,
         compiled_instructions: &[yellowstone_grpc_proto::prelude::CompiledInstruction],
         signature: Signature,
         slot: Option<u64>,
@@ -212,16 +464,16 @@ impl EventParser {
         recv_us: i64,
         accounts: &[Pubkey],
         inner_instructions: &[yellowstone_grpc_proto::prelude::InnerInstructions],
-        bot_wallet: Option<Pubkey>,
+        mut program_logs: &[ProgramDataLog],
+        balances: Option<&TransactionBalances<'_>>,
+        fees: TransactionFees,
         transaction_index: Option<u64>,
         callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
         // 获取交易的指令和账户
         let mut accounts = accounts.to_vec();
         // 检查交易中是否包含程序
-        let has_program = accounts
-            .iter()
-            .any(|account| Self::should_handle(protocols, event_type_filter, account));
+        let has_program = accounts.iter().any(|account| Self::should_handle(options, account));
         if has_program {
             // 解析每个指令
             for (index, instruction) in compiled_instructions.iter().enumerate() {
@@ -235,10 +487,9 @@ impl EventParser {
                     if *max_idx as usize >= accounts.len() {
                         accounts.resize(*max_idx as usize + 1, Pubkey::default());
                     }
-                    if Self::should_handle(protocols, event_type_filter, &program_id) {
+                    if Self::should_handle(options, &program_id) {
                         Self::parse_events_from_grpc_instruction(
-                            protocols,
-                            event_type_filter,
+                            options,
                             instruction,
                             &accounts,
                             signature,
@@ -247,9 +498,11 @@ impl EventParser {
                             recv_us,
                             index as i64,
                             None,
-                            bot_wallet,
+                            None,
+                            fees,
                             transaction_index,
                             inner_instructions,
+                            balances,
                             callback.clone(),
                         )?;
                     }
@@ -267,8 +520,7 @@ impl EventParser {
                                     data: data.to_vec(),
                                 };
                             Self::parse_events_from_grpc_instruction(
-                                protocols,
-                                event_type_filter,
+                                options,
                                 &instruction,
                                 &accounts,
                                 signature,
@@ -277,13 +529,27 @@ impl EventParser {
                                 recv_us,
                                 inner_instructions.index as i64,
                                 Some(inner_index as i64),
-                                bot_wallet,
+                                Some(program_id),
+                                fees,
                                 transaction_index,
                                 Some(&inner_instructions),
+                                balances,
                                 callback.clone(),
                             )?;
                         }
                     }
+                    program_logs = Self::parse_events_from_program_logs(
+                        options,
+                        program_logs,
+                        index as i64,
+                        signature,
+                        slot.unwrap_or(0),
+                        block_time,
+                        recv_us,
+                        fees,
+                        transaction_index,
+                        callback.clone(),
+                    );
                 }
             }
         }
@@ -296,8 +562,7 @@ impl EventParser {
     /// to protocol-specific parsers, handles inner instructions, and processes swap data.
     #[allow(clippy::too_many_arguments)]
     fn parse_events_from_grpc_instruction(
-        protocols: &[Protocol],
-        event_type_filter: Option<&EventTypeFilter>,
+        options: ParseOptions<'_>,
         instruction: &yellowstone_grpc_proto::prelude::CompiledInstruction,
         accounts: &[Pubkey],
         signature: Signature,
@@ -306,9 +571,11 @@ impl EventParser {
         recv_us: i64,
         outer_index: i64,
         inner_index: Option<i64>,
-        bot_wallet: Option<Pubkey>,
+        outer_program: Option<Pubkey>,
+        fees: TransactionFees,
         transaction_index: Option<u64>,
         inner_instructions: Option<&yellowstone_grpc_proto::prelude::InnerInstructions>,
+        balances: Option<&TransactionBalances<'_>>,
         callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
         // 添加边界检查以防止越界访问
@@ -317,28 +584,29 @@ impl EventParser {
             return Ok(());
         }
         let program_id = accounts[program_id_index];
-        if !Self::should_handle(protocols, event_type_filter, &program_id) {
+        if !Self::should_handle(options, &program_id) {
             return Ok(());
         }
 
+        let custom_parser = protocol_parser_for(&program_id);
         let is_cu_program = EventDispatcher::is_compute_budget_program(&program_id);
 
         let disc_len = match program_id {
-            RAYDIUM_AMM_V4_PROGRAM_ID => 1,
+            RAYDIUM_AMM_V4_PROGRAM_ID | PHOENIX_PROGRAM_ID | SANCTUM_INFINITY_PROGRAM_ID => 1,
             _ => 8,
         };
 
         // 检查指令数据长度（至少需要 disc_len 字节的 discriminator）
-        if !is_cu_program && instruction.data.len() < disc_len {
+        if custom_parser.is_none() && !is_cu_program && instruction.data.len() < disc_len {
             return Ok(());
         }
         // 创建元数据
         let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
-        let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
-        let metadata = EventMetadata::new(
+        let block_time_ms = timestamp_millis(timestamp.seconds, timestamp.nanos);
+        let mut metadata = EventMetadata::new(
             signature,
             slot,
-            timestamp.seconds,
+            block_time.map(|t| t.seconds),
             block_time_ms,
             Default::default(), // protocol will be set by dispatcher
             Default::default(), // event_type will be set by dispatcher
@@ -348,13 +616,45 @@ impl EventParser {
             recv_us,
             transaction_index,
         );
+        metadata.set_transaction_fees(fees);
+        if let (Some(inner_index), Some(outer_program)) = (inner_index, outer_program) {
+            let stack_height = inner_instructions
+                .and_then(|inner| inner.instructions.get(inner_index as usize))
+                .and_then(|inner| inner.stack_height);
+            metadata.set_cpi_context(Self::cpi_depth(stack_height), outer_program);
+        }
+
+        // 注册了自定义解析器的程序交给它解析，优先于内置协议
+        if let Some(parser) = custom_parser {
+            let account_pubkeys: Vec<Pubkey> = instruction
+                .accounts
+                .iter()
+                .map(|&idx| accounts.get(idx as usize).copied().unwrap_or_default())
+                .collect();
+            let ctx = ParseContext {
+                program_id,
+                data: &instruction.data,
+                accounts: &account_pubkeys,
+                metadata: &metadata,
+            };
+            for mut event in parser.parse(&ctx) {
+                event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+                event = Self::process_event(event, options.bot_wallet);
+                if Self::passes_filters(&event, options) {
+                    callback(&event);
+                }
+            }
+            return Ok(());
+        }
 
         if is_cu_program {
             if let Some(event) = EventDispatcher::dispatch_compute_budget_instruction(
                 &instruction.data,
                 metadata.clone(),
             ) {
-                callback(&event);
+                if Self::passes_filters(&event, options) {
+                    callback(&event);
+                }
             }
             return Ok(());
         }
@@ -369,20 +669,47 @@ impl EventParser {
         let instruction_discriminator = &instruction.data[..disc_len];
         let instruction_data = &instruction.data[disc_len..];
 
-        // 构建账户公钥列表
+        // 指令产出的事件类型不在过滤器中时直接跳过，省去解码开销
+        if let Some(filter) = options.event_type_filter {
+            if EventDispatcher::instruction_event_type(&protocol, instruction_discriminator)
+                .is_some_and(|event_type| !filter.includes(&event_type))
+            {
+                return Ok(());
+            }
+        }
+
+        // 程序日志指令 (如 Phoenix Log) 一条指令包含多个事件，直接逐个回调
+        if let Some(events) = Self::dispatch_log_instruction_with_metrics(
+            &protocol,
+            instruction_discriminator,
+            instruction_data,
+            metadata.clone(),
+        ) {
+            for mut event in events {
+                event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+                if Self::passes_filters(&event, options) {
+                    callback(&event);
+                }
+            }
+            return Ok(());
+        }
+
+        // 构建账户公钥列表；无法解析的下标 (没有交易 meta 时地址查找表中的账户) 用默认公钥占位，
+        // 保持后续账户的位置不变
         let account_pubkeys: Vec<Pubkey> = instruction
             .accounts
             .iter()
-            .filter_map(|&idx| accounts.get(idx as usize).copied())
+            .map(|&idx| accounts.get(idx as usize).copied().unwrap_or_default())
             .collect();
 
         // 使用 EventDispatcher 解析 instruction 事件
-        let mut event = match EventDispatcher::dispatch_instruction(
-            protocol.clone(),
+        let mut event = match Self::dispatch_instruction_with_metrics(
+            &protocol,
             instruction_discriminator,
             instruction_data,
             &account_pubkeys,
             metadata.clone(),
+            options.parse_error_callback,
         ) {
             Some(e) => e,
             None => return Ok(()),
@@ -394,7 +721,12 @@ impl EventParser {
             // 并行执行两个任务: 解析 inner event 和提取 swap_data
             let (inner_event_result, swap_data_result) = std::thread::scope(|s| {
                 let inner_event_handle = s.spawn(|| {
-                    for inner_instruction in inner_instructions_ref.instructions.iter() {
+                    // CPI 事件总在发出它的指令之后，只扫描当前指令之后的部分，避免同一外层指令中
+                    // 多次 CPI 调用 (如一笔交易迁移多个联合曲线) 都取到第一条事件
+                    let collect_all = EventDispatcher::collects_all_inner_events(&protocol);
+                    let skip = inner_index.map_or(0, |i| i as usize + 1);
+                    let mut collected: Option<DexEvent> = None;
+                    for inner_instruction in inner_instructions_ref.instructions.iter().skip(skip) {
                         let inner_data = &inner_instruction.data;
                         // 检查长度（需要 16 字节的 discriminator）
                         if inner_data.len() < 16 {
@@ -409,10 +741,16 @@ impl EventParser {
                             inner_instruction_data,
                             metadata.clone(),
                         ) {
-                            return Some(inner_event);
+                            if !collect_all {
+                                return Some(inner_event);
+                            }
+                            match collected.as_mut() {
+                                Some(collected_event) => merge(collected_event, inner_event),
+                                None => collected = Some(inner_event),
+                            }
                         }
                     }
-                    None
+                    collected
                 });
 
                 let swap_data_handle = s.spawn(|| {
@@ -420,7 +758,7 @@ impl EventParser {
                         parse_swap_data_from_next_grpc_instructions(
                             &event,
                             inner_instructions_ref,
-                            inner_index.unwrap_or(-1_i64) as i8,
+                            inner_index.unwrap_or(-1),
                             accounts,
                         )
                     } else {
@@ -439,12 +777,12 @@ impl EventParser {
         }
 
         // 特殊处理: PumpFun MIGRATE 指令需要 inner instruction data
-        if matches!(protocol, Protocol::PumpFun) {
-            const PUMPFUN_MIGRATE_IX: &[u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
-            if instruction_discriminator == PUMPFUN_MIGRATE_IX && inner_instruction_event.is_none()
-            {
-                return Ok(());
-            }
+        // 迁移成功时才有 CompletePumpAmmMigrationEvent，每次迁移只产出一个事件
+        if matches!(protocol, Protocol::PumpFun)
+            && instruction_discriminator == pumpfun_discriminators::MIGRATE_IX
+            && inner_instruction_event.is_none()
+        {
+            return Ok(());
         }
 
         // 合并事件
@@ -452,10 +790,23 @@ impl EventParser {
             merge(&mut event, inner_instruction_event);
         }
 
+        // 填充交易前后的余额变化
+        if let Some(balances) = balances {
+            EventDispatcher::apply_transaction_balances(
+                &mut event,
+                balances,
+                &instruction.accounts,
+                accounts,
+            );
+        }
+
         // 设置处理时间（使用高性能时钟）
         event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
-        event = Self::process_event(event, bot_wallet);
-        callback(&event);
+        event = Self::process_event(event, options.bot_wallet);
+        // 未包含的事件类型和成交额低于阈值的交易在克隆交给回调前丢弃
+        if Self::passes_filters(&event, options) {
+            callback(&event);
+        }
 
         Ok(())
     }
@@ -470,8 +821,7 @@ impl EventParser {
     /// Used when parsing VersionedTransaction or RPC data.
     #[allow(clippy::too_many_arguments)]
     fn parse_events_from_instruction(
-        protocols: &[Protocol],
-        event_type_filter: Option<&EventTypeFilter>,
+        options: ParseOptions<'_>,
         instruction: &CompiledInstruction,
         accounts: &[Pubkey],
         signature: Signature,
@@ -480,7 +830,8 @@ impl EventParser {
         recv_us: i64,
         outer_index: i64,
         inner_index: Option<i64>,
-        bot_wallet: Option<Pubkey>,
+        outer_program: Option<Pubkey>,
+        fees: TransactionFees,
         transaction_index: Option<u64>,
         inner_instructions: Option<&InnerInstructions>,
         callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
@@ -491,29 +842,30 @@ impl EventParser {
             return Ok(());
         }
         let program_id = accounts[program_id_index];
-        if !Self::should_handle(protocols, event_type_filter, &program_id) {
+        if !Self::should_handle(options, &program_id) {
             return Ok(());
         }
 
+        let custom_parser = protocol_parser_for(&program_id);
         let is_cu_program = EventDispatcher::is_compute_budget_program(&program_id);
 
         let disc_len = match program_id {
-            RAYDIUM_AMM_V4_PROGRAM_ID => 1,
+            RAYDIUM_AMM_V4_PROGRAM_ID | PHOENIX_PROGRAM_ID | SANCTUM_INFINITY_PROGRAM_ID => 1,
             _ => 8,
         };
 
         // 检查指令数据长度（至少需要 8 字节的 discriminator）
-        if !is_cu_program && instruction.data.len() < disc_len {
+        if custom_parser.is_none() && !is_cu_program && instruction.data.len() < disc_len {
             return Ok(());
         }
 
         // 创建元数据
         let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
-        let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
-        let metadata = EventMetadata::new(
+        let block_time_ms = timestamp_millis(timestamp.seconds, timestamp.nanos);
+        let mut metadata = EventMetadata::new(
             signature,
             slot,
-            timestamp.seconds,
+            block_time.map(|t| t.seconds),
             block_time_ms,
             Default::default(), // protocol will be set by dispatcher
             Default::default(), // event_type will be set by dispatcher
@@ -523,13 +875,45 @@ impl EventParser {
             recv_us,
             transaction_index,
         );
+        metadata.set_transaction_fees(fees);
+        if let (Some(inner_index), Some(outer_program)) = (inner_index, outer_program) {
+            let stack_height = inner_instructions
+                .and_then(|inner| inner.instructions.get(inner_index as usize))
+                .and_then(|inner| inner.stack_height);
+            metadata.set_cpi_context(Self::cpi_depth(stack_height), outer_program);
+        }
+
+        // 注册了自定义解析器的程序交给它解析，优先于内置协议
+        if let Some(parser) = custom_parser {
+            let account_pubkeys: Vec<Pubkey> = instruction
+                .accounts
+                .iter()
+                .map(|&idx| accounts.get(idx as usize).copied().unwrap_or_default())
+                .collect();
+            let ctx = ParseContext {
+                program_id,
+                data: &instruction.data,
+                accounts: &account_pubkeys,
+                metadata: &metadata,
+            };
+            for mut event in parser.parse(&ctx) {
+                event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+                event = Self::process_event(event, options.bot_wallet);
+                if Self::passes_filters(&event, options) {
+                    callback(&event);
+                }
+            }
+            return Ok(());
+        }
 
         if is_cu_program {
             if let Some(event) = EventDispatcher::dispatch_compute_budget_instruction(
                 &instruction.data,
                 metadata.clone(),
             ) {
-                callback(&event);
+                if Self::passes_filters(&event, options) {
+                    callback(&event);
+                }
             }
             return Ok(());
         }
@@ -544,20 +928,47 @@ impl EventParser {
         let instruction_discriminator = &instruction.data[..disc_len];
         let instruction_data = &instruction.data[disc_len..];
 
-        // 构建账户公钥列表
+        // 指令产出的事件类型不在过滤器中时直接跳过，省去解码开销
+        if let Some(filter) = options.event_type_filter {
+            if EventDispatcher::instruction_event_type(&protocol, instruction_discriminator)
+                .is_some_and(|event_type| !filter.includes(&event_type))
+            {
+                return Ok(());
+            }
+        }
+
+        // 程序日志指令 (如 Phoenix Log) 一条指令包含多个事件，直接逐个回调
+        if let Some(events) = Self::dispatch_log_instruction_with_metrics(
+            &protocol,
+            instruction_discriminator,
+            instruction_data,
+            metadata.clone(),
+        ) {
+            for mut event in events {
+                event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+                if Self::passes_filters(&event, options) {
+                    callback(&event);
+                }
+            }
+            return Ok(());
+        }
+
+        // 构建账户公钥列表；无法解析的下标 (没有交易 meta 时地址查找表中的账户) 用默认公钥占位，
+        // 保持后续账户的位置不变
         let account_pubkeys: Vec<Pubkey> = instruction
             .accounts
             .iter()
-            .filter_map(|&idx| accounts.get(idx as usize).copied())
+            .map(|&idx| accounts.get(idx as usize).copied().unwrap_or_default())
             .collect();
 
         // 使用 EventDispatcher 解析 instruction 事件
-        let mut event = match EventDispatcher::dispatch_instruction(
-            protocol.clone(),
+        let mut event = match Self::dispatch_instruction_with_metrics(
+            &protocol,
             instruction_discriminator,
             instruction_data,
             &account_pubkeys,
             metadata.clone(),
+            options.parse_error_callback,
         ) {
             Some(e) => e,
             None => return Ok(()),
@@ -569,7 +980,12 @@ impl EventParser {
             // 并行执行两个任务: 解析 inner event 和提取 swap_data
             let (inner_event_result, swap_data_result) = std::thread::scope(|s| {
                 let inner_event_handle = s.spawn(|| {
-                    for inner_instruction in inner_instructions_ref.instructions.iter() {
+                    // CPI 事件总在发出它的指令之后，只扫描当前指令之后的部分，避免同一外层指令中
+                    // 多次 CPI 调用 (如一笔交易迁移多个联合曲线) 都取到第一条事件
+                    let collect_all = EventDispatcher::collects_all_inner_events(&protocol);
+                    let skip = inner_index.map_or(0, |i| i as usize + 1);
+                    let mut collected: Option<DexEvent> = None;
+                    for inner_instruction in inner_instructions_ref.instructions.iter().skip(skip) {
                         let inner_data = &inner_instruction.instruction.data;
                         // 检查长度（需要 16 字节的 discriminator）
                         if inner_data.len() < 16 {
@@ -584,10 +1000,16 @@ impl EventParser {
                             inner_instruction_data,
                             metadata.clone(),
                         ) {
-                            return Some(inner_event);
+                            if !collect_all {
+                                return Some(inner_event);
+                            }
+                            match collected.as_mut() {
+                                Some(collected_event) => merge(collected_event, inner_event),
+                                None => collected = Some(inner_event),
+                            }
                         }
                     }
-                    None
+                    collected
                 });
 
                 let swap_data_handle = s.spawn(|| {
@@ -595,7 +1017,7 @@ impl EventParser {
                         parse_swap_data_from_next_instructions(
                             &event,
                             inner_instructions_ref,
-                            inner_index.unwrap_or(-1_i64) as i8,
+                            inner_index.unwrap_or(-1),
                             accounts,
                         )
                     } else {
@@ -614,12 +1036,12 @@ impl EventParser {
         }
 
         // 特殊处理: PumpFun MIGRATE 指令需要 inner instruction data
-        if matches!(protocol, Protocol::PumpFun) {
-            const PUMPFUN_MIGRATE_IX: &[u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
-            if instruction_discriminator == PUMPFUN_MIGRATE_IX && inner_instruction_event.is_none()
-            {
-                return Ok(());
-            }
+        // 迁移成功时才有 CompletePumpAmmMigrationEvent，每次迁移只产出一个事件
+        if matches!(protocol, Protocol::PumpFun)
+            && instruction_discriminator == pumpfun_discriminators::MIGRATE_IX
+            && inner_instruction_event.is_none()
+        {
+            return Ok(());
         }
 
         // 合并事件
@@ -629,8 +1051,11 @@ impl EventParser {
 
         // 设置处理时间（使用高性能时钟）
         event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
-        event = Self::process_event(event, bot_wallet);
-        callback(&event);
+        event = Self::process_event(event, options.bot_wallet);
+        // 未包含的事件类型和成交额低于阈值的交易在克隆交给回调前丢弃
+        if Self::passes_filters(&event, options) {
+            callback(&event);
+        }
 
         Ok(())
     }
@@ -641,15 +1066,15 @@ impl EventParser {
 
     /// Check if instruction should be processed based on protocol filter
     ///
-    /// Determines whether a program_id matches any of the protocols we're interested in.
-    fn should_handle(
-        protocols: &[Protocol],
-        _event_type_filter: Option<&EventTypeFilter>,
-        program_id: &Pubkey,
-    ) -> bool {
+    /// Determines whether a program_id matches any of the protocols we're interested in, or has a
+    /// registered custom parser.
+    fn should_handle(options: ParseOptions<'_>, program_id: &Pubkey) -> bool {
+        if protocol_parser_for(program_id).is_some() {
+            return true;
+        }
         // 使用 EventDispatcher 来匹配协议
         if let Some(protocol) = EventDispatcher::match_protocol_by_program_id(program_id) {
-            protocols.contains(&protocol)
+            EventDispatcher::is_protocol_selected(options.protocols, &protocol)
         } else if EventDispatcher::is_compute_budget_program(program_id) {
             return true;
         } else {
@@ -657,6 +1082,184 @@ impl EventParser {
         }
     }
 
+    /// inner instruction 的 CPI 深度，stack height 从外层指令的 1 开始计数
+    ///
+    /// 较早的交易 meta 没有 stack height，此时按外层指令直接调用 (深度 1) 处理
+    fn cpi_depth(stack_height: Option<u32>) -> u8 {
+        stack_height.map_or(1, |height| height.saturating_sub(1).clamp(1, u8::MAX as u32) as u8)
+    }
+
+    /// 解析指令，开启 enable_metrics 时按协议和事件类型记录解析耗时
+    ///
+    /// 判别器已登记但解码失败时计为解析错误并上报给 `parse_errors`，未识别的指令不计入
+    fn dispatch_instruction_with_metrics(
+        protocol: &Protocol,
+        discriminator: &[u8],
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+        parse_errors: Option<&ParseErrorCallback>,
+    ) -> Option<DexEvent> {
+        let metrics = MetricsManager::global();
+        if !metrics.is_enabled() && parse_errors.is_none() {
+            return EventDispatcher::dispatch_instruction(
+                protocol.clone(),
+                discriminator,
+                data,
+                accounts,
+                metadata,
+            );
+        }
+        // 只在需要上报错误时保留元数据
+        let error_metadata = parse_errors.map(|callback| (callback, metadata.clone()));
+        let started = Instant::now();
+        let event = EventDispatcher::dispatch_instruction(
+            protocol.clone(),
+            discriminator,
+            data,
+            accounts,
+            metadata,
+        );
+        let elapsed = started.elapsed();
+        let event_type = match &event {
+            Some(event) => Some(event.metadata().event_type.clone()),
+            None => EventDispatcher::instruction_event_type(protocol, discriminator),
+        };
+        if let Some(event_type) = event_type {
+            metrics.record_parse(protocol, &event_type, elapsed, event.is_some());
+            if let (None, Some((callback, metadata))) = (&event, error_metadata) {
+                callback(&ParseError {
+                    signature: metadata.signature,
+                    slot: metadata.slot,
+                    protocol: protocol.clone(),
+                    event_type,
+                    program_id: metadata.program_id,
+                    outer_index: metadata.outer_index,
+                    inner_index: metadata.inner_index,
+                    data: [discriminator, data].concat(),
+                });
+            }
+        }
+        event
+    }
+
+    /// 解析程序日志指令，解析耗时平均分配给其中的每个事件
+    fn dispatch_log_instruction_with_metrics(
+        protocol: &Protocol,
+        discriminator: &[u8],
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Vec<DexEvent>> {
+        let metrics = MetricsManager::global();
+        if !metrics.is_enabled() {
+            return EventDispatcher::dispatch_log_instruction(protocol, discriminator, data, metadata);
+        }
+        let started = Instant::now();
+        let events =
+            EventDispatcher::dispatch_log_instruction(protocol, discriminator, data, metadata)?;
+        if !events.is_empty() {
+            let elapsed = started.elapsed() / events.len() as u32;
+            for event in &events {
+                metrics.record_parse(protocol, &event.metadata().event_type, elapsed, true);
+            }
+        }
+        Some(events)
+    }
+
+    /// 提取选中协议通过交易日志输出的事件数据，没有选中这类协议时不解析日志
+    fn program_data_logs(
+        protocols: &[Protocol],
+        log_messages: &[String],
+        instruction_program_ids: impl Iterator<Item = Pubkey>,
+    ) -> Vec<ProgramDataLog> {
+        if !protocols.iter().any(EventDispatcher::uses_program_data_logs) {
+            return Vec::new();
+        }
+        let instruction_program_ids: Vec<Pubkey> = instruction_program_ids.collect();
+        extract_program_data_logs(log_messages, &instruction_program_ids, |program_id| {
+            EventDispatcher::match_protocol_by_program_id(program_id).is_some_and(|protocol| {
+                EventDispatcher::uses_program_data_logs(&protocol)
+                    && EventDispatcher::is_protocol_selected(protocols, &protocol)
+            })
+        })
+    }
+
+    /// 解析外层指令 `outer_index` (含其 inner instructions) 输出的日志事件
+    ///
+    /// 日志事件在该指令的指令事件之后回调，返回剩余未处理的日志
+    #[allow(clippy::too_many_arguments)]
+    fn parse_events_from_program_logs<'a>(
+        options: ParseOptions<'_>,
+        program_logs: &'a [ProgramDataLog],
+        outer_index: i64,
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        fees: TransactionFees,
+        transaction_index: Option<u64>,
+        callback: Arc<dyn for<'b> Fn(&'b DexEvent) + Send + Sync>,
+    ) -> &'a [ProgramDataLog] {
+        let end = program_logs.partition_point(|log| log.outer_index <= outer_index);
+        let (current, rest) = program_logs.split_at(end);
+        let metrics = MetricsManager::global();
+        for log in current {
+            let Some(protocol) = EventDispatcher::match_protocol_by_program_id(&log.program_id)
+            else {
+                continue;
+            };
+            if !EventDispatcher::is_protocol_selected(options.protocols, &protocol) {
+                continue;
+            }
+            let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
+            let block_time_ms = timestamp_millis(timestamp.seconds, timestamp.nanos);
+            let mut metadata = EventMetadata::new(
+                signature,
+                slot,
+                block_time.map(|t| t.seconds),
+                block_time_ms,
+                Default::default(), // protocol will be set by dispatcher
+                Default::default(), // event_type will be set by dispatcher
+                log.program_id,
+                log.outer_index,
+                log.inner_index,
+                recv_us,
+                transaction_index,
+            );
+            metadata.set_transaction_fees(fees);
+            if let Some(outer_program) = log.outer_program {
+                metadata.set_cpi_context(log.cpi_depth, outer_program);
+            }
+
+            let started = metrics.is_enabled().then(Instant::now);
+            let Some(mut event) =
+                EventDispatcher::dispatch_program_data_log(&protocol, &log.data, metadata)
+            else {
+                continue;
+            };
+            if let Some(started) = started {
+                metrics.record_parse(
+                    &protocol,
+                    &event.metadata().event_type,
+                    started.elapsed(),
+                    true,
+                );
+            }
+            event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+            event = Self::process_event(event, options.bot_wallet);
+            if Self::passes_filters(&event, options) {
+                callback(&event);
+            }
+        }
+        rest
+    }
+
+    /// 事件类型过滤和最小成交额过滤均通过时返回 true
+    fn passes_filters(event: &DexEvent, options: ParseOptions<'_>) -> bool {
+        options.event_type_filter.is_none_or(|filter| filter.includes(&event.metadata().event_type))
+            && options.min_quote_amount.is_none_or(|filter| filter.passes(event))
+    }
+
     // ================================================================================================
     // Event Post-Processing
     // ================================================================================================
@@ -667,6 +1270,8 @@ impl EventParser {
     /// - PumpFun: Tracks dev addresses and marks dev trades
     /// - PumpSwap: Fills swap data amounts
     /// - Bonk: Tracks pool creators and marks dev trades
+    /// - Meteora DLMM: Fills swap data mints and amounts by swap direction
+    /// - Jupiter: Builds swap data from the route hops
     /// - General: Marks bot wallet trades
     fn process_event(event: DexEvent, bot_wallet: Option<Pubkey>) -> DexEvent {
         let signature = event.metadata().signature; // Copy the signature to avoid borrowing issues
@@ -731,7 +1336,103 @@ impl EventParser {
                 trade_info.is_bot = Some(trade_info.payer) == bot_wallet;
                 DexEvent::BonkTradeEvent(trade_info)
             }
+            DexEvent::MeteoraDlmmSwapEvent(mut trade_info) => {
+                if let Some(swap_data) = trade_info.metadata.swap_data.as_mut() {
+                    let (from_mint, to_mint) = if trade_info.swap_for_y {
+                        (trade_info.token_x_mint, trade_info.token_y_mint)
+                    } else {
+                        (trade_info.token_y_mint, trade_info.token_x_mint)
+                    };
+                    swap_data.from_mint = from_mint;
+                    swap_data.to_mint = to_mint;
+                    swap_data.from_amount = trade_info.amount_in;
+                    swap_data.to_amount = trade_info.amount_out;
+                }
+                DexEvent::MeteoraDlmmSwapEvent(trade_info)
+            }
+            DexEvent::JupiterRoute(mut route_info) => {
+                // Jupiter: swap data 由路由首尾两端的 hop 汇总得到，数量溢出时不填充
+                if let (false, Some(from_amount), Some(to_amount)) = (
+                    route_info.hops.is_empty(),
+                    route_info.total_in_amount(),
+                    route_info.total_out_amount(),
+                ) {
+                    let swap_data = SwapData {
+                        from_mint: route_info.source_mint,
+                        to_mint: route_info.destination_mint,
+                        from_amount,
+                        to_amount,
+                        description: None,
+                    };
+                    route_info.metadata.set_swap_data(swap_data);
+                }
+                DexEvent::JupiterRoute(route_info)
+            }
             _ => event,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::{filter::EventTypeFilter, EventType};
+    use crate::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+    use parking_lot::Mutex;
+
+    /// 数据只有判别器的 PumpFun sell 指令，解码必然失败并上报解析错误，用来判断是否调用了解码器
+    fn parse_truncated_sell(
+        filter: &EventTypeFilter,
+        signature: Signature,
+        parse_errors: &ParseErrorCallback,
+    ) -> Vec<DexEvent> {
+        let instruction = CompiledInstruction {
+            program_id_index: 0,
+            accounts: Vec::new(),
+            data: pumpfun_discriminators::SELL_IX.to_vec(),
+        };
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let collected = events.clone();
+        let options = ParseOptions::new(&[Protocol::PumpFun])
+            .with_event_type_filter(Some(filter))
+            .with_parse_error_callback(Some(parse_errors));
+        EventParser::parse_events_from_instruction(
+            options,
+            &instruction,
+            &[PUMPFUN_PROGRAM_ID],
+            signature,
+            0,
+            None,
+            0,
+            0,
+            None,
+            None,
+            TransactionFees::default(),
+            None,
+            None,
+            Arc::new(move |event: &DexEvent| collected.lock().push(event.clone())),
+        )
+        .unwrap();
+        let events = events.lock().clone();
+        events
+    }
+
+    #[test]
+    fn buy_only_filter_skips_sell_decoding() {
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let reported = errors.clone();
+        let parse_errors: ParseErrorCallback =
+            Arc::new(move |error: &ParseError| reported.lock().push(error.signature));
+
+        let buy_only = EventTypeFilter { include: vec![EventType::PumpFunBuy] };
+        let skipped = Signature::from([11; 64]);
+        assert!(parse_truncated_sell(&buy_only, skipped, &parse_errors).is_empty());
+
+        // 对照：过滤器包含 sell 时解码器被调用，截断的数据上报解析错误
+        let sell = EventTypeFilter { include: vec![EventType::PumpFunSell] };
+        let decoded = Signature::from([12; 64]);
+        assert!(parse_truncated_sell(&sell, decoded, &parse_errors).is_empty());
+
+        assert_eq!(*errors.lock(), vec![decoded]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::streaming::event_parser::DexEvent;
+use solana_sdk::pubkey::Pubkey;
 
 pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
     match instruction_event {
@@ -21,6 +22,12 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
                 e.creator = cpie.creator;
                 e.creator_fee_basis_points = cpie.creator_fee_basis_points;
                 e.creator_fee = cpie.creator_fee;
+                e.track_volume = cpie.track_volume;
+                e.total_unclaimed_tokens = cpie.total_unclaimed_tokens;
+                e.total_claimed_tokens = cpie.total_claimed_tokens;
+                e.current_sol_volume = cpie.current_sol_volume;
+                e.last_update_timestamp = cpie.last_update_timestamp;
+                e.bonding_curve_complete = cpie.bonding_curve_complete;
             }
             _ => {}
         },
@@ -369,6 +376,55 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
             _ => {}
         },
 
+        // Meteora DLMM events
+        DexEvent::MeteoraDlmmSwapEvent(e) => {
+            if let DexEvent::MeteoraDlmmSwapEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.from = cpie.from;
+                e.start_bin_id = cpie.start_bin_id;
+                e.end_bin_id = cpie.end_bin_id;
+                e.amount_in = cpie.amount_in;
+                e.amount_out = cpie.amount_out;
+                e.swap_for_y = cpie.swap_for_y;
+                e.fee = cpie.fee;
+                e.protocol_fee = cpie.protocol_fee;
+                e.fee_bps = cpie.fee_bps;
+                e.host_fee = cpie.host_fee;
+                e.active_bin_id = cpie.end_bin_id;
+            }
+        }
+        DexEvent::MeteoraDlmmAddLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDlmmAddLiquidityEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.from = cpie.from;
+                e.position = cpie.position;
+                e.amounts = cpie.amounts;
+                e.active_bin_id = cpie.active_bin_id;
+            }
+        }
+        DexEvent::MeteoraDlmmRemoveLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDlmmRemoveLiquidityEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.from = cpie.from;
+                e.position = cpie.position;
+                e.amounts = cpie.amounts;
+                e.active_bin_id = cpie.active_bin_id;
+            }
+        }
+
+        // Jupiter events
+        DexEvent::JupiterRoute(e) => {
+            if let DexEvent::JupiterRoute(cpie) = cpi_log_event {
+                // 每个 SwapEvent 对应一跳，按顺序追加
+                e.hops.extend(cpie.hops);
+                if e.source_mint == Pubkey::default() {
+                    if let Some(first_hop) = e.hops.first() {
+                        e.source_mint = first_hop.input_mint;
+                    }
+                }
+            }
+        }
+
         _ => {}
     }
 }
@@ -1,10 +1,25 @@
 pub mod account_event_parser;
+pub mod codec;
 pub mod common_event_parser;
 pub mod dispatcher;
 pub mod global_state;
+pub mod parse_options;
 pub mod parser_cache;
+pub mod pool_created;
+pub mod pool_state;
+pub mod protocol_parser;
+pub mod trade;
 pub mod traits;
 
+pub use codec::{decode_event, encode_event, EventCodecError};
+pub use parse_options::ParseOptions;
+pub use pool_created::PoolCreated;
+pub use pool_state::NormalizedPoolState;
+pub use protocol_parser::{
+    clear_protocol_parsers, register_protocol_parser, unregister_protocol_parser, CustomEvent,
+    ParseContext, ProtocolParser,
+};
+pub use trade::NormalizedTrade;
 pub use traits::DexEvent;
 pub use dispatcher::EventDispatcher;
 
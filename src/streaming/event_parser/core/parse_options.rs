@@ -0,0 +1,67 @@
+use crate::streaming::common::{AccountLamportsCache, AccountOwnerCache};
+use crate::streaming::event_parser::{
+    common::{
+        filter::{EventTypeFilter, MinQuoteAmountFilter},
+        ParseErrorCallback,
+    },
+    Protocol,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// 解析交易时使用的协议和过滤条件
+///
+/// `EventParser` 的入口函数和逐条指令的解析共用同一份选项；流式订阅按订阅创建
+#[derive(Clone, Copy, Default)]
+pub struct ParseOptions<'a> {
+    /// 解析的协议，未选中协议的指令直接跳过
+    pub protocols: &'a [Protocol],
+    /// 只回调包含的事件类型，指令产出的事件类型不在其中时不解码
+    pub event_type_filter: Option<&'a EventTypeFilter>,
+    /// 成交额低于阈值的交易事件不回调
+    pub min_quote_amount: Option<&'a MinQuoteAmountFilter>,
+    /// 该钱包发起的交易事件标记为 `is_bot`
+    pub bot_wallet: Option<Pubkey>,
+    /// 订阅自己的账户 owner 缓存，设置时账户更新检测 owner 变化
+    pub owner_cache: Option<&'a AccountOwnerCache>,
+    /// 订阅自己的账户 lamports 缓存，设置时账户更新检测 SOL 余额变化
+    pub lamports_cache: Option<&'a AccountLamportsCache>,
+    /// 订阅自己的解析错误回调，未设置时解析器不收集错误上下文
+    pub parse_error_callback: Option<&'a ParseErrorCallback>,
+}
+
+impl<'a> ParseOptions<'a> {
+    /// 解析 `protocols`，不做过滤
+    pub fn new(protocols: &'a [Protocol]) -> Self {
+        Self { protocols, ..Default::default() }
+    }
+
+    pub fn with_event_type_filter(mut self, filter: Option<&'a EventTypeFilter>) -> Self {
+        self.event_type_filter = filter;
+        self
+    }
+
+    pub fn with_min_quote_amount(mut self, filter: Option<&'a MinQuoteAmountFilter>) -> Self {
+        self.min_quote_amount = filter;
+        self
+    }
+
+    pub fn with_bot_wallet(mut self, bot_wallet: Option<Pubkey>) -> Self {
+        self.bot_wallet = bot_wallet;
+        self
+    }
+
+    pub fn with_owner_cache(mut self, cache: Option<&'a AccountOwnerCache>) -> Self {
+        self.owner_cache = cache;
+        self
+    }
+
+    pub fn with_lamports_cache(mut self, cache: Option<&'a AccountLamportsCache>) -> Self {
+        self.lamports_cache = cache;
+        self
+    }
+
+    pub fn with_parse_error_callback(mut self, callback: Option<&'a ParseErrorCallback>) -> Self {
+        self.parse_error_callback = callback;
+        self
+    }
+}
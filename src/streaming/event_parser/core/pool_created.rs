@@ -0,0 +1,123 @@
+use crate::streaming::event_parser::common::ProtocolType;
+use crate::streaming::event_parser::core::trade::is_quote_mint;
+use crate::streaming::event_parser::protocols::raydium_amm_v4::events::RaydiumAmmV4Initialize2Event;
+use crate::streaming::event_parser::protocols::raydium_cpmm::events::RaydiumCpmmInitializeEvent;
+use solana_sdk::pubkey::Pubkey;
+
+/// 跨协议统一的新建池子视图，通过 `DexEvent::as_pool_created` 获取
+///
+/// 来自创建池子的指令本身 (Raydium AMM v4 `initialize2`、CPMM `initialize`)，在创建交易中产出。
+/// 失败的交易同样会产出，需要时检查 `metadata().succeeded`
+///
+/// base/quote 的约定与 `NormalizedTrade` 相同：SOL/WSOL/USDC 一侧为 quote，
+/// 两侧都不是 (或都是) 时沿用协议自身的顺序 (coin/pc、token0/token1)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolCreated {
+    pub protocol: ProtocolType,
+    /// 新池子的地址
+    pub pool: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    /// 创建时注入的 base 数量，最小单位
+    pub base_reserve: u64,
+    /// 创建时注入的 quote 数量，最小单位
+    pub quote_reserve: u64,
+    pub lp_mint: Pubkey,
+    /// 创建者钱包
+    pub creator: Pubkey,
+    /// 开放交易的 unix 时间戳，0 表示立即开放
+    pub open_time: u64,
+}
+
+impl PoolCreated {
+    /// 按 base/quote 约定排列协议顺序的 (mint, 储备)
+    fn from_sides(
+        protocol: ProtocolType,
+        pool: Pubkey,
+        (mint_a, reserve_a): (Pubkey, u64),
+        (mint_b, reserve_b): (Pubkey, u64),
+        lp_mint: Pubkey,
+        creator: Pubkey,
+        open_time: u64,
+    ) -> Self {
+        let ((base_mint, base_reserve), (quote_mint, quote_reserve)) =
+            if is_quote_mint(&mint_a) && !is_quote_mint(&mint_b) {
+                ((mint_b, reserve_b), (mint_a, reserve_a))
+            } else {
+                ((mint_a, reserve_a), (mint_b, reserve_b))
+            };
+        Self {
+            protocol,
+            pool,
+            base_mint,
+            quote_mint,
+            base_reserve,
+            quote_reserve,
+            lp_mint,
+            creator,
+            open_time,
+        }
+    }
+}
+
+impl From<&RaydiumAmmV4Initialize2Event> for PoolCreated {
+    fn from(e: &RaydiumAmmV4Initialize2Event) -> Self {
+        Self::from_sides(
+            ProtocolType::RaydiumAmmV4,
+            e.amm,
+            (e.coin_mint, e.init_coin_amount),
+            (e.pc_mint, e.init_pc_amount),
+            e.lp_mint,
+            e.user_wallet,
+            e.open_time,
+        )
+    }
+}
+
+impl From<&RaydiumCpmmInitializeEvent> for PoolCreated {
+    fn from(e: &RaydiumCpmmInitializeEvent) -> Self {
+        Self::from_sides(
+            ProtocolType::RaydiumCpmm,
+            e.pool_state,
+            (e.token0_mint, e.init_amount0),
+            (e.token1_mint, e.init_amount1),
+            e.lp_mint,
+            e.creator,
+            e.open_time,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::filter::{USDC_MINT, WSOL_MINT};
+
+    fn cpmm_initialize(token0_mint: Pubkey, token1_mint: Pubkey) -> RaydiumCpmmInitializeEvent {
+        RaydiumCpmmInitializeEvent {
+            pool_state: Pubkey::new_from_array([1; 32]),
+            token0_mint,
+            token1_mint,
+            init_amount0: 100,
+            init_amount1: 200,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn quote_mint_on_the_second_side_keeps_protocol_order() {
+        let mint = Pubkey::new_from_array([2; 32]);
+        let created = PoolCreated::from(&cpmm_initialize(mint, USDC_MINT));
+        assert_eq!(created.protocol, ProtocolType::RaydiumCpmm);
+        assert_eq!((created.base_mint, created.base_reserve), (mint, 100));
+        assert_eq!((created.quote_mint, created.quote_reserve), (USDC_MINT, 200));
+    }
+
+    #[test]
+    fn quote_mints_on_both_sides_keep_protocol_order() {
+        let created = PoolCreated::from(&cpmm_initialize(WSOL_MINT, USDC_MINT));
+        assert_eq!((created.base_mint, created.quote_mint), (WSOL_MINT, USDC_MINT));
+        let created = PoolCreated::from(&cpmm_initialize(USDC_MINT, WSOL_MINT));
+        assert_eq!((created.base_mint, created.quote_mint), (USDC_MINT, WSOL_MINT));
+    }
+}
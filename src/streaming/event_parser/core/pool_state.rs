@@ -0,0 +1,197 @@
+use crate::streaming::event_parser::common::filter::WSOL_MINT;
+use crate::streaming::event_parser::common::sqrt_price_to_price;
+use crate::streaming::event_parser::protocols::bonk::events::BonkPoolStateAccountEvent;
+use crate::streaming::event_parser::protocols::meteora_damm_v2::events::MeteoraDammV2PoolAccountEvent;
+use crate::streaming::event_parser::protocols::meteora_damm_v2::types::FEE_DENOMINATOR;
+use crate::streaming::event_parser::protocols::pumpfun::events::PumpFunBondingCurveAccountEvent;
+use crate::streaming::event_parser::protocols::pumpswap::events::PumpSwapPoolAccountEvent;
+use crate::streaming::event_parser::protocols::raydium_amm_v4::events::RaydiumAmmV4AmmInfoAccountEvent;
+use crate::streaming::event_parser::protocols::raydium_clmm::events::RaydiumClmmPoolStateAccountEvent;
+use crate::streaming::event_parser::protocols::raydium_cpmm::events::RaydiumCpmmPoolStateAccountEvent;
+use solana_sdk::pubkey::Pubkey;
+
+/// 跨协议统一的池子账户视图，通过 `DexEvent::as_pool_state` 获取
+///
+/// - a/b 两侧沿用协议自身的顺序 (coin/pc、token0/token1、base/quote、token a/b)
+/// - 储备只在池子账户本身保存时返回 (联合曲线为定价用的虚拟储备)；
+///   AMM 的储备在 vault 代币账户中，可用 `vault_a`/`vault_b` 另外订阅
+/// - 手续费率只在池子账户本身保存时返回；CPMM/CLMM 的费率在 AmmConfig 账户中，
+///   PumpFun/PumpSwap 的费率在全局配置中
+///
+/// 账户中没有的字段返回 `Pubkey::default()`
+pub trait NormalizedPoolState {
+    /// 池子 (或联合曲线) 账户地址
+    fn pool(&self) -> Pubkey;
+    fn mint_a(&self) -> Pubkey;
+    fn mint_b(&self) -> Pubkey;
+    fn vault_a(&self) -> Pubkey;
+    fn vault_b(&self) -> Pubkey;
+    /// (a, b) 两侧储备，最小单位
+    fn reserves(&self) -> Option<(u64, u64)> {
+        None
+    }
+    /// 集中流动性池当前的 (liquidity, sqrt_price)，sqrt_price 为 Q64.64 定点数
+    fn concentrated_liquidity(&self) -> Option<(u128, u128)> {
+        None
+    }
+    /// 集中流动性池的当前价格 (每个 a 可换得的 b，界面单位)，见 `sqrt_price_to_price`
+    fn concentrated_price(&self, decimals_a: u8, decimals_b: u8) -> Option<f64> {
+        self.concentrated_liquidity()
+            .map(|(_, sqrt_price)| sqrt_price_to_price(sqrt_price, decimals_a, decimals_b))
+    }
+    /// 交易手续费率，0.0025 表示 0.25%
+    fn fee_rate(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl NormalizedPoolState for RaydiumAmmV4AmmInfoAccountEvent {
+    fn pool(&self) -> Pubkey {
+        self.pubkey
+    }
+    fn mint_a(&self) -> Pubkey {
+        self.amm_info.coin_mint
+    }
+    fn mint_b(&self) -> Pubkey {
+        self.amm_info.pc_mint
+    }
+    fn vault_a(&self) -> Pubkey {
+        self.amm_info.token_coin
+    }
+    fn vault_b(&self) -> Pubkey {
+        self.amm_info.token_pc
+    }
+    fn fee_rate(&self) -> Option<f64> {
+        let fees = &self.amm_info.fees;
+        (fees.swap_fee_denominator > 0)
+            .then(|| fees.swap_fee_numerator as f64 / fees.swap_fee_denominator as f64)
+    }
+}
+
+impl NormalizedPoolState for RaydiumCpmmPoolStateAccountEvent {
+    fn pool(&self) -> Pubkey {
+        self.pubkey
+    }
+    fn mint_a(&self) -> Pubkey {
+        self.pool_state.token0_mint
+    }
+    fn mint_b(&self) -> Pubkey {
+        self.pool_state.token1_mint
+    }
+    fn vault_a(&self) -> Pubkey {
+        self.pool_state.token0_vault
+    }
+    fn vault_b(&self) -> Pubkey {
+        self.pool_state.token1_vault
+    }
+}
+
+impl NormalizedPoolState for RaydiumClmmPoolStateAccountEvent {
+    fn pool(&self) -> Pubkey {
+        self.pubkey
+    }
+    fn mint_a(&self) -> Pubkey {
+        self.pool_state.token_mint0
+    }
+    fn mint_b(&self) -> Pubkey {
+        self.pool_state.token_mint1
+    }
+    fn vault_a(&self) -> Pubkey {
+        self.pool_state.token_vault0
+    }
+    fn vault_b(&self) -> Pubkey {
+        self.pool_state.token_vault1
+    }
+    fn concentrated_liquidity(&self) -> Option<(u128, u128)> {
+        Some((self.pool_state.liquidity, self.pool_state.sqrt_price_x64))
+    }
+}
+
+impl NormalizedPoolState for MeteoraDammV2PoolAccountEvent {
+    fn pool(&self) -> Pubkey {
+        self.pubkey
+    }
+    fn mint_a(&self) -> Pubkey {
+        self.pool.token_a_mint
+    }
+    fn mint_b(&self) -> Pubkey {
+        self.pool.token_b_mint
+    }
+    fn vault_a(&self) -> Pubkey {
+        self.pool.token_a_vault
+    }
+    fn vault_b(&self) -> Pubkey {
+        self.pool.token_b_vault
+    }
+    fn concentrated_liquidity(&self) -> Option<(u128, u128)> {
+        Some((self.pool.liquidity, self.pool.sqrt_price))
+    }
+    /// 基础费率，不含动态费率和随时间递减的费率调度
+    fn fee_rate(&self) -> Option<f64> {
+        Some(self.pool.pool_fees.base_fee.cliff_fee_numerator as f64 / FEE_DENOMINATOR as f64)
+    }
+}
+
+impl NormalizedPoolState for PumpSwapPoolAccountEvent {
+    fn pool(&self) -> Pubkey {
+        self.pubkey
+    }
+    fn mint_a(&self) -> Pubkey {
+        self.pool.base_mint
+    }
+    fn mint_b(&self) -> Pubkey {
+        self.pool.quote_mint
+    }
+    fn vault_a(&self) -> Pubkey {
+        self.pool.pool_base_token_account
+    }
+    fn vault_b(&self) -> Pubkey {
+        self.pool.pool_quote_token_account
+    }
+}
+
+impl NormalizedPoolState for BonkPoolStateAccountEvent {
+    fn pool(&self) -> Pubkey {
+        self.pubkey
+    }
+    fn mint_a(&self) -> Pubkey {
+        self.pool_state.base_mint
+    }
+    fn mint_b(&self) -> Pubkey {
+        self.pool_state.quote_mint
+    }
+    fn vault_a(&self) -> Pubkey {
+        self.pool_state.base_vault
+    }
+    fn vault_b(&self) -> Pubkey {
+        self.pool_state.quote_vault
+    }
+    fn reserves(&self) -> Option<(u64, u64)> {
+        Some((self.pool_state.virtual_base, self.pool_state.virtual_quote))
+    }
+}
+
+/// 联合曲线账户不保存 mint 和 vault，a 侧 mint 为 `Pubkey::default()`
+impl NormalizedPoolState for PumpFunBondingCurveAccountEvent {
+    fn pool(&self) -> Pubkey {
+        self.pubkey
+    }
+    fn mint_a(&self) -> Pubkey {
+        Pubkey::default()
+    }
+    fn mint_b(&self) -> Pubkey {
+        WSOL_MINT
+    }
+    fn vault_a(&self) -> Pubkey {
+        Pubkey::default()
+    }
+    fn vault_b(&self) -> Pubkey {
+        Pubkey::default()
+    }
+    fn reserves(&self) -> Option<(u64, u64)> {
+        Some((
+            self.bonding_curve.virtual_token_reserves,
+            self.bonding_curve.virtual_sol_reserves,
+        ))
+    }
+}
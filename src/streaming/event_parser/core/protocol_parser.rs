@@ -0,0 +1,136 @@
+use crate::streaming::event_parser::{
+    common::{EventMetadata, EventType, ProtocolType},
+    core::dispatcher::EventDispatcher,
+    protocols::{
+        phoenix::parser::PHOENIX_PROGRAM_ID, raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID,
+        sanctum::parser::SANCTUM_INFINITY_PROGRAM_ID,
+    },
+    DexEvent, Protocol,
+};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+
+/// 交给协议解析器的一条指令
+#[derive(Debug, Clone, Copy)]
+pub struct ParseContext<'a> {
+    /// 指令调用的程序
+    pub program_id: Pubkey,
+    /// 完整的指令数据，包含 discriminator
+    pub data: &'a [u8],
+    /// 指令的账户，地址查找表中无法解析的账户为默认公钥
+    pub accounts: &'a [Pubkey],
+    /// 已填好签名、slot、指令下标、手续费和 CPI 信息的元数据，产出事件时以它为模板
+    pub metadata: &'a EventMetadata,
+}
+
+impl ParseContext<'_> {
+    /// 以 `metadata` 为模板生成 `DexEvent::CustomEvent`
+    pub fn custom_event(&self, name: impl Into<String>, data: Vec<u8>) -> DexEvent {
+        let mut metadata = self.metadata.clone();
+        metadata.protocol = ProtocolType::Custom;
+        metadata.event_type = EventType::Custom;
+        DexEvent::CustomEvent(CustomEvent { metadata, name: name.into(), data })
+    }
+}
+
+/// 按程序解析指令的协议解析器
+///
+/// 内置协议 (`Protocol`) 实现了同一个 trait；自定义解析器通过 `register_protocol_parser`
+/// 注册后，对应程序的指令 (包括 inner instructions) 交给它解析
+pub trait ProtocolParser: Send + Sync {
+    /// 解析器负责的程序
+    fn program_id(&self) -> Pubkey;
+
+    /// 解析一条指令，无法识别时返回空列表
+    fn parse(&self, ctx: &ParseContext) -> Vec<DexEvent>;
+}
+
+/// 自定义解析器产出的事件
+///
+/// `data` 的编码由解析器自行决定，`name` 用于区分同一解析器产出的不同事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomEvent {
+    pub metadata: EventMetadata,
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl ProtocolParser for Protocol {
+    fn program_id(&self) -> Pubkey {
+        EventDispatcher::get_program_id(self.clone())
+    }
+
+    /// 只解析指令本身，不合并 CPI 日志事件，也不填充余额变化和 swap_data
+    fn parse(&self, ctx: &ParseContext) -> Vec<DexEvent> {
+        let disc_len = match ctx.program_id {
+            RAYDIUM_AMM_V4_PROGRAM_ID | PHOENIX_PROGRAM_ID | SANCTUM_INFINITY_PROGRAM_ID => 1,
+            _ => 8,
+        };
+        if ctx.data.len() < disc_len {
+            return Vec::new();
+        }
+        let (discriminator, data) = ctx.data.split_at(disc_len);
+        if let Some(events) = EventDispatcher::dispatch_log_instruction(
+            self,
+            discriminator,
+            data,
+            ctx.metadata.clone(),
+        ) {
+            return events;
+        }
+        EventDispatcher::dispatch_instruction(
+            self.clone(),
+            discriminator,
+            data,
+            ctx.accounts,
+            ctx.metadata.clone(),
+        )
+        .into_iter()
+        .collect()
+    }
+}
+
+/// 与解析错误回调一样在进程内全局生效，数量通常很少，按程序线性查找
+static PROTOCOL_PARSERS: RwLock<Vec<(Pubkey, Arc<dyn ProtocolParser>)>> = RwLock::new(Vec::new());
+
+/// 注册自定义协议解析器，替换同一程序已注册的解析器
+///
+/// 注册的程序优先于内置协议解析，且不受订阅协议列表的限制；交易订阅仍需包含该程序
+/// (例如放入 `account_include`) 才能收到它的交易。解析器在解析任务中同步执行，应尽快返回
+pub fn register_protocol_parser<P>(parser: P)
+where
+    P: ProtocolParser + 'static,
+{
+    let program_id = parser.program_id();
+    let parser: Arc<dyn ProtocolParser> = Arc::new(parser);
+    let mut parsers = PROTOCOL_PARSERS.write();
+    match parsers.iter_mut().find(|(id, _)| *id == program_id) {
+        Some(entry) => entry.1 = parser,
+        None => parsers.push((program_id, parser)),
+    }
+}
+
+/// 移除某个程序的自定义解析器，返回是否存在
+pub fn unregister_protocol_parser(program_id: &Pubkey) -> bool {
+    let mut parsers = PROTOCOL_PARSERS.write();
+    let len = parsers.len();
+    parsers.retain(|(id, _)| id != program_id);
+    parsers.len() != len
+}
+
+/// 移除全部自定义解析器
+pub fn clear_protocol_parsers() {
+    PROTOCOL_PARSERS.write().clear();
+}
+
+/// 程序对应的自定义解析器，未注册时返回 None
+#[inline]
+pub(crate) fn protocol_parser_for(program_id: &Pubkey) -> Option<Arc<dyn ProtocolParser>> {
+    let parsers = PROTOCOL_PARSERS.read();
+    if parsers.is_empty() {
+        return None;
+    }
+    parsers.iter().find(|(id, _)| id == program_id).map(|(_, parser)| parser.clone())
+}
@@ -0,0 +1,462 @@
+use crate::streaming::event_parser::common::filter::{NATIVE_SOL_MINT, USDC_MINT, WSOL_MINT};
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::bonk::events::BonkTradeEvent;
+use crate::streaming::event_parser::protocols::bonk::types::TradeDirection;
+use crate::streaming::event_parser::protocols::jupiter::events::JupiterRouteEvent;
+use crate::streaming::event_parser::protocols::lifinity::events::LifinitySwapEvent;
+use crate::streaming::event_parser::protocols::meteora_damm_v2::events::{
+    MeteoraDammV2Swap2Event, MeteoraDammV2SwapEvent,
+};
+use crate::streaming::event_parser::protocols::meteora_dlmm::events::MeteoraDlmmSwapEvent;
+use crate::streaming::event_parser::protocols::moonshot::events::MoonshotTradeEvent;
+use crate::streaming::event_parser::protocols::orca_whirlpool::events::OrcaWhirlpoolSwapEvent;
+use crate::streaming::event_parser::protocols::openbook_v2::events::OpenBookV2FillEvent;
+use crate::streaming::event_parser::protocols::openbook_v2::types::OpenBookV2Side;
+use crate::streaming::event_parser::protocols::phoenix::events::PhoenixFillEvent;
+use crate::streaming::event_parser::protocols::phoenix::types::PhoenixSide;
+use crate::streaming::event_parser::protocols::pumpfun::events::PumpFunTradeEvent;
+use crate::streaming::event_parser::protocols::pumpswap::events::{
+    PumpSwapBuyEvent, PumpSwapSellEvent,
+};
+use crate::streaming::event_parser::protocols::raydium_amm_v4::events::RaydiumAmmV4SwapEvent;
+use crate::streaming::event_parser::protocols::raydium_clmm::events::{
+    RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event,
+};
+use crate::streaming::event_parser::protocols::raydium_cpmm::events::RaydiumCpmmSwapEvent;
+use crate::streaming::event_parser::protocols::sanctum::events::SanctumSwapEvent;
+use solana_sdk::pubkey::Pubkey;
+
+/// 跨协议统一的交易视图，通过 `DexEvent::as_trade` 获取
+///
+/// base/quote 的约定：
+/// - 联合曲线 (PumpFun、Bonk、Moonshot) 和 PumpSwap 使用协议自身的 base/quote
+/// - 其他 AMM 以 SOL/WSOL/USDC 一侧为 quote；两侧都不是 (或都是) 时以买入的一侧为 base
+/// - `is_buy` 表示交易者买入 base
+///
+/// 数量为最小单位。事件没有实际成交数量时优先取 `swap_data`，再退回指令参数
+/// (exact in 的输出为最小输出量)。事件中没有的字段返回 `Pubkey::default()` 或 0
+pub trait NormalizedTrade {
+    fn base_mint(&self) -> Pubkey;
+    fn quote_mint(&self) -> Pubkey;
+    fn base_amount(&self) -> u64;
+    fn quote_amount(&self) -> u64;
+    fn is_buy(&self) -> bool;
+    /// 交易所在的池子 (或联合曲线、市场)
+    fn pool(&self) -> Pubkey;
+}
+
+/// 按输入/输出方向归一化后的交易两侧
+struct TradeSides {
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    base_amount: u64,
+    quote_amount: u64,
+    is_buy: bool,
+}
+
+pub(crate) fn is_quote_mint(mint: &Pubkey) -> bool {
+    *mint == WSOL_MINT || *mint == NATIVE_SOL_MINT || *mint == USDC_MINT
+}
+
+impl TradeSides {
+    fn from_direction(in_mint: Pubkey, in_amount: u64, out_mint: Pubkey, out_amount: u64) -> Self {
+        if is_quote_mint(&out_mint) && !is_quote_mint(&in_mint) {
+            Self {
+                base_mint: in_mint,
+                quote_mint: out_mint,
+                base_amount: in_amount,
+                quote_amount: out_amount,
+                is_buy: false,
+            }
+        } else {
+            Self {
+                base_mint: out_mint,
+                quote_mint: in_mint,
+                base_amount: out_amount,
+                quote_amount: in_amount,
+                is_buy: true,
+            }
+        }
+    }
+
+    /// 由 `swap_data` 计算，缺失时返回全默认值
+    fn from_swap_data(metadata: &EventMetadata) -> Self {
+        match &metadata.swap_data {
+            Some(swap_data) => Self::from_direction(
+                swap_data.from_mint,
+                swap_data.from_amount,
+                swap_data.to_mint,
+                swap_data.to_amount,
+            ),
+            None => Self::from_direction(Pubkey::default(), 0, Pubkey::default(), 0),
+        }
+    }
+
+    /// 已知输入/输出 mint 时使用，数量优先取 `swap_data`
+    fn with_amounts_from_swap_data(
+        metadata: &EventMetadata,
+        in_mint: Pubkey,
+        in_amount: u64,
+        out_mint: Pubkey,
+        out_amount: u64,
+    ) -> Self {
+        match &metadata.swap_data {
+            Some(swap_data) if swap_data.from_amount > 0 || swap_data.to_amount > 0 => {
+                Self::from_direction(in_mint, swap_data.from_amount, out_mint, swap_data.to_amount)
+            }
+            _ => Self::from_direction(in_mint, in_amount, out_mint, out_amount),
+        }
+    }
+}
+
+/// 通过 `sides()` 实现 `NormalizedTrade`
+macro_rules! impl_normalized_trade_by_sides {
+    ($($event:ty => $pool:ident),* $(,)?) => {
+        $(
+            impl NormalizedTrade for $event {
+                fn base_mint(&self) -> Pubkey {
+                    self.sides().base_mint
+                }
+                fn quote_mint(&self) -> Pubkey {
+                    self.sides().quote_mint
+                }
+                fn base_amount(&self) -> u64 {
+                    self.sides().base_amount
+                }
+                fn quote_amount(&self) -> u64 {
+                    self.sides().quote_amount
+                }
+                fn is_buy(&self) -> bool {
+                    self.sides().is_buy
+                }
+                fn pool(&self) -> Pubkey {
+                    self.$pool
+                }
+            }
+        )*
+    };
+}
+
+impl NormalizedTrade for BonkTradeEvent {
+    fn base_mint(&self) -> Pubkey {
+        self.base_token_mint
+    }
+    fn quote_mint(&self) -> Pubkey {
+        self.quote_token_mint
+    }
+    fn base_amount(&self) -> u64 {
+        if self.is_buy() {
+            self.amount_out
+        } else {
+            self.amount_in
+        }
+    }
+    fn quote_amount(&self) -> u64 {
+        if self.is_buy() {
+            self.amount_in
+        } else {
+            self.amount_out
+        }
+    }
+    fn is_buy(&self) -> bool {
+        self.trade_direction == TradeDirection::Buy
+    }
+    fn pool(&self) -> Pubkey {
+        self.pool_state
+    }
+}
+
+impl NormalizedTrade for PumpFunTradeEvent {
+    fn base_mint(&self) -> Pubkey {
+        self.mint
+    }
+    fn quote_mint(&self) -> Pubkey {
+        WSOL_MINT
+    }
+    fn base_amount(&self) -> u64 {
+        self.token_amount
+    }
+    fn quote_amount(&self) -> u64 {
+        self.sol_amount
+    }
+    fn is_buy(&self) -> bool {
+        self.is_buy
+    }
+    fn pool(&self) -> Pubkey {
+        self.bonding_curve
+    }
+}
+
+impl NormalizedTrade for PumpSwapBuyEvent {
+    fn base_mint(&self) -> Pubkey {
+        self.base_mint
+    }
+    fn quote_mint(&self) -> Pubkey {
+        self.quote_mint
+    }
+    fn base_amount(&self) -> u64 {
+        self.base_amount_out
+    }
+    fn quote_amount(&self) -> u64 {
+        self.user_quote_amount_in
+    }
+    fn is_buy(&self) -> bool {
+        true
+    }
+    fn pool(&self) -> Pubkey {
+        self.pool
+    }
+}
+
+impl NormalizedTrade for PumpSwapSellEvent {
+    fn base_mint(&self) -> Pubkey {
+        self.base_mint
+    }
+    fn quote_mint(&self) -> Pubkey {
+        self.quote_mint
+    }
+    fn base_amount(&self) -> u64 {
+        self.base_amount_in
+    }
+    fn quote_amount(&self) -> u64 {
+        self.user_quote_amount_out
+    }
+    fn is_buy(&self) -> bool {
+        false
+    }
+    fn pool(&self) -> Pubkey {
+        self.pool
+    }
+}
+
+impl NormalizedTrade for MoonshotTradeEvent {
+    fn base_mint(&self) -> Pubkey {
+        self.mint
+    }
+    fn quote_mint(&self) -> Pubkey {
+        WSOL_MINT
+    }
+    fn base_amount(&self) -> u64 {
+        self.token_amount
+    }
+    fn quote_amount(&self) -> u64 {
+        self.collateral_amount
+    }
+    fn is_buy(&self) -> bool {
+        self.is_buy
+    }
+    fn pool(&self) -> Pubkey {
+        self.curve_account
+    }
+}
+
+/// Phoenix 成交事件只有 lot/tick，没有市场参数：mint 为默认值，
+/// `base_amount` 为成交的 base lots，`quote_amount` 为 0
+impl NormalizedTrade for PhoenixFillEvent {
+    fn base_mint(&self) -> Pubkey {
+        Pubkey::default()
+    }
+    fn quote_mint(&self) -> Pubkey {
+        Pubkey::default()
+    }
+    fn base_amount(&self) -> u64 {
+        self.base_lots_filled
+    }
+    fn quote_amount(&self) -> u64 {
+        0
+    }
+    fn is_buy(&self) -> bool {
+        self.side == PhoenixSide::Bid
+    }
+    fn pool(&self) -> Pubkey {
+        self.market
+    }
+}
+
+/// 与 Phoenix 相同，日志中只有 lot：mint 为默认值，
+/// `base_amount` 为成交的 base lots，`quote_amount` 为 0
+impl NormalizedTrade for OpenBookV2FillEvent {
+    fn base_mint(&self) -> Pubkey {
+        Pubkey::default()
+    }
+    fn quote_mint(&self) -> Pubkey {
+        Pubkey::default()
+    }
+    fn base_amount(&self) -> u64 {
+        self.quantity.max(0) as u64
+    }
+    fn quote_amount(&self) -> u64 {
+        0
+    }
+    fn is_buy(&self) -> bool {
+        self.side == OpenBookV2Side::Bid
+    }
+    fn pool(&self) -> Pubkey {
+        self.market
+    }
+}
+
+// 指令中没有 mint，只能依赖 swap_data
+impl RaydiumAmmV4SwapEvent {
+    fn sides(&self) -> TradeSides {
+        TradeSides::from_swap_data(&self.metadata)
+    }
+}
+
+impl RaydiumClmmSwapEvent {
+    fn sides(&self) -> TradeSides {
+        TradeSides::from_swap_data(&self.metadata)
+    }
+}
+
+impl RaydiumClmmSwapV2Event {
+    fn sides(&self) -> TradeSides {
+        let (in_amount, out_amount) = if self.is_base_input {
+            (self.amount, self.other_amount_threshold)
+        } else {
+            (self.other_amount_threshold, self.amount)
+        };
+        TradeSides::with_amounts_from_swap_data(
+            &self.metadata,
+            self.input_vault_mint,
+            in_amount,
+            self.output_vault_mint,
+            out_amount,
+        )
+    }
+}
+
+impl RaydiumCpmmSwapEvent {
+    fn sides(&self) -> TradeSides {
+        let in_amount = if self.amount_in > 0 { self.amount_in } else { self.max_amount_in };
+        let out_amount = if self.amount_out > 0 { self.amount_out } else { self.minimum_amount_out };
+        TradeSides::with_amounts_from_swap_data(
+            &self.metadata,
+            self.input_token_mint,
+            in_amount,
+            self.output_token_mint,
+            out_amount,
+        )
+    }
+}
+
+impl OrcaWhirlpoolSwapEvent {
+    fn sides(&self) -> TradeSides {
+        let (in_mint, out_mint) = if self.a_to_b {
+            (self.token_mint_a, self.token_mint_b)
+        } else {
+            (self.token_mint_b, self.token_mint_a)
+        };
+        let (in_amount, out_amount) = if self.amount_specified_is_input {
+            (self.amount, self.other_amount_threshold)
+        } else {
+            (self.other_amount_threshold, self.amount)
+        };
+        TradeSides::with_amounts_from_swap_data(
+            &self.metadata,
+            in_mint,
+            in_amount,
+            out_mint,
+            out_amount,
+        )
+    }
+}
+
+impl MeteoraDlmmSwapEvent {
+    fn sides(&self) -> TradeSides {
+        let (in_mint, out_mint) = if self.swap_for_y {
+            (self.token_x_mint, self.token_y_mint)
+        } else {
+            (self.token_y_mint, self.token_x_mint)
+        };
+        TradeSides::from_direction(in_mint, self.amount_in, out_mint, self.amount_out)
+    }
+}
+
+/// DAMM v2 的 trade_direction: 0 为 A -> B，1 为 B -> A
+macro_rules! impl_meteora_damm_v2_sides {
+    ($($event:ty),*) => {
+        $(
+            impl $event {
+                fn sides(&self) -> TradeSides {
+                    let (in_mint, out_mint) = if self.trade_direction == 0 {
+                        (self.token_a_mint, self.token_b_mint)
+                    } else {
+                        (self.token_b_mint, self.token_a_mint)
+                    };
+                    TradeSides::from_direction(
+                        in_mint,
+                        self.included_fee_input_amount,
+                        out_mint,
+                        self.output_amount,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_meteora_damm_v2_sides!(MeteoraDammV2SwapEvent, MeteoraDammV2Swap2Event);
+
+impl LifinitySwapEvent {
+    fn sides(&self) -> TradeSides {
+        TradeSides::from_direction(self.in_mint, self.in_amount, self.out_mint, self.out_amount)
+    }
+}
+
+impl SanctumSwapEvent {
+    fn sides(&self) -> TradeSides {
+        TradeSides::from_direction(
+            self.src_lst_mint,
+            self.in_amount,
+            self.dst_lst_mint,
+            self.out_amount,
+        )
+    }
+}
+
+impl JupiterRouteEvent {
+    fn sides(&self) -> TradeSides {
+        TradeSides::from_direction(
+            self.source_mint,
+            self.total_in_amount().unwrap_or_default(),
+            self.destination_mint,
+            self.total_out_amount().unwrap_or_default(),
+        )
+    }
+}
+
+impl_normalized_trade_by_sides!(
+    RaydiumAmmV4SwapEvent => amm,
+    RaydiumClmmSwapEvent => pool_state,
+    RaydiumClmmSwapV2Event => pool_state,
+    RaydiumCpmmSwapEvent => pool_state,
+    OrcaWhirlpoolSwapEvent => whirlpool,
+    MeteoraDlmmSwapEvent => lb_pair,
+    MeteoraDammV2SwapEvent => pool,
+    MeteoraDammV2Swap2Event => pool,
+    LifinitySwapEvent => pool,
+    SanctumSwapEvent => pool_state,
+);
+
+/// Jupiter 路由可能跨多个池子，`pool` 返回 `Pubkey::default()`
+impl NormalizedTrade for JupiterRouteEvent {
+    fn base_mint(&self) -> Pubkey {
+        self.sides().base_mint
+    }
+    fn quote_mint(&self) -> Pubkey {
+        self.sides().quote_mint
+    }
+    fn base_amount(&self) -> u64 {
+        self.sides().base_amount
+    }
+    fn quote_amount(&self) -> u64 {
+        self.sides().quote_amount
+    }
+    fn is_buy(&self) -> bool {
+        self.sides().is_buy
+    }
+    fn pool(&self) -> Pubkey {
+        Pubkey::default()
+    }
+}
@@ -1,20 +1,40 @@
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::streaming::event_parser::core::account_event_parser::{
-    NonceAccountEvent, TokenAccountEvent, TokenInfoEvent,
+    AccountOwnerChangedEvent, LamportsChangedEvent, NonceAccountEvent, RawAccountEvent,
+    TokenAccountEvent, TokenInfoEvent,
 };
+use crate::streaming::event_parser::core::pool_created::PoolCreated;
+use crate::streaming::event_parser::core::pool_state::NormalizedPoolState;
+use crate::streaming::event_parser::core::protocol_parser::CustomEvent;
+use crate::streaming::event_parser::core::trade::NormalizedTrade;
 use crate::streaming::event_parser::core::common_event_parser::{
-    SetComputeUnitLimitEvent, SetComputeUnitPriceEvent,
+    CommitmentUpgradeEvent, ReconnectEvent, SetComputeUnitLimitEvent, SetComputeUnitPriceEvent,
+    SlotLagEvent, SlotRollbackEvent,
 };
 use crate::streaming::event_parser::protocols::block::block_meta_event::BlockMetaEvent;
+use crate::streaming::event_parser::protocols::block::entry_event::EntryEvent;
 use crate::streaming::event_parser::protocols::bonk::events::*;
+use crate::streaming::event_parser::protocols::jupiter::events::*;
 use crate::streaming::event_parser::protocols::meteora_damm_v2::events::*;
+use crate::streaming::event_parser::protocols::meteora_dlmm::events::*;
+use crate::streaming::event_parser::protocols::lifinity::events::*;
+use crate::streaming::event_parser::protocols::moonshot::events::*;
+use crate::streaming::event_parser::protocols::openbook_v2::events::*;
+use crate::streaming::event_parser::protocols::orca_whirlpool::events::*;
+use crate::streaming::event_parser::protocols::phoenix::events::*;
 use crate::streaming::event_parser::protocols::pumpfun::events::*;
 use crate::streaming::event_parser::protocols::pumpswap::events::*;
 use crate::streaming::event_parser::protocols::raydium_amm_v4::events::*;
 use crate::streaming::event_parser::protocols::raydium_clmm::events::*;
 use crate::streaming::event_parser::protocols::raydium_cpmm::events::*;
+use crate::streaming::event_parser::protocols::sanctum::events::*;
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use crate::streaming::common::format_ui_amount;
+use crate::streaming::event_parser::common::filter::{NATIVE_SOL_MINT, USDC_MINT, WSOL_MINT};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug};
 
 /// Unified Event Enum - Replaces the trait-based approach with a type-safe enum
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -81,6 +101,30 @@ pub enum DexEvent {
     MeteoraDammV2InitializeCustomizablePoolEvent(MeteoraDammV2InitializeCustomizablePoolEvent),
     MeteoraDammV2InitializePoolWithDynamicConfigEvent(MeteoraDammV2InitializePoolWithDynamicConfigEvent),
 
+    // Orca Whirlpool events
+    OrcaWhirlpoolSwapEvent(OrcaWhirlpoolSwapEvent),
+    OrcaWhirlpoolIncreaseLiquidityEvent(OrcaWhirlpoolIncreaseLiquidityEvent),
+    OrcaWhirlpoolDecreaseLiquidityEvent(OrcaWhirlpoolDecreaseLiquidityEvent),
+    OrcaWhirlpoolInitializePoolEvent(OrcaWhirlpoolInitializePoolEvent),
+
+    // Meteora DLMM events
+    MeteoraDlmmSwapEvent(MeteoraDlmmSwapEvent),
+    MeteoraDlmmAddLiquidityEvent(MeteoraDlmmAddLiquidityEvent),
+    MeteoraDlmmRemoveLiquidityEvent(MeteoraDlmmRemoveLiquidityEvent),
+    MeteoraDlmmGoToABinEvent(MeteoraDlmmGoToABinEvent),
+
+    // Phoenix events
+    PhoenixFillEvent(PhoenixFillEvent),
+    PhoenixPlaceEvent(PhoenixPlaceEvent),
+    PhoenixReduceEvent(PhoenixReduceEvent),
+
+    // Jupiter events
+    JupiterRoute(JupiterRouteEvent),
+
+    // Moonshot events
+    MoonshotTradeEvent(MoonshotTradeEvent),
+    MoonshotMigrationEvent(MoonshotMigrationEvent),
+
     // Common events
     TokenAccountEvent(TokenAccountEvent),
     NonceAccountEvent(NonceAccountEvent),
@@ -88,6 +132,30 @@ pub enum DexEvent {
     BlockMetaEvent(BlockMetaEvent),
     SetComputeUnitLimitEvent(SetComputeUnitLimitEvent),
     SetComputeUnitPriceEvent(SetComputeUnitPriceEvent),
+    ReconnectEvent(ReconnectEvent),
+    CommitmentUpgradeEvent(CommitmentUpgradeEvent),
+    SlotLagEvent(SlotLagEvent),
+
+    // 二进制编码按变体序号区分，新增变体只追加在末尾
+    MeteoraDammV2PoolAccountEvent(MeteoraDammV2PoolAccountEvent),
+    LifinitySwapEvent(LifinitySwapEvent),
+    OpenBookV2FillEvent(OpenBookV2FillEvent),
+    RaydiumClmmOpenPositionEvent(RaydiumClmmOpenPositionEvent),
+    RaydiumClmmIncreaseLiquidityEvent(RaydiumClmmIncreaseLiquidityEvent),
+    RaydiumClmmDecreaseLiquidityEvent(RaydiumClmmDecreaseLiquidityEvent),
+    RaydiumClmmIncreaseLiquidityLogEvent(RaydiumClmmIncreaseLiquidityLogEvent),
+    RaydiumClmmDecreaseLiquidityLogEvent(RaydiumClmmDecreaseLiquidityLogEvent),
+    RaydiumClmmCollectPersonalFeeEvent(RaydiumClmmCollectPersonalFeeEvent),
+    RaydiumClmmLiquidityChangeEvent(RaydiumClmmLiquidityChangeEvent),
+    AccountOwnerChangedEvent(AccountOwnerChangedEvent),
+    SlotRollbackEvent(SlotRollbackEvent),
+    RawAccountEvent(RawAccountEvent),
+    SanctumSwapEvent(SanctumSwapEvent),
+    RaydiumClmmSwapLogEvent(RaydiumClmmSwapLogEvent),
+    OrcaWhirlpoolTradedEvent(OrcaWhirlpoolTradedEvent),
+    EntryEvent(EntryEvent),
+    LamportsChangedEvent(LamportsChangedEvent),
+    CustomEvent(CustomEvent),
 }
 
 impl DexEvent {
@@ -127,6 +195,16 @@ impl DexEvent {
             DexEvent::RaydiumClmmCreatePoolEvent(e) => &e.metadata,
             DexEvent::RaydiumClmmOpenPositionWithToken22NftEvent(e) => &e.metadata,
             DexEvent::RaydiumClmmOpenPositionV2Event(e) => &e.metadata,
+            DexEvent::RaydiumClmmOpenPositionEvent(e) => &e.metadata,
+            DexEvent::RaydiumClmmIncreaseLiquidityEvent(e) => &e.metadata,
+            DexEvent::RaydiumClmmDecreaseLiquidityEvent(e) => &e.metadata,
+            DexEvent::RaydiumClmmIncreaseLiquidityLogEvent(e) => &e.metadata,
+            DexEvent::RaydiumClmmDecreaseLiquidityLogEvent(e) => &e.metadata,
+            DexEvent::RaydiumClmmCollectPersonalFeeEvent(e) => &e.metadata,
+            DexEvent::RaydiumClmmLiquidityChangeEvent(e) => &e.metadata,
+            DexEvent::AccountOwnerChangedEvent(e) => &e.metadata,
+            DexEvent::SlotRollbackEvent(e) => &e.metadata,
+            DexEvent::RawAccountEvent(e) => &e.metadata,
             DexEvent::RaydiumClmmAmmConfigAccountEvent(e) => &e.metadata,
             DexEvent::RaydiumClmmPoolStateAccountEvent(e) => &e.metadata,
             DexEvent::RaydiumClmmTickArrayStateAccountEvent(e) => &e.metadata,
@@ -141,12 +219,38 @@ impl DexEvent {
             DexEvent::MeteoraDammV2InitializePoolEvent(e) => &e.metadata,
             DexEvent::MeteoraDammV2InitializeCustomizablePoolEvent(e) => &e.metadata,
             DexEvent::MeteoraDammV2InitializePoolWithDynamicConfigEvent(e) => &e.metadata,
+            DexEvent::OrcaWhirlpoolSwapEvent(e) => &e.metadata,
+            DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(e) => &e.metadata,
+            DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(e) => &e.metadata,
+            DexEvent::OrcaWhirlpoolInitializePoolEvent(e) => &e.metadata,
+            DexEvent::MeteoraDlmmSwapEvent(e) => &e.metadata,
+            DexEvent::MeteoraDlmmAddLiquidityEvent(e) => &e.metadata,
+            DexEvent::MeteoraDlmmRemoveLiquidityEvent(e) => &e.metadata,
+            DexEvent::MeteoraDlmmGoToABinEvent(e) => &e.metadata,
+            DexEvent::PhoenixFillEvent(e) => &e.metadata,
+            DexEvent::PhoenixPlaceEvent(e) => &e.metadata,
+            DexEvent::PhoenixReduceEvent(e) => &e.metadata,
+            DexEvent::JupiterRoute(e) => &e.metadata,
+            DexEvent::MoonshotTradeEvent(e) => &e.metadata,
+            DexEvent::MoonshotMigrationEvent(e) => &e.metadata,
+            DexEvent::LifinitySwapEvent(e) => &e.metadata,
+            DexEvent::OpenBookV2FillEvent(e) => &e.metadata,
+            DexEvent::SanctumSwapEvent(e) => &e.metadata,
+            DexEvent::RaydiumClmmSwapLogEvent(e) => &e.metadata,
+            DexEvent::OrcaWhirlpoolTradedEvent(e) => &e.metadata,
+            DexEvent::EntryEvent(e) => &e.metadata,
+            DexEvent::LamportsChangedEvent(e) => &e.metadata,
+            DexEvent::CustomEvent(e) => &e.metadata,
             DexEvent::TokenAccountEvent(e) => &e.metadata,
             DexEvent::NonceAccountEvent(e) => &e.metadata,
             DexEvent::TokenInfoEvent(e) => &e.metadata,
             DexEvent::BlockMetaEvent(e) => &e.metadata,
             DexEvent::SetComputeUnitLimitEvent(e) => &e.metadata,
             DexEvent::SetComputeUnitPriceEvent(e) => &e.metadata,
+            DexEvent::ReconnectEvent(e) => &e.metadata,
+            DexEvent::CommitmentUpgradeEvent(e) => &e.metadata,
+            DexEvent::SlotLagEvent(e) => &e.metadata,
+            DexEvent::MeteoraDammV2PoolAccountEvent(e) => &e.metadata,
         }
     }
 
@@ -186,6 +290,16 @@ impl DexEvent {
             DexEvent::RaydiumClmmCreatePoolEvent(e) => &mut e.metadata,
             DexEvent::RaydiumClmmOpenPositionWithToken22NftEvent(e) => &mut e.metadata,
             DexEvent::RaydiumClmmOpenPositionV2Event(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmOpenPositionEvent(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmIncreaseLiquidityEvent(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmDecreaseLiquidityEvent(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmIncreaseLiquidityLogEvent(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmDecreaseLiquidityLogEvent(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmCollectPersonalFeeEvent(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmLiquidityChangeEvent(e) => &mut e.metadata,
+            DexEvent::AccountOwnerChangedEvent(e) => &mut e.metadata,
+            DexEvent::SlotRollbackEvent(e) => &mut e.metadata,
+            DexEvent::RawAccountEvent(e) => &mut e.metadata,
             DexEvent::RaydiumClmmAmmConfigAccountEvent(e) => &mut e.metadata,
             DexEvent::RaydiumClmmPoolStateAccountEvent(e) => &mut e.metadata,
             DexEvent::RaydiumClmmTickArrayStateAccountEvent(e) => &mut e.metadata,
@@ -200,12 +314,370 @@ impl DexEvent {
             DexEvent::MeteoraDammV2InitializePoolEvent(e) => &mut e.metadata,
             DexEvent::MeteoraDammV2InitializeCustomizablePoolEvent(e) => &mut e.metadata,
             DexEvent::MeteoraDammV2InitializePoolWithDynamicConfigEvent(e) => &mut e.metadata,
+            DexEvent::OrcaWhirlpoolSwapEvent(e) => &mut e.metadata,
+            DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(e) => &mut e.metadata,
+            DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(e) => &mut e.metadata,
+            DexEvent::OrcaWhirlpoolInitializePoolEvent(e) => &mut e.metadata,
+            DexEvent::MeteoraDlmmSwapEvent(e) => &mut e.metadata,
+            DexEvent::MeteoraDlmmAddLiquidityEvent(e) => &mut e.metadata,
+            DexEvent::MeteoraDlmmRemoveLiquidityEvent(e) => &mut e.metadata,
+            DexEvent::MeteoraDlmmGoToABinEvent(e) => &mut e.metadata,
+            DexEvent::PhoenixFillEvent(e) => &mut e.metadata,
+            DexEvent::PhoenixPlaceEvent(e) => &mut e.metadata,
+            DexEvent::PhoenixReduceEvent(e) => &mut e.metadata,
+            DexEvent::JupiterRoute(e) => &mut e.metadata,
+            DexEvent::MoonshotTradeEvent(e) => &mut e.metadata,
+            DexEvent::MoonshotMigrationEvent(e) => &mut e.metadata,
+            DexEvent::LifinitySwapEvent(e) => &mut e.metadata,
+            DexEvent::OpenBookV2FillEvent(e) => &mut e.metadata,
+            DexEvent::SanctumSwapEvent(e) => &mut e.metadata,
+            DexEvent::RaydiumClmmSwapLogEvent(e) => &mut e.metadata,
+            DexEvent::OrcaWhirlpoolTradedEvent(e) => &mut e.metadata,
+            DexEvent::EntryEvent(e) => &mut e.metadata,
+            DexEvent::LamportsChangedEvent(e) => &mut e.metadata,
+            DexEvent::CustomEvent(e) => &mut e.metadata,
             DexEvent::TokenAccountEvent(e) => &mut e.metadata,
             DexEvent::NonceAccountEvent(e) => &mut e.metadata,
             DexEvent::TokenInfoEvent(e) => &mut e.metadata,
             DexEvent::BlockMetaEvent(e) => &mut e.metadata,
             DexEvent::SetComputeUnitLimitEvent(e) => &mut e.metadata,
             DexEvent::SetComputeUnitPriceEvent(e) => &mut e.metadata,
+            DexEvent::ReconnectEvent(e) => &mut e.metadata,
+            DexEvent::CommitmentUpgradeEvent(e) => &mut e.metadata,
+            DexEvent::SlotLagEvent(e) => &mut e.metadata,
+            DexEvent::MeteoraDammV2PoolAccountEvent(e) => &mut e.metadata,
+        }
+    }
+
+    /// 是否为交易（swap/成交）类事件
+    pub fn is_trade(&self) -> bool {
+        self.as_trade().is_some()
+    }
+
+    /// 交易类事件的统一视图，非交易事件返回 None
+    pub fn as_trade(&self) -> Option<&dyn NormalizedTrade> {
+        match self {
+            DexEvent::BonkTradeEvent(e) => Some(e),
+            DexEvent::PumpFunTradeEvent(e) => Some(e),
+            DexEvent::PumpSwapBuyEvent(e) => Some(e),
+            DexEvent::PumpSwapSellEvent(e) => Some(e),
+            DexEvent::RaydiumAmmV4SwapEvent(e) => Some(e),
+            DexEvent::RaydiumClmmSwapEvent(e) => Some(e),
+            DexEvent::RaydiumClmmSwapV2Event(e) => Some(e),
+            DexEvent::RaydiumCpmmSwapEvent(e) => Some(e),
+            DexEvent::MeteoraDammV2SwapEvent(e) => Some(e),
+            DexEvent::MeteoraDammV2Swap2Event(e) => Some(e),
+            DexEvent::OrcaWhirlpoolSwapEvent(e) => Some(e),
+            DexEvent::MeteoraDlmmSwapEvent(e) => Some(e),
+            DexEvent::PhoenixFillEvent(e) => Some(e),
+            DexEvent::JupiterRoute(e) => Some(e),
+            DexEvent::MoonshotTradeEvent(e) => Some(e),
+            DexEvent::LifinitySwapEvent(e) => Some(e),
+            DexEvent::OpenBookV2FillEvent(e) => Some(e),
+            DexEvent::SanctumSwapEvent(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// 池子账户事件的统一视图（mint、vault、储备、手续费率），其他事件返回 None
+    pub fn as_pool_state(&self) -> Option<&dyn NormalizedPoolState> {
+        match self {
+            DexEvent::RaydiumAmmV4AmmInfoAccountEvent(e) => Some(e),
+            DexEvent::RaydiumCpmmPoolStateAccountEvent(e) => Some(e),
+            DexEvent::RaydiumClmmPoolStateAccountEvent(e) => Some(e),
+            DexEvent::MeteoraDammV2PoolAccountEvent(e) => Some(e),
+            DexEvent::PumpSwapPoolAccountEvent(e) => Some(e),
+            DexEvent::BonkPoolStateAccountEvent(e) => Some(e),
+            DexEvent::PumpFunBondingCurveAccountEvent(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// 新建池子事件的统一视图（池子地址、base/quote mint、初始储备、LP mint），其他事件返回 None
+    pub fn as_pool_created(&self) -> Option<PoolCreated> {
+        match self {
+            DexEvent::RaydiumAmmV4Initialize2Event(e) => Some(e.into()),
+            DexEvent::RaydiumCpmmInitializeEvent(e) => Some(e.into()),
+            _ => None,
         }
     }
+
+    /// 事件涉及的代币 mint（交易对的 base/quote，不含 LP/仓位 NFT）
+    ///
+    /// 优先取事件字段，另外合并 `swap_data` 中的 mint，结果已去重且不含默认值
+    pub fn token_mints(&self) -> Vec<Pubkey> {
+        let mut mints: Vec<Pubkey> = match self {
+            DexEvent::BonkTradeEvent(e) => vec![e.base_token_mint, e.quote_token_mint],
+            DexEvent::BonkPoolCreateEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::BonkMigrateToAmmEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::BonkMigrateToCpswapEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::BonkPoolStateAccountEvent(e) => {
+                vec![e.pool_state.base_mint, e.pool_state.quote_mint]
+            }
+            DexEvent::PumpFunCreateTokenEvent(e) => vec![e.mint],
+            DexEvent::PumpFunCreateV2TokenEvent(e) => vec![e.mint],
+            DexEvent::PumpFunTradeEvent(e) => vec![e.mint],
+            DexEvent::PumpFunMigrateEvent(e) => vec![e.mint, e.wsol_mint],
+            DexEvent::PumpSwapBuyEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::PumpSwapSellEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::PumpSwapCreatePoolEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::PumpSwapDepositEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::PumpSwapWithdrawEvent(e) => vec![e.base_mint, e.quote_mint],
+            DexEvent::PumpSwapPoolAccountEvent(e) => vec![e.pool.base_mint, e.pool.quote_mint],
+            DexEvent::RaydiumAmmV4Initialize2Event(e) => vec![e.coin_mint, e.pc_mint],
+            DexEvent::RaydiumAmmV4AmmInfoAccountEvent(e) => {
+                vec![e.amm_info.coin_mint, e.amm_info.pc_mint]
+            }
+            DexEvent::RaydiumClmmSwapV2Event(e) => vec![e.input_vault_mint, e.output_vault_mint],
+            DexEvent::RaydiumClmmIncreaseLiquidityV2Event(e) => vec![e.vault0_mint, e.vault1_mint],
+            DexEvent::RaydiumClmmDecreaseLiquidityV2Event(e) => vec![e.vault0_mint, e.vault1_mint],
+            DexEvent::RaydiumClmmCreatePoolEvent(e) => vec![e.token_mint0, e.token_mint1],
+            DexEvent::RaydiumClmmOpenPositionWithToken22NftEvent(e) => {
+                vec![e.vault0_mint, e.vault1_mint]
+            }
+            DexEvent::RaydiumClmmOpenPositionV2Event(e) => vec![e.vault0_mint, e.vault1_mint],
+            DexEvent::RaydiumClmmPoolStateAccountEvent(e) => {
+                vec![e.pool_state.token_mint0, e.pool_state.token_mint1]
+            }
+            DexEvent::RaydiumCpmmSwapEvent(e) => vec![e.input_token_mint, e.output_token_mint],
+            DexEvent::RaydiumCpmmDepositEvent(e) => vec![e.vault0_mint, e.vault1_mint],
+            DexEvent::RaydiumCpmmWithdrawEvent(e) => vec![e.vault0_mint, e.vault1_mint],
+            DexEvent::RaydiumCpmmInitializeEvent(e) => vec![e.token0_mint, e.token1_mint],
+            DexEvent::RaydiumCpmmPoolStateAccountEvent(e) => {
+                vec![e.pool_state.token0_mint, e.pool_state.token1_mint]
+            }
+            DexEvent::MeteoraDammV2SwapEvent(e) => vec![e.token_a_mint, e.token_b_mint],
+            DexEvent::MeteoraDammV2Swap2Event(e) => vec![e.token_a_mint, e.token_b_mint],
+            DexEvent::MeteoraDammV2InitializePoolEvent(e) => vec![e.token_a_mint, e.token_b_mint],
+            DexEvent::MeteoraDammV2InitializeCustomizablePoolEvent(e) => {
+                vec![e.token_a_mint, e.token_b_mint]
+            }
+            DexEvent::MeteoraDammV2InitializePoolWithDynamicConfigEvent(e) => {
+                vec![e.token_a_mint, e.token_b_mint]
+            }
+            DexEvent::MeteoraDammV2PoolAccountEvent(e) => {
+                vec![e.pool.token_a_mint, e.pool.token_b_mint]
+            }
+            DexEvent::OrcaWhirlpoolSwapEvent(e) => vec![e.token_mint_a, e.token_mint_b],
+            DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(e) => vec![e.token_mint_a, e.token_mint_b],
+            DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(e) => vec![e.token_mint_a, e.token_mint_b],
+            DexEvent::OrcaWhirlpoolInitializePoolEvent(e) => vec![e.token_mint_a, e.token_mint_b],
+            DexEvent::MeteoraDlmmSwapEvent(e) => vec![e.token_x_mint, e.token_y_mint],
+            DexEvent::MeteoraDlmmAddLiquidityEvent(e) => vec![e.token_x_mint, e.token_y_mint],
+            DexEvent::MeteoraDlmmRemoveLiquidityEvent(e) => vec![e.token_x_mint, e.token_y_mint],
+            DexEvent::JupiterRoute(e) => std::iter::once(e.source_mint)
+                .chain(std::iter::once(e.destination_mint))
+                .chain(e.hops.iter().flat_map(|hop| [hop.input_mint, hop.output_mint]))
+                .collect(),
+            DexEvent::MoonshotTradeEvent(e) => vec![e.mint],
+            DexEvent::MoonshotMigrationEvent(e) => vec![e.mint],
+            DexEvent::LifinitySwapEvent(e) => vec![e.in_mint, e.out_mint],
+            DexEvent::SanctumSwapEvent(e) => vec![e.src_lst_mint, e.dst_lst_mint],
+            DexEvent::TokenInfoEvent(e) => vec![e.pubkey],
+            _ => Vec::new(),
+        };
+        if let Some(swap_data) = &self.metadata().swap_data {
+            mints.push(swap_data.from_mint);
+            mints.push(swap_data.to_mint);
+        }
+        let mut unique = Vec::with_capacity(mints.len());
+        for mint in mints {
+            if mint != Pubkey::default() && !unique.contains(&mint) {
+                unique.push(mint);
+            }
+        }
+        unique
+    }
+
+    /// 把事件扁平化为键值对，用于通用日志或 OpenTelemetry 属性
+    ///
+    /// 包含元数据 (签名、slot、协议、事件类型等) 和逗号分隔的 `token_mints`；交易事件另有
+    /// `NormalizedTrade` 的 `pool`、`base_mint`、`quote_mint`、`is_buy`、`base_amount`、
+    /// `quote_amount`，以及 `metadata.token_info` 中的 `base_symbol`、`base_ui_amount`、
+    /// `quote_symbol`、`quote_ui_amount`。地址和签名为 base58，数量 (lamports 和代币最小单位)
+    /// 为十进制整数，值为 None 的字段不输出
+    pub fn to_attributes(&self) -> BTreeMap<String, String> {
+        let metadata = self.metadata();
+        let mut attributes = BTreeMap::new();
+        let mut insert = |key: &str, value: String| {
+            attributes.insert(key.to_string(), value);
+        };
+        insert("signature", metadata.signature.to_string());
+        insert("slot", metadata.slot.to_string());
+        insert("protocol", format!("{:?}", metadata.protocol));
+        insert("event_type", metadata.event_type.to_string());
+        insert("program_id", metadata.program_id.to_string());
+        insert("succeeded", metadata.succeeded.to_string());
+        insert("outer_index", metadata.outer_index.to_string());
+        insert("recv_us", metadata.recv_us.to_string());
+        insert("received_us", metadata.received_us.to_string());
+        let optional = [
+            ("transaction_index", metadata.transaction_index.map(|index| index.to_string())),
+            ("inner_index", metadata.inner_index.map(|index| index.to_string())),
+            ("block_time", metadata.block_time.map(|time| time.to_string())),
+            ("commitment", metadata.commitment.map(|level| format!("{level:?}"))),
+            ("priority_fee_lamports", metadata.priority_fee_lamports.map(|fee| fee.to_string())),
+            ("compute_units_consumed", metadata.compute_units_consumed.map(|cu| cu.to_string())),
+            ("jito_tip_lamports", metadata.jito_tip_lamports.map(|tip| tip.to_string())),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                insert(key, value);
+            }
+        }
+        let mints = self.token_mints();
+        if !mints.is_empty() {
+            let mints: Vec<String> = mints.iter().map(Pubkey::to_string).collect();
+            insert("token_mints", mints.join(","));
+        }
+        if let Some(trade) = self.as_trade() {
+            insert("pool", trade.pool().to_string());
+            insert("base_mint", trade.base_mint().to_string());
+            insert("quote_mint", trade.quote_mint().to_string());
+            insert("is_buy", trade.is_buy().to_string());
+            insert("base_amount", trade.base_amount().to_string());
+            insert("quote_amount", trade.quote_amount().to_string());
+        }
+        if let Some(info) = &metadata.token_info {
+            let token_fields = [
+                ("base_symbol", &info.base_symbol),
+                ("base_ui_amount", &info.base_ui_amount),
+                ("quote_symbol", &info.quote_symbol),
+                ("quote_ui_amount", &info.quote_ui_amount),
+            ];
+            for (key, value) in token_fields {
+                if let Some(value) = value {
+                    insert(key, value.clone());
+                }
+            }
+        }
+        attributes
+    }
+}
+
+/// 单行摘要，便于在日志中阅读；需要完整字段时使用 `Debug`
+///
+/// 交易事件形如 `PumpFunBuy buy mint=<base mint> tokens=4567 sol=1.23 @slot=123 sig=<签名>`：
+/// SOL/WSOL 和 USDC 按各自精度换算，其他数量在有 `metadata.token_info` 时为界面数量，否则为
+/// 最小单位。其他事件输出涉及的 mint；失败的交易末尾附加 `failed`
+impl fmt::Display for DexEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let metadata = self.metadata();
+        write!(f, "{}", metadata.event_type)?;
+        if let Some(trade) = self.as_trade() {
+            let info = metadata.token_info.as_ref();
+            let side = if trade.is_buy() { "buy" } else { "sell" };
+            write!(f, " {side} mint={}", trade.base_mint())?;
+            let base_ui = info.and_then(|info| info.base_ui_amount.as_deref());
+            write_amount(f, "tokens", &trade.base_mint(), trade.base_amount(), base_ui)?;
+            let quote_ui = info.and_then(|info| info.quote_ui_amount.as_deref());
+            write_amount(f, "quote", &trade.quote_mint(), trade.quote_amount(), quote_ui)?;
+        } else {
+            let mints = self.token_mints();
+            if !mints.is_empty() {
+                let mints: Vec<String> = mints.iter().map(Pubkey::to_string).collect();
+                write!(f, " mints={}", mints.join(","))?;
+            }
+        }
+        write!(f, " @slot={}", metadata.slot)?;
+        if metadata.signature != Signature::default() {
+            write!(f, " sig={}", metadata.signature)?;
+        }
+        if !metadata.succeeded {
+            write!(f, " failed")?;
+        }
+        Ok(())
+    }
+}
+
+/// 输出一侧的数量，SOL 和 USDC 以币种作为标签
+fn write_amount(
+    f: &mut fmt::Formatter<'_>,
+    label: &str,
+    mint: &Pubkey,
+    amount: u64,
+    ui_amount: Option<&str>,
+) -> fmt::Result {
+    if *mint == NATIVE_SOL_MINT || *mint == WSOL_MINT {
+        write!(f, " sol={}", format_ui_amount(amount, 9))
+    } else if *mint == USDC_MINT {
+        write!(f, " usdc={}", format_ui_amount(amount, 6))
+    } else if let Some(ui_amount) = ui_amount {
+        write!(f, " {label}={ui_amount}")
+    } else {
+        write!(f, " {label}={amount}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::types::{EventType, ProtocolType, TradeTokenInfo};
+
+    const MINT: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    fn pumpfun_buy() -> DexEvent {
+        let mut trade = PumpFunTradeEvent::default();
+        trade.metadata.slot = 123;
+        trade.metadata.succeeded = true;
+        trade.metadata.event_type = EventType::PumpFunBuy;
+        trade.metadata.protocol = ProtocolType::PumpFun;
+        trade.mint = MINT;
+        trade.bonding_curve = Pubkey::new_from_array([8; 32]);
+        trade.is_buy = true;
+        trade.token_amount = 4_567_000_000;
+        trade.sol_amount = 1_230_000_000;
+        DexEvent::PumpFunTradeEvent(trade)
+    }
+
+    #[test]
+    fn attributes_flatten_metadata_and_trade_fields() {
+        let mut event = pumpfun_buy();
+        event.metadata_mut().priority_fee_lamports = Some(5_000);
+        event.metadata_mut().token_info = Some(TradeTokenInfo {
+            base_symbol: Some("MEME".to_string()),
+            base_ui_amount: Some("4567".to_string()),
+            ..Default::default()
+        });
+        let attributes = event.to_attributes();
+        let get = |key: &str| attributes.get(key).map(String::as_str);
+
+        assert_eq!(get("slot"), Some("123"));
+        assert_eq!(get("protocol"), Some("PumpFun"));
+        assert_eq!(get("event_type"), Some("PumpFunBuy"));
+        assert_eq!(get("succeeded"), Some("true"));
+        assert_eq!(get("priority_fee_lamports"), Some("5000"));
+        assert_eq!(get("base_mint"), Some(MINT.to_string().as_str()));
+        assert_eq!(get("quote_mint"), Some(WSOL_MINT.to_string().as_str()));
+        assert_eq!(get("pool"), Some(Pubkey::new_from_array([8; 32]).to_string().as_str()));
+        assert_eq!((get("is_buy"), get("base_amount")), (Some("true"), Some("4567000000")));
+        assert_eq!(get("quote_amount"), Some("1230000000"));
+        assert_eq!((get("base_symbol"), get("base_ui_amount")), (Some("MEME"), Some("4567")));
+        // 值为 None 的字段不输出
+        for key in ["inner_index", "block_time", "jito_tip_lamports", "quote_symbol"] {
+            assert_eq!(get(key), None, "{key}");
+        }
+    }
+
+    #[test]
+    fn display_summarises_trades_on_one_line() {
+        let mut event = pumpfun_buy();
+        assert_eq!(
+            event.to_string(),
+            format!("PumpFunBuy buy mint={MINT} tokens=4567000000 sol=1.23 @slot=123")
+        );
+
+        // 有 token_info 时使用界面数量，失败的交易附加 failed
+        event.metadata_mut().token_info =
+            Some(TradeTokenInfo { base_ui_amount: Some("4567".to_string()), ..Default::default() });
+        event.metadata_mut().signature = Signature::from([1; 64]);
+        event.metadata_mut().succeeded = false;
+        assert_eq!(
+            event.to_string(),
+            format!(
+                "PumpFunBuy buy mint={MINT} tokens=4567 sol=1.23 @slot=123 sig={} failed",
+                Signature::from([1; 64])
+            )
+        );
+    }
 }
@@ -2,5 +2,14 @@ pub mod common;
 pub mod core;
 pub mod protocols;
 
+pub use core::codec::{decode_event, encode_event, EventCodecError};
+pub use core::parse_options::ParseOptions;
+pub use core::pool_created::PoolCreated;
+pub use core::pool_state::NormalizedPoolState;
+pub use core::protocol_parser::{
+    clear_protocol_parsers, register_protocol_parser, unregister_protocol_parser, CustomEvent,
+    ParseContext, ProtocolParser,
+};
+pub use core::trade::NormalizedTrade;
 pub use core::traits::DexEvent;
 pub use protocols::types::Protocol;
\ No newline at end of file
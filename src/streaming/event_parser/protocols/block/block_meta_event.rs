@@ -10,19 +10,31 @@ pub struct BlockMetaEvent {
     pub metadata: EventMetadata,
     pub slot: u64,
     pub block_hash: String,
+    /// 父区块的 slot，与 `slot` 不连续时说明中间的 slot 被跳过
+    pub parent_slot: u64,
+    pub parent_block_hash: String,
+    pub block_height: Option<u64>,
+    /// 区块内已执行的交易数量
+    pub executed_transaction_count: u64,
 }
 
 impl BlockMetaEvent {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         slot: u64,
         block_hash: String,
+        parent_slot: u64,
+        parent_block_hash: String,
+        block_height: Option<u64>,
+        executed_transaction_count: u64,
+        block_time: Option<i64>,
         block_time_ms: i64,
         recv_us: i64,
     ) -> Self {
         let metadata = EventMetadata::new(
             Signature::default(),
             slot,
-            block_time_ms / 1000,
+            block_time,
             block_time_ms,
             crate::streaming::event_parser::common::types::ProtocolType::Common,
             EventType::BlockMeta,
@@ -32,6 +44,14 @@ impl BlockMetaEvent {
             recv_us,
             None,
         );
-        Self { metadata, slot, block_hash }
+        Self {
+            metadata,
+            slot,
+            block_hash,
+            parent_slot,
+            parent_block_hash,
+            block_height,
+            executed_transaction_count,
+        }
     }
 }
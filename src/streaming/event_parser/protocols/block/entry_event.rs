@@ -0,0 +1,59 @@
+use crate::streaming::event_parser::common::{types::EventType, EventMetadata};
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::signature::Signature;
+
+/// Entry 事件，按 `index` 排序即为 leader 在 slot 内打包交易的顺序
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EntryEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub slot: u64,
+    /// entry 在 slot 内的序号
+    pub index: u64,
+    /// 与上一个 entry 之间的 PoH 哈希次数
+    pub num_hashes: u64,
+    pub hash: String,
+    /// entry 中的交易数量
+    pub num_transactions: u64,
+    /// entry 中第一笔交易在 slot 内的序号
+    pub starting_transaction_index: u64,
+}
+
+impl EntryEvent {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        slot: u64,
+        index: u64,
+        num_hashes: u64,
+        hash: String,
+        num_transactions: u64,
+        starting_transaction_index: u64,
+        block_time: Option<i64>,
+        block_time_ms: i64,
+        recv_us: i64,
+    ) -> Self {
+        let metadata = EventMetadata::new(
+            Signature::default(),
+            slot,
+            block_time,
+            block_time_ms,
+            crate::streaming::event_parser::common::types::ProtocolType::Common,
+            EventType::Entry,
+            solana_sdk::pubkey::Pubkey::default(),
+            0,
+            None,
+            recv_us,
+            None,
+        );
+        Self {
+            metadata,
+            slot,
+            index,
+            num_hashes,
+            hash,
+            num_transactions,
+            starting_transaction_index,
+        }
+    }
+}
@@ -1 +1,2 @@
-pub mod block_meta_event;
\ No newline at end of file
+pub mod block_meta_event;
+pub mod entry_event;
@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::streaming::event_parser::protocols::bonk::types::{
     CurveParams, MintParams, PoolStatus, TradeDirection, VestingParams,
@@ -14,6 +15,7 @@ use solana_sdk::pubkey::Pubkey;
 pub struct BonkTradeEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
     pub total_base_sell: u64,
     pub virtual_base: u64,
@@ -38,36 +40,50 @@ pub struct BonkTradeEvent {
     #[borsh(skip)]
     pub share_fee_rate: u64,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub global_config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub platform_config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub user_base_token: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub user_quote_token: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_token_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_token_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_token_program: Pubkey,
     #[borsh(skip)]
     pub is_dev_create_token_trade: bool,
     #[borsh(skip)]
     pub is_bot: bool,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub platform_associated_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub creator_associated_account: Pubkey,
 }
 
@@ -85,26 +101,36 @@ pub fn bonk_trade_event_log_decode(data: &[u8]) -> Option<BonkTradeEvent> {
 pub struct BonkPoolCreateEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub config: Pubkey,
     pub base_mint_param: MintParams,
     pub curve_param: CurveParams,
     pub vesting_param: VestingParams,
     pub amm_fee_on: Option<AmmFeeOn>,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub global_config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub platform_config: Pubkey,
 }
 
@@ -126,68 +152,100 @@ pub struct BonkMigrateToAmmEvent {
     pub quote_lot_size: u64,
     pub market_vault_signer_nonce: u8,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub openbook_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub market: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub request_queue: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_queue: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub bids: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub asks: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub market_vault_signer: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub market_base_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub market_quote_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_pool: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_open_orders: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_lp_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_base_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_quote_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_target_orders: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_create_fee_destination: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub global_config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_lp_token: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub spl_token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub rent_program: Pubkey,
 }
 
@@ -196,34 +254,63 @@ pub struct BonkMigrateToAmmEvent {
 pub struct BonkMigrateToCpswapEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub platform_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_base_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_quote_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_create_pool_fee: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub cpswap_observation: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lock_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lock_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lock_lp_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub global_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub base_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_lp_token: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub base_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub rent_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub metadata_program: Pubkey,
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
@@ -231,9 +318,11 @@ pub struct BonkMigrateToCpswapEvent {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BonkPoolStateAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub pool_state: PoolState,
@@ -243,9 +332,11 @@ pub struct BonkPoolStateAccountEvent {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BonkGlobalConfigAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub global_config: GlobalConfig,
@@ -255,9 +346,11 @@ pub struct BonkGlobalConfigAccountEvent {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BonkPlatformConfigAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub platform_config: PlatformConfig,
@@ -15,6 +15,19 @@ use crate::streaming::event_parser::{
 pub const BONK_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj");
 
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::BUY_EXACT_IN, EventType::BonkBuyExactIn),
+    (discriminators::BUY_EXACT_OUT, EventType::BonkBuyExactOut),
+    (discriminators::SELL_EXACT_IN, EventType::BonkSellExactIn),
+    (discriminators::SELL_EXACT_OUT, EventType::BonkSellExactOut),
+    (discriminators::INITIALIZE, EventType::BonkInitialize),
+    (discriminators::INITIALIZE_V2, EventType::BonkInitializeV2),
+    (discriminators::INITIALIZE_WITH_TOKEN_2022, EventType::BonkInitializeWithToken2022),
+    (discriminators::MIGRATE_TO_AMM, EventType::BonkMigrateToAmm),
+    (discriminators::MIGRATE_TO_CP_SWAP, EventType::BonkMigrateToCpswap),
+];
+
 /// 解析 Bonk instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
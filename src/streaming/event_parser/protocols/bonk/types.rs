@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -113,12 +114,19 @@ pub struct PoolState {
     pub platform_fee: u64,
     pub migrate_fee: u64,
     pub vesting_schedule: VestingSchedule,
+    #[serde(with = "serde_base58::pubkey")]
     pub global_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub platform_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub base_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
     pub padding: [u64; 8],
 }
@@ -165,10 +173,15 @@ pub struct GlobalConfig {
     pub min_base_sell_rate: u64,
     pub min_base_migrate_rate: u64,
     pub min_quote_fund_raising: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_fee_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub migrate_fee_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub migrate_to_amm_wallet: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub migrate_to_cpswap_wallet: Pubkey,
     pub padding: [u64; 16],
 }
@@ -209,7 +222,9 @@ pub fn global_config_parser(
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PlatformConfig {
     pub epoch: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub platform_fee_wallet: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub platform_nft_wallet: Pubkey,
     pub platform_scale: u64,
     pub creator_scale: u64,
@@ -0,0 +1,101 @@
+use crate::streaming::event_parser::common::serde_base58;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+
+/// Jupiter 路由中的单跳 swap (对应 CPI Log 中的 SwapEvent)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct JupiterSwapHop {
+    /// 本跳使用的 AMM 程序
+    #[serde(with = "serde_base58::pubkey")]
+    pub amm: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub input_mint: Pubkey,
+    pub in_amount: u64,
+    #[serde(with = "serde_base58::pubkey")]
+    pub output_mint: Pubkey,
+    pub out_amount: u64,
+}
+
+/// Jupiter v6 路由事件
+///
+/// 对应 route / shared_accounts_route / exact_out_route 等路由指令，
+/// 每一跳 swap 都会单独 emit 一个 SwapEvent，全部按执行顺序收集到 `hops` 中，不做截断
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JupiterRouteEvent {
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub hops: Vec<JupiterSwapHop>,
+
+    // 来自指令参数 (exact in 路由)
+    pub in_amount: u64,
+    pub quoted_out_amount: u64,
+    // 来自指令参数 (exact out 路由)
+    pub out_amount: u64,
+    pub quoted_in_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+
+    // 来自指令账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_transfer_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub source_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub destination_token_account: Pubkey,
+    /// route / route_with_token_ledger 指令没有 source_mint 账户，取第一跳的 input_mint
+    #[serde(with = "serde_base58::pubkey")]
+    pub source_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub destination_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub platform_fee_account: Pubkey,
+}
+
+impl JupiterRouteEvent {
+    /// 路由的总输入数量 (所有从 source_mint 出发的 hop 之和，兼容拆单路由)
+    ///
+    /// hop 数量来自按 discriminator 匹配的 inner instruction，求和溢出时返回 None
+    pub fn total_in_amount(&self) -> Option<u64> {
+        self.hops
+            .iter()
+            .filter(|hop| hop.input_mint == self.source_mint)
+            .try_fold(0u64, |total, hop| total.checked_add(hop.in_amount))
+    }
+
+    /// 路由的总输出数量 (所有输出为 destination_mint 的 hop 之和)，求和溢出时返回 None
+    pub fn total_out_amount(&self) -> Option<u64> {
+        self.hops
+            .iter()
+            .filter(|hop| hop.output_mint == self.destination_mint)
+            .try_fold(0u64, |total, hop| total.checked_add(hop.out_amount))
+    }
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const ROUTE_IX: &[u8] = &[229, 23, 203, 151, 122, 227, 173, 42];
+    pub const ROUTE_WITH_TOKEN_LEDGER_IX: &[u8] = &[150, 86, 71, 116, 167, 93, 14, 104];
+    pub const EXACT_OUT_ROUTE_IX: &[u8] = &[208, 51, 239, 151, 123, 43, 237, 92];
+    pub const SHARED_ACCOUNTS_ROUTE_IX: &[u8] = &[193, 32, 155, 51, 65, 214, 156, 129];
+    pub const SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER_IX: &[u8] =
+        &[230, 121, 143, 80, 119, 159, 106, 170];
+    pub const SHARED_ACCOUNTS_EXACT_OUT_ROUTE_IX: &[u8] = &[176, 209, 105, 168, 154, 125, 69, 62];
+
+    // Event discriminators (CPI Log Event)
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 64, 198, 205, 232, 38, 8, 113, 226];
+}
+
+/// Decode swap event from CPI log
+pub const JUPITER_SWAP_EVENT_LOG_SIZE: usize = 112;
+pub fn jupiter_swap_event_decode(data: &[u8]) -> Option<JupiterSwapHop> {
+    if data.len() < JUPITER_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<JupiterSwapHop>(&data[..JUPITER_SWAP_EVENT_LOG_SIZE]).ok()
+}
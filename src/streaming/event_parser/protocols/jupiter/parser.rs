@@ -0,0 +1,226 @@
+use crate::streaming::event_parser::{
+    common::{read_u16_le, read_u64_le, read_u8, EventMetadata, EventType},
+    protocols::jupiter::{discriminators, jupiter_swap_event_decode, JupiterRouteEvent},
+    DexEvent,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Jupiter v6 聚合器程序ID
+pub const JUPITER_V6_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// 路由指令参数的类型
+///
+/// route_plan 是变长的 Vec<RoutePlanStep>，其后的定长参数从数据尾部读取
+#[derive(Clone, Copy)]
+enum RouteKind {
+    /// in_amount + quoted_out_amount + slippage_bps + platform_fee_bps
+    ExactIn,
+    /// quoted_out_amount + slippage_bps + platform_fee_bps (in_amount 来自 token ledger)
+    TokenLedger,
+    /// out_amount + quoted_in_amount + slippage_bps + platform_fee_bps
+    ExactOut,
+}
+
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::ROUTE_IX, EventType::JupiterRoute),
+    (discriminators::ROUTE_WITH_TOKEN_LEDGER_IX, EventType::JupiterRouteWithTokenLedger),
+    (discriminators::EXACT_OUT_ROUTE_IX, EventType::JupiterExactOutRoute),
+    (discriminators::SHARED_ACCOUNTS_ROUTE_IX, EventType::JupiterSharedAccountsRoute),
+    (discriminators::SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER_IX, EventType::JupiterSharedAccountsRouteWithTokenLedger),
+    (discriminators::SHARED_ACCOUNTS_EXACT_OUT_ROUTE_IX, EventType::JupiterSharedAccountsExactOutRoute),
+];
+
+/// 解析 Jupiter instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_jupiter_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::ROUTE_IX => parse_route_instruction(
+            data,
+            accounts,
+            metadata,
+            EventType::JupiterRoute,
+            RouteKind::ExactIn,
+        ),
+        discriminators::ROUTE_WITH_TOKEN_LEDGER_IX => parse_route_instruction(
+            data,
+            accounts,
+            metadata,
+            EventType::JupiterRouteWithTokenLedger,
+            RouteKind::TokenLedger,
+        ),
+        discriminators::EXACT_OUT_ROUTE_IX => parse_exact_out_route_instruction(
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::SHARED_ACCOUNTS_ROUTE_IX => parse_shared_accounts_route_instruction(
+            data,
+            accounts,
+            metadata,
+            EventType::JupiterSharedAccountsRoute,
+            RouteKind::ExactIn,
+        ),
+        discriminators::SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER_IX => {
+            parse_shared_accounts_route_instruction(
+                data,
+                accounts,
+                metadata,
+                EventType::JupiterSharedAccountsRouteWithTokenLedger,
+                RouteKind::TokenLedger,
+            )
+        }
+        discriminators::SHARED_ACCOUNTS_EXACT_OUT_ROUTE_IX => {
+            parse_shared_accounts_route_instruction(
+                data,
+                accounts,
+                metadata,
+                EventType::JupiterSharedAccountsExactOutRoute,
+                RouteKind::ExactOut,
+            )
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Jupiter inner instruction data (CPI events)
+///
+/// 每个 SwapEvent 只包含一跳，解析为仅有一个 hop 的 `JupiterRoute`，
+/// 由 `EventParser` 收集全部 hop 后合并到路由指令事件中
+pub fn parse_jupiter_inner_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_EVENT => parse_swap_inner_instruction(data, metadata),
+        _ => None,
+    }
+}
+
+/// 从指令数据尾部读取定长参数
+fn fill_route_args(event: &mut JupiterRouteEvent, data: &[u8], kind: RouteKind) -> Option<()> {
+    let tail_len = match kind {
+        RouteKind::ExactIn | RouteKind::ExactOut => 19,
+        RouteKind::TokenLedger => 11,
+    };
+    // route_plan 至少包含 4 字节的长度前缀
+    if data.len() < 4 + tail_len {
+        return None;
+    }
+    let offset = data.len() - tail_len;
+    match kind {
+        RouteKind::ExactIn => {
+            event.in_amount = read_u64_le(data, offset)?;
+            event.quoted_out_amount = read_u64_le(data, offset + 8)?;
+        }
+        RouteKind::TokenLedger => {
+            event.quoted_out_amount = read_u64_le(data, offset)?;
+        }
+        RouteKind::ExactOut => {
+            event.out_amount = read_u64_le(data, offset)?;
+            event.quoted_in_amount = read_u64_le(data, offset + 8)?;
+        }
+    }
+    event.slippage_bps = read_u16_le(data, data.len() - 3)?;
+    event.platform_fee_bps = read_u8(data, data.len() - 1)?;
+    Some(())
+}
+
+/// 解析 route / route_with_token_ledger 指令
+fn parse_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+    event_type: EventType,
+    kind: RouteKind,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 9 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        user_transfer_authority: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: accounts[3],
+        destination_mint: accounts[5],
+        platform_fee_account: accounts[6],
+        ..Default::default()
+    };
+    fill_route_args(&mut event, data, kind)?;
+    Some(DexEvent::JupiterRoute(event))
+}
+
+/// 解析 exact_out_route 指令
+fn parse_exact_out_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::JupiterExactOutRoute;
+
+    if accounts.len() < 11 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        user_transfer_authority: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: accounts[3],
+        source_mint: accounts[5],
+        destination_mint: accounts[6],
+        platform_fee_account: accounts[7],
+        ..Default::default()
+    };
+    fill_route_args(&mut event, data, RouteKind::ExactOut)?;
+    Some(DexEvent::JupiterRoute(event))
+}
+
+/// 解析 shared_accounts_* 系列路由指令
+///
+/// 三条指令账户布局一致 (token_ledger 版本在 token_2022_program 之后多一个 token_ledger)，
+/// 数据以 1 字节 id 开头
+fn parse_shared_accounts_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+    event_type: EventType,
+    kind: RouteKind,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if data.is_empty() || accounts.len() < 13 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        user_transfer_authority: accounts[2],
+        source_token_account: accounts[3],
+        destination_token_account: accounts[6],
+        source_mint: accounts[7],
+        destination_mint: accounts[8],
+        platform_fee_account: accounts[9],
+        ..Default::default()
+    };
+    fill_route_args(&mut event, &data[1..], kind)?;
+    Some(DexEvent::JupiterRoute(event))
+}
+
+/// 解析 swap inner instruction (CPI event)
+fn parse_swap_inner_instruction(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    // Note: event_type will be set by instruction parser
+    let hop = jupiter_swap_event_decode(data)?;
+    Some(DexEvent::JupiterRoute(JupiterRouteEvent { metadata, hops: vec![hop], ..Default::default() }))
+}
@@ -0,0 +1,2 @@
+// 此文件用于定义 Jupiter 的账户数据结构
+// Jupiter 聚合器本身不持有池子状态，暂时留空
@@ -0,0 +1,66 @@
+use crate::streaming::event_parser::common::serde_base58;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+
+/// Lifinity v2 兑换事件
+///
+/// Lifinity 没有 CPI 事件，`in_mint` / `out_mint` / `in_amount` / `out_amount` / `fee`
+/// 在 gRPC 交易中由池子 vault 和手续费账户的余额变化得到；
+/// 没有交易 meta (如 shred) 时均为默认值，实际数量未知，请求的数量见 `amount_in` / `minimum_amount_out`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifinitySwapEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub in_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub out_mint: Pubkey,
+    /// 池子收到的输入代币数量
+    pub in_amount: u64,
+    /// 池子转出的输出代币数量
+    pub out_amount: u64,
+    /// 手续费账户的余额增加量，单位为 `fee_mint`
+    pub fee: u64,
+    #[serde(with = "serde_base58::pubkey")]
+    pub fee_mint: Pubkey,
+
+    // 来自指令参数
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+
+    // 来自指令账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub authority: Pubkey,
+    /// 池子 (amm) 账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_transfer_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub source_info: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub destination_info: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub swap_source: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub swap_destination: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub fee_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub oracle_main_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub oracle_sub_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub oracle_pc_account: Pubkey,
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const SWAP_IX: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+}
@@ -0,0 +1,172 @@
+use crate::streaming::event_parser::{
+    common::{read_u64_le, EventMetadata, EventType},
+    protocols::lifinity::{discriminators, LifinitySwapEvent},
+    DexEvent,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Lifinity v2 程序ID
+pub const LIFINITY_V2_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("2wT8Yq49kHgDzXuPxZSaeLaH1qbmGXtEyPy64bL7aD3c");
+
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] =
+    &[(discriminators::SWAP_IX, EventType::LifinitySwap)];
+
+/// 解析 Lifinity instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_lifinity_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_IX => parse_swap_instruction(data, accounts, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 Lifinity inner instruction data
+///
+/// Lifinity 没有 Anchor CPI 事件
+pub fn parse_lifinity_inner_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 swap 指令
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::LifinitySwap;
+
+    if data.len() < 16 || accounts.len() < 13 {
+        return None;
+    }
+    let amount_in = read_u64_le(data, 0)?;
+    let minimum_amount_out = read_u64_le(data, 8)?;
+
+    Some(DexEvent::LifinitySwapEvent(LifinitySwapEvent {
+        metadata,
+        amount_in,
+        minimum_amount_out,
+        authority: accounts[0],
+        pool: accounts[1],
+        user_transfer_authority: accounts[2],
+        source_info: accounts[3],
+        destination_info: accounts[4],
+        swap_source: accounts[5],
+        swap_destination: accounts[6],
+        pool_mint: accounts[7],
+        fee_account: accounts[8],
+        token_program: accounts[9],
+        oracle_main_account: accounts[10],
+        oracle_sub_account: accounts[11],
+        oracle_pc_account: accounts[12],
+        ..Default::default()
+    }))
+}
+
+/// 用交易前后的余额变化补全 Lifinity 事件，其他事件不做处理
+///
+/// 需要先填充 `metadata.balance_changes`；
+/// 同一笔交易中对同一个池子多次兑换时，得到的是合计变化量
+pub fn fill_lifinity_event_from_balances(event: &mut DexEvent) {
+    let DexEvent::LifinitySwapEvent(e) = event else {
+        return;
+    };
+    let token_change = |account: &Pubkey| {
+        e.metadata.balance_changes.iter().find(|change| change.account == *account).cloned()
+    };
+    let swap_source = token_change(&e.swap_source);
+    let swap_destination = token_change(&e.swap_destination);
+    let fee_account = token_change(&e.fee_account);
+
+    let (Some(swap_source), Some(swap_destination)) = (swap_source, swap_destination) else {
+        return;
+    };
+    e.in_mint = swap_source.mint;
+    e.in_amount = swap_source.post_amount.saturating_sub(swap_source.pre_amount);
+    e.out_mint = swap_destination.mint;
+    e.out_amount = swap_destination.pre_amount.saturating_sub(swap_destination.post_amount);
+    if let Some(change) = fee_account {
+        e.fee_mint = change.mint;
+        e.fee = change.post_amount.saturating_sub(change.pre_amount);
+    }
+    if let Some(swap_data) = e.metadata.swap_data.as_mut() {
+        swap_data.from_mint = e.in_mint;
+        swap_data.to_mint = e.out_mint;
+        swap_data.from_amount = e.in_amount;
+        swap_data.to_amount = e.out_amount;
+        swap_data.description = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::TokenBalanceChange;
+
+    #[test]
+    fn swap_amounts_wait_for_balance_changes() {
+        let mut data = 1_000u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&900u64.to_le_bytes());
+        let accounts: Vec<Pubkey> = (1..=13).map(|n| Pubkey::new_from_array([n; 32])).collect();
+        let Some(DexEvent::LifinitySwapEvent(swap)) =
+            parse_swap_instruction(&data, &accounts, EventMetadata::default())
+        else {
+            panic!("swap not parsed");
+        };
+        assert_eq!((swap.amount_in, swap.minimum_amount_out), (1_000, 900));
+        assert_eq!((swap.in_amount, swap.out_amount), (0, 0));
+    }
+
+    #[test]
+    fn swap_amounts_and_mints_come_from_vault_changes() {
+        let change =
+            |account: Pubkey, mint: u8, pre_amount: u64, post_amount: u64| TokenBalanceChange {
+                account,
+                mint: Pubkey::new_from_array([mint; 32]),
+                pre_amount,
+                post_amount,
+                ..Default::default()
+            };
+        let mut swap = LifinitySwapEvent {
+            swap_source: Pubkey::new_from_array([6; 32]),
+            swap_destination: Pubkey::new_from_array([7; 32]),
+            fee_account: Pubkey::new_from_array([9; 32]),
+            ..Default::default()
+        };
+        swap.metadata.balance_changes = vec![
+            change(swap.swap_source, 20, 10_000, 13_000),
+            change(swap.swap_destination, 21, 50_000, 47_100),
+            change(swap.fee_account, 20, 0, 9),
+        ];
+        let mut event = DexEvent::LifinitySwapEvent(swap);
+        fill_lifinity_event_from_balances(&mut event);
+
+        let DexEvent::LifinitySwapEvent(swap) = event else { unreachable!() };
+        assert_eq!((swap.in_mint, swap.in_amount), (Pubkey::new_from_array([20; 32]), 3_000));
+        assert_eq!((swap.out_mint, swap.out_amount), (Pubkey::new_from_array([21; 32]), 2_900));
+        assert_eq!(swap.fee, 9);
+
+        // 缺少任一 vault 的变化时保持不变
+        let mut swap = LifinitySwapEvent {
+            swap_source: Pubkey::new_from_array([6; 32]),
+            swap_destination: Pubkey::new_from_array([7; 32]),
+            ..Default::default()
+        };
+        swap.metadata.balance_changes = vec![change(swap.swap_source, 20, 10_000, 13_000)];
+        let mut event = DexEvent::LifinitySwapEvent(swap);
+        fill_lifinity_event_from_balances(&mut event);
+        let DexEvent::LifinitySwapEvent(swap) = event else { unreachable!() };
+        assert_eq!((swap.in_amount, swap.out_amount), (0, 0));
+    }
+}
@@ -1,8 +1,10 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_damm_v2::types::Pool;
 
 /// Base fee parameters
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
@@ -41,6 +43,7 @@ pub struct MeteoraDammV2SwapEvent {
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
     pub trade_direction: u8, // 0 or 1
     pub collect_fee_mode: u8,
@@ -74,30 +77,43 @@ pub struct MeteoraDammV2SwapEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub input_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub output_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::option_pubkey")]
     pub referral_token_account: Option<Pubkey>,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub program: Pubkey,
 }
 
@@ -108,6 +124,7 @@ pub struct MeteoraDammV2Swap2Event {
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
     pub trade_direction: u8, // 0 or 1
     pub collect_fee_mode: u8,
@@ -141,32 +158,46 @@ pub struct MeteoraDammV2Swap2Event {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub input_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub output_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::option_pubkey")]
     pub referral_token_account: Option<Pubkey>,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub sysvar: Pubkey,
 }
 
@@ -177,11 +208,17 @@ pub struct MeteoraDammV2InitializePoolEvent {
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub alpha_vault: Pubkey,
 
     // Pool fees
@@ -207,32 +244,46 @@ pub struct MeteoraDammV2InitializePoolEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer_token_a: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer_token_b: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
@@ -243,11 +294,17 @@ pub struct MeteoraDammV2InitializeCustomizablePoolEvent {
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub alpha_vault: Pubkey,
 
     // Pool fees
@@ -273,34 +330,49 @@ pub struct MeteoraDammV2InitializeCustomizablePoolEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer_token_a: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer_token_b: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_2022_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
@@ -311,11 +383,17 @@ pub struct MeteoraDammV2InitializePoolWithDynamicConfigEvent {
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub alpha_vault: Pubkey,
 
     // Pool fees
@@ -341,39 +419,70 @@ pub struct MeteoraDammV2InitializePoolWithDynamicConfigEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_creator_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub position: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer_token_a: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub payer_token_b: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_2022_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub config: Pubkey,
 }
 
+/// 池状态
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2PoolAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub pool: Pool,
+}
+
 /// Event discriminators
 pub mod discriminators {
     // Instruction discriminators
@@ -397,6 +506,9 @@ pub mod discriminators {
         0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d, 0xe4, 0x32, 0xf6, 0x55, 0xcb, 0x42, 0x86,
         0x25,
     ]; // initialize pool event
+
+    // 账号鉴别器
+    pub const POOL: &[u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
 }
 
 /// Decode swap event from CPI log
@@ -8,12 +8,37 @@ use crate::streaming::event_parser::{
     },
     DexEvent,
 };
+use crate::streaming::event_parser::protocols::meteora_damm_v2::types;
+use crate::streaming::grpc::AccountPretty;
 use solana_sdk::pubkey::Pubkey;
 
 /// Meteora DAMM v2 程序ID
 pub const METEORA_DAMM_V2_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG");
 
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::SWAP_IX, EventType::MeteoraDammV2Swap),
+    (discriminators::SWAP2_IX, EventType::MeteoraDammV2Swap2),
+    (discriminators::INITIALIZE_POOL_IX, EventType::MeteoraDammV2InitializePool),
+    (discriminators::INITIALIZE_CUSTOMIZABLE_POOL_IX, EventType::MeteoraDammV2InitializeCustomizablePool),
+    (discriminators::INITIALIZE_POOL_WITH_DYNAMIC_CONFIG_IX, EventType::MeteoraDammV2InitializePoolWithDynamicConfig),
+];
+
+/// 解析 Meteora DAMM v2 账户数据
+///
+/// 根据判别器路由到具体的账户解析函数
+pub fn parse_meteora_damm_v2_account_data(
+    discriminator: &[u8],
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::POOL => types::pool_parser(account, metadata),
+        _ => None,
+    }
+}
+
 /// 解析 Meteora DAMM v2 instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
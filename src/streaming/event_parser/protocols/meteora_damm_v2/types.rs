@@ -1,2 +1,223 @@
-// 此文件用于定义 Meteora DAMM v2 的账户数据结构
-// 暂时留空,后续如需要解析 Pool 账户状态时可以在这里添加
+use crate::streaming::event_parser::common::serde_base58;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        protocols::meteora_damm_v2::MeteoraDammV2PoolAccountEvent,
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+/// 手续费分母，`cliff_fee_numerator / FEE_DENOMINATOR` 为基础费率
+pub const FEE_DENOMINATOR: u64 = 1_000_000_000;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeStruct {
+    pub cliff_fee_numerator: u64,
+    pub fee_scheduler_mode: u8,
+    pub padding_0: [u8; 5],
+    pub number_of_period: u16,
+    pub period_frequency: u64,
+    pub reduction_factor: u64,
+    pub padding_1: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeStruct {
+    pub initialized: u8,
+    pub padding: [u8; 7],
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+    pub bin_step: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub last_update_timestamp: u64,
+    pub bin_step_u128: u128,
+    pub sqrt_price_reference: u128,
+    pub volatility_accumulator: u128,
+    pub volatility_reference: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeesStruct {
+    pub base_fee: BaseFeeStruct,
+    pub protocol_fee_percent: u8,
+    pub partner_fee_percent: u8,
+    pub referral_fee_percent: u8,
+    pub padding_0: [u8; 5],
+    pub dynamic_fee: DynamicFeeStruct,
+    pub padding_1: [u64; 2],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolMetrics {
+    pub total_lp_a_fee: u128,
+    pub total_lp_b_fee: u128,
+    pub total_protocol_a_fee: u64,
+    pub total_protocol_b_fee: u64,
+    pub total_partner_a_fee: u64,
+    pub total_partner_b_fee: u64,
+    pub total_position: u64,
+    pub padding: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    pub initialized: u8,
+    pub reward_token_flag: u8,
+    pub padding_0: [u8; 6],
+    pub padding_1: [u8; 8],
+    #[serde(with = "serde_base58::pubkey")]
+    pub mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub reward_per_token_stored: [u8; 32],
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Pool {
+    pub pool_fees: PoolFeesStruct,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_a_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_b_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_a_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_b_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub whitelisted_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub partner: Pubkey,
+    pub liquidity: u128,
+    pub padding: u128,
+    pub protocol_a_fee: u64,
+    pub protocol_b_fee: u64,
+    pub partner_a_fee: u64,
+    pub partner_b_fee: u64,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub sqrt_price: u128,
+    pub activation_point: u64,
+    pub activation_type: u8,
+    pub pool_status: u8,
+    pub token_a_flag: u8,
+    pub token_b_flag: u8,
+    pub collect_fee_mode: u8,
+    pub pool_type: u8,
+    pub padding_0: [u8; 2],
+    pub fee_a_per_liquidity: [u8; 32],
+    pub fee_b_per_liquidity: [u8; 32],
+    pub permanent_lock_liquidity: u128,
+    pub metrics: PoolMetrics,
+    #[serde(with = "serde_base58::pubkey")]
+    pub creator: Pubkey,
+    pub padding_1: [u64; 6],
+    pub reward_infos: [RewardInfo; 2],
+}
+
+pub const POOL_SIZE: usize = 1104;
+
+pub fn pool_decode(data: &[u8]) -> Option<Pool> {
+    if data.len() < POOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Pool>(&data[..POOL_SIZE]).ok()
+}
+
+pub fn pool_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDammV2Pool;
+
+    if account.data.len() < POOL_SIZE + 8 {
+        return None;
+    }
+    let pool = pool_decode(&account.data[8..POOL_SIZE + 8])?;
+    Some(DexEvent::MeteoraDammV2PoolAccountEvent(MeteoraDammV2PoolAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        pool,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::account_event_parser::AccountEventParser;
+    use crate::streaming::event_parser::protocols::meteora_damm_v2::{
+        discriminators, parser::METEORA_DAMM_V2_PROGRAM_ID,
+    };
+    use crate::streaming::event_parser::Protocol;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    /// 判别器 + Pool 数据，只填充测试用到的字段
+    fn pool_account(data_len: usize) -> AccountPretty {
+        let mut data = discriminators::POOL.to_vec();
+        data.resize(8 + POOL_SIZE, 0);
+        let pool = &mut data[8..];
+        pool[0..8].copy_from_slice(&2_500_000u64.to_le_bytes());
+        for (offset, n) in [(160, 1), (192, 2), (224, 3), (256, 4)] {
+            pool[offset..offset + 32].copy_from_slice(&key(n).to_bytes());
+        }
+        pool[352..368].copy_from_slice(&1_000_000u128.to_le_bytes());
+        pool[448..464].copy_from_slice(&(1u128 << 64).to_le_bytes());
+        pool[640..672].copy_from_slice(&key(5).to_bytes());
+        data.truncate(data_len);
+        AccountPretty {
+            pubkey: key(9),
+            owner: METEORA_DAMM_V2_PROGRAM_ID,
+            lamports: 1_000,
+            data,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pool_account_decodes_into_normalized_pool_state() {
+        let account = pool_account(8 + POOL_SIZE);
+        let event =
+            AccountEventParser::parse_account_event(&[Protocol::MeteoraDammV2], account, None)
+                .unwrap();
+        let DexEvent::MeteoraDammV2PoolAccountEvent(pool_event) = &event else {
+            panic!("unexpected event {event:?}")
+        };
+        assert_eq!(pool_event.metadata.event_type, EventType::AccountMeteoraDammV2Pool);
+        assert_eq!(pool_event.pool.creator, key(5));
+
+        let state = event.as_pool_state().unwrap();
+        assert_eq!(state.pool(), key(9));
+        assert_eq!((state.mint_a(), state.mint_b()), (key(1), key(2)));
+        assert_eq!((state.vault_a(), state.vault_b()), (key(3), key(4)));
+        assert_eq!(state.concentrated_liquidity(), Some((1_000_000, 1u128 << 64)));
+        assert_eq!(state.concentrated_price(6, 6), Some(1.0));
+        assert_eq!(state.fee_rate(), Some(0.0025));
+        assert_eq!(state.reserves(), None);
+    }
+
+    #[test]
+    fn truncated_pool_account_is_not_decoded() {
+        let account = pool_account(8 + POOL_SIZE - 1);
+        assert!(pool_parser(&account, EventMetadata::default()).is_none());
+        let event =
+            AccountEventParser::parse_account_event(&[Protocol::MeteoraDammV2], account, None);
+        assert!(!matches!(event, Some(DexEvent::MeteoraDammV2PoolAccountEvent(_))));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::streaming::event_parser::common::serde_base58;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+
+/// remove_liquidity 指令中单个 bin 的移除比例
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinLiquidityReduction {
+    pub bin_id: i32,
+    pub bps_to_remove: u16,
+}
+
+/// Meteora DLMM Swap Event (对应 swap / swap2 / swap_exact_out / swap_exact_out2 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
+    pub lb_pair: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub from: Pubkey,
+    pub start_bin_id: i32,
+    pub end_bin_id: i32,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub swap_for_y: bool,
+    /// 本次 swap 收取的总手续费 (input token)
+    pub fee: u64,
+    pub protocol_fee: u64,
+    pub fee_bps: u128,
+    pub host_fee: u64,
+
+    /// swap 完成后的 active bin id (等于 end_bin_id)
+    #[borsh(skip)]
+    pub active_bin_id: i32,
+
+    // 来自指令参数
+    #[borsh(skip)]
+    pub min_amount_out: u64,
+    #[borsh(skip)]
+    pub max_in_amount: u64,
+
+    // 来自指令账户
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_token_in: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_token_out: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub oracle: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub host_fee_in: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub user: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_x_program: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_y_program: Pubkey,
+    /// swap 经过的 bin array 账户 (remaining accounts)
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::vec_pubkey")]
+    pub bin_arrays: Vec<Pubkey>,
+}
+
+/// Meteora DLMM 添加流动性 (add_liquidity_by_strategy)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmAddLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
+    pub lb_pair: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub from: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+
+    // 来自指令参数
+    #[borsh(skip)]
+    pub amount_x: u64,
+    #[borsh(skip)]
+    pub amount_y: u64,
+    #[borsh(skip)]
+    pub active_id: i32,
+    #[borsh(skip)]
+    pub max_active_bin_slippage: i32,
+    #[borsh(skip)]
+    pub min_bin_id: i32,
+    #[borsh(skip)]
+    pub max_bin_id: i32,
+    #[borsh(skip)]
+    pub strategy_type: u8,
+
+    // 来自指令账户
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_token_x: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_token_y: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub bin_array_lower: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub bin_array_upper: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub sender: Pubkey,
+}
+
+/// Meteora DLMM 移除流动性 (remove_liquidity)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmRemoveLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    #[serde(with = "serde_base58::pubkey")]
+    pub lb_pair: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub from: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+
+    // 来自指令参数
+    #[borsh(skip)]
+    pub bin_liquidity_removal: Vec<BinLiquidityReduction>,
+
+    // 来自指令账户
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_token_x: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub user_token_y: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub bin_array_lower: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub bin_array_upper: Pubkey,
+    #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
+    pub sender: Pubkey,
+}
+
+/// Meteora DLMM 跨 bin 事件 (GoToABin)
+///
+/// swap 跨越 bin 时由程序单独 emit，按出现顺序作为独立事件回调
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmGoToABinEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub lb_pair: Pubkey,
+    pub from_bin_id: i32,
+    pub to_bin_id: i32,
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const SWAP_IX: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP2_IX: &[u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+    pub const SWAP_EXACT_OUT_IX: &[u8] = &[250, 73, 101, 33, 38, 207, 75, 184];
+    pub const SWAP_EXACT_OUT2_IX: &[u8] = &[43, 215, 247, 132, 137, 60, 243, 81];
+    pub const ADD_LIQUIDITY_BY_STRATEGY_IX: &[u8] = &[7, 3, 150, 127, 148, 40, 61, 200];
+    pub const REMOVE_LIQUIDITY_IX: &[u8] = &[80, 85, 209, 72, 24, 206, 177, 108];
+
+    // emit_cpi 事件指令的前 8 字节，后面 8 字节是具体事件类型
+    pub const EVENT_IX_TAG: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29];
+    pub const GO_TO_A_BIN_EVENT_DISC: &[u8] = &[59, 138, 76, 68, 138, 131, 176, 67];
+
+    // Event discriminators (CPI Log Event)
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 81, 108, 227, 190, 205, 208, 10, 196];
+    pub const ADD_LIQUIDITY_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 31, 94, 125, 90, 227, 52, 61, 186];
+    pub const REMOVE_LIQUIDITY_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 116, 244, 97, 232, 103, 31, 152, 58];
+}
+
+/// Decode swap event from CPI log
+pub const METEORA_DLMM_SWAP_EVENT_LOG_SIZE: usize = 129;
+pub fn meteora_dlmm_swap_event_decode(data: &[u8]) -> Option<MeteoraDlmmSwapEvent> {
+    if data.len() < METEORA_DLMM_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmSwapEvent>(&data[..METEORA_DLMM_SWAP_EVENT_LOG_SIZE]).ok()
+}
+
+/// Decode add liquidity event from CPI log
+pub const METEORA_DLMM_ADD_LIQUIDITY_EVENT_LOG_SIZE: usize = 116;
+pub fn meteora_dlmm_add_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmAddLiquidityEvent> {
+    if data.len() < METEORA_DLMM_ADD_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmAddLiquidityEvent>(
+        &data[..METEORA_DLMM_ADD_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode remove liquidity event from CPI log
+pub const METEORA_DLMM_REMOVE_LIQUIDITY_EVENT_LOG_SIZE: usize = 116;
+pub fn meteora_dlmm_remove_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmRemoveLiquidityEvent> {
+    if data.len() < METEORA_DLMM_REMOVE_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmRemoveLiquidityEvent>(
+        &data[..METEORA_DLMM_REMOVE_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode GoToABin event from CPI log
+pub const METEORA_DLMM_GO_TO_A_BIN_EVENT_LOG_SIZE: usize = 40;
+pub fn meteora_dlmm_go_to_a_bin_event_decode(data: &[u8]) -> Option<MeteoraDlmmGoToABinEvent> {
+    if data.len() < METEORA_DLMM_GO_TO_A_BIN_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmGoToABinEvent>(&data[..METEORA_DLMM_GO_TO_A_BIN_EVENT_LOG_SIZE])
+        .ok()
+}
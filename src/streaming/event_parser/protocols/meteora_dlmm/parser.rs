@@ -0,0 +1,282 @@
+use crate::streaming::event_parser::{
+    common::{read_i32_le, read_u16_le, read_u32_le, read_u64_le, read_u8, EventMetadata, EventType},
+    protocols::meteora_dlmm::{
+        discriminators, meteora_dlmm_add_liquidity_event_decode,
+        meteora_dlmm_go_to_a_bin_event_decode, meteora_dlmm_remove_liquidity_event_decode,
+        meteora_dlmm_swap_event_decode, BinLiquidityReduction, MeteoraDlmmAddLiquidityEvent,
+        MeteoraDlmmGoToABinEvent, MeteoraDlmmRemoveLiquidityEvent, MeteoraDlmmSwapEvent,
+    },
+    DexEvent,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Meteora DLMM (LB pair) 程序ID
+pub const METEORA_DLMM_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::SWAP_IX, EventType::MeteoraDlmmSwap),
+    (discriminators::SWAP2_IX, EventType::MeteoraDlmmSwap2),
+    (discriminators::SWAP_EXACT_OUT_IX, EventType::MeteoraDlmmSwapExactOut),
+    (discriminators::SWAP_EXACT_OUT2_IX, EventType::MeteoraDlmmSwapExactOut2),
+    (discriminators::ADD_LIQUIDITY_BY_STRATEGY_IX, EventType::MeteoraDlmmAddLiquidityByStrategy),
+    (discriminators::REMOVE_LIQUIDITY_IX, EventType::MeteoraDlmmRemoveLiquidity),
+];
+
+/// 解析 Meteora DLMM instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数。
+/// GoToABin 事件以 emit_cpi 自调用的形式出现，没有对应的业务指令可合并，
+/// 因此在这里作为独立事件解析。
+pub fn parse_meteora_dlmm_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_IX => {
+            parse_swap_instruction(data, accounts, metadata, EventType::MeteoraDlmmSwap, false)
+        }
+        discriminators::SWAP2_IX => {
+            parse_swap_instruction(data, accounts, metadata, EventType::MeteoraDlmmSwap2, true)
+        }
+        discriminators::SWAP_EXACT_OUT_IX => parse_swap_exact_out_instruction(
+            data,
+            accounts,
+            metadata,
+            EventType::MeteoraDlmmSwapExactOut,
+            false,
+        ),
+        discriminators::SWAP_EXACT_OUT2_IX => parse_swap_exact_out_instruction(
+            data,
+            accounts,
+            metadata,
+            EventType::MeteoraDlmmSwapExactOut2,
+            true,
+        ),
+        discriminators::ADD_LIQUIDITY_BY_STRATEGY_IX => {
+            parse_add_liquidity_by_strategy_instruction(data, accounts, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_IX => {
+            parse_remove_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::EVENT_IX_TAG => parse_go_to_a_bin_event(data, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 Meteora DLMM inner instruction data (CPI events)
+///
+/// 根据判别器路由到具体的 inner instruction 解析函数
+pub fn parse_meteora_dlmm_inner_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_EVENT => parse_swap_inner_instruction(data, metadata),
+        discriminators::ADD_LIQUIDITY_EVENT => {
+            parse_add_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_EVENT => {
+            parse_remove_liquidity_inner_instruction(data, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 组装 swap 事件的账户部分
+///
+/// swap 与 swap2 的前 13 个账户一致，swap2 额外带 memo_program，
+/// event_authority / program 之后的 remaining accounts 为 bin arrays
+fn build_swap_event(
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+    is_v2: bool,
+) -> Option<MeteoraDlmmSwapEvent> {
+    let fixed_accounts = if is_v2 { 16 } else { 15 };
+    if accounts.len() < fixed_accounts {
+        return None;
+    }
+    Some(MeteoraDlmmSwapEvent {
+        metadata,
+        lb_pair: accounts[0],
+        bin_array_bitmap_extension: accounts[1],
+        reserve_x: accounts[2],
+        reserve_y: accounts[3],
+        user_token_in: accounts[4],
+        user_token_out: accounts[5],
+        token_x_mint: accounts[6],
+        token_y_mint: accounts[7],
+        oracle: accounts[8],
+        host_fee_in: accounts[9],
+        user: accounts[10],
+        token_x_program: accounts[11],
+        token_y_program: accounts[12],
+        bin_arrays: accounts[fixed_accounts..].to_vec(),
+        ..Default::default()
+    })
+}
+
+/// 解析 swap / swap2 指令
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+    event_type: EventType,
+    is_v2: bool,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if data.len() < 16 {
+        return None;
+    }
+
+    let mut event = build_swap_event(accounts, metadata, is_v2)?;
+    event.amount_in = read_u64_le(data, 0)?;
+    event.min_amount_out = read_u64_le(data, 8)?;
+    Some(DexEvent::MeteoraDlmmSwapEvent(event))
+}
+
+/// 解析 swap_exact_out / swap_exact_out2 指令
+fn parse_swap_exact_out_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+    event_type: EventType,
+    is_v2: bool,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if data.len() < 16 {
+        return None;
+    }
+
+    let mut event = build_swap_event(accounts, metadata, is_v2)?;
+    event.max_in_amount = read_u64_le(data, 0)?;
+    event.amount_out = read_u64_le(data, 8)?;
+    Some(DexEvent::MeteoraDlmmSwapEvent(event))
+}
+
+/// 解析 add_liquidity_by_strategy 指令
+fn parse_add_liquidity_by_strategy_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmAddLiquidityByStrategy;
+
+    // amount_x + amount_y + active_id + max_active_bin_slippage + strategy_parameters
+    if data.len() < 33 || accounts.len() < 14 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmAddLiquidityEvent(MeteoraDlmmAddLiquidityEvent {
+        metadata,
+        amount_x: read_u64_le(data, 0)?,
+        amount_y: read_u64_le(data, 8)?,
+        active_id: read_i32_le(data, 16)?,
+        max_active_bin_slippage: read_i32_le(data, 20)?,
+        min_bin_id: read_i32_le(data, 24)?,
+        max_bin_id: read_i32_le(data, 28)?,
+        strategy_type: read_u8(data, 32)?,
+        position: accounts[0],
+        lb_pair: accounts[1],
+        bin_array_bitmap_extension: accounts[2],
+        user_token_x: accounts[3],
+        user_token_y: accounts[4],
+        reserve_x: accounts[5],
+        reserve_y: accounts[6],
+        token_x_mint: accounts[7],
+        token_y_mint: accounts[8],
+        bin_array_lower: accounts[9],
+        bin_array_upper: accounts[10],
+        sender: accounts[11],
+        ..Default::default()
+    }))
+}
+
+/// 解析 remove_liquidity 指令
+fn parse_remove_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmRemoveLiquidity;
+
+    if data.len() < 4 || accounts.len() < 14 {
+        return None;
+    }
+
+    // Vec<BinLiquidityReduction>: u32 长度 + N * (i32 bin_id + u16 bps_to_remove)
+    let len = read_u32_le(data, 0)? as usize;
+    let mut bin_liquidity_removal = Vec::with_capacity(len.min(data.len() / 6));
+    for i in 0..len {
+        let offset = 4 + i * 6;
+        bin_liquidity_removal.push(BinLiquidityReduction {
+            bin_id: read_i32_le(data, offset)?,
+            bps_to_remove: read_u16_le(data, offset + 4)?,
+        });
+    }
+
+    Some(DexEvent::MeteoraDlmmRemoveLiquidityEvent(MeteoraDlmmRemoveLiquidityEvent {
+        metadata,
+        bin_liquidity_removal,
+        position: accounts[0],
+        lb_pair: accounts[1],
+        bin_array_bitmap_extension: accounts[2],
+        user_token_x: accounts[3],
+        user_token_y: accounts[4],
+        reserve_x: accounts[5],
+        reserve_y: accounts[6],
+        token_x_mint: accounts[7],
+        token_y_mint: accounts[8],
+        bin_array_lower: accounts[9],
+        bin_array_upper: accounts[10],
+        sender: accounts[11],
+        ..Default::default()
+    }))
+}
+
+/// 解析 GoToABin 事件 (emit_cpi 自调用指令)
+fn parse_go_to_a_bin_event(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < 8 || &data[..8] != discriminators::GO_TO_A_BIN_EVENT_DISC {
+        return None;
+    }
+    metadata.event_type = EventType::MeteoraDlmmGoToABin;
+    let event = meteora_dlmm_go_to_a_bin_event_decode(&data[8..])?;
+    Some(DexEvent::MeteoraDlmmGoToABinEvent(MeteoraDlmmGoToABinEvent { metadata, ..event }))
+}
+
+/// 解析 swap inner instruction (CPI event)
+fn parse_swap_inner_instruction(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    // Note: event_type will be set by instruction parser
+    let event = meteora_dlmm_swap_event_decode(data)?;
+    Some(DexEvent::MeteoraDlmmSwapEvent(MeteoraDlmmSwapEvent { metadata, ..event }))
+}
+
+/// 解析 add liquidity inner instruction (CPI event)
+fn parse_add_liquidity_inner_instruction(
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let event = meteora_dlmm_add_liquidity_event_decode(data)?;
+    Some(DexEvent::MeteoraDlmmAddLiquidityEvent(MeteoraDlmmAddLiquidityEvent {
+        metadata,
+        ..event
+    }))
+}
+
+/// 解析 remove liquidity inner instruction (CPI event)
+fn parse_remove_liquidity_inner_instruction(
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let event = meteora_dlmm_remove_liquidity_event_decode(data)?;
+    Some(DexEvent::MeteoraDlmmRemoveLiquidityEvent(MeteoraDlmmRemoveLiquidityEvent {
+        metadata,
+        ..event
+    }))
+}
@@ -0,0 +1,2 @@
+// 此文件用于定义 Meteora DLMM 的账户数据结构
+// 暂时留空,后续如需要解析 LbPair / BinArray 账户状态时可以在这里添加
@@ -1,11 +1,21 @@
 pub mod block;
 pub mod bonk;
+pub mod jupiter;
+pub mod lifinity;
 pub mod meteora_damm_v2;
+pub mod meteora_dlmm;
+pub mod moonshot;
+pub mod openbook_v2;
+pub mod orca_whirlpool;
+pub mod phoenix;
 pub mod pumpfun;
 pub mod pumpswap;
 pub mod raydium_amm_v4;
 pub mod raydium_clmm;
 pub mod raydium_cpmm;
+pub mod raydium_launchpad;
+pub mod sanctum;
 pub mod types;
 pub use block::block_meta_event::BlockMetaEvent;
+pub use block::entry_event::EntryEvent;
 pub use types::Protocol;
@@ -0,0 +1,74 @@
+use crate::streaming::event_parser::common::serde_base58;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::moonshot::types::MoonshotFixedSide;
+
+/// Moonshot 买入 / 卖出事件
+///
+/// Moonshot 没有 CPI 事件，`collateral_amount` / `token_amount` / `curve_progress`
+/// 在 gRPC 交易中由余额变化计算得到；没有交易 meta (如 shred) 时为指令参数中的数量，进度为 0
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MoonshotTradeEvent {
+    pub metadata: EventMetadata,
+    pub is_buy: bool,
+    /// 抵押品 (SOL) 数量
+    pub collateral_amount: u64,
+    /// 代币数量
+    pub token_amount: u64,
+    /// 曲线进度百分比 (0-100)
+    pub curve_progress: f64,
+
+    // 来自指令参数
+    pub fixed_side: MoonshotFixedSide,
+    pub slippage_bps: u64,
+
+    // 来自指令账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub sender: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub sender_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub curve_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub curve_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub dex_fee: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub helio_fee: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub config_account: Pubkey,
+}
+
+/// Moonshot 曲线完成迁移事件 (migrate_funds)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoonshotMigrationEvent {
+    pub metadata: EventMetadata,
+    /// 迁移出曲线的代币数量
+    pub tokens_migrated: u64,
+    /// 迁移出曲线的抵押品 (SOL) 数量
+    pub collateral_migrated: u64,
+
+    // 来自指令账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub migration_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub curve_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub curve_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub migration_authority_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub mint: Pubkey,
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const BUY_IX: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
+    pub const SELL_IX: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
+    pub const MIGRATE_FUNDS_IX: &[u8] = &[42, 229, 10, 231, 189, 62, 193, 174];
+}
@@ -0,0 +1,5 @@
+pub mod events;
+pub mod parser;
+pub mod types;
+
+pub use events::*;
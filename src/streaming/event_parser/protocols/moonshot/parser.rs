@@ -0,0 +1,203 @@
+use crate::streaming::event_parser::{
+    common::{balances::TransactionBalances, read_u64_le, read_u8, EventMetadata, EventType},
+    protocols::moonshot::{
+        discriminators,
+        types::{curve_progress_from_remaining, MoonshotFixedSide},
+        MoonshotMigrationEvent, MoonshotTradeEvent,
+    },
+    DexEvent,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Moonshot程序ID
+pub const MOONSHOT_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG");
+
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::BUY_IX, EventType::MoonshotBuy),
+    (discriminators::SELL_IX, EventType::MoonshotSell),
+    (discriminators::MIGRATE_FUNDS_IX, EventType::MoonshotMigration),
+];
+
+/// 解析 Moonshot instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_moonshot_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::BUY_IX => parse_trade_instruction(data, accounts, metadata, true),
+        discriminators::SELL_IX => parse_trade_instruction(data, accounts, metadata, false),
+        discriminators::MIGRATE_FUNDS_IX => parse_migrate_funds_instruction(accounts, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 Moonshot inner instruction data
+///
+/// Moonshot 没有 Anchor CPI 事件
+pub fn parse_moonshot_inner_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 buy / sell 指令
+fn parse_trade_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+    is_buy: bool,
+) -> Option<DexEvent> {
+    metadata.event_type = if is_buy { EventType::MoonshotBuy } else { EventType::MoonshotSell };
+
+    // TradeParams: token_amount + collateral_amount + fixed_side + slippage_bps
+    if data.len() < 25 || accounts.len() < 11 {
+        return None;
+    }
+
+    Some(DexEvent::MoonshotTradeEvent(MoonshotTradeEvent {
+        metadata,
+        is_buy,
+        token_amount: read_u64_le(data, 0)?,
+        collateral_amount: read_u64_le(data, 8)?,
+        fixed_side: MoonshotFixedSide::from_u8(read_u8(data, 16)?)?,
+        slippage_bps: read_u64_le(data, 17)?,
+        sender: accounts[0],
+        sender_token_account: accounts[1],
+        curve_account: accounts[2],
+        curve_token_account: accounts[3],
+        dex_fee: accounts[4],
+        helio_fee: accounts[5],
+        mint: accounts[6],
+        config_account: accounts[7],
+        ..Default::default()
+    }))
+}
+
+/// 解析 migrate_funds 指令
+fn parse_migrate_funds_instruction(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MoonshotMigration;
+
+    if accounts.len() < 12 {
+        return None;
+    }
+
+    Some(DexEvent::MoonshotMigrationEvent(MoonshotMigrationEvent {
+        metadata,
+        migration_authority: accounts[1],
+        curve_account: accounts[2],
+        curve_token_account: accounts[3],
+        migration_authority_token_account: accounts[4],
+        mint: accounts[5],
+        ..Default::default()
+    }))
+}
+
+/// 用交易前后的余额变化补全 Moonshot 事件，其他事件不做处理
+///
+/// 需要先填充 `metadata.balance_changes`；
+/// 同一笔交易中对同一条曲线多次交易时，得到的是合计变化量
+pub fn fill_moonshot_event_from_balances(
+    event: &mut DexEvent,
+    balances: &TransactionBalances<'_>,
+    accounts: &[Pubkey],
+) {
+    let lamports_delta = |account: &Pubkey| {
+        let index = accounts.iter().position(|a| a == account)?;
+        balances.lamports(index).map(|(pre, post)| pre.abs_diff(post))
+    };
+    let token_change = |metadata: &EventMetadata, account: &Pubkey| {
+        metadata.balance_changes.iter().find(|change| change.account == *account).cloned()
+    };
+
+    match event {
+        DexEvent::MoonshotTradeEvent(e) => {
+            if let Some(change) = token_change(&e.metadata, &e.curve_token_account) {
+                e.token_amount = change.pre_amount.abs_diff(change.post_amount);
+                e.curve_progress = curve_progress_from_remaining(change.post_amount);
+            }
+            if let Some(collateral_amount) = lamports_delta(&e.curve_account) {
+                e.collateral_amount = collateral_amount;
+            }
+            if let Some(swap_data) = e.metadata.swap_data.as_mut() {
+                swap_data.from_amount = if e.is_buy { e.collateral_amount } else { e.token_amount };
+                swap_data.to_amount = if e.is_buy { e.token_amount } else { e.collateral_amount };
+            }
+        }
+        DexEvent::MoonshotMigrationEvent(e) => {
+            if let Some(change) = token_change(&e.metadata, &e.curve_token_account) {
+                e.tokens_migrated = change.pre_amount.abs_diff(change.post_amount);
+            }
+            if let Some(collateral_migrated) = lamports_delta(&e.curve_account) {
+                e.collateral_migrated = collateral_migrated;
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::{TokenBalance, TransactionStatusMeta, UiTokenAmount};
+
+    fn token_balance(account_index: u32, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index,
+            mint: Pubkey::new_from_array([7; 32]).to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals: 9,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn trade_amounts_and_curve_progress_come_from_balances() {
+        let accounts: Vec<Pubkey> = (1..=11).map(|n| Pubkey::new_from_array([n; 32])).collect();
+        let mut data = 1_000u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&50_000_000u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&100u64.to_le_bytes());
+        let mut event = parse_moonshot_instruction_data(
+            discriminators::BUY_IX,
+            &data,
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+
+        // 曲线账户收到 49_500_000 lamports，曲线代币账户卖出 1e15
+        let meta = TransactionStatusMeta {
+            pre_balances: vec![0, 0, 10_000_000_000],
+            post_balances: vec![0, 0, 10_049_500_000],
+            pre_token_balances: vec![token_balance(3, 600_000_000_000_000_000)],
+            post_token_balances: vec![token_balance(3, 599_000_000_000_000_000)],
+            ..Default::default()
+        };
+        let balances = TransactionBalances::from_grpc_meta(&meta);
+        event.metadata_mut().balance_changes =
+            vec![balances.token_balance_change(3, &accounts).unwrap()];
+        fill_moonshot_event_from_balances(&mut event, &balances, &accounts);
+
+        let DexEvent::MoonshotTradeEvent(trade) = event else { unreachable!() };
+        assert!(trade.is_buy);
+        assert_eq!(trade.fixed_side, MoonshotFixedSide::ExactOut);
+        assert_eq!(trade.token_amount, 1_000_000_000_000_000);
+        assert_eq!(trade.collateral_amount, 49_500_000);
+        // 已售出 401e15，可售部分为 800e15
+        assert_eq!(trade.curve_progress, 50.125);
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// 代币总量 (10 亿，精度 9)
+pub const MOONSHOT_TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000;
+/// 迁移前可在曲线上卖出的代币数量 (总量的 80%)
+pub const MOONSHOT_CURVE_SELLABLE_AMOUNT: u64 = 800_000_000_000_000_000;
+
+/// 交易参数中的固定端
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MoonshotFixedSide {
+    /// 固定输入数量
+    #[default]
+    ExactIn,
+    /// 固定输出数量
+    ExactOut,
+}
+
+impl MoonshotFixedSide {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(MoonshotFixedSide::ExactIn),
+            1 => Some(MoonshotFixedSide::ExactOut),
+            _ => None,
+        }
+    }
+}
+
+/// 根据曲线代币账户剩余数量计算曲线进度 (0-100)
+pub fn curve_progress_from_remaining(curve_token_remaining: u64) -> f64 {
+    let sold = MOONSHOT_TOTAL_SUPPLY.saturating_sub(curve_token_remaining);
+    (sold as f64 * 100.0 / MOONSHOT_CURVE_SELLABLE_AMOUNT as f64).min(100.0)
+}
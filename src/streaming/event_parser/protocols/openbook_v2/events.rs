@@ -0,0 +1,43 @@
+use crate::streaming::event_parser::common::serde_base58;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::openbook_v2::types::OpenBookV2Side;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 成交
+///
+/// 每次吃单与一个 maker 挂单撮合产出一个事件，side 为吃单方向。
+/// price 为每个 base lot 的 quote lots，quantity 为成交的 base lots，
+/// 换算为代币数量需要市场账户中的 base_lot_size / quote_lot_size
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenBookV2FillEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub market: Pubkey,
+    /// maker 的 OpenOrders 账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub maker: Pubkey,
+    /// taker 的 OpenOrders 账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub taker: Pubkey,
+    pub side: OpenBookV2Side,
+    pub price: i64,
+    pub quantity: i64,
+    /// maker 挂单已全部成交
+    pub maker_out: bool,
+    pub maker_slot: u8,
+    pub maker_client_order_id: u64,
+    pub maker_fee: u64,
+    pub maker_timestamp: u64,
+    pub taker_client_order_id: u64,
+    pub taker_fee_ceil: u64,
+    /// 市场内的事件序号
+    pub seq_num: u64,
+    pub timestamp: u64,
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 程序日志事件鉴别器，"Program data:" 日志 base64 解码后的前 8 字节
+    pub const FILL_LOG: &[u8] = &[150, 23, 41, 148, 152, 162, 215, 64];
+}
@@ -0,0 +1,75 @@
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{EventMetadata, EventType},
+    protocols::openbook_v2::{
+        discriminators,
+        types::{FillLog, OpenBookV2Side, FILL_LOG_LEN},
+        OpenBookV2FillEvent,
+    },
+    DexEvent,
+};
+
+/// OpenBook v2 程序ID
+pub const OPENBOOK_V2_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb");
+
+/// 解析 OpenBook v2 instruction data
+///
+/// 下单、吃单指令本身不包含成交结果，成交来自程序日志，由 `parse_openbook_v2_program_data` 解析
+pub fn parse_openbook_v2_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _accounts: &[Pubkey],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 OpenBook v2 inner instruction data
+///
+/// OpenBook v2 没有 Anchor CPI 事件
+pub fn parse_openbook_v2_inner_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 OpenBook v2 通过 `sol_log_data` 输出的事件
+///
+/// 撮合时程序把成交写入市场的 EventHeap 账户 (供 consume_events 结算 maker)，
+/// 同时在交易日志中输出一条 "Program data:" 的 FillLog。EventHeap 是环形缓冲区，
+/// 只能看到最新状态且没有市场地址，因此这里解析交易日志：
+/// `data` 为 base64 解码后的日志，前 8 字节为 Anchor 事件判别器。
+/// 目前只输出 FillLog，其余日志 (下单、结算、存款等) 会被跳过。
+pub fn parse_openbook_v2_program_data(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    if data.len() < 8 + FILL_LOG_LEN || &data[..8] != discriminators::FILL_LOG {
+        return None;
+    }
+    let log = FillLog::try_from_slice(&data[8..8 + FILL_LOG_LEN]).ok()?;
+    metadata.event_type = EventType::OpenBookV2Fill;
+    Some(DexEvent::OpenBookV2FillEvent(OpenBookV2FillEvent {
+        metadata,
+        market: log.market,
+        maker: log.maker,
+        taker: log.taker,
+        side: OpenBookV2Side::from_u8(log.taker_side)?,
+        price: log.price,
+        quantity: log.quantity,
+        maker_out: log.maker_out,
+        maker_slot: log.maker_slot,
+        maker_client_order_id: log.maker_client_order_id,
+        maker_fee: log.maker_fee,
+        maker_timestamp: log.maker_timestamp,
+        taker_client_order_id: log.taker_client_order_id,
+        taker_fee_ceil: log.taker_fee_ceil,
+        seq_num: log.seq_num,
+        timestamp: log.timestamp,
+    }))
+}
@@ -0,0 +1,43 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 订单方向
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub enum OpenBookV2Side {
+    #[default]
+    Bid,
+    Ask,
+}
+
+impl OpenBookV2Side {
+    pub fn from_u8(side: u8) -> Option<Self> {
+        match side {
+            0 => Some(OpenBookV2Side::Bid),
+            1 => Some(OpenBookV2Side::Ask),
+            _ => None,
+        }
+    }
+}
+
+/// 撮合时通过 `sol_log_data` 输出的 FillLog (Anchor 事件布局，不含 8 字节判别器)
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+pub struct FillLog {
+    pub market: Pubkey,
+    pub taker_side: u8,
+    pub maker_slot: u8,
+    pub maker_out: bool,
+    pub timestamp: u64,
+    pub seq_num: u64,
+    pub maker: Pubkey,
+    pub maker_client_order_id: u64,
+    pub maker_fee: u64,
+    pub maker_timestamp: u64,
+    pub taker: Pubkey,
+    pub taker_client_order_id: u64,
+    pub taker_fee_ceil: u64,
+    pub price: i64,
+    pub quantity: i64,
+}
+
+pub const FILL_LOG_LEN: usize = 32 + 1 + 1 + 1 + 8 + 8 + 32 + 8 + 8 + 8 + 32 + 8 + 8 + 8 + 8;
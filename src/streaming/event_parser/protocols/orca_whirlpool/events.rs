@@ -0,0 +1,203 @@
+use crate::streaming::event_parser::common::serde_base58;
+use crate::streaming::event_parser::common::{sqrt_price_to_price, EventMetadata};
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 交易 (swap / swap_v2)
+///
+/// swap 指令不携带 mint 账户，token_mint_a / token_mint_b 仅在 swap_v2 中填充
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub whirlpool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_owner_account_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_owner_account_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_2: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub oracle: Pubkey,
+}
+
+/// 增加流动性 (increase_liquidity / increase_liquidity_v2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolIncreaseLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub liquidity_amount: u128,
+    pub token_max_a: u64,
+    pub token_max_b: u64,
+
+    #[serde(with = "serde_base58::pubkey")]
+    pub whirlpool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_owner_account_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_owner_account_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_upper: Pubkey,
+}
+
+/// 减少流动性 (decrease_liquidity / decrease_liquidity_v2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolDecreaseLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub liquidity_amount: u128,
+    pub token_min_a: u64,
+    pub token_min_b: u64,
+
+    #[serde(with = "serde_base58::pubkey")]
+    pub whirlpool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_owner_account_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_owner_account_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_upper: Pubkey,
+}
+
+/// 初始化池 (initialize_pool / initialize_pool_v2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolInitializePoolEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub tick_spacing: u16,
+    pub initial_sqrt_price: u128,
+
+    #[serde(with = "serde_base58::pubkey")]
+    pub whirlpools_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_mint_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub funder: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub whirlpool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault_b: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub fee_tier: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_a: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program_b: Pubkey,
+}
+
+/// 交易结果 (程序日志 Traded)
+///
+/// 由 swap / swap_v2 以及 two_hop_swap 系列指令的每一跳输出，包含交易前后的平方根价格、
+/// 实际成交数量和手续费；`pre_sqrt_price` / `post_sqrt_price` 为 Q64.64 定点数
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolTradedEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub whirlpool: Pubkey,
+    pub a_to_b: bool,
+    pub pre_sqrt_price: u128,
+    pub post_sqrt_price: u128,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+impl OrcaWhirlpoolTradedEvent {
+    /// 交易后的价格 (每个 token a 可换得的 token b，界面单位)，见 `sqrt_price_to_price`
+    pub fn price(&self, decimals_a: u8, decimals_b: u8) -> f64 {
+        sqrt_price_to_price(self.post_sqrt_price, decimals_a, decimals_b)
+    }
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP_V2: &[u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
+    pub const INCREASE_LIQUIDITY: &[u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+    pub const INCREASE_LIQUIDITY_V2: &[u8] = &[133, 29, 89, 223, 69, 238, 176, 10];
+    pub const DECREASE_LIQUIDITY: &[u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+    pub const DECREASE_LIQUIDITY_V2: &[u8] = &[58, 127, 188, 62, 79, 82, 196, 96];
+    pub const INITIALIZE_POOL: &[u8] = &[95, 180, 10, 172, 84, 174, 232, 40];
+    pub const INITIALIZE_POOL_V2: &[u8] = &[207, 45, 87, 242, 27, 63, 204, 67];
+
+    // 程序日志事件鉴别器
+    pub const TRADED_EVENT: &[u8] = &[225, 202, 73, 175, 147, 43, 160, 150];
+}
+
+/// Traded 日志事件的长度 (不含 8 字节判别器)
+pub const TRADED_EVENT_LOG_SIZE: usize = 32 + 1 + 16 * 2 + 8 * 6;
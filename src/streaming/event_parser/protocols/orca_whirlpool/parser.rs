@@ -0,0 +1,360 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{read_u128_le, read_u16_le, read_u64_le, read_u8_le, EventMetadata, EventType},
+    protocols::orca_whirlpool::{
+        discriminators, OrcaWhirlpoolDecreaseLiquidityEvent, OrcaWhirlpoolIncreaseLiquidityEvent,
+        OrcaWhirlpoolInitializePoolEvent, OrcaWhirlpoolSwapEvent, OrcaWhirlpoolTradedEvent,
+        TRADED_EVENT_LOG_SIZE,
+    },
+    DexEvent,
+};
+
+/// Orca Whirlpool程序ID
+pub const WHIRLPOOL_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::SWAP, EventType::OrcaWhirlpoolSwap),
+    (discriminators::SWAP_V2, EventType::OrcaWhirlpoolSwapV2),
+    (discriminators::INCREASE_LIQUIDITY, EventType::OrcaWhirlpoolIncreaseLiquidity),
+    (discriminators::INCREASE_LIQUIDITY_V2, EventType::OrcaWhirlpoolIncreaseLiquidityV2),
+    (discriminators::DECREASE_LIQUIDITY, EventType::OrcaWhirlpoolDecreaseLiquidity),
+    (discriminators::DECREASE_LIQUIDITY_V2, EventType::OrcaWhirlpoolDecreaseLiquidityV2),
+    (discriminators::INITIALIZE_POOL, EventType::OrcaWhirlpoolInitializePool),
+    (discriminators::INITIALIZE_POOL_V2, EventType::OrcaWhirlpoolInitializePoolV2),
+];
+
+/// 解析 Orca Whirlpool instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_orca_whirlpool_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP => parse_swap_instruction(data, accounts, metadata),
+        discriminators::SWAP_V2 => parse_swap_v2_instruction(data, accounts, metadata),
+        discriminators::INCREASE_LIQUIDITY => {
+            parse_increase_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::INCREASE_LIQUIDITY_V2 => {
+            parse_increase_liquidity_v2_instruction(data, accounts, metadata)
+        }
+        discriminators::DECREASE_LIQUIDITY => {
+            parse_decrease_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::DECREASE_LIQUIDITY_V2 => {
+            parse_decrease_liquidity_v2_instruction(data, accounts, metadata)
+        }
+        discriminators::INITIALIZE_POOL => {
+            parse_initialize_pool_instruction(data, accounts, metadata)
+        }
+        discriminators::INITIALIZE_POOL_V2 => {
+            parse_initialize_pool_v2_instruction(data, accounts, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Orca Whirlpool inner instruction data
+///
+/// Orca Whirlpool 没有 CPI inner instruction 事件，成交数量由后续的 token transfer 提取
+pub fn parse_orca_whirlpool_inner_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 Orca Whirlpool 通过 `emit!` 输出到交易日志的事件
+///
+/// 指令只有请求数量和价格上限，交易前后的价格和实际成交数量来自 Traded 日志。
+/// `data` 为 base64 解码后的日志，前 8 字节为 Anchor 事件判别器，未识别的日志会被跳过
+pub fn parse_orca_whirlpool_program_data(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    if data.len() < 8 + TRADED_EVENT_LOG_SIZE {
+        return None;
+    }
+    let (discriminator, data) = data.split_at(8);
+    match discriminator {
+        discriminators::TRADED_EVENT => {
+            metadata.event_type = EventType::OrcaWhirlpoolTraded;
+            let mut event: OrcaWhirlpoolTradedEvent =
+                borsh::from_slice(&data[..TRADED_EVENT_LOG_SIZE]).ok()?;
+            event.metadata = metadata;
+            Some(DexEvent::OrcaWhirlpoolTradedEvent(event))
+        }
+        _ => None,
+    }
+}
+
+/// 解析交易指令事件
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolSwap;
+
+    if data.len() < 34 || accounts.len() < 11 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolSwapEvent(OrcaWhirlpoolSwapEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        other_amount_threshold: read_u64_le(data, 8)?,
+        sqrt_price_limit: read_u128_le(data, 16)?,
+        amount_specified_is_input: read_u8_le(data, 32)? == 1,
+        a_to_b: read_u8_le(data, 33)? == 1,
+        token_program_a: accounts[0],
+        token_program_b: accounts[0],
+        token_authority: accounts[1],
+        whirlpool: accounts[2],
+        token_owner_account_a: accounts[3],
+        token_vault_a: accounts[4],
+        token_owner_account_b: accounts[5],
+        token_vault_b: accounts[6],
+        tick_array_0: accounts[7],
+        tick_array_1: accounts[8],
+        tick_array_2: accounts[9],
+        oracle: accounts[10],
+        ..Default::default()
+    }))
+}
+
+/// 解析交易 v2 指令事件
+fn parse_swap_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolSwapV2;
+
+    if data.len() < 34 || accounts.len() < 15 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolSwapEvent(OrcaWhirlpoolSwapEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        other_amount_threshold: read_u64_le(data, 8)?,
+        sqrt_price_limit: read_u128_le(data, 16)?,
+        amount_specified_is_input: read_u8_le(data, 32)? == 1,
+        a_to_b: read_u8_le(data, 33)? == 1,
+        token_program_a: accounts[0],
+        token_program_b: accounts[1],
+        token_authority: accounts[3],
+        whirlpool: accounts[4],
+        token_mint_a: accounts[5],
+        token_mint_b: accounts[6],
+        token_owner_account_a: accounts[7],
+        token_vault_a: accounts[8],
+        token_owner_account_b: accounts[9],
+        token_vault_b: accounts[10],
+        tick_array_0: accounts[11],
+        tick_array_1: accounts[12],
+        tick_array_2: accounts[13],
+        oracle: accounts[14],
+    }))
+}
+
+/// 解析增加流动性指令事件
+fn parse_increase_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolIncreaseLiquidity;
+
+    if data.len() < 32 || accounts.len() < 11 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(OrcaWhirlpoolIncreaseLiquidityEvent {
+        metadata,
+        liquidity_amount: read_u128_le(data, 0)?,
+        token_max_a: read_u64_le(data, 16)?,
+        token_max_b: read_u64_le(data, 24)?,
+        whirlpool: accounts[0],
+        token_program_a: accounts[1],
+        token_program_b: accounts[1],
+        position_authority: accounts[2],
+        position: accounts[3],
+        position_token_account: accounts[4],
+        token_owner_account_a: accounts[5],
+        token_owner_account_b: accounts[6],
+        token_vault_a: accounts[7],
+        token_vault_b: accounts[8],
+        tick_array_lower: accounts[9],
+        tick_array_upper: accounts[10],
+        ..Default::default()
+    }))
+}
+
+/// 解析增加流动性 v2 指令事件
+fn parse_increase_liquidity_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolIncreaseLiquidityV2;
+
+    if data.len() < 32 || accounts.len() < 15 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(OrcaWhirlpoolIncreaseLiquidityEvent {
+        metadata,
+        liquidity_amount: read_u128_le(data, 0)?,
+        token_max_a: read_u64_le(data, 16)?,
+        token_max_b: read_u64_le(data, 24)?,
+        whirlpool: accounts[0],
+        token_program_a: accounts[1],
+        token_program_b: accounts[2],
+        position_authority: accounts[4],
+        position: accounts[5],
+        position_token_account: accounts[6],
+        token_mint_a: accounts[7],
+        token_mint_b: accounts[8],
+        token_owner_account_a: accounts[9],
+        token_owner_account_b: accounts[10],
+        token_vault_a: accounts[11],
+        token_vault_b: accounts[12],
+        tick_array_lower: accounts[13],
+        tick_array_upper: accounts[14],
+    }))
+}
+
+/// 解析减少流动性指令事件
+fn parse_decrease_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolDecreaseLiquidity;
+
+    if data.len() < 32 || accounts.len() < 11 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(OrcaWhirlpoolDecreaseLiquidityEvent {
+        metadata,
+        liquidity_amount: read_u128_le(data, 0)?,
+        token_min_a: read_u64_le(data, 16)?,
+        token_min_b: read_u64_le(data, 24)?,
+        whirlpool: accounts[0],
+        token_program_a: accounts[1],
+        token_program_b: accounts[1],
+        position_authority: accounts[2],
+        position: accounts[3],
+        position_token_account: accounts[4],
+        token_owner_account_a: accounts[5],
+        token_owner_account_b: accounts[6],
+        token_vault_a: accounts[7],
+        token_vault_b: accounts[8],
+        tick_array_lower: accounts[9],
+        tick_array_upper: accounts[10],
+        ..Default::default()
+    }))
+}
+
+/// 解析减少流动性 v2 指令事件
+fn parse_decrease_liquidity_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolDecreaseLiquidityV2;
+
+    if data.len() < 32 || accounts.len() < 15 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(OrcaWhirlpoolDecreaseLiquidityEvent {
+        metadata,
+        liquidity_amount: read_u128_le(data, 0)?,
+        token_min_a: read_u64_le(data, 16)?,
+        token_min_b: read_u64_le(data, 24)?,
+        whirlpool: accounts[0],
+        token_program_a: accounts[1],
+        token_program_b: accounts[2],
+        position_authority: accounts[4],
+        position: accounts[5],
+        position_token_account: accounts[6],
+        token_mint_a: accounts[7],
+        token_mint_b: accounts[8],
+        token_owner_account_a: accounts[9],
+        token_owner_account_b: accounts[10],
+        token_vault_a: accounts[11],
+        token_vault_b: accounts[12],
+        tick_array_lower: accounts[13],
+        tick_array_upper: accounts[14],
+    }))
+}
+
+/// 解析初始化池指令事件
+fn parse_initialize_pool_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolInitializePool;
+
+    // data: bumps(u8) + tick_spacing(u16) + initial_sqrt_price(u128)
+    if data.len() < 19 || accounts.len() < 11 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolInitializePoolEvent(OrcaWhirlpoolInitializePoolEvent {
+        metadata,
+        tick_spacing: read_u16_le(data, 1)?,
+        initial_sqrt_price: read_u128_le(data, 3)?,
+        whirlpools_config: accounts[0],
+        token_mint_a: accounts[1],
+        token_mint_b: accounts[2],
+        funder: accounts[3],
+        whirlpool: accounts[4],
+        token_vault_a: accounts[5],
+        token_vault_b: accounts[6],
+        fee_tier: accounts[7],
+        token_program_a: accounts[8],
+        token_program_b: accounts[8],
+    }))
+}
+
+/// 解析初始化池 v2 指令事件
+fn parse_initialize_pool_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolInitializePoolV2;
+
+    if data.len() < 18 || accounts.len() < 14 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolInitializePoolEvent(OrcaWhirlpoolInitializePoolEvent {
+        metadata,
+        tick_spacing: read_u16_le(data, 0)?,
+        initial_sqrt_price: read_u128_le(data, 2)?,
+        whirlpools_config: accounts[0],
+        token_mint_a: accounts[1],
+        token_mint_b: accounts[2],
+        funder: accounts[5],
+        whirlpool: accounts[6],
+        token_vault_a: accounts[7],
+        token_vault_b: accounts[8],
+        fee_tier: accounts[9],
+        token_program_a: accounts[10],
+        token_program_b: accounts[11],
+    }))
+}
@@ -0,0 +1,68 @@
+use crate::streaming::event_parser::common::serde_base58;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::phoenix::types::PhoenixSide;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 成交
+///
+/// trader 为吃单方 (Log 头部的 signer)，side 为吃单方向
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixFillEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub trader: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub maker: Pubkey,
+    pub side: PhoenixSide,
+    pub price_in_ticks: u64,
+    pub base_lots_filled: u64,
+    pub base_lots_remaining: u64,
+    pub order_sequence_number: u64,
+    pub sequence_number: u64,
+    pub timestamp: i64,
+}
+
+/// 挂单
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixPlaceEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub trader: Pubkey,
+    pub side: PhoenixSide,
+    pub price_in_ticks: u64,
+    pub base_lots_placed: u64,
+    pub client_order_id: u128,
+    pub order_sequence_number: u64,
+    pub sequence_number: u64,
+    pub timestamp: i64,
+}
+
+/// 减单 / 撤单
+///
+/// base_lots_remaining 为 0 时 event_type 为 PhoenixCancel，否则为 PhoenixReduce
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixReduceEvent {
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub trader: Pubkey,
+    pub side: PhoenixSide,
+    pub price_in_ticks: u64,
+    pub base_lots_removed: u64,
+    pub base_lots_remaining: u64,
+    pub order_sequence_number: u64,
+    pub sequence_number: u64,
+    pub timestamp: i64,
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // Phoenix 使用 1 字节指令 tag，Log 指令为程序自调用输出事件
+    pub const LOG_IX: &[u8] = &[15];
+}
@@ -0,0 +1,154 @@
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{EventMetadata, EventType},
+    protocols::phoenix::{
+        discriminators,
+        types::{AuditLogHeader, PhoenixMarketEvent, PhoenixSide, AUDIT_LOG_HEADER_LEN},
+        PhoenixFillEvent, PhoenixPlaceEvent, PhoenixReduceEvent,
+    },
+    DexEvent,
+};
+
+/// Phoenix程序ID
+pub const PHOENIX_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY");
+
+/// 解析 Phoenix instruction data
+///
+/// Phoenix 的业务指令本身不产生事件，事件全部来自 Log 指令，
+/// 由 `parse_phoenix_log_events` 解析
+pub fn parse_phoenix_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _accounts: &[Pubkey],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 Phoenix inner instruction data
+///
+/// Phoenix 没有 Anchor CPI 事件
+pub fn parse_phoenix_inner_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 Phoenix Log 指令中的事件
+///
+/// Phoenix 通过自调用 Log 指令 (tag 15) 批量输出 `PhoenixMarketEvent`:
+/// 数据为一个 Header 事件，后面紧跟若干个事件。
+/// 目前输出 Fill / Place / Reduce 事件，其余事件 (Evict, FillSummary, Fee 等) 会被跳过。
+pub fn parse_phoenix_log_events(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Vec<DexEvent> {
+    let mut events = Vec::new();
+    if discriminator != discriminators::LOG_IX || data.len() < AUDIT_LOG_HEADER_LEN {
+        return events;
+    }
+
+    let header = match PhoenixMarketEvent::try_from_slice(&data[..AUDIT_LOG_HEADER_LEN]) {
+        Ok(PhoenixMarketEvent::Header { header }) => header,
+        _ => return events,
+    };
+
+    let mut buf = &data[AUDIT_LOG_HEADER_LEN..];
+    while !buf.is_empty() {
+        let market_event = match PhoenixMarketEvent::deserialize(&mut buf) {
+            Ok(e) => e,
+            Err(_) => break,
+        };
+        if let Some(event) = convert_market_event(&header, market_event, metadata.clone()) {
+            events.push(event);
+        }
+    }
+    events
+}
+
+/// 将原始 Phoenix 事件转换为 DexEvent
+fn convert_market_event(
+    header: &AuditLogHeader,
+    market_event: PhoenixMarketEvent,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match market_event {
+        PhoenixMarketEvent::Fill {
+            maker_id,
+            order_sequence_number,
+            price_in_ticks,
+            base_lots_filled,
+            base_lots_remaining,
+            ..
+        } => {
+            metadata.event_type = EventType::PhoenixFill;
+            Some(DexEvent::PhoenixFillEvent(PhoenixFillEvent {
+                metadata,
+                market: header.market,
+                trader: header.signer,
+                maker: maker_id,
+                // 成交的是 maker 的挂单，吃单方向相反
+                side: PhoenixSide::from_order_sequence_number(order_sequence_number).opposite(),
+                price_in_ticks,
+                base_lots_filled,
+                base_lots_remaining,
+                order_sequence_number,
+                sequence_number: header.sequence_number,
+                timestamp: header.timestamp,
+            }))
+        }
+        PhoenixMarketEvent::Place {
+            order_sequence_number,
+            client_order_id,
+            price_in_ticks,
+            base_lots_placed,
+            ..
+        } => {
+            metadata.event_type = EventType::PhoenixPlace;
+            Some(DexEvent::PhoenixPlaceEvent(PhoenixPlaceEvent {
+                metadata,
+                market: header.market,
+                trader: header.signer,
+                side: PhoenixSide::from_order_sequence_number(order_sequence_number),
+                price_in_ticks,
+                base_lots_placed,
+                client_order_id,
+                order_sequence_number,
+                sequence_number: header.sequence_number,
+                timestamp: header.timestamp,
+            }))
+        }
+        PhoenixMarketEvent::Reduce {
+            order_sequence_number,
+            price_in_ticks,
+            base_lots_removed,
+            base_lots_remaining,
+            ..
+        } => {
+            metadata.event_type = if base_lots_remaining == 0 {
+                EventType::PhoenixCancel
+            } else {
+                EventType::PhoenixReduce
+            };
+            Some(DexEvent::PhoenixReduceEvent(PhoenixReduceEvent {
+                metadata,
+                market: header.market,
+                trader: header.signer,
+                side: PhoenixSide::from_order_sequence_number(order_sequence_number),
+                price_in_ticks,
+                base_lots_removed,
+                base_lots_remaining,
+                order_sequence_number,
+                sequence_number: header.sequence_number,
+                timestamp: header.timestamp,
+            }))
+        }
+        _ => None,
+    }
+}
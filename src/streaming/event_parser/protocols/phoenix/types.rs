@@ -0,0 +1,109 @@
+use crate::streaming::event_parser::common::serde_base58;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 订单方向
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PhoenixSide {
+    #[default]
+    Bid,
+    Ask,
+}
+
+impl PhoenixSide {
+    /// Phoenix 的买单使用按位取反的 order_sequence_number，最高位为 1
+    pub fn from_order_sequence_number(order_sequence_number: u64) -> Self {
+        if order_sequence_number >> 63 == 1 {
+            PhoenixSide::Bid
+        } else {
+            PhoenixSide::Ask
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            PhoenixSide::Bid => PhoenixSide::Ask,
+            PhoenixSide::Ask => PhoenixSide::Bid,
+        }
+    }
+}
+
+/// Log 指令中每一批事件前的头部
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AuditLogHeader {
+    pub instruction: u8,
+    pub sequence_number: u64,
+    pub timestamp: i64,
+    pub slot: u64,
+    #[serde(with = "serde_base58::pubkey")]
+    pub market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub signer: Pubkey,
+    pub total_events: u16,
+}
+
+/// Phoenix Log 指令中的原始事件 (borsh 枚举，1 字节 tag)
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+pub enum PhoenixMarketEvent {
+    Uninitialized,
+    Header {
+        header: AuditLogHeader,
+    },
+    Fill {
+        index: u16,
+        maker_id: Pubkey,
+        order_sequence_number: u64,
+        price_in_ticks: u64,
+        base_lots_filled: u64,
+        base_lots_remaining: u64,
+    },
+    Place {
+        index: u16,
+        order_sequence_number: u64,
+        client_order_id: u128,
+        price_in_ticks: u64,
+        base_lots_placed: u64,
+    },
+    Reduce {
+        index: u16,
+        order_sequence_number: u64,
+        price_in_ticks: u64,
+        base_lots_removed: u64,
+        base_lots_remaining: u64,
+    },
+    Evict {
+        index: u16,
+        maker_id: Pubkey,
+        order_sequence_number: u64,
+        price_in_ticks: u64,
+        base_lots_evicted: u64,
+    },
+    FillSummary {
+        index: u16,
+        client_order_id: u128,
+        total_base_lots_filled: u64,
+        total_quote_lots_filled: u64,
+        total_fee_in_quote_lots: u64,
+    },
+    Fee {
+        index: u16,
+        fees_collected_in_quote_lots: u64,
+    },
+    TimeInForce {
+        index: u16,
+        order_sequence_number: u64,
+        last_valid_slot: u64,
+        last_valid_unix_timestamp_in_seconds: u64,
+    },
+    ExpiredOrder {
+        index: u16,
+        maker_id: Pubkey,
+        order_sequence_number: u64,
+        price_in_ticks: u64,
+        base_lots_removed: u64,
+    },
+}
+
+/// Header 事件长度: 1 字节 tag + AuditLogHeader
+pub const AUDIT_LOG_HEADER_LEN: usize = 1 + 1 + 8 + 8 + 8 + 32 + 32 + 2;
@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -12,9 +13,13 @@ pub struct PumpFunCreateTokenEvent {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[serde(with = "serde_base58::pubkey")]
     pub mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub bonding_curve: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
     pub timestamp: i64,
     pub virtual_token_reserves: u64,
@@ -22,12 +27,15 @@ pub struct PumpFunCreateTokenEvent {
     pub real_token_reserves: u64,
     pub token_total_supply: u64,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
     #[borsh(skip)]
     pub is_mayhem_mode: bool,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub mint_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_bonding_curve: Pubkey,
 }
 
@@ -38,20 +46,27 @@ pub struct PumpFunCreateV2TokenEvent {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[serde(with = "serde_base58::pubkey")]
     pub mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub bonding_curve: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
     pub timestamp: i64,
     pub virtual_token_reserves: u64,
     pub virtual_sol_reserves: u64,
     pub real_token_reserves: u64,
     pub token_total_supply: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
     pub is_mayhem_mode: bool,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub mint_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_bonding_curve: Pubkey,
 }
 
@@ -182,19 +197,23 @@ pub fn pumpfun_create_v2_token_event_log_decode(data: &[u8]) -> Option<PumpFunCr
 pub struct PumpFunTradeEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub mint: Pubkey,
     pub sol_amount: u64,
     pub token_amount: u64,
     pub is_buy: bool,
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
     pub timestamp: i64,
     pub virtual_sol_reserves: u64,
     pub virtual_token_reserves: u64,
     pub real_sol_reserves: u64,
     pub real_token_reserves: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub fee_recipient: Pubkey,
     pub fee_basis_points: u64,
     pub fee: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
     pub creator_fee_basis_points: u64,
     pub creator_fee: u64,
@@ -203,6 +222,10 @@ pub struct PumpFunTradeEvent {
     pub total_claimed_tokens: u64,
     pub current_sol_volume: u64,
     pub last_update_timestamp: i64,
+    /// 交易后联合曲线是否已完成 (真实代币储备耗尽，等待迁移)，由交易日志中的储备判断；
+    /// 没有交易日志时 (如 ShredStream) 为 None
+    #[borsh(skip)]
+    pub bonding_curve_complete: Option<bool>,
 
     #[borsh(skip)]
     pub max_sol_cost: u64,
@@ -216,36 +239,49 @@ pub struct PumpFunTradeEvent {
     pub is_dev_create_token_trade: bool, // 是否是dev创建token的交易
 
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub global: Pubkey,
     // #[borsh(skip)]
     // pub fee_recipient: Pubkey,
     // #[borsh(skip)]
     // pub mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_user: Pubkey,
     // #[borsh(skip)]
     // pub user: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub creator_vault: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub global_volume_accumulator: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub user_volume_accumulator: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub fee_config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub fee_program: Pubkey,
 }
 
@@ -258,59 +294,89 @@ pub fn pumpfun_trade_event_log_decode(data: &[u8]) -> Option<PumpFunTradeEvent>
     borsh::from_slice::<PumpFunTradeEvent>(&data[..PUMPFUN_TRADE_EVENT_LOG_SIZE]).ok()
 }
 
+/// 联合曲线完成后迁移到 PumpSwap 的事件 (migrate 指令)
+///
+/// 只在交易中带有 CompletePumpAmmMigrationEvent 日志 (即迁移成功) 时产出，每次迁移一个事件
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpFunMigrateEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub mint: Pubkey,
+    /// 联合曲线最终储备中迁入池子的代币数量
     pub mint_amount: u64,
+    /// 联合曲线最终储备中迁入池子的 SOL (lamports)
     pub sol_amount: u64,
     pub pool_migration_fee: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub bonding_curve: Pubkey,
     pub timestamp: i64,
+    /// 新建的 PumpSwap 池子地址
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
 
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub global: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub withdraw_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pump_amm: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority_mint_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_authority_wsol_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_global_config: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub wsol_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_quote_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub token_2022_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pump_amm_event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub program: Pubkey,
 }
 
@@ -328,9 +394,11 @@ pub fn pumpfun_migrate_event_log_decode(data: &[u8]) -> Option<PumpFunMigrateEve
 pub struct PumpFunBondingCurveAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub bonding_curve: BondingCurve,
@@ -341,9 +409,11 @@ pub struct PumpFunBondingCurveAccountEvent {
 pub struct PumpFunGlobalAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub global: Global,
@@ -13,6 +13,15 @@ use solana_sdk::pubkey::Pubkey;
 pub const PUMPFUN_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
 
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::CREATE_TOKEN_IX, EventType::PumpFunCreateToken),
+    (discriminators::CREATE_V2_TOKEN_IX, EventType::PumpFunCreateV2Token),
+    (discriminators::BUY_IX, EventType::PumpFunBuy),
+    (discriminators::SELL_IX, EventType::PumpFunSell),
+    (discriminators::MIGRATE_IX, EventType::PumpFunMigrate),
+];
+
 /// 解析 PumpFun instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
@@ -103,7 +112,13 @@ fn parse_trade_inner_instruction(data: &[u8], metadata: EventMetadata) -> Option
     // 注意：inner instruction 的 trade event 不设置 event_type
     // 因为它会被合并到 instruction event 中，而 instruction event 已经设置了正确的 event_type
     if let Some(event) = pumpfun_trade_event_log_decode(data) {
-        Some(DexEvent::PumpFunTradeEvent(PumpFunTradeEvent { metadata, ..event }))
+        // 买入耗尽真实代币储备时程序将曲线标记为完成
+        let bonding_curve_complete = Some(event.real_token_reserves == 0);
+        Some(DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+            metadata,
+            bonding_curve_complete,
+            ..event
+        }))
     } else {
         None
     }
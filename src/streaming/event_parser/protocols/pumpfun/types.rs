@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -19,6 +20,7 @@ pub struct BondingCurve {
     pub real_sol_reserves: u64,
     pub token_total_supply: u64,
     pub complete: bool,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
     pub is_mayhem_mode: bool,
 }
@@ -59,22 +61,30 @@ pub fn bonding_curve_parser(
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct Global {
     pub initialized: bool,
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub fee_recipient: Pubkey,
     pub initial_virtual_token_reserves: u64,
     pub initial_virtual_sol_reserves: u64,
     pub initial_real_token_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub withdraw_authority: Pubkey,
     pub enable_migrate: bool,
     pub pool_migration_fee: u64,
     pub creator_fee_basis_points: u64,
+    #[serde(with = "serde_base58::pubkey_array")]
     pub fee_recipients: [Pubkey; 7],
+    #[serde(with = "serde_base58::pubkey")]
     pub set_creator_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub admin_set_creator_authority: Pubkey,
     pub create_v2_enabled: bool,
+    #[serde(with = "serde_base58::pubkey")]
     pub whitelist_pda: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub reserved_fee_recipient: Pubkey,
     pub mayhem_mode_enabled: bool,
 }
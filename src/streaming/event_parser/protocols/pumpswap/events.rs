@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -24,12 +25,19 @@ pub struct PumpSwapBuyEvent {
     pub protocol_fee: u64,
     pub quote_amount_in_with_lp_fee: u64,
     pub user_quote_amount_in: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_base_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_quote_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_fee_recipient: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_fee_recipient_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator: Pubkey,
     pub coin_creator_fee_basis_points: u64,
     pub coin_creator_fee: u64,
@@ -39,20 +47,28 @@ pub struct PumpSwapBuyEvent {
     pub current_sol_volume: u64,
     pub last_update_timestamp: i64,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_quote_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator_vault_ata: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator_vault_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_token_program: Pubkey,
 }
 
@@ -84,30 +100,45 @@ pub struct PumpSwapSellEvent {
     pub protocol_fee: u64,
     pub quote_amount_out_without_lp_fee: u64,
     pub user_quote_amount_out: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_base_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_quote_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_fee_recipient: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_fee_recipient_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator: Pubkey,
     pub coin_creator_fee_basis_points: u64,
     pub coin_creator_fee: u64,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_quote_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator_vault_ata: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator_vault_authority: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_token_program: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_token_program: Pubkey,
 }
 
@@ -127,8 +158,11 @@ pub struct PumpSwapCreatePoolEvent {
     pub metadata: EventMetadata,
     pub timestamp: i64,
     pub index: u16,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
     pub base_mint_decimals: u8,
     pub quote_mint_decimals: u8,
@@ -140,16 +174,24 @@ pub struct PumpSwapCreatePoolEvent {
     pub initial_liquidity: u64,
     pub lp_token_amount_out: u64,
     pub pool_bump: u8,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_base_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_quote_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_quote_token_account: Pubkey,
 }
 
@@ -162,7 +204,10 @@ pub fn pump_swap_create_pool_event_log_decode(data: &[u8]) -> Option<PumpSwapCre
     borsh::from_slice::<PumpSwapCreatePoolEvent>(&data[..PUMP_SWAP_CREATE_POOL_EVENT_LOG_SIZE]).ok()
 }
 
-/// 存款事件
+/// 存款事件 (添加流动性)
+///
+/// 指令阶段只有 `lp_token_amount_out` 和最大投入数量；合并 CPI 日志后 `base_amount_in` /
+/// `quote_amount_in` 为实际投入数量，储备和 `lp_mint_supply` 为存款后的值
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpSwapDepositEvent {
     #[borsh(skip)]
@@ -178,21 +223,37 @@ pub struct PumpSwapDepositEvent {
     pub base_amount_in: u64,
     pub quote_amount_in: u64,
     pub lp_mint_supply: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_base_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_quote_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_quote_token_account: Pubkey,
 }
 
+impl PumpSwapDepositEvent {
+    /// LP 代币供应量的变化，存款为正
+    pub fn lp_token_delta(&self) -> i128 {
+        self.lp_token_amount_out as i128
+    }
+}
+
 pub const PUMP_SWAP_DEPOSIT_EVENT_LOG_SIZE: usize = 248;
 
 pub fn pump_swap_deposit_event_log_decode(data: &[u8]) -> Option<PumpSwapDepositEvent> {
@@ -202,7 +263,10 @@ pub fn pump_swap_deposit_event_log_decode(data: &[u8]) -> Option<PumpSwapDeposit
     borsh::from_slice::<PumpSwapDepositEvent>(&data[..PUMP_SWAP_DEPOSIT_EVENT_LOG_SIZE]).ok()
 }
 
-/// 提款事件
+/// 提款事件 (移除流动性)
+///
+/// 指令阶段只有 `lp_token_amount_in` 和最小取出数量；合并 CPI 日志后 `base_amount_out` /
+/// `quote_amount_out` 为实际取出数量，储备和 `lp_mint_supply` 为提款后的值
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpSwapWithdrawEvent {
     #[borsh(skip)]
@@ -218,21 +282,37 @@ pub struct PumpSwapWithdrawEvent {
     pub base_amount_out: u64,
     pub quote_amount_out: u64,
     pub lp_mint_supply: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_base_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_quote_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_quote_token_account: Pubkey,
 }
 
+impl PumpSwapWithdrawEvent {
+    /// LP 代币供应量的变化，提款为负
+    pub fn lp_token_delta(&self) -> i128 {
+        -(self.lp_token_amount_in as i128)
+    }
+}
+
 pub const PUMP_SWAP_WITHDRAW_EVENT_LOG_SIZE: usize = 248;
 
 pub fn pump_swap_withdraw_event_log_decode(data: &[u8]) -> Option<PumpSwapWithdrawEvent> {
@@ -247,9 +327,11 @@ pub fn pump_swap_withdraw_event_log_decode(data: &[u8]) -> Option<PumpSwapWithdr
 pub struct PumpSwapGlobalConfigAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub global_config: GlobalConfig,
@@ -260,9 +342,11 @@ pub struct PumpSwapGlobalConfigAccountEvent {
 pub struct PumpSwapPoolAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub pool: Pool,
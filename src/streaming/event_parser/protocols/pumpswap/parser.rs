@@ -14,6 +14,15 @@ use solana_sdk::pubkey::Pubkey;
 pub const PUMPSWAP_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA");
 
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::BUY_IX, EventType::PumpSwapBuy),
+    (discriminators::SELL_IX, EventType::PumpSwapSell),
+    (discriminators::CREATE_POOL_IX, EventType::PumpSwapCreatePool),
+    (discriminators::DEPOSIT_IX, EventType::PumpSwapDeposit),
+    (discriminators::WITHDRAW_IX, EventType::PumpSwapWithdraw),
+];
+
 /// 解析 PumpSwap instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
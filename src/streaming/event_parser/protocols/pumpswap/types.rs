@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -13,14 +14,19 @@ use crate::streaming::{
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct GlobalConfig {
+    #[serde(with = "serde_base58::pubkey")]
     pub admin: Pubkey,
     pub lp_fee_basis_points: u64,
     pub protocol_fee_basis_points: u64,
     pub disable_flags: u8,
+    #[serde(with = "serde_base58::pubkey_array")]
     pub protocol_fee_recipients: [Pubkey; 8],
     pub coin_creator_fee_basis_points: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub admin_set_coin_creator_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub whitelist_pda: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub reserved_fee_recipient: Pubkey,
     pub mayhem_mode_enabled: bool,
 }
@@ -62,13 +68,20 @@ pub fn global_config_parser(
 pub struct Pool {
     pub pool_bump: u8,
     pub index: u16,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub base_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub quote_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_base_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_quote_token_account: Pubkey,
     pub lp_supply: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_creator: Pubkey,
     pub is_mayhem_mode: bool,
 }
@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::{
     streaming::event_parser::protocols::raydium_amm_v4::types::AmmInfo,
@@ -18,23 +19,41 @@ pub struct RaydiumAmmV4SwapEvent {
     pub max_amount_in: u64,
     pub amount_out: u64,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_open_orders: Pubkey,
+    #[serde(with = "serde_base58::option_pubkey")]
     pub amm_target_orders: Option<Pubkey>,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_coin_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_pc_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_bids: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_asks: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_event_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_coin_vault_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_pc_vault_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_vault_signer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_source_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_destination_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_source_owner: Pubkey,
 }
 
@@ -47,19 +66,33 @@ pub struct RaydiumAmmV4DepositEvent {
     pub max_pc_amount: u64,
     pub base_side: u64,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_open_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_target_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint_address: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_coin_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_pc_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_coin_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_pc_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_lp_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_event_queue: Pubkey,
 }
 
@@ -73,26 +106,47 @@ pub struct RaydiumAmmV4Initialize2Event {
     pub init_pc_amount: u64,
     pub init_coin_amount: u64,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub spl_associated_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub rent: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_open_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pc_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_coin_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_pc_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_withdraw_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_target_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_temp_lp: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_wallet: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_token_coin: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_token_pc: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_lp_token_account: Pubkey,
 }
 
@@ -103,27 +157,49 @@ pub struct RaydiumAmmV4WithdrawEvent {
     pub metadata: EventMetadata,
     pub amount: u64,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_open_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_target_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint_address: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_coin_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_pc_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_withdraw_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_temp_lp_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_coin_vault_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_pc_vault_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_vault_signer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_lp_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_coin_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_pc_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub user_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_event_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_bids: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_asks: Pubkey,
 }
 
@@ -133,22 +209,39 @@ pub struct RaydiumAmmV4WithdrawPnlEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_open_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_coin_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_pc_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_pnl_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pc_pnl_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pnl_owner_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_target_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_event_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_coin_vault_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_pc_vault_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_vault_signer: Pubkey,
 }
 
@@ -157,9 +250,11 @@ pub struct RaydiumAmmV4WithdrawPnlEvent {
 pub struct RaydiumAmmV4AmmInfoAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub amm_info: AmmInfo,
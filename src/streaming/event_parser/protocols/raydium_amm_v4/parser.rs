@@ -12,6 +12,16 @@ use solana_sdk::pubkey::Pubkey;
 pub const RAYDIUM_AMM_V4_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
 
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::SWAP_BASE_IN, EventType::RaydiumAmmV4SwapBaseIn),
+    (discriminators::SWAP_BASE_OUT, EventType::RaydiumAmmV4SwapBaseOut),
+    (discriminators::DEPOSIT, EventType::RaydiumAmmV4Deposit),
+    (discriminators::INITIALIZE2, EventType::RaydiumAmmV4Initialize2),
+    (discriminators::WITHDRAW, EventType::RaydiumAmmV4Withdraw),
+    (discriminators::WITHDRAW_PNL, EventType::RaydiumAmmV4WithdrawPnl),
+];
+
 /// 解析 Raydium AMM V4 instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -61,17 +62,29 @@ pub struct AmmInfo {
     pub sys_decimal_value: u64,
     pub fees: Fees,
     pub out_put: OutPutData,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_coin: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_pc: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pc_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub open_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub market: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_dex: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub target_orders: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub withdraw_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_temp_lp: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_owner: Pubkey,
     pub lp_amount: u64,
     pub client_order_id: u64,
@@ -112,20 +125,29 @@ pub fn amm_info_parser(account: &AccountPretty, mut metadata: EventMetadata) ->
 pub struct MarketState {
     pub padding: [u8; 5],
     pub account_flags: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub own_address: Pubkey,
     pub vault_signer_nonce: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub coin_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pc_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_coin_vault_account: Pubkey,
     pub coin_deposits_total: u64,
     pub coin_fees_accrued: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_pc_vault_account: Pubkey,
     pub pc_deposits_total: u64,
     pub pc_fees_accrued: u64,
     pub pc_dust_threshold: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub request_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_event_queue: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_bids: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub serum_asks: Pubkey,
     pub coin_lot_size: u64,
     pub pc_lot_size: u64,
@@ -1,8 +1,10 @@
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::serde_base58;
+use crate::streaming::event_parser::common::{sqrt_price_to_price, EventMetadata};
 use crate::streaming::event_parser::protocols::raydium_clmm::types::{PoolState, TickArrayState};
 use crate::{
     streaming::event_parser::protocols::raydium_clmm::types::AmmConfig,
 };
+use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
@@ -14,16 +16,27 @@ pub struct RaydiumClmmSwapEvent {
     pub other_amount_threshold: u64,
     pub sqrt_price_limit_x64: u128,
     pub is_base_input: bool,
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub observation_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array: Pubkey,
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
@@ -36,19 +49,33 @@ pub struct RaydiumClmmSwapV2Event {
     pub other_amount_threshold: u64,
     pub sqrt_price_limit_x64: u128,
     pub is_base_input: bool,
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub observation_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program2022: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub memo_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_vault_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_vault_mint: Pubkey,
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
@@ -56,11 +83,17 @@ pub struct RaydiumClmmSwapV2Event {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RaydiumClmmClosePositionEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
 }
 
@@ -71,22 +104,39 @@ pub struct RaydiumClmmDecreaseLiquidityV2Event {
     pub liquidity: u128,
     pub amount0_min: u64,
     pub amount1_min: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_upper: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub recipient_token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub recipient_token_account1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program2022: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub memo_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault1_mint: Pubkey,
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
@@ -96,18 +146,31 @@ pub struct RaydiumClmmCreatePoolEvent {
     pub metadata: EventMetadata,
     pub sqrt_price_x64: u128,
     pub open_time: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_mint0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_mint1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub observation_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_bitmap: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub rent: Pubkey,
 }
 
@@ -119,20 +182,35 @@ pub struct RaydiumClmmIncreaseLiquidityV2Event {
     pub amount0_max: u64,
     pub amount1_max: u64,
     pub base_flag: Option<bool>,
+    #[serde(with = "serde_base58::pubkey")]
     pub nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_upper: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_account1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program2022: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault1_mint: Pubkey,
 }
 
@@ -150,25 +228,45 @@ pub struct RaydiumClmmOpenPositionWithToken22NftEvent {
     pub with_metadata: bool,
     pub base_flag: Option<bool>,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_upper: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_account1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub rent: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program2022: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault1_mint: Pubkey,
 }
 
@@ -186,38 +284,292 @@ pub struct RaydiumClmmOpenPositionV2Event {
     pub with_metadata: bool,
     pub base_flag: Option<bool>,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub position_nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub metadata_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub tick_array_upper: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_account1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub rent: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub metadata_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program2022: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault1_mint: Pubkey,
+    #[serde(with = "serde_base58::vec_pubkey")]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
+/// 打开仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmOpenPositionEvent {
+    pub metadata: EventMetadata,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount0_max: u64,
+    pub amount1_max: u64,
+
+    #[serde(with = "serde_base58::pubkey")]
+    pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_nft_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub metadata_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub protocol_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_upper: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_account1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub rent: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub associated_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub metadata_program: Pubkey,
+}
+
+/// 增加流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmIncreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity: u128,
+    pub amount0_max: u64,
+    pub amount1_max: u64,
+    #[serde(with = "serde_base58::pubkey")]
+    pub nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub protocol_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_upper: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_account1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program: Pubkey,
+}
+
+/// 减少流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmDecreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity: u128,
+    pub amount0_min: u64,
+    pub amount1_min: u64,
+    #[serde(with = "serde_base58::pubkey")]
+    pub nft_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub nft_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub personal_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub protocol_position: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_lower: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub tick_array_upper: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub recipient_token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub recipient_token_account1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_program: Pubkey,
+    #[serde(with = "serde_base58::vec_pubkey")]
+    pub remaining_accounts: Vec<Pubkey>,
+}
+
+/// 仓位增加流动性的结果 (程序日志 IncreaseLiquidityEvent)
+///
+/// 由 open_position 和 increase_liquidity 系列指令输出，`amount0` / `amount1` 为实际存入数量
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumClmmIncreaseLiquidityLogEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub amount0: u64,
+    pub amount1: u64,
+}
+
+/// 仓位减少流动性的结果 (程序日志 DecreaseLiquidityEvent)
+///
+/// `decrease_amount0` / `decrease_amount1` 为取出的本金，`fee_amount0` / `fee_amount1` 为同时领取的手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumClmmDecreaseLiquidityLogEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub decrease_amount0: u64,
+    pub decrease_amount1: u64,
+    pub fee_amount0: u64,
+    pub fee_amount1: u64,
+    pub reward_amounts: [u64; 3],
+}
+
+/// 领取仓位手续费 (程序日志 CollectPersonalFeeEvent)
+///
+/// CLMM 没有单独的领取指令，手续费在 decrease_liquidity 系列指令中领取 (liquidity 可以为 0)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumClmmCollectPersonalFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub position_nft_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub recipient_token_account0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub recipient_token_account1: Pubkey,
+    pub amount0: u64,
+    pub amount1: u64,
+}
+
+/// 池子在价格区间内的流动性变化 (程序日志 LiquidityChangeEvent)
+///
+/// 与同一指令的 IncreaseLiquidityLog / DecreaseLiquidityLog 一起出现，提供仓位的 tick 区间
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumClmmLiquidityChangeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool_state: Pubkey,
+    /// 池子当前 tick
+    pub tick: i32,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    /// 变化前池子的当前流动性
+    pub liquidity_before: u128,
+    /// 变化后池子的当前流动性
+    pub liquidity_after: u128,
+}
+
+impl RaydiumClmmLiquidityChangeEvent {
+    /// 池子当前流动性的变化量，当前 tick 不在仓位区间内时为 0
+    pub fn liquidity_delta(&self) -> i128 {
+        self.liquidity_after as i128 - self.liquidity_before as i128
+    }
+}
+
+/// 交易结果 (程序日志 SwapEvent)
+///
+/// 由 swap / swap_v2 指令输出，`amount_0` / `amount_1` 为实际成交数量，
+/// `sqrt_price_x64`、`liquidity` 和 `tick` 为交易后池子的状态
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumClmmSwapLogEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub sender: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_account_0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub token_account_1: Pubkey,
+    pub amount_0: u64,
+    pub transfer_fee_0: u64,
+    pub amount_1: u64,
+    pub transfer_fee_1: u64,
+    /// true 表示 token0 换 token1
+    pub zero_for_one: bool,
+    /// 交易后的平方根价格，Q64.64 定点数
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
+}
+
+impl RaydiumClmmSwapLogEvent {
+    /// 交易后的价格 (每个 token0 可换得的 token1，界面单位)，见 `sqrt_price_to_price`
+    pub fn price(&self, decimals_0: u8, decimals_1: u8) -> f64 {
+        sqrt_price_to_price(self.sqrt_price_x64, decimals_0, decimals_1)
+    }
+}
+
 /// 池配置
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RaydiumClmmAmmConfigAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub amm_config: AmmConfig,
@@ -227,9 +579,11 @@ pub struct RaydiumClmmAmmConfigAccountEvent {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RaydiumClmmPoolStateAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub pool_state: PoolState,
@@ -239,9 +593,11 @@ pub struct RaydiumClmmPoolStateAccountEvent {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RaydiumClmmTickArrayStateAccountEvent {
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub tick_array_state: TickArrayState,
@@ -258,9 +614,37 @@ pub mod discriminators {
     pub const CREATE_POOL: &[u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
     pub const OPEN_POSITION_WITH_TOKEN_22_NFT: &[u8] = &[77, 255, 174, 82, 125, 29, 201, 46];
     pub const OPEN_POSITION_V2: &[u8] = &[77, 184, 74, 214, 112, 86, 241, 199];
+    pub const OPEN_POSITION: &[u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+    pub const INCREASE_LIQUIDITY: &[u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+    pub const DECREASE_LIQUIDITY: &[u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+
+    // 程序日志事件鉴别器
+    pub const INCREASE_LIQUIDITY_EVENT: &[u8] = &[49, 79, 105, 212, 32, 34, 30, 84];
+    pub const DECREASE_LIQUIDITY_EVENT: &[u8] = &[58, 222, 86, 58, 68, 50, 85, 56];
+    pub const COLLECT_PERSONAL_FEE_EVENT: &[u8] = &[166, 174, 105, 192, 81, 161, 83, 105];
+    pub const LIQUIDITY_CHANGE_EVENT: &[u8] = &[126, 240, 175, 206, 158, 88, 153, 107];
+    pub const SWAP_EVENT: &[u8] = &[64, 198, 205, 232, 38, 8, 113, 226];
 
     // 账号鉴别器
     pub const AMM_CONFIG: &[u8] = &[218, 244, 33, 104, 203, 203, 43, 111];
     pub const POOL_STATE: &[u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
     pub const TICK_ARRAY_STATE: &[u8] = &[192, 155, 85, 205, 49, 249, 129, 42];
 }
+
+/// 程序日志事件的长度 (不含 8 字节判别器)
+///
+/// 新版程序在 IncreaseLiquidityEvent / DecreaseLiquidityEvent 末尾追加了 Token-2022
+/// 转账手续费字段，这里只解析新旧版本共有的前缀
+pub const INCREASE_LIQUIDITY_EVENT_LOG_SIZE: usize = 32 + 16 + 8 * 2;
+pub const DECREASE_LIQUIDITY_EVENT_LOG_SIZE: usize = 32 + 16 + 8 * 4 + 8 * 3;
+pub const COLLECT_PERSONAL_FEE_EVENT_LOG_SIZE: usize = 32 * 3 + 8 * 2;
+pub const LIQUIDITY_CHANGE_EVENT_LOG_SIZE: usize = 32 + 4 * 3 + 16 * 2;
+pub const SWAP_EVENT_LOG_SIZE: usize = 32 * 4 + 8 * 4 + 1 + 16 * 2 + 4;
+
+/// 从程序日志解码事件，`data` 不含判别器
+pub fn raydium_clmm_log_event_decode<T: BorshDeserialize>(data: &[u8], size: usize) -> Option<T> {
+    if data.len() < size {
+        return None;
+    }
+    borsh::from_slice::<T>(&data[..size]).ok()
+}
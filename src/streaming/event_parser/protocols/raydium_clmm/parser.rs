@@ -4,10 +4,16 @@ use crate::streaming::event_parser::{
         EventType,
     },
     protocols::raydium_clmm::{
-        discriminators, RaydiumClmmClosePositionEvent, RaydiumClmmCreatePoolEvent,
-        RaydiumClmmDecreaseLiquidityV2Event, RaydiumClmmIncreaseLiquidityV2Event,
+        discriminators, raydium_clmm_log_event_decode, RaydiumClmmClosePositionEvent,
+        RaydiumClmmCollectPersonalFeeEvent, RaydiumClmmCreatePoolEvent,
+        RaydiumClmmDecreaseLiquidityEvent, RaydiumClmmDecreaseLiquidityLogEvent,
+        RaydiumClmmDecreaseLiquidityV2Event, RaydiumClmmIncreaseLiquidityEvent,
+        RaydiumClmmIncreaseLiquidityLogEvent, RaydiumClmmIncreaseLiquidityV2Event,
+        RaydiumClmmLiquidityChangeEvent, RaydiumClmmOpenPositionEvent,
         RaydiumClmmOpenPositionV2Event, RaydiumClmmOpenPositionWithToken22NftEvent,
-        RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event,
+        RaydiumClmmSwapEvent, RaydiumClmmSwapLogEvent, RaydiumClmmSwapV2Event,
+        COLLECT_PERSONAL_FEE_EVENT_LOG_SIZE, DECREASE_LIQUIDITY_EVENT_LOG_SIZE,
+        INCREASE_LIQUIDITY_EVENT_LOG_SIZE, LIQUIDITY_CHANGE_EVENT_LOG_SIZE, SWAP_EVENT_LOG_SIZE,
     },
     DexEvent,
 };
@@ -17,6 +23,21 @@ use solana_sdk::pubkey::Pubkey;
 pub const RAYDIUM_CLMM_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
 
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::SWAP, EventType::RaydiumClmmSwap),
+    (discriminators::SWAP_V2, EventType::RaydiumClmmSwapV2),
+    (discriminators::CLOSE_POSITION, EventType::RaydiumClmmClosePosition),
+    (discriminators::DECREASE_LIQUIDITY_V2, EventType::RaydiumClmmDecreaseLiquidityV2),
+    (discriminators::CREATE_POOL, EventType::RaydiumClmmCreatePool),
+    (discriminators::INCREASE_LIQUIDITY_V2, EventType::RaydiumClmmIncreaseLiquidityV2),
+    (discriminators::OPEN_POSITION_WITH_TOKEN_22_NFT, EventType::RaydiumClmmOpenPositionWithToken22Nft),
+    (discriminators::OPEN_POSITION_V2, EventType::RaydiumClmmOpenPositionV2),
+    (discriminators::OPEN_POSITION, EventType::RaydiumClmmOpenPosition),
+    (discriminators::INCREASE_LIQUIDITY, EventType::RaydiumClmmIncreaseLiquidity),
+    (discriminators::DECREASE_LIQUIDITY, EventType::RaydiumClmmDecreaseLiquidity),
+];
+
 /// 解析 Raydium CLMM instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
@@ -45,6 +66,13 @@ pub fn parse_raydium_clmm_instruction_data(
         discriminators::OPEN_POSITION_V2 => {
             parse_open_position_v2_instruction(data, accounts, metadata)
         }
+        discriminators::OPEN_POSITION => parse_open_position_instruction(data, accounts, metadata),
+        discriminators::INCREASE_LIQUIDITY => {
+            parse_increase_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::DECREASE_LIQUIDITY => {
+            parse_decrease_liquidity_instruction(data, accounts, metadata)
+        }
         _ => None,
     }
 }
@@ -60,6 +88,58 @@ pub fn parse_raydium_clmm_inner_instruction_data(
     None
 }
 
+/// 解析 Raydium CLMM 通过 `emit!` 输出到交易日志的事件
+///
+/// 指令本身只有请求的流动性和滑点上限，仓位的 NFT mint、实际存取数量、领取的手续费、
+/// tick 区间以及交易后的价格来自程序日志。`data` 为 base64 解码后的日志，
+/// 前 8 字节为 Anchor 事件判别器。未识别的日志会被跳过
+pub fn parse_raydium_clmm_program_data(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    if data.len() < 8 {
+        return None;
+    }
+    let (discriminator, data) = data.split_at(8);
+    match discriminator {
+        discriminators::INCREASE_LIQUIDITY_EVENT => {
+            metadata.event_type = EventType::RaydiumClmmIncreaseLiquidityLog;
+            let mut event: RaydiumClmmIncreaseLiquidityLogEvent =
+                raydium_clmm_log_event_decode(data, INCREASE_LIQUIDITY_EVENT_LOG_SIZE)?;
+            event.metadata = metadata;
+            Some(DexEvent::RaydiumClmmIncreaseLiquidityLogEvent(event))
+        }
+        discriminators::DECREASE_LIQUIDITY_EVENT => {
+            metadata.event_type = EventType::RaydiumClmmDecreaseLiquidityLog;
+            let mut event: RaydiumClmmDecreaseLiquidityLogEvent =
+                raydium_clmm_log_event_decode(data, DECREASE_LIQUIDITY_EVENT_LOG_SIZE)?;
+            event.metadata = metadata;
+            Some(DexEvent::RaydiumClmmDecreaseLiquidityLogEvent(event))
+        }
+        discriminators::COLLECT_PERSONAL_FEE_EVENT => {
+            metadata.event_type = EventType::RaydiumClmmCollectPersonalFee;
+            let mut event: RaydiumClmmCollectPersonalFeeEvent =
+                raydium_clmm_log_event_decode(data, COLLECT_PERSONAL_FEE_EVENT_LOG_SIZE)?;
+            event.metadata = metadata;
+            Some(DexEvent::RaydiumClmmCollectPersonalFeeEvent(event))
+        }
+        discriminators::LIQUIDITY_CHANGE_EVENT => {
+            metadata.event_type = EventType::RaydiumClmmLiquidityChange;
+            let mut event: RaydiumClmmLiquidityChangeEvent =
+                raydium_clmm_log_event_decode(data, LIQUIDITY_CHANGE_EVENT_LOG_SIZE)?;
+            event.metadata = metadata;
+            Some(DexEvent::RaydiumClmmLiquidityChangeEvent(event))
+        }
+        discriminators::SWAP_EVENT => {
+            metadata.event_type = EventType::RaydiumClmmSwapLog;
+            let mut event: RaydiumClmmSwapLogEvent =
+                raydium_clmm_log_event_decode(data, SWAP_EVENT_LOG_SIZE)?;
+            event.metadata = metadata;
+            Some(DexEvent::RaydiumClmmSwapLogEvent(event))
+        }
+        _ => None,
+    }
+}
 
 /// 解析 Raydium CLMM 账户数据
 ///
@@ -83,6 +163,111 @@ pub fn parse_raydium_clmm_account_data(
     }
 }
 
+/// 解析打开仓位指令事件
+fn parse_open_position_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmOpenPosition;
+
+    if data.len() < 48 || accounts.len() < 19 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmOpenPositionEvent(RaydiumClmmOpenPositionEvent {
+        metadata,
+        tick_lower_index: read_i32_le(data, 0)?,
+        tick_upper_index: read_i32_le(data, 4)?,
+        tick_array_lower_start_index: read_i32_le(data, 8)?,
+        tick_array_upper_start_index: read_i32_le(data, 12)?,
+        liquidity: read_u128_le(data, 16)?,
+        amount0_max: read_u64_le(data, 32)?,
+        amount1_max: read_u64_le(data, 40)?,
+        payer: accounts[0],
+        position_nft_owner: accounts[1],
+        position_nft_mint: accounts[2],
+        position_nft_account: accounts[3],
+        metadata_account: accounts[4],
+        pool_state: accounts[5],
+        protocol_position: accounts[6],
+        tick_array_lower: accounts[7],
+        tick_array_upper: accounts[8],
+        personal_position: accounts[9],
+        token_account0: accounts[10],
+        token_account1: accounts[11],
+        token_vault0: accounts[12],
+        token_vault1: accounts[13],
+        rent: accounts[14],
+        system_program: accounts[15],
+        token_program: accounts[16],
+        associated_token_program: accounts[17],
+        metadata_program: accounts[18],
+    }))
+}
+
+/// 解析增加流动性指令事件
+fn parse_increase_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmIncreaseLiquidity;
+
+    if data.len() < 32 || accounts.len() < 12 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmIncreaseLiquidityEvent(RaydiumClmmIncreaseLiquidityEvent {
+        metadata,
+        liquidity: read_u128_le(data, 0)?,
+        amount0_max: read_u64_le(data, 16)?,
+        amount1_max: read_u64_le(data, 24)?,
+        nft_owner: accounts[0],
+        nft_account: accounts[1],
+        pool_state: accounts[2],
+        protocol_position: accounts[3],
+        personal_position: accounts[4],
+        tick_array_lower: accounts[5],
+        tick_array_upper: accounts[6],
+        token_account0: accounts[7],
+        token_account1: accounts[8],
+        token_vault0: accounts[9],
+        token_vault1: accounts[10],
+        token_program: accounts[11],
+    }))
+}
+
+/// 解析减少流动性指令事件
+fn parse_decrease_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmDecreaseLiquidity;
+
+    if data.len() < 32 || accounts.len() < 12 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmDecreaseLiquidityEvent(RaydiumClmmDecreaseLiquidityEvent {
+        metadata,
+        liquidity: read_u128_le(data, 0)?,
+        amount0_min: read_u64_le(data, 16)?,
+        amount1_min: read_u64_le(data, 24)?,
+        nft_owner: accounts[0],
+        nft_account: accounts[1],
+        personal_position: accounts[2],
+        pool_state: accounts[3],
+        protocol_position: accounts[4],
+        token_vault0: accounts[5],
+        token_vault1: accounts[6],
+        tick_array_lower: accounts[7],
+        tick_array_upper: accounts[8],
+        recipient_token_account0: accounts[9],
+        recipient_token_account1: accounts[10],
+        token_program: accounts[11],
+        remaining_accounts: accounts[12..].to_vec(),
+    }))
+}
+
 /// 解析打开仓位V2指令事件
 fn parse_open_position_v2_instruction(
     data: &[u8],
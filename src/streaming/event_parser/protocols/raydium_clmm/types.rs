@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -18,12 +19,14 @@ use crate::streaming::{
 pub struct AmmConfig {
     pub bump: u8,
     pub index: u16,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub protocol_fee_rate: u32,
     pub trade_fee_rate: u32,
     pub tick_spacing: u16,
     pub fund_fee_rate: u32,
     pub padding_u32: u32,
+    #[serde(with = "serde_base58::pubkey")]
     pub fund_owner: Pubkey,
     pub padding: [u64; 3],
 }
@@ -67,8 +70,11 @@ pub struct RewardInfo {
     pub emissions_per_second_x64: u128,
     pub reward_total_emissioned: u64,
     pub reward_claimed: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
     pub reward_growth_global_x64: u128,
 }
@@ -76,12 +82,19 @@ pub struct RewardInfo {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PoolState {
     pub bump: [u8; 1],
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_mint0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_mint1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_vault1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub observation_key: Pubkey,
     pub mint_decimals0: u8,
     pub mint_decimals1: u8,
@@ -172,6 +185,7 @@ impl Default for TickState {
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct TickArrayState {
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_id: Pubkey,
     pub start_tick_index: i32,
     #[serde(with = "serde_big_array::BigArray")]
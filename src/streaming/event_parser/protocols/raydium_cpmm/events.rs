@@ -1,3 +1,4 @@
+use crate::streaming::event_parser::common::serde_base58;
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::streaming::event_parser::protocols::raydium_cpmm::types::PoolState;
 use crate::{
@@ -16,19 +17,48 @@ pub struct RaydiumCpmmSwapEvent {
     pub minimum_amount_out: u64,
     pub max_amount_in: u64,
     pub amount_out: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub payer: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_token_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub input_token_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub output_token_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub observation_state: Pubkey,
+
+    // 按 AmmConfig 的费率和输入 vault 实际收到的数量计算，单位为输入代币的最小单位；
+    // 没有交易 meta 或 `amm_config` 不在缓存中 (见 `set_raydium_cpmm_amm_config`) 时为 None；
+    // 同一笔交易中对同一个池子多次兑换、vault 的变化与本指令的数量不一致时也为 None
+    /// 交易手续费总额，`lp_fee + protocol_fee + fund_fee`
+    #[borsh(skip)]
+    pub trade_fee: Option<u64>,
+    /// 留在池子中归 LP 的部分
+    #[borsh(skip)]
+    pub lp_fee: Option<u64>,
+    /// 协议费
+    #[borsh(skip)]
+    pub protocol_fee: Option<u64>,
+    /// 基金费
+    #[borsh(skip)]
+    pub fund_fee: Option<u64>,
 }
 
 
@@ -41,18 +71,31 @@ pub struct RaydiumCpmmDepositEvent {
     pub maximum_token0_amount: u64,
     pub maximum_token1_amount: u64,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner_lp_token: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program2022: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault1_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
 }
 
@@ -65,25 +108,45 @@ pub struct RaydiumCpmmInitializeEvent {
     pub init_amount1: u64,
     pub open_time: u64,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator_token0: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator_token1: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub creator_lp_token: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub create_pool_fee: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub observation_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub associated_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub system_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub rent: Pubkey,
 }
 
@@ -96,19 +159,33 @@ pub struct RaydiumCpmmWithdrawEvent {
     pub minimum_token0_amount: u64,
     pub minimum_token1_amount: u64,
 
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub authority: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner_lp_token: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_account: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token_program2022: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub vault1_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub memo_program: Pubkey,
 }
 
@@ -117,9 +194,11 @@ pub struct RaydiumCpmmWithdrawEvent {
 pub struct RaydiumCpmmAmmConfigAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub amm_config: AmmConfig,
@@ -130,9 +209,11 @@ pub struct RaydiumCpmmAmmConfigAccountEvent {
 pub struct RaydiumCpmmPoolStateAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[serde(with = "serde_base58::pubkey")]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub pool_state: PoolState,
@@ -3,16 +3,28 @@ use solana_sdk::pubkey::Pubkey;
 use crate::streaming::event_parser::{
     common::{read_u64_le, EventMetadata, EventType},
     protocols::raydium_cpmm::{
-        discriminators, RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent, RaydiumCpmmSwapEvent,
-        RaydiumCpmmWithdrawEvent,
+        discriminators, types::raydium_cpmm_amm_config, RaydiumCpmmDepositEvent,
+        RaydiumCpmmInitializeEvent, RaydiumCpmmSwapEvent, RaydiumCpmmWithdrawEvent,
     },
     DexEvent,
 };
 
+/// 费率的分母，AmmConfig 中的费率均为百万分之一
+const FEE_RATE_DENOMINATOR: u128 = 1_000_000;
+
 /// Raydium CPMM程序ID
 pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
 
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::SWAP_BASE_IN, EventType::RaydiumCpmmSwapBaseInput),
+    (discriminators::SWAP_BASE_OUT, EventType::RaydiumCpmmSwapBaseOutput),
+    (discriminators::DEPOSIT, EventType::RaydiumCpmmDeposit),
+    (discriminators::INITIALIZE, EventType::RaydiumCpmmInitialize),
+    (discriminators::WITHDRAW, EventType::RaydiumCpmmWithdraw),
+];
+
 /// 解析 Raydium CPMM instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
@@ -242,3 +254,99 @@ fn parse_swap_base_output_instruction(
         ..Default::default()
     }))
 }
+
+/// 用输入 vault 的余额变化和缓存的 AmmConfig 计算 CPMM swap 的手续费拆分，其他事件不做处理
+///
+/// 需要先填充 `metadata.balance_changes`。交易手续费向上取整，协议费和基金费从中向下取整，
+/// 其余归 LP
+///
+/// vault 的余额变化是整笔交易的合计，同一笔交易中对同一个池子多次兑换时无法拆分到每条指令，
+/// 方向相反时甚至会相互抵消。因此只在 vault 的变化与本指令自身的数量一致时填充：
+/// swap_base_input 要求输入 vault 收到 `amount_in`，swap_base_output 要求输出 vault 付出
+/// `amount_out`，否则保持 None；收取 Token-2022 转账手续费的代币数量不一致，同样保持 None
+pub fn fill_raydium_cpmm_fees_from_balances(event: &mut DexEvent) {
+    let DexEvent::RaydiumCpmmSwapEvent(e) = event else {
+        return;
+    };
+    let Some(config) = raydium_cpmm_amm_config(&e.amm_config) else {
+        return;
+    };
+    let vault_change =
+        |vault: &Pubkey| e.metadata.balance_changes.iter().find(|change| change.account == *vault);
+    let Some(received) = vault_change(&e.input_vault)
+        .and_then(|change| change.post_amount.checked_sub(change.pre_amount))
+    else {
+        return;
+    };
+    let single_swap = match e.metadata.event_type {
+        EventType::RaydiumCpmmSwapBaseInput => received == e.amount_in,
+        EventType::RaydiumCpmmSwapBaseOutput => vault_change(&e.output_vault)
+            .and_then(|change| change.pre_amount.checked_sub(change.post_amount))
+            .is_some_and(|sent| sent == e.amount_out),
+        _ => false,
+    };
+    if !single_swap {
+        return;
+    }
+    let amount_in = received as u128;
+    let trade_fee = (amount_in * config.trade_fee_rate as u128).div_ceil(FEE_RATE_DENOMINATOR);
+    let protocol_fee = trade_fee * config.protocol_fee_rate as u128 / FEE_RATE_DENOMINATOR;
+    let fund_fee = trade_fee * config.fund_fee_rate as u128 / FEE_RATE_DENOMINATOR;
+    let lp_fee = trade_fee.saturating_sub(protocol_fee + fund_fee);
+    let to_u64 = |amount: u128| u64::try_from(amount).ok();
+    e.trade_fee = to_u64(trade_fee);
+    e.lp_fee = to_u64(lp_fee);
+    e.protocol_fee = to_u64(protocol_fee);
+    e.fund_fee = to_u64(fund_fee);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::TokenBalanceChange,
+        protocols::raydium_cpmm::types::{set_raydium_cpmm_amm_config, AmmConfig},
+    };
+
+    fn swap_with_vault_changes(amm_config: Pubkey, input: (u64, u64)) -> DexEvent {
+        let mut swap = RaydiumCpmmSwapEvent {
+            amm_config,
+            input_vault: Pubkey::new_from_array([7; 32]),
+            output_vault: Pubkey::new_from_array([8; 32]),
+            amount_in: 1_000_000,
+            ..Default::default()
+        };
+        swap.metadata.event_type = EventType::RaydiumCpmmSwapBaseInput;
+        swap.metadata.balance_changes = vec![TokenBalanceChange {
+            account: swap.input_vault,
+            pre_amount: input.0,
+            post_amount: input.1,
+            ..Default::default()
+        }];
+        DexEvent::RaydiumCpmmSwapEvent(swap)
+    }
+
+    #[test]
+    fn fees_need_vault_change_matching_the_instruction() {
+        let amm_config = Pubkey::new_unique();
+        set_raydium_cpmm_amm_config(
+            amm_config,
+            AmmConfig { trade_fee_rate: 2_500, protocol_fee_rate: 120_000, ..Default::default() },
+        );
+
+        let mut event = swap_with_vault_changes(amm_config, (5_000_000, 6_000_000));
+        fill_raydium_cpmm_fees_from_balances(&mut event);
+        let DexEvent::RaydiumCpmmSwapEvent(swap) = event else { unreachable!() };
+        assert_eq!(swap.trade_fee, Some(2_500));
+        assert_eq!(swap.protocol_fee, Some(300));
+        assert_eq!(swap.lp_fee, Some(2_200));
+
+        // 同一池子的另一笔兑换使 vault 的合计变化与本指令的数量不一致
+        for input in [(5_000_000, 7_000_000), (5_000_000, 5_000_000), (6_000_000, 5_000_000)] {
+            let mut event = swap_with_vault_changes(amm_config, input);
+            fill_raydium_cpmm_fees_from_balances(&mut event);
+            let DexEvent::RaydiumCpmmSwapEvent(swap) = event else { unreachable!() };
+            assert_eq!(swap.trade_fee, None, "{input:?}");
+        }
+    }
+}
@@ -1,6 +1,9 @@
+use crate::streaming::event_parser::common::serde_base58;
 use borsh::BorshDeserialize;
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
 
 use crate::streaming::{
     event_parser::{
@@ -22,13 +25,31 @@ pub struct AmmConfig {
     pub protocol_fee_rate: u64,
     pub fund_fee_rate: u64,
     pub create_pool_fee: u64,
+    #[serde(with = "serde_base58::pubkey")]
     pub protocol_owner: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub fund_owner: Pubkey,
     pub padding: [u64; 16],
 }
 
 pub const AMM_CONFIG_SIZE: usize = 228;
 
+lazy_static::lazy_static! {
+    static ref AMM_CONFIGS: RwLock<HashMap<Pubkey, AmmConfig>> = RwLock::new(HashMap::new());
+}
+
+/// 写入 AmmConfig 缓存，用于计算 swap 事件的手续费拆分，对之后解析的交易生效
+///
+/// 解析到的 AmmConfig 账户事件会自动写入；也可以在启动时通过 RPC 查询后手动写入
+pub fn set_raydium_cpmm_amm_config(address: Pubkey, config: AmmConfig) {
+    AMM_CONFIGS.write().insert(address, config);
+}
+
+/// 缓存中的 AmmConfig
+pub fn raydium_cpmm_amm_config(address: &Pubkey) -> Option<AmmConfig> {
+    AMM_CONFIGS.read().get(address).cloned()
+}
+
 pub fn amm_config_decode(data: &[u8]) -> Option<AmmConfig> {
     if data.len() < AMM_CONFIG_SIZE {
         return None;
@@ -43,6 +64,7 @@ pub fn amm_config_parser(account: &AccountPretty, mut metadata: EventMetadata) -
         return None;
     }
     if let Some(amm_config) = amm_config_decode(&account.data[8..AMM_CONFIG_SIZE + 8]) {
+        set_raydium_cpmm_amm_config(account.pubkey, amm_config.clone());
         Some(DexEvent::RaydiumCpmmAmmConfigAccountEvent(RaydiumCpmmAmmConfigAccountEvent {
             metadata,
             pubkey: account.pubkey,
@@ -59,15 +81,25 @@ pub fn amm_config_parser(account: &AccountPretty, mut metadata: EventMetadata) -
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PoolState {
+    #[serde(with = "serde_base58::pubkey")]
     pub amm_config: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub pool_creator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_vault: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub lp_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token0_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub token1_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
     pub observation_key: Pubkey,
     pub auth_bump: u8,
     pub status: u8,
@@ -0,0 +1,17 @@
+use crate::streaming::event_parser::protocols::bonk::events::{
+    BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent, BonkPoolCreateEvent, BonkTradeEvent,
+};
+
+/// 买入/卖出 (包含交易前后的 virtual/real 储备)
+pub type RaydiumLaunchpadTradeEvent = BonkTradeEvent;
+
+/// 创建池
+pub type RaydiumLaunchpadPoolCreateEvent = BonkPoolCreateEvent;
+
+/// 迁移到 Raydium AMM V4
+pub type RaydiumLaunchpadMigrateToAmmEvent = BonkMigrateToAmmEvent;
+
+/// 迁移到 Raydium CPMM
+pub type RaydiumLaunchpadMigrateToCpswapEvent = BonkMigrateToCpswapEvent;
+
+pub use crate::streaming::event_parser::protocols::bonk::events::discriminators;
@@ -0,0 +1,9 @@
+//! Raydium Launchpad (LaunchLab)
+//!
+//! Bonk (letsbonk.fun) 就是部署在 Raydium LaunchLab 程序上的发射平台，两者共用同一个程序ID
+//! 和指令/事件格式。这里复用 `bonk` 模块的解析逻辑，只提供 Launchpad 命名的入口。
+
+pub mod events;
+pub mod parser;
+
+pub use events::*;
@@ -0,0 +1,46 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::EventMetadata,
+        protocols::bonk::parser::{
+            parse_bonk_account_data, parse_bonk_inner_instruction_data,
+            parse_bonk_instruction_data, BONK_PROGRAM_ID,
+        },
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+/// Raydium Launchpad (LaunchLab) 程序ID，与 `BONK_PROGRAM_ID` 相同
+pub const RAYDIUM_LAUNCHPAD_PROGRAM_ID: Pubkey = BONK_PROGRAM_ID;
+
+/// 解析 Raydium Launchpad instruction data
+///
+/// 产出的事件为 `DexEvent::Bonk*` 系列，metadata.protocol 为 `ProtocolType::Bonk`
+pub fn parse_raydium_launchpad_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    parse_bonk_instruction_data(discriminator, data, accounts, metadata)
+}
+
+/// 解析 Raydium Launchpad inner instruction data
+pub fn parse_raydium_launchpad_inner_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    parse_bonk_inner_instruction_data(discriminator, data, metadata)
+}
+
+/// 解析 Raydium Launchpad 账户数据
+pub fn parse_raydium_launchpad_account_data(
+    discriminator: &[u8],
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    parse_bonk_account_data(discriminator, account, metadata)
+}
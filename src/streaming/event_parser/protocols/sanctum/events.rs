@@ -0,0 +1,67 @@
+use crate::streaming::event_parser::common::serde_base58;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+
+/// Sanctum Infinity 兑换事件 (两种 LST 之间的 SwapExactIn / SwapExactOut)
+///
+/// Infinity 没有 CPI 事件，`in_amount` / `out_amount` 在 gRPC 交易中由用户 LST 账户的余额变化得到；
+/// 没有交易 meta (如 shred) 时为指令参数：SwapExactIn 为 `amount` / `limit_amount`，
+/// SwapExactOut 为 `limit_amount` / `amount`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SanctumSwapEvent {
+    pub metadata: EventMetadata,
+    /// 用户支付的输入 LST 数量
+    pub in_amount: u64,
+    /// 用户收到的输出 LST 数量
+    pub out_amount: u64,
+
+    // 来自指令参数
+    /// 是否为 SwapExactOut
+    pub exact_out: bool,
+    /// 输入 LST 在池子 LST 列表中的下标
+    pub src_lst_index: u32,
+    /// 输出 LST 在池子 LST 列表中的下标
+    pub dst_lst_index: u32,
+    /// SwapExactIn 为输入数量，SwapExactOut 为输出数量
+    pub amount: u64,
+    /// SwapExactIn 为 `min_amount_out`，SwapExactOut 为 `max_amount_in`
+    pub limit_amount: u64,
+
+    // 来自指令账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub signer: Pubkey,
+    /// 输入 LST mint
+    #[serde(with = "serde_base58::pubkey")]
+    pub src_lst_mint: Pubkey,
+    /// 输出 LST mint
+    #[serde(with = "serde_base58::pubkey")]
+    pub dst_lst_mint: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub src_lst_acc: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub dst_lst_acc: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub protocol_fee_accumulator: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub src_lst_token_program: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub dst_lst_token_program: Pubkey,
+    /// 池子状态账户
+    #[serde(with = "serde_base58::pubkey")]
+    pub pool_state: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub lst_state_list: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub src_pool_reserves: Pubkey,
+    #[serde(with = "serde_base58::pubkey")]
+    pub dst_pool_reserves: Pubkey,
+}
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器 (单字节)
+    pub const SWAP_EXACT_IN_IX: &[u8] = &[1];
+    pub const SWAP_EXACT_OUT_IX: &[u8] = &[2];
+}
@@ -0,0 +1,145 @@
+use crate::streaming::event_parser::{
+    common::{read_u32_le, read_u64_le, EventMetadata, EventType},
+    protocols::sanctum::{discriminators, SanctumSwapEvent},
+    DexEvent,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Sanctum Infinity (S Controller) 程序ID
+pub const SANCTUM_INFINITY_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("5ocnV1qiCgaQR8Jb8xWnVbApfaygJ8tNoZfgPwsgx9kx");
+
+/// 指令判别器与其产出的事件类型，用于在解码前按 `EventTypeFilter` 跳过指令
+pub const INSTRUCTION_EVENT_TYPES: &[(&[u8], EventType)] = &[
+    (discriminators::SWAP_EXACT_IN_IX, EventType::SanctumSwapExactIn),
+    (discriminators::SWAP_EXACT_OUT_IX, EventType::SanctumSwapExactOut),
+];
+
+/// 解析 Sanctum instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_sanctum_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_EXACT_IN_IX => parse_swap_instruction(data, accounts, metadata, false),
+        discriminators::SWAP_EXACT_OUT_IX => parse_swap_instruction(data, accounts, metadata, true),
+        _ => None,
+    }
+}
+
+/// 解析 Sanctum inner instruction data
+///
+/// Sanctum Infinity 没有 CPI 事件
+pub fn parse_sanctum_inner_instruction_data(
+    _discriminator: &[u8],
+    _data: &[u8],
+    _metadata: EventMetadata,
+) -> Option<DexEvent> {
+    None
+}
+
+/// 解析 swap_exact_in / swap_exact_out 指令
+///
+/// 参数：src/dst 定价账户数量 (各 1 字节，跳过)、src_lst_index、dst_lst_index (u32)、
+/// limit_amount、amount (u64)；前 12 个账户固定，之后是数量可变的定价程序账户
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+    exact_out: bool,
+) -> Option<DexEvent> {
+    metadata.event_type =
+        if exact_out { EventType::SanctumSwapExactOut } else { EventType::SanctumSwapExactIn };
+
+    if data.len() < 26 || accounts.len() < 12 {
+        return None;
+    }
+    let src_lst_index = read_u32_le(data, 2)?;
+    let dst_lst_index = read_u32_le(data, 6)?;
+    let limit_amount = read_u64_le(data, 10)?;
+    let amount = read_u64_le(data, 18)?;
+    let (in_amount, out_amount) =
+        if exact_out { (limit_amount, amount) } else { (amount, limit_amount) };
+
+    Some(DexEvent::SanctumSwapEvent(SanctumSwapEvent {
+        metadata,
+        in_amount,
+        out_amount,
+        exact_out,
+        src_lst_index,
+        dst_lst_index,
+        amount,
+        limit_amount,
+        signer: accounts[0],
+        src_lst_mint: accounts[1],
+        dst_lst_mint: accounts[2],
+        src_lst_acc: accounts[3],
+        dst_lst_acc: accounts[4],
+        protocol_fee_accumulator: accounts[5],
+        src_lst_token_program: accounts[6],
+        dst_lst_token_program: accounts[7],
+        pool_state: accounts[8],
+        lst_state_list: accounts[9],
+        src_pool_reserves: accounts[10],
+        dst_pool_reserves: accounts[11],
+    }))
+}
+
+/// 用交易前后的余额变化补全 Sanctum 事件，其他事件不做处理
+///
+/// 需要先填充 `metadata.balance_changes`；
+/// 同一笔交易中同一个用户账户多次变化时，得到的是合计变化量
+pub fn fill_sanctum_event_from_balances(event: &mut DexEvent) {
+    let DexEvent::SanctumSwapEvent(e) = event else {
+        return;
+    };
+    let token_change = |account: &Pubkey| {
+        e.metadata.balance_changes.iter().find(|change| change.account == *account).cloned()
+    };
+    let (Some(src), Some(dst)) = (token_change(&e.src_lst_acc), token_change(&e.dst_lst_acc))
+    else {
+        return;
+    };
+    e.in_amount = src.pre_amount.saturating_sub(src.post_amount);
+    e.out_amount = dst.post_amount.saturating_sub(dst.pre_amount);
+    if let Some(swap_data) = e.metadata.swap_data.as_mut() {
+        swap_data.from_amount = e.in_amount;
+        swap_data.to_amount = e.out_amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::TokenBalanceChange;
+
+    #[test]
+    fn actual_amounts_come_from_user_token_accounts() {
+        let mut swap = SanctumSwapEvent {
+            src_lst_acc: Pubkey::new_from_array([4; 32]),
+            dst_lst_acc: Pubkey::new_from_array([5; 32]),
+            in_amount: 1_000_000,
+            out_amount: 990_000,
+            ..Default::default()
+        };
+        let change = |account: Pubkey, pre_amount: u64, post_amount: u64| TokenBalanceChange {
+            account,
+            pre_amount,
+            post_amount,
+            ..Default::default()
+        };
+        swap.metadata.balance_changes = vec![
+            change(swap.src_lst_acc, 5_000_000, 4_000_000),
+            change(swap.dst_lst_acc, 0, 995_000),
+        ];
+        let mut event = DexEvent::SanctumSwapEvent(swap);
+        fill_sanctum_event_from_balances(&mut event);
+
+        let DexEvent::SanctumSwapEvent(swap) = event else { unreachable!() };
+        assert_eq!((swap.in_amount, swap.out_amount), (1_000_000, 995_000));
+    }
+}
@@ -2,8 +2,13 @@ use crate::streaming::event_parser::protocols::{
     bonk::parser::BONK_PROGRAM_ID, meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
     pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID,
     raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID, raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
-    raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID,
+    raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID, orca_whirlpool::parser::WHIRLPOOL_PROGRAM_ID,
+    meteora_dlmm::parser::METEORA_DLMM_PROGRAM_ID, phoenix::parser::PHOENIX_PROGRAM_ID,
+    raydium_launchpad::parser::RAYDIUM_LAUNCHPAD_PROGRAM_ID, jupiter::parser::JUPITER_V6_PROGRAM_ID,
+    moonshot::parser::MOONSHOT_PROGRAM_ID, lifinity::parser::LIFINITY_V2_PROGRAM_ID,
+    openbook_v2::parser::OPENBOOK_V2_PROGRAM_ID, sanctum::parser::SANCTUM_INFINITY_PROGRAM_ID,
 };
+use crate::streaming::event_parser::common::EventType;
 use anyhow::{anyhow, Result};
 use solana_sdk::pubkey::Pubkey;
 
@@ -17,18 +22,189 @@ pub enum Protocol {
     RaydiumClmm,
     RaydiumAmmV4,
     MeteoraDammV2,
+    OrcaWhirlpool,
+    MeteoraDlmm,
+    Phoenix,
+    /// Raydium LaunchLab，与 Bonk 使用同一个程序，产出 `DexEvent::Bonk*` 事件
+    RaydiumLaunchpad,
+    Jupiter,
+    Moonshot,
+    /// Lifinity v2
+    Lifinity,
+    /// OpenBook v2 订单簿，成交来自交易日志
+    OpenBookV2,
+    /// Sanctum Infinity，LST 之间的兑换
+    Sanctum,
 }
 
 impl Protocol {
+    /// 所有支持的协议
+    pub fn all() -> Vec<Protocol> {
+        vec![
+            Protocol::PumpSwap,
+            Protocol::PumpFun,
+            Protocol::Bonk,
+            Protocol::RaydiumCpmm,
+            Protocol::RaydiumClmm,
+            Protocol::RaydiumAmmV4,
+            Protocol::MeteoraDammV2,
+            Protocol::OrcaWhirlpool,
+            Protocol::MeteoraDlmm,
+            Protocol::Phoenix,
+            Protocol::RaydiumLaunchpad,
+            Protocol::Jupiter,
+            Protocol::Moonshot,
+            Protocol::Lifinity,
+            Protocol::OpenBookV2,
+            Protocol::Sanctum,
+        ]
+    }
+
+    /// 协议的程序ID，Raydium LaunchLab 与 Bonk 相同
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Protocol::PumpSwap => PUMPSWAP_PROGRAM_ID,
+            Protocol::PumpFun => PUMPFUN_PROGRAM_ID,
+            Protocol::Bonk => BONK_PROGRAM_ID,
+            Protocol::RaydiumCpmm => RAYDIUM_CPMM_PROGRAM_ID,
+            Protocol::RaydiumClmm => RAYDIUM_CLMM_PROGRAM_ID,
+            Protocol::RaydiumAmmV4 => RAYDIUM_AMM_V4_PROGRAM_ID,
+            Protocol::MeteoraDammV2 => METEORA_DAMM_V2_PROGRAM_ID,
+            Protocol::OrcaWhirlpool => WHIRLPOOL_PROGRAM_ID,
+            Protocol::MeteoraDlmm => METEORA_DLMM_PROGRAM_ID,
+            Protocol::Phoenix => PHOENIX_PROGRAM_ID,
+            Protocol::RaydiumLaunchpad => RAYDIUM_LAUNCHPAD_PROGRAM_ID,
+            Protocol::Jupiter => JUPITER_V6_PROGRAM_ID,
+            Protocol::Moonshot => MOONSHOT_PROGRAM_ID,
+            Protocol::Lifinity => LIFINITY_V2_PROGRAM_ID,
+            Protocol::OpenBookV2 => OPENBOOK_V2_PROGRAM_ID,
+            Protocol::Sanctum => SANCTUM_INFINITY_PROGRAM_ID,
+        }
+    }
+
     pub fn get_program_id(&self) -> Vec<Pubkey> {
+        vec![self.program_id()]
+    }
+
+    /// 协议可能产出的事件类型 (指令、CPI 日志和账户事件)，不含区块、Compute Budget 等通用事件
+    ///
+    /// Raydium LaunchLab 产出 Bonk 事件，返回与 Bonk 相同的列表
+    pub fn event_types(&self) -> Vec<EventType> {
+        use EventType::*;
         match self {
-            Protocol::PumpSwap => vec![PUMPSWAP_PROGRAM_ID],
-            Protocol::PumpFun => vec![PUMPFUN_PROGRAM_ID],
-            Protocol::Bonk => vec![BONK_PROGRAM_ID],
-            Protocol::RaydiumCpmm => vec![RAYDIUM_CPMM_PROGRAM_ID],
-            Protocol::RaydiumClmm => vec![RAYDIUM_CLMM_PROGRAM_ID],
-            Protocol::RaydiumAmmV4 => vec![RAYDIUM_AMM_V4_PROGRAM_ID],
-            Protocol::MeteoraDammV2 => vec![METEORA_DAMM_V2_PROGRAM_ID],
+            Protocol::PumpSwap => vec![
+                PumpSwapBuy,
+                PumpSwapSell,
+                PumpSwapCreatePool,
+                PumpSwapDeposit,
+                PumpSwapWithdraw,
+                AccountPumpSwapGlobalConfig,
+                AccountPumpSwapPool,
+            ],
+            Protocol::PumpFun => vec![
+                PumpFunCreateToken,
+                PumpFunCreateV2Token,
+                PumpFunBuy,
+                PumpFunSell,
+                PumpFunMigrate,
+                AccountPumpFunBondingCurve,
+                AccountPumpFunGlobal,
+            ],
+            Protocol::Bonk | Protocol::RaydiumLaunchpad => vec![
+                BonkBuyExactIn,
+                BonkBuyExactOut,
+                BonkSellExactIn,
+                BonkSellExactOut,
+                BonkInitialize,
+                BonkInitializeV2,
+                BonkInitializeWithToken2022,
+                BonkMigrateToAmm,
+                BonkMigrateToCpswap,
+                AccountBonkPoolState,
+                AccountBonkGlobalConfig,
+                AccountBonkPlatformConfig,
+                AccountBonkVestingRecord,
+            ],
+            Protocol::RaydiumCpmm => vec![
+                RaydiumCpmmSwapBaseInput,
+                RaydiumCpmmSwapBaseOutput,
+                RaydiumCpmmDeposit,
+                RaydiumCpmmInitialize,
+                RaydiumCpmmWithdraw,
+                AccountRaydiumCpmmAmmConfig,
+                AccountRaydiumCpmmPoolState,
+            ],
+            Protocol::RaydiumClmm => vec![
+                RaydiumClmmSwap,
+                RaydiumClmmSwapV2,
+                RaydiumClmmClosePosition,
+                RaydiumClmmIncreaseLiquidityV2,
+                RaydiumClmmDecreaseLiquidityV2,
+                RaydiumClmmCreatePool,
+                RaydiumClmmOpenPositionWithToken22Nft,
+                RaydiumClmmOpenPositionV2,
+                RaydiumClmmOpenPosition,
+                RaydiumClmmIncreaseLiquidity,
+                RaydiumClmmDecreaseLiquidity,
+                RaydiumClmmIncreaseLiquidityLog,
+                RaydiumClmmDecreaseLiquidityLog,
+                RaydiumClmmCollectPersonalFee,
+                RaydiumClmmLiquidityChange,
+                RaydiumClmmSwapLog,
+                AccountRaydiumClmmAmmConfig,
+                AccountRaydiumClmmPoolState,
+                AccountRaydiumClmmTickArrayState,
+            ],
+            Protocol::RaydiumAmmV4 => vec![
+                RaydiumAmmV4SwapBaseIn,
+                RaydiumAmmV4SwapBaseOut,
+                RaydiumAmmV4Deposit,
+                RaydiumAmmV4Initialize2,
+                RaydiumAmmV4Withdraw,
+                RaydiumAmmV4WithdrawPnl,
+                AccountRaydiumAmmV4AmmInfo,
+            ],
+            Protocol::MeteoraDammV2 => vec![
+                MeteoraDammV2Swap,
+                MeteoraDammV2Swap2,
+                MeteoraDammV2InitializePool,
+                MeteoraDammV2InitializeCustomizablePool,
+                MeteoraDammV2InitializePoolWithDynamicConfig,
+                AccountMeteoraDammV2Pool,
+            ],
+            Protocol::OrcaWhirlpool => vec![
+                OrcaWhirlpoolSwap,
+                OrcaWhirlpoolSwapV2,
+                OrcaWhirlpoolIncreaseLiquidity,
+                OrcaWhirlpoolIncreaseLiquidityV2,
+                OrcaWhirlpoolDecreaseLiquidity,
+                OrcaWhirlpoolDecreaseLiquidityV2,
+                OrcaWhirlpoolInitializePool,
+                OrcaWhirlpoolInitializePoolV2,
+                OrcaWhirlpoolTraded,
+            ],
+            Protocol::MeteoraDlmm => vec![
+                MeteoraDlmmSwap,
+                MeteoraDlmmSwap2,
+                MeteoraDlmmSwapExactOut,
+                MeteoraDlmmSwapExactOut2,
+                MeteoraDlmmAddLiquidityByStrategy,
+                MeteoraDlmmRemoveLiquidity,
+                MeteoraDlmmGoToABin,
+            ],
+            Protocol::Phoenix => vec![PhoenixFill, PhoenixPlace, PhoenixReduce, PhoenixCancel],
+            Protocol::Jupiter => vec![
+                JupiterRoute,
+                JupiterRouteWithTokenLedger,
+                JupiterExactOutRoute,
+                JupiterSharedAccountsRoute,
+                JupiterSharedAccountsRouteWithTokenLedger,
+                JupiterSharedAccountsExactOutRoute,
+            ],
+            Protocol::Moonshot => vec![MoonshotBuy, MoonshotSell, MoonshotMigration],
+            Protocol::Lifinity => vec![LifinitySwap],
+            Protocol::OpenBookV2 => vec![OpenBookV2Fill],
+            Protocol::Sanctum => vec![SanctumSwapExactIn, SanctumSwapExactOut],
         }
     }
 }
@@ -43,6 +219,15 @@ impl std::fmt::Display for Protocol {
             Protocol::RaydiumClmm => write!(f, "RaydiumClmm"),
             Protocol::RaydiumAmmV4 => write!(f, "RaydiumAmmV4"),
             Protocol::MeteoraDammV2 => write!(f, "MeteoraDammV2"),
+            Protocol::OrcaWhirlpool => write!(f, "OrcaWhirlpool"),
+            Protocol::MeteoraDlmm => write!(f, "MeteoraDlmm"),
+            Protocol::Phoenix => write!(f, "Phoenix"),
+            Protocol::RaydiumLaunchpad => write!(f, "RaydiumLaunchpad"),
+            Protocol::Jupiter => write!(f, "Jupiter"),
+            Protocol::Moonshot => write!(f, "Moonshot"),
+            Protocol::Lifinity => write!(f, "Lifinity"),
+            Protocol::OpenBookV2 => write!(f, "OpenBookV2"),
+            Protocol::Sanctum => write!(f, "Sanctum"),
         }
     }
 }
@@ -59,6 +244,15 @@ impl std::str::FromStr for Protocol {
             "raydiumclmm" => Ok(Protocol::RaydiumClmm),
             "raydiumammv4" => Ok(Protocol::RaydiumAmmV4),
             "meteoradamm_v2" => Ok(Protocol::MeteoraDammV2),
+            "orcawhirlpool" => Ok(Protocol::OrcaWhirlpool),
+            "meteoradlmm" => Ok(Protocol::MeteoraDlmm),
+            "phoenix" => Ok(Protocol::Phoenix),
+            "raydiumlaunchpad" | "launchlab" => Ok(Protocol::RaydiumLaunchpad),
+            "jupiter" => Ok(Protocol::Jupiter),
+            "moonshot" => Ok(Protocol::Moonshot),
+            "lifinity" => Ok(Protocol::Lifinity),
+            "openbookv2" | "openbook" => Ok(Protocol::OpenBookV2),
+            "sanctum" | "infinity" => Ok(Protocol::Sanctum),
             _ => Err(anyhow!("Unsupported protocol: {}", s)),
         }
     }
@@ -0,0 +1,141 @@
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use yellowstone_grpc_proto::geyser::{
+    subscribe_request_filter_accounts_filter::Filter,
+    subscribe_request_filter_accounts_filter_memcmp::Data, SubscribeRequestFilterAccountsFilter,
+    SubscribeRequestFilterAccountsFilterMemcmp,
+};
+
+use crate::streaming::common::{StreamError, StreamResult};
+use crate::streaming::yellowstone_grpc::AccountFilter;
+
+/// Yellowstone 单个账户过滤器允许的 memcmp/datasize 等条件数量上限
+const MAX_FILTERS: usize = 4;
+/// Yellowstone 单个 memcmp 条件允许的最大比较字节数
+const MAX_MEMCMP_BYTES: usize = 128;
+
+impl AccountFilter {
+    /// 以构建器方式组合账户、owner 和数据过滤条件
+    pub fn builder() -> AccountFilterBuilder {
+        AccountFilterBuilder::default()
+    }
+
+    /// 账户数据 `offset` 处与 `bytes` 相同
+    pub fn memcmp(offset: u64, bytes: impl Into<Vec<u8>>) -> SubscribeRequestFilterAccountsFilter {
+        SubscribeRequestFilterAccountsFilter {
+            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                offset,
+                data: Some(Data::Bytes(bytes.into())),
+            })),
+        }
+    }
+
+    /// 账户数据长度等于 `size`
+    pub fn datasize(size: u64) -> SubscribeRequestFilterAccountsFilter {
+        SubscribeRequestFilterAccountsFilter { filter: Some(Filter::Datasize(size)) }
+    }
+}
+
+/// `AccountFilter` 构建器
+///
+/// `build()` 时检查常见错误：地址无法解析、memcmp 为空或超过 128 字节、多个不同的 datasize、
+/// memcmp 范围超出 datasize、条件超过 4 个。这些请求会被服务端拒绝或永远匹配不到账户
+#[derive(Debug, Clone, Default)]
+pub struct AccountFilterBuilder {
+    account: Vec<String>,
+    owner: Vec<String>,
+    filters: Vec<SubscribeRequestFilterAccountsFilter>,
+}
+
+impl AccountFilterBuilder {
+    /// 订阅指定账户
+    pub fn account(mut self, account: impl ToString) -> Self {
+        self.account.push(account.to_string());
+        self
+    }
+
+    /// 订阅属于指定程序的账户
+    pub fn owner(mut self, owner: impl ToString) -> Self {
+        self.owner.push(owner.to_string());
+        self
+    }
+
+    /// 要求账户数据 `offset` 处与 `bytes` 相同，例如 offset 0 处的 Anchor 判别器
+    pub fn memcmp(self, offset: u64, bytes: impl Into<Vec<u8>>) -> Self {
+        self.filter(AccountFilter::memcmp(offset, bytes))
+    }
+
+    /// 要求账户数据长度等于 `size`
+    pub fn datasize(self, size: u64) -> Self {
+        self.filter(AccountFilter::datasize(size))
+    }
+
+    /// 添加任意数据过滤条件 (如 lamports、token_account_state)
+    pub fn filter(mut self, filter: SubscribeRequestFilterAccountsFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// 校验并生成 `AccountFilter`
+    pub fn build(self) -> StreamResult<AccountFilter> {
+        for address in self.account.iter().chain(&self.owner) {
+            if Pubkey::from_str(address).is_err() {
+                return Err(StreamError::Config(format!(
+                    "invalid account filter address: {address}"
+                )));
+            }
+        }
+        if self.filters.len() > MAX_FILTERS {
+            return Err(StreamError::Config(format!(
+                "account filter has {} data filters, at most {MAX_FILTERS} are allowed",
+                self.filters.len()
+            )));
+        }
+
+        let mut datasize: Option<u64> = None;
+        for filter in &self.filters {
+            if let Some(Filter::Datasize(size)) = filter.filter {
+                if datasize.is_some_and(|existing| existing != size) {
+                    return Err(StreamError::Config(
+                        "account filter has conflicting datasize filters".to_string(),
+                    ));
+                }
+                datasize = Some(size);
+            }
+        }
+        for filter in &self.filters {
+            let Some(Filter::Memcmp(memcmp)) = &filter.filter else {
+                continue;
+            };
+            let len = match &memcmp.data {
+                Some(Data::Bytes(bytes)) => bytes.len(),
+                Some(Data::Base58(data)) => solana_sdk::bs58::decode(data)
+                    .into_vec()
+                    .map_err(|e| StreamError::Config(format!("invalid base58 memcmp data: {e}")))?
+                    .len(),
+                Some(Data::Base64(_)) | None => continue,
+            };
+            if len == 0 {
+                return Err(StreamError::Config("memcmp filter has no bytes".to_string()));
+            }
+            if len > MAX_MEMCMP_BYTES {
+                return Err(StreamError::Config(format!(
+                    "memcmp filter compares {len} bytes, at most {MAX_MEMCMP_BYTES} are allowed"
+                )));
+            }
+            let end = memcmp.offset.checked_add(len as u64).ok_or_else(|| {
+                StreamError::Config(format!("memcmp offset {} overflows", memcmp.offset))
+            })?;
+            if let Some(size) = datasize {
+                if end > size {
+                    return Err(StreamError::Config(format!(
+                        "memcmp at offset {} with {len} bytes exceeds datasize {size}",
+                        memcmp.offset
+                    )));
+                }
+            }
+        }
+
+        Ok(AccountFilter { account: self.account, owner: self.owner, filters: self.filters })
+    }
+}
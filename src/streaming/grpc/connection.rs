@@ -1,7 +1,7 @@
 use std::time::Duration;
 use tonic::transport::channel::ClientTlsConfig;
-use yellowstone_grpc_client::{GeyserGrpcClient, Interceptor};
-use crate::common::AnyResult;
+use yellowstone_grpc_client::{GeyserGrpcBuilderError, GeyserGrpcClient, Interceptor};
+use crate::streaming::common::{StreamError, StreamResult};
 use crate::streaming::common::constants::{
     DEFAULT_CONNECT_TIMEOUT, DEFAULT_REQUEST_TIMEOUT, DEFAULT_MAX_DECODING_MESSAGE_SIZE
 };
@@ -20,10 +20,18 @@ impl GrpcConnectionPool {
         }
     }
 
-    pub async fn create_connection(&self) -> AnyResult<GeyserGrpcClient<impl Interceptor>> {
-        let builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
+    pub async fn create_connection(&self) -> StreamResult<GeyserGrpcClient<impl Interceptor>> {
+        let builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())
+            .map_err(|e| match e {
+                GeyserGrpcBuilderError::TonicError(e) => StreamError::InvalidEndpoint(e),
+                e => e.into(),
+            })?
             .x_token(self.x_token.clone())?
-            .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .tls_config(ClientTlsConfig::new().with_native_roots())
+            .map_err(|e| match e {
+                GeyserGrpcBuilderError::TonicError(e) => StreamError::Tls(e),
+                e => e.into(),
+            })?
             .max_decoding_message_size(DEFAULT_MAX_DECODING_MESSAGE_SIZE)
             .connect_timeout(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT))
             .timeout(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT));
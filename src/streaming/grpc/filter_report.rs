@@ -0,0 +1,323 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::{EventType, ACCOUNT_EVENT_TYPES};
+use crate::streaming::event_parser::core::EventDispatcher;
+use crate::streaming::event_parser::Protocol;
+use crate::streaming::yellowstone_grpc::{AccountFilter, TransactionFilter};
+
+/// `validate_filters` 发现的问题，`index` 为过滤器在列表中的下标 (对应请求中的
+/// `transaction_{index}` / `account_{index}`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterWarning {
+    /// 没有交易过滤器也没有账户过滤器，只会收到 block 事件
+    NoFilters,
+    /// 事件类型过滤的 include 为空，所有事件都会被丢弃
+    EmptyEventTypeFilter,
+    /// 事件类型过滤不包含交易事件，交易过滤器不会被订阅
+    TransactionFiltersUnused,
+    /// 事件类型过滤不包含账户事件，账户过滤器不会被订阅
+    AccountFiltersUnused,
+    /// 事件类型过滤包含交易事件，但没有交易过滤器
+    MissingTransactionFilter,
+    /// 事件类型过滤包含账户事件，但没有账户过滤器
+    MissingAccountFilter,
+    /// 交易过滤器没有 account_include、account_required 和签名，会订阅全网交易
+    UnboundedTransactionFilter { index: usize },
+    /// 账户过滤器没有 account 和 owner，会订阅全网账户
+    EmptyAccountFilter { index: usize },
+    /// 地址无法解析，服务端会拒绝订阅
+    InvalidPubkey { filter: String, key: String },
+    /// 签名无法解析，服务端会拒绝订阅
+    InvalidSignature { index: usize, signature: String },
+    /// 地址同时出现在 account_include 和 account_exclude 中，包含该地址的交易都会被排除
+    IncludeExcludeOverlap { index: usize, account: String },
+    /// 地址同时出现在 account_required 和 account_exclude 中，过滤器匹配不到任何交易
+    RequiredExcluded { index: usize, account: String },
+    /// 交易过滤器订阅了协议的程序，但该协议不在解析的协议列表中
+    ProtocolNotSelected { index: usize, protocol: Protocol },
+    /// 交易过滤器订阅了协议的程序，但事件类型过滤不包含该协议的任何事件
+    ProtocolEventTypesExcluded { index: usize, protocol: Protocol },
+    /// 事件类型所属的协议不在解析的协议列表中，不会产生该类型的事件
+    EventTypeProtocolNotSelected { event_type: EventType, protocol: Protocol },
+}
+
+impl FilterWarning {
+    /// 订阅会被服务端拒绝，或者对应的过滤器不会产生任何事件
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            Self::NoFilters
+                | Self::EmptyEventTypeFilter
+                | Self::InvalidPubkey { .. }
+                | Self::InvalidSignature { .. }
+                | Self::RequiredExcluded { .. }
+        )
+    }
+}
+
+impl fmt::Display for FilterWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoFilters => write!(f, "no transaction or account filter"),
+            Self::EmptyEventTypeFilter => {
+                write!(f, "event type filter includes no event type, every event is dropped")
+            }
+            Self::TransactionFiltersUnused => write!(
+                f,
+                "event type filter includes no transaction event, transaction filters are unused"
+            ),
+            Self::AccountFiltersUnused => {
+                write!(f, "event type filter includes no account event, account filters are unused")
+            }
+            Self::MissingTransactionFilter => write!(
+                f,
+                "event type filter includes transaction events but there is no transaction filter"
+            ),
+            Self::MissingAccountFilter => write!(
+                f,
+                "event type filter includes account events but there is no account filter"
+            ),
+            Self::UnboundedTransactionFilter { index } => write!(
+                f,
+                "transaction_{index} has no account_include, account_required or signature and \
+                 matches every transaction"
+            ),
+            Self::EmptyAccountFilter { index } => {
+                write!(f, "account_{index} has no account or owner and matches every account")
+            }
+            Self::InvalidPubkey { filter, key } => {
+                write!(f, "invalid pubkey {key:?} in filter {filter}")
+            }
+            Self::InvalidSignature { index, signature } => {
+                write!(f, "invalid signature {signature:?} in filter transaction_{index}")
+            }
+            Self::IncludeExcludeOverlap { index, account } => write!(
+                f,
+                "{account} is both included and excluded in transaction_{index}, transactions \
+                 touching it never match"
+            ),
+            Self::RequiredExcluded { index, account } => write!(
+                f,
+                "{account} is both required and excluded in transaction_{index}, the filter \
+                 matches nothing"
+            ),
+            Self::ProtocolNotSelected { index, protocol } => write!(
+                f,
+                "transaction_{index} subscribes to the {protocol} program but {protocol} is not \
+                 in the protocol list"
+            ),
+            Self::ProtocolEventTypesExcluded { index, protocol } => write!(
+                f,
+                "transaction_{index} subscribes to the {protocol} program but the event type \
+                 filter includes no {protocol} event"
+            ),
+            Self::EventTypeProtocolNotSelected { event_type, protocol } => write!(
+                f,
+                "event type {event_type} is included but {protocol} is not in the protocol list"
+            ),
+        }
+    }
+}
+
+/// `validate_filters` 的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterReport {
+    pub warnings: Vec<FilterWarning>,
+}
+
+impl FilterReport {
+    /// 没有发现问题
+    pub fn is_ok(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// 包含 `FilterWarning::is_error` 的问题
+    pub fn has_errors(&self) -> bool {
+        self.warnings.iter().any(FilterWarning::is_error)
+    }
+}
+
+impl fmt::Display for FilterReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, warning) in self.warnings.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{warning}")?;
+        }
+        Ok(())
+    }
+}
+
+/// 在订阅前检查过滤条件之间的矛盾，不连接服务端
+///
+/// 参数与 `subscribe_events_immediate` 相同。检查内容包括：过滤器为空或会订阅全网数据、
+/// 地址和签名无法解析、同一地址同时被包含和排除、事件类型过滤与交易/账户过滤器不匹配、
+/// 交易过滤器订阅的协议程序没有被选中或其事件都被事件类型过滤排除
+///
+/// ```rust,ignore
+/// let report = validate_filters(&protocols, &transaction_filter, &account_filter, None);
+/// for warning in &report.warnings {
+///     log::warn!("{warning}");
+/// }
+/// ```
+pub fn validate_filters(
+    protocols: &[Protocol],
+    transaction_filter: &[TransactionFilter],
+    account_filter: &[AccountFilter],
+    event_type_filter: Option<&EventTypeFilter>,
+) -> FilterReport {
+    let mut warnings = Vec::new();
+    if transaction_filter.is_empty() && account_filter.is_empty() {
+        warnings.push(FilterWarning::NoFilters);
+    }
+
+    if let Some(event_filter) = event_type_filter {
+        if event_filter.include.is_empty() {
+            warnings.push(FilterWarning::EmptyEventTypeFilter);
+        } else {
+            let include_transaction = event_filter.include_transaction_event();
+            let include_account = event_filter.include_account_event();
+            if !include_transaction && !transaction_filter.is_empty() {
+                warnings.push(FilterWarning::TransactionFiltersUnused);
+            }
+            if include_transaction && transaction_filter.is_empty() {
+                warnings.push(FilterWarning::MissingTransactionFilter);
+            }
+            if !include_account && !account_filter.is_empty() {
+                warnings.push(FilterWarning::AccountFiltersUnused);
+            }
+            if include_account && account_filter.is_empty() {
+                warnings.push(FilterWarning::MissingAccountFilter);
+            }
+        }
+        for event_type in &event_filter.include {
+            if let Some(protocol) = event_type_protocol(event_type) {
+                if !EventDispatcher::is_protocol_selected(protocols, &protocol) {
+                    warnings.push(FilterWarning::EventTypeProtocolNotSelected {
+                        event_type: event_type.clone(),
+                        protocol,
+                    });
+                }
+            }
+        }
+    }
+
+    for (index, filter) in transaction_filter.iter().enumerate() {
+        let name = format!("transaction_{index}");
+        for key in filter
+            .account_include
+            .iter()
+            .chain(&filter.account_exclude)
+            .chain(&filter.account_required)
+        {
+            if Pubkey::from_str(key).is_err() {
+                warnings
+                    .push(FilterWarning::InvalidPubkey { filter: name.clone(), key: key.clone() });
+            }
+        }
+        if let Some(signature) = &filter.signature {
+            if Signature::from_str(signature).is_err() {
+                warnings
+                    .push(FilterWarning::InvalidSignature { index, signature: signature.clone() });
+            }
+        }
+        if filter.account_include.is_empty()
+            && filter.account_required.is_empty()
+            && filter.signature.is_none()
+        {
+            warnings.push(FilterWarning::UnboundedTransactionFilter { index });
+        }
+        for account in &filter.account_exclude {
+            if filter.account_include.contains(account) {
+                warnings
+                    .push(FilterWarning::IncludeExcludeOverlap { index, account: account.clone() });
+            }
+            if filter.account_required.contains(account) {
+                warnings.push(FilterWarning::RequiredExcluded { index, account: account.clone() });
+            }
+        }
+
+        // 订阅的协议程序 (Raydium Launchpad 与 Bonk 共用程序，统一按 Bonk 处理)
+        let mut subscribed: Vec<Protocol> = Vec::new();
+        for key in filter.account_include.iter().chain(&filter.account_required) {
+            let Some(protocol) = Pubkey::from_str(key)
+                .ok()
+                .and_then(|program_id| EventDispatcher::match_protocol_by_program_id(&program_id))
+            else {
+                continue;
+            };
+            if !subscribed.contains(&protocol) {
+                subscribed.push(protocol);
+            }
+        }
+        for protocol in subscribed {
+            if !EventDispatcher::is_protocol_selected(protocols, &protocol) {
+                warnings.push(FilterWarning::ProtocolNotSelected { index, protocol });
+                continue;
+            }
+            // 不包含任何交易事件时已报告 TransactionFiltersUnused
+            let Some(event_filter) =
+                event_type_filter.filter(|filter| filter.include_transaction_event())
+            else {
+                continue;
+            };
+            let has_protocol_event = event_filter.include.iter().any(|event_type| {
+                !ACCOUNT_EVENT_TYPES.contains(event_type)
+                    && event_type_protocol(event_type).is_some_and(|event_protocol| {
+                        EventDispatcher::is_protocol_selected(&[event_protocol], &protocol)
+                    })
+            });
+            if !has_protocol_event {
+                warnings.push(FilterWarning::ProtocolEventTypesExcluded { index, protocol });
+            }
+        }
+    }
+
+    for (index, filter) in account_filter.iter().enumerate() {
+        let name = format!("account_{index}");
+        for key in filter.account.iter().chain(&filter.owner) {
+            if Pubkey::from_str(key).is_err() {
+                warnings
+                    .push(FilterWarning::InvalidPubkey { filter: name.clone(), key: key.clone() });
+            }
+        }
+        if filter.account.is_empty() && filter.owner.is_empty() {
+            warnings.push(FilterWarning::EmptyAccountFilter { index });
+        }
+    }
+
+    FilterReport { warnings }
+}
+
+/// 事件类型所属的协议，通用事件 (区块、Compute Budget、Token 账户等) 返回 None
+///
+/// 由 `Protocol::event_types` 反查，Raydium LaunchLab 与 Bonk 共用事件，按 `Protocol::all` 的顺序归为 Bonk
+fn event_type_protocol(event_type: &EventType) -> Option<Protocol> {
+    Protocol::all().into_iter().find(|protocol| protocol.event_types().contains(event_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_type_protocol_follows_protocol_event_types() {
+        for protocol in Protocol::all() {
+            for event_type in protocol.event_types() {
+                let owner = event_type_protocol(&event_type).unwrap();
+                assert!(
+                    EventDispatcher::is_protocol_selected(&[owner], &protocol),
+                    "{event_type:?}"
+                );
+            }
+        }
+        assert_eq!(event_type_protocol(&EventType::BonkBuyExactIn), Some(Protocol::Bonk));
+        assert_eq!(event_type_protocol(&EventType::BlockMeta), None);
+        assert_eq!(event_type_protocol(&EventType::TokenAccount), None);
+    }
+}
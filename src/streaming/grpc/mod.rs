@@ -1,10 +1,16 @@
 // gRPC 相关模块
+pub mod account_filter;
+pub mod filter_report;
 pub mod connection;
 pub mod pool;
+pub(crate) mod proxy;
 pub mod subscription;
+pub(crate) mod tls;
 pub mod types;
 
 // 重新导出主要类型
+pub use account_filter::*;
+pub use filter_report::*;
 pub use connection::*;
 pub use pool::*;
 pub use subscription::*;
@@ -1,11 +1,14 @@
-use super::types::{AccountPretty, BlockMetaPretty, TransactionPretty};
+use super::types::{AccountPretty, BlockMetaPretty, EntryPretty, TransactionPretty};
 use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::collections::VecDeque;
 use std::ops::DerefMut;
 use std::sync::{Arc, Mutex};
 use yellowstone_grpc_proto::{
-    geyser::{SubscribeUpdateAccount, SubscribeUpdateBlockMeta, SubscribeUpdateTransaction},
+    geyser::{
+        SubscribeUpdateAccount, SubscribeUpdateBlockMeta, SubscribeUpdateEntry,
+        SubscribeUpdateTransaction,
+    },
     prost_types::Timestamp,
 };
 
@@ -195,7 +198,12 @@ impl PooledBlockMetaPretty {
     ) {
         self.block_meta.slot = block_update.slot;
         self.block_meta.block_hash = block_update.blockhash;
+        self.block_meta.parent_slot = block_update.parent_slot;
+        self.block_meta.parent_block_hash = block_update.parent_blockhash;
+        self.block_meta.block_height = block_update.block_height.map(|h| h.block_height);
+        self.block_meta.executed_transaction_count = block_update.executed_transaction_count;
         self.block_meta.block_time = block_time;
+        self.block_meta.unix_block_time = block_update.block_time.map(|t| t.timestamp);
         self.block_meta.recv_us = get_high_perf_clock();
     }
 }
@@ -206,7 +214,10 @@ impl Drop for PooledBlockMetaPretty {
         if pool.len() < self.max_size {
             // 清理数据
             self.block_meta.block_hash.clear();
+            self.block_meta.parent_block_hash.clear();
+            self.block_meta.block_height = None;
             self.block_meta.block_time = None;
+            self.block_meta.unix_block_time = None;
             pool.push_back(std::mem::take(&mut self.block_meta));
         }
     }
@@ -434,4 +445,18 @@ pub mod factory {
     ) -> TransactionPretty {
         GLOBAL_POOL_MANAGER.get_event_pool().create_transaction_event_optimized(update, block_time)
     }
+
+    /// 创建 entry 事件，entry 不含大块数据，不经过对象池
+    pub fn create_entry_pretty(update: SubscribeUpdateEntry) -> EntryPretty {
+        EntryPretty {
+            slot: update.slot,
+            index: update.index,
+            num_hashes: update.num_hashes,
+            hash: solana_sdk::bs58::encode(&update.hash).into_string(),
+            executed_transaction_count: update.executed_transaction_count,
+            starting_transaction_index: update.starting_transaction_index,
+            recv_us: get_high_perf_clock(),
+            received_us: 0,
+        }
+    }
 }
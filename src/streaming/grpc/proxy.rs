@@ -0,0 +1,101 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::future::BoxFuture;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::TokioIo;
+use std::io;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tonic::codegen::http::Uri;
+use tonic::codegen::Service;
+
+use crate::streaming::common::{ConnectionConfig, StreamError, StreamResult};
+
+/// 代理响应头的最大长度，超过时视为代理异常
+const MAX_RESPONSE_HEADER_LEN: usize = 8192;
+
+/// 通过 HTTP 代理的 `CONNECT` 隧道连接 endpoint
+///
+/// 只负责建立到 endpoint 的 TCP 隧道，`https` endpoint 的 TLS 仍由 tonic 在隧道之上完成。
+/// TCP keepalive 与 nodelay 作用于到代理的连接
+#[derive(Clone)]
+pub(crate) struct HttpProxyConnector {
+    proxy: Uri,
+    /// `Proxy-Authorization` 的 Basic 凭证，代理 URL 不含用户信息时为 None
+    authorization: Option<String>,
+    http: HttpConnector,
+}
+
+impl HttpProxyConnector {
+    /// 解析代理 URL，只支持 `http://[user:password@]host:port`
+    pub(crate) fn new(proxy: &str, config: &ConnectionConfig) -> StreamResult<Self> {
+        let invalid =
+            |reason: &str| StreamError::Config(format!("invalid proxy {proxy}: {reason}"));
+        let uri: Uri = proxy.parse().map_err(|_| invalid("not a valid URL"))?;
+        if uri.scheme_str() != Some("http") {
+            return Err(invalid("only http:// proxies are supported"));
+        }
+        let authority = uri.authority().ok_or_else(|| invalid("missing host"))?;
+        let (authorization, host) = match authority.as_str().rsplit_once('@') {
+            Some((credentials, host)) => (Some(STANDARD.encode(credentials)), host),
+            None => (None, authority.as_str()),
+        };
+        let proxy = Uri::builder()
+            .scheme("http")
+            .authority(host)
+            .path_and_query("/")
+            .build()
+            .map_err(|_| invalid("invalid host"))?;
+
+        let mut http = HttpConnector::new();
+        http.set_nodelay(config.tcp_nodelay);
+        http.set_keepalive(config.tcp_keepalive.map(Duration::from_secs));
+        Ok(Self { proxy, authorization, http })
+    }
+}
+
+impl Service<Uri> for HttpProxyConnector {
+    type Response = TokioIo<TcpStream>;
+    type Error = io::Error;
+    type Future = BoxFuture<'static, io::Result<Self::Response>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let connect = self.http.call(self.proxy.clone());
+        let authorization = self.authorization.clone();
+        Box::pin(async move {
+            let host = uri.host().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "endpoint has no host")
+            })?;
+            let port =
+                uri.port_u16().unwrap_or(if uri.scheme_str() == Some("https") { 443 } else { 80 });
+            let mut stream = connect.await.map_err(io::Error::other)?.into_inner();
+
+            let mut request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+            if let Some(authorization) = authorization {
+                request.push_str(&format!("Proxy-Authorization: Basic {authorization}\r\n"));
+            }
+            request.push_str("\r\n");
+            stream.write_all(request.as_bytes()).await?;
+
+            // 逐字节读取响应头，避免读走隧道中的数据
+            let mut response = Vec::new();
+            while !response.ends_with(b"\r\n\r\n") {
+                if response.len() >= MAX_RESPONSE_HEADER_LEN {
+                    return Err(io::Error::other("proxy response header too long"));
+                }
+                response.push(stream.read_u8().await?);
+            }
+            let status_line = String::from_utf8_lossy(&response);
+            let status_line = status_line.lines().next().unwrap_or_default();
+            if status_line.split_whitespace().nth(1) != Some("200") {
+                return Err(io::Error::other(format!("proxy CONNECT failed: {status_line}")));
+            }
+            Ok(TokioIo::new(stream))
+        })
+    }
+}
@@ -1,44 +1,203 @@
 use futures::{channel::mpsc, sink::Sink, Stream};
 use maplit::hashmap;
-use std::{collections::HashMap, time::Duration};
-use tonic::{transport::channel::ClientTlsConfig, Status};
-use yellowstone_grpc_client::{GeyserGrpcClient, Interceptor};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tonic::Status;
+use tonic_health::pb::health_client::HealthClient;
+use yellowstone_grpc_client::{
+    GeyserGrpcBuilderError, GeyserGrpcClient, Interceptor, InterceptorXToken,
+};
+use yellowstone_grpc_proto::geyser::geyser_client::GeyserClient;
 use yellowstone_grpc_proto::geyser::{
     CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccounts,SubscribeRequestFilterBlocks,
-    SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterTransactions, SubscribeUpdate,
+    SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterEntry,
+    SubscribeRequestFilterTransactions, SubscribeUpdate,
 };
 
+use super::proxy::HttpProxyConnector;
+use super::tls::apply_tls_config;
 use super::types::AccountsFilterMap;
 use super::types::TransactionsFilterMap;
-use crate::common::AnyResult;
+use crate::streaming::common::{
+    BlockSubscriptionConfig, EndpointHealth, MetricsManager, StreamError, StreamResult,
+    XTokenPool,
+};
+use crate::streaming::common::endpoint_health::EndpointHealthTracker;
 use crate::streaming::common::StreamClientConfig as ClientConfig;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::EventType;
 use crate::streaming::yellowstone_grpc::AccountFilter;
 use crate::streaming::yellowstone_grpc::TransactionFilter;
 
 /// Subscription manager
 #[derive(Clone)]
 pub struct SubscriptionManager {
-    endpoint: String,
-    x_token: Option<String>,
+    /// (endpoint, x_token)，按优先级排列
+    endpoints: Arc<Vec<(String, Option<String>)>>,
+    /// 当前使用的 endpoint 下标，克隆之间共享
+    active_endpoint: Arc<AtomicUsize>,
+    /// 各 endpoint 的连接统计，克隆之间共享
+    endpoint_health: Arc<EndpointHealthTracker>,
+    /// `config.x_tokens`，每次连接尝试轮换一个
+    x_tokens: XTokenPool,
+    /// 当前的 `max_decoding_message_size`，收到超长更新后可能被提高，克隆之间共享
+    decoding_limit: Arc<AtomicUsize>,
     config: ClientConfig,
 }
 
 impl SubscriptionManager {
     /// Create a new subscription manager
     pub fn new(endpoint: String, x_token: Option<String>, config: ClientConfig) -> Self {
-        Self { endpoint, x_token, config }
+        Self::new_with_endpoints(vec![(endpoint, x_token)], config)
+    }
+
+    /// Create a subscription manager that fails over between several endpoints
+    pub fn new_with_endpoints(
+        endpoints: Vec<(String, Option<String>)>,
+        config: ClientConfig,
+    ) -> Self {
+        let endpoint_health =
+            EndpointHealthTracker::new(endpoints.iter().map(|(endpoint, _)| endpoint.as_str()));
+        Self {
+            endpoints: Arc::new(endpoints),
+            active_endpoint: Arc::new(AtomicUsize::new(0)),
+            endpoint_health,
+            x_tokens: XTokenPool::new(config.x_tokens.clone()),
+            decoding_limit: Arc::new(AtomicUsize::new(config.connection.max_decoding_message_size)),
+            config,
+        }
     }
 
     /// Create gRPC connection
-    pub async fn connect(&self) -> AnyResult<GeyserGrpcClient<impl Interceptor>> {
-        let builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())?
-            .x_token(self.x_token.clone())?
-            .tls_config(ClientTlsConfig::new().with_native_roots())?
-            .max_decoding_message_size(self.config.connection.max_decoding_message_size)
-            .connect_timeout(Duration::from_secs(self.config.connection.connect_timeout))
-            .timeout(Duration::from_secs(self.config.connection.request_timeout));
-        Ok(builder.connect().await?)
+    ///
+    /// Starts with the active endpoint and tries the following ones in order on connection
+    /// error, wrapping around; the first endpoint that connects becomes the active one.
+    /// With `x_tokens` configured, every attempt uses the next token of the pool instead of
+    /// the endpoint's own x_token.
+    pub async fn connect(&self) -> StreamResult<GeyserGrpcClient<impl Interceptor>> {
+        let start = self.active_endpoint.load(Ordering::Acquire);
+        let mut last_error = None;
+        for offset in 0..self.endpoints.len() {
+            let index = (start + offset) % self.endpoints.len();
+            let (endpoint, endpoint_x_token) = &self.endpoints[index];
+            let pool_x_token = self.x_tokens.next_token();
+            let x_token = pool_x_token.map(str::to_string).or_else(|| endpoint_x_token.clone());
+            let result = self.connect_endpoint(endpoint, &x_token).await;
+            if let Some(pool_x_token) = pool_x_token {
+                MetricsManager::global().record_x_token_connection(pool_x_token, result.is_ok());
+            }
+            self.endpoint_health.record(index, result.is_ok());
+            match result {
+                Ok(client) => {
+                    if index != start {
+                        tracing::warn!(endpoint = %endpoint, "failed over to gRPC endpoint");
+                    }
+                    self.active_endpoint.store(index, Ordering::Release);
+                    return Ok(client);
+                }
+                Err(e) => {
+                    tracing::error!(endpoint = %endpoint, error = ?e, "gRPC connection failed");
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error
+            .unwrap_or_else(|| StreamError::Config("No gRPC endpoint configured".into())))
+    }
+
+    #[tracing::instrument(name = "grpc_connect", level = "info", skip_all, fields(endpoint = %endpoint))]
+    async fn connect_endpoint(
+        &self,
+        endpoint: &str,
+        x_token: &Option<String>,
+    ) -> StreamResult<GeyserGrpcClient<impl Interceptor>> {
+        let connection = &self.config.connection;
+        let mut builder = GeyserGrpcClient::build_from_shared(endpoint.to_string())
+            .map_err(|e| match e {
+                GeyserGrpcBuilderError::TonicError(e) => StreamError::InvalidEndpoint(e),
+                e => e.into(),
+            })?
+            .x_token(x_token.clone())?
+            // 不设置 endpoint 级的请求超时，长连接的订阅流不受其限制
+            .connect_timeout(Duration::from_secs(connection.connect_timeout))
+            .tcp_keepalive(connection.tcp_keepalive.map(Duration::from_secs))
+            .tcp_nodelay(connection.tcp_nodelay)
+            .initial_stream_window_size(connection.initial_stream_window_size)
+            .initial_connection_window_size(connection.initial_connection_window_size)
+            .http2_adaptive_window(connection.http2_adaptive_window);
+        builder.endpoint = apply_tls_config(builder.endpoint, &self.config.tls)?;
+        let channel = match &connection.proxy {
+            Some(proxy) => {
+                let connector = HttpProxyConnector::new(proxy, connection)?;
+                builder.endpoint.connect_with_connector(connector).await
+            }
+            None => builder.endpoint.connect().await,
+        }
+        .map_err(StreamError::Connection)?;
+
+        // GeyserGrpcBuilder 不支持自定义连接器，按其 connect() 的方式组装客户端
+        let interceptor = InterceptorXToken { x_token: builder.x_token, x_request_snapshot: false };
+        let mut geyser = GeyserClient::with_interceptor(channel.clone(), interceptor.clone())
+            .max_decoding_message_size(self.decoding_limit());
+        if let Some(encoding) = connection.compression.encoding() {
+            geyser = geyser.accept_compressed(encoding);
+        }
+        tracing::info!("gRPC connection established");
+        Ok(GeyserGrpcClient::new(HealthClient::with_interceptor(channel, interceptor), geyser))
+    }
+
+    /// Maximum decoding message size used for new connections
+    pub fn decoding_limit(&self) -> usize {
+        self.decoding_limit.load(Ordering::Acquire)
+    }
+
+    /// Raise the decoding limit of the next connections after an update of `message_len`
+    /// bytes was rejected
+    ///
+    /// The new limit fits the update and is at least double the current one, capped at
+    /// `max_decoding_message_size_ceiling`. Returns None when no ceiling is configured or the
+    /// limit is already at the ceiling.
+    pub fn raise_decoding_limit(&self, message_len: usize) -> Option<usize> {
+        let ceiling = self.config.connection.max_decoding_message_size_ceiling?;
+        let current = self.decoding_limit();
+        let raised = message_len.max(current.saturating_mul(2)).min(ceiling);
+        (raised > current).then(|| {
+            self.decoding_limit.store(raised, Ordering::Release);
+            raised
+        })
+    }
+
+    /// Get the endpoint currently in use (or the next one to be tried)
+    pub fn active_endpoint(&self) -> &str {
+        &self.endpoints[self.active_endpoint.load(Ordering::Acquire)].0
+    }
+
+    /// Get all configured endpoints in priority order
+    pub fn endpoints(&self) -> impl Iterator<Item = &str> {
+        self.endpoints.iter().map(|(endpoint, _)| endpoint.as_str())
+    }
+
+    /// Get the connection health of each endpoint of this manager, in priority order
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoint_health.snapshot()
+    }
+
+    /// Clone sharing everything but the endpoint health, for a secondary connection whose
+    /// active endpoint is tracked on its own
+    pub(crate) fn with_own_endpoint_health(&self) -> Self {
+        Self {
+            endpoint_health: EndpointHealthTracker::new(self.endpoints()),
+            ..self.clone()
+        }
     }
 
     /// Create subscription request and return stream
@@ -48,54 +207,69 @@ impl SubscriptionManager {
         accounts: Option<AccountsFilterMap>,
         commitment: Option<CommitmentLevel>,
         event_type_filter: Option<&EventTypeFilter>,
-    ) -> AnyResult<(
+    ) -> StreamResult<(
         impl Sink<SubscribeRequest, Error = mpsc::SendError>,
         impl Stream<Item = Result<SubscribeUpdate, Status>>,
         SubscribeRequest,
     )> {
-        let blocks_meta =
-            if event_type_filter.is_some() && event_type_filter.unwrap().include_block_event() {
-                hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} }
-            } else if event_type_filter.is_none() {
-                hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} }
-            } else {
-                hashmap! {}
-            };
-        let blocks =
-            if event_type_filter.is_some() && event_type_filter.unwrap().include_block_event() {
-                hashmap! { "".to_owned() => SubscribeRequestFilterBlocks {
-                    account_include: vec![],
-                    include_transactions: Some(true), // 是否包含交易详情
-                    include_accounts: Some(false),
-                    include_entries: Some(false),
-                } }
-            } else if event_type_filter.is_none() {
-                // 默认不订阅完整 Block，因为数据量太大。如果你想默认全订阅，可以改成 hashmap! {...}
-                // 这里我建议依然留空，除非显式要求，或者你可以硬编码开启测试
-                hashmap! { "".to_owned() => SubscribeRequestFilterBlocks {
-                    account_include: vec![],
-                    include_transactions: Some(true),
-                    include_accounts: Some(false),
-                    include_entries: Some(false),
-                } }
-            } else {
-                hashmap! {}
-            };
-        let subscribe_request = SubscribeRequest {
+        let subscribe_request =
+            self.build_subscribe_request(transactions, accounts, commitment, event_type_filter);
+        let (sink, stream) = self.subscribe(subscribe_request.clone()).await?;
+        Ok((sink, stream, subscribe_request))
+    }
+
+    /// Open a new connection and subscribe with an existing request (used for reconnection)
+    pub async fn subscribe(
+        &self,
+        subscribe_request: SubscribeRequest,
+    ) -> StreamResult<(
+        impl Sink<SubscribeRequest, Error = mpsc::SendError>,
+        impl Stream<Item = Result<SubscribeUpdate, Status>>,
+    )> {
+        let mut client = self.connect().await?;
+        let endpoint = self.active_endpoint().to_string();
+        let transactions = subscribe_request.transactions.len();
+        let accounts = subscribe_request.accounts.len();
+        let commitment = subscribe_request.commitment;
+        let subscribe_timeout = Duration::from_secs(self.config.connection.subscribe_timeout);
+        let subscribe = client.subscribe_with_request(Some(subscribe_request));
+        let subscription = tokio::time::timeout(subscribe_timeout, subscribe)
+            .await
+            .map_err(|_| StreamError::Timeout("subscribe"))??;
+        tracing::info!(
+            endpoint = %endpoint,
+            transactions,
+            accounts,
+            commitment,
+            "subscribe request sent"
+        );
+        Ok(subscription)
+    }
+
+    /// Start a request builder that uses this manager's block subscription configuration
+    pub fn request_builder(&self) -> SubscribeRequestBuilder {
+        SubscribeRequestBuilder::new().blocks(self.config.blocks.clone())
+    }
+
+    /// Build subscription request
+    pub fn build_subscribe_request(
+        &self,
+        transactions: Option<TransactionsFilterMap>,
+        accounts: Option<AccountsFilterMap>,
+        commitment: Option<CommitmentLevel>,
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> SubscribeRequest {
+        let (blocks_meta, blocks, entry) =
+            block_filter_maps(&self.config.blocks, event_type_filter);
+        SubscribeRequest {
             accounts: accounts.unwrap_or_default(),
             transactions: transactions.unwrap_or_default(),
             blocks_meta,
             blocks,
-            commitment: if let Some(commitment) = commitment {
-                Some(commitment as i32)
-            } else {
-                Some(CommitmentLevel::Processed.into())
-            },
+            entry,
+            commitment: Some(commitment.unwrap_or(CommitmentLevel::Processed) as i32),
             ..Default::default()
-        };
-        let mut client = self.connect().await?;
-        let (sink, stream) = client.subscribe_with_request(Some(subscribe_request.clone())).await?;
-        Ok((sink, stream, subscribe_request))
+        }
     }
 
     /// Create account subscription request and return stream
@@ -104,25 +278,7 @@ impl SubscriptionManager {
         account_filter: Vec<AccountFilter>,
         event_type_filter: Option<&EventTypeFilter>,
     ) -> Option<AccountsFilterMap> {
-        if event_type_filter.is_some() && !event_type_filter.unwrap().include_account_event() {
-            return None;
-        }
-        if account_filter.len() == 0 {
-            return None;
-        }
-        let mut accounts = HashMap::new();
-        for (index, af) in account_filter.iter().enumerate() {
-            accounts.insert(
-                format!("account_{}", index),
-                SubscribeRequestFilterAccounts {
-                    account: af.account.clone(),
-                    owner: af.owner.clone(),
-                    filters: af.filters.clone(),
-                    nonempty_txn_signature: None,
-                },
-            );
-        }
-        Some(accounts)
+        account_filter_map(&account_filter, event_type_filter)
     }
 
     /// Generate subscription request filter
@@ -131,24 +287,7 @@ impl SubscriptionManager {
         transaction_filter: Vec<TransactionFilter>,
         event_type_filter: Option<&EventTypeFilter>,
     ) -> Option<TransactionsFilterMap> {
-        if event_type_filter.is_some() && !event_type_filter.unwrap().include_transaction_event() {
-            return None;
-        }
-        let mut transactions = HashMap::new();
-        for (index, tf) in transaction_filter.iter().enumerate() {
-            transactions.insert(
-                format!("transaction_{}", index),
-                SubscribeRequestFilterTransactions {
-                    vote: Some(false),
-                    failed: Some(false),
-                    signature: None,
-                    account_include: tf.account_include.clone(),
-                    account_exclude: tf.account_exclude.clone(),
-                    account_required: tf.account_required.clone(),
-                },
-            );
-        }
-        Some(transactions)
+        transaction_filter_map(&transaction_filter, event_type_filter)
     }
 
     /// Get configuration
@@ -156,3 +295,211 @@ impl SubscriptionManager {
         &self.config
     }
 }
+
+/// `SubscribeRequest` 构建器
+///
+/// 集中处理交易过滤器的默认值（排除投票和失败交易）、事件类型过滤和 block / block meta 订阅，
+/// `build()` 时校验请求至少包含一个交易、账户或 block 过滤器
+///
+/// ```rust,ignore
+/// let request = SubscribeRequestBuilder::new()
+///     .transactions(vec![transaction_filter])
+///     .accounts(vec![account_filter])
+///     .commitment(CommitmentLevel::Confirmed)
+///     .event_types(EventTypeFilter { include: vec![EventType::PumpFunBuy] })
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SubscribeRequestBuilder {
+    transactions: Vec<TransactionFilter>,
+    accounts: Vec<AccountFilter>,
+    commitment: Option<CommitmentLevel>,
+    event_type_filter: Option<EventTypeFilter>,
+    blocks: BlockSubscriptionConfig,
+    from_slot: Option<u64>,
+}
+
+impl SubscribeRequestBuilder {
+    /// 默认订阅 block meta，确认级别为 Processed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加交易过滤器，每个过滤器对应请求中的一个 `transaction_{index}`
+    pub fn transactions(mut self, filters: Vec<TransactionFilter>) -> Self {
+        self.transactions.extend(filters);
+        self
+    }
+
+    /// 追加账户过滤器，每个过滤器对应请求中的一个 `account_{index}`
+    pub fn accounts(mut self, filters: Vec<AccountFilter>) -> Self {
+        self.accounts.extend(filters);
+        self
+    }
+
+    /// 订阅的确认级别
+    pub fn commitment(mut self, commitment: CommitmentLevel) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// 按事件类型裁剪订阅：不包含交易、账户或 block 事件时不订阅对应的数据
+    pub fn event_types(mut self, filter: EventTypeFilter) -> Self {
+        self.event_type_filter = Some(filter);
+        self
+    }
+
+    /// block 和 block meta 订阅配置
+    pub fn blocks(mut self, blocks: BlockSubscriptionConfig) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    /// 从指定 slot 开始订阅，服务端仍保留该 slot 时会先重放之后的数据
+    pub fn from_slot(mut self, slot: u64) -> Self {
+        self.from_slot = Some(slot);
+        self
+    }
+
+    /// 构建请求，没有任何过滤器时返回 `StreamError::Config`
+    pub fn build(self) -> StreamResult<SubscribeRequest> {
+        let event_type_filter = self.event_type_filter.as_ref();
+        let (blocks_meta, blocks, entry) = block_filter_maps(&self.blocks, event_type_filter);
+        let request = SubscribeRequest {
+            transactions: transaction_filter_map(&self.transactions, event_type_filter)
+                .unwrap_or_default(),
+            accounts: account_filter_map(&self.accounts, event_type_filter).unwrap_or_default(),
+            blocks_meta,
+            blocks,
+            entry,
+            commitment: Some(self.commitment.unwrap_or(CommitmentLevel::Processed) as i32),
+            from_slot: self.from_slot,
+            ..Default::default()
+        };
+        validate_subscribe_request(&request)?;
+        Ok(request)
+    }
+}
+
+/// 检查订阅请求：至少包含一个交易、账户或区块过滤器，且过滤器中的地址和签名均合法
+///
+/// 服务端收到非法请求会直接断开流，在发送前检查可以避免一次重连
+pub fn validate_subscribe_request(request: &SubscribeRequest) -> StreamResult<()> {
+    if request.transactions.is_empty()
+        && request.accounts.is_empty()
+        && request.blocks_meta.is_empty()
+        && request.blocks.is_empty()
+        && request.entry.is_empty()
+    {
+        return Err(StreamError::Config(
+            "subscribe request has no transaction, account or block filter".to_string(),
+        ));
+    }
+    let check_pubkeys = |name: &str, keys: &[String]| {
+        keys.iter().try_for_each(|key| {
+            Pubkey::from_str(key).map(|_| ()).map_err(|_| {
+                StreamError::Config(format!("invalid pubkey {key:?} in filter {name}"))
+            })
+        })
+    };
+    for (name, filter) in &request.transactions {
+        check_pubkeys(name, &filter.account_include)?;
+        check_pubkeys(name, &filter.account_exclude)?;
+        check_pubkeys(name, &filter.account_required)?;
+        if let Some(signature) = &filter.signature {
+            Signature::from_str(signature).map_err(|_| {
+                StreamError::Config(format!("invalid signature {signature:?} in filter {name}"))
+            })?;
+        }
+    }
+    for (name, filter) in &request.accounts {
+        check_pubkeys(name, &filter.account)?;
+        check_pubkeys(name, &filter.owner)?;
+    }
+    Ok(())
+}
+
+fn transaction_filter_map(
+    transaction_filter: &[TransactionFilter],
+    event_type_filter: Option<&EventTypeFilter>,
+) -> Option<TransactionsFilterMap> {
+    if event_type_filter.is_some_and(|filter| !filter.include_transaction_event()) {
+        return None;
+    }
+    let mut transactions = HashMap::new();
+    for (index, tf) in transaction_filter.iter().enumerate() {
+        transactions.insert(
+            format!("transaction_{}", index),
+            SubscribeRequestFilterTransactions {
+                // None 表示不按该条件过滤
+                vote: (!tf.include_votes).then_some(false),
+                failed: (!tf.include_failed).then_some(false),
+                signature: tf.signature.clone(),
+                account_include: tf.account_include.clone(),
+                account_exclude: tf.account_exclude.clone(),
+                account_required: tf.account_required.clone(),
+            },
+        );
+    }
+    Some(transactions)
+}
+
+fn account_filter_map(
+    account_filter: &[AccountFilter],
+    event_type_filter: Option<&EventTypeFilter>,
+) -> Option<AccountsFilterMap> {
+    if event_type_filter.is_some_and(|filter| !filter.include_account_event()) {
+        return None;
+    }
+    if account_filter.is_empty() {
+        return None;
+    }
+    let mut accounts = HashMap::new();
+    for (index, af) in account_filter.iter().enumerate() {
+        accounts.insert(
+            format!("account_{}", index),
+            SubscribeRequestFilterAccounts {
+                account: af.account.clone(),
+                owner: af.owner.clone(),
+                filters: af.filters.clone(),
+                nonempty_txn_signature: None,
+            },
+        );
+    }
+    Some(accounts)
+}
+
+fn block_filter_maps(
+    block_config: &BlockSubscriptionConfig,
+    event_type_filter: Option<&EventTypeFilter>,
+) -> (
+    HashMap<String, SubscribeRequestFilterBlocksMeta>,
+    HashMap<String, SubscribeRequestFilterBlocks>,
+    HashMap<String, SubscribeRequestFilterEntry>,
+) {
+    let include_block_event = event_type_filter.is_none_or(|f| f.includes(&EventType::BlockMeta));
+    let blocks_meta = if include_block_event && block_config.subscribe_block_meta {
+        hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} }
+    } else {
+        hashmap! {}
+    };
+    // 完整 Block 数据量很大，只在配置中显式开启时订阅
+    let blocks = if include_block_event && block_config.subscribe_blocks {
+        hashmap! { "".to_owned() => SubscribeRequestFilterBlocks {
+            account_include: vec![],
+            include_transactions: Some(block_config.include_transactions),
+            include_accounts: Some(block_config.include_accounts),
+            include_entries: Some(block_config.include_entries),
+        } }
+    } else {
+        hashmap! {}
+    };
+    // entry 每个 slot 有数百条，只在配置中显式开启时订阅
+    let include_entry_event = event_type_filter.is_none_or(|f| f.includes(&EventType::Entry));
+    let entry = if include_entry_event && block_config.subscribe_entries {
+        hashmap! { "".to_owned() => SubscribeRequestFilterEntry {} }
+    } else {
+        hashmap! {}
+    };
+    (blocks_meta, blocks, entry)
+}
@@ -0,0 +1,95 @@
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, SignatureScheme};
+use std::sync::Arc;
+use tonic::transport::{Certificate, ClientTlsConfig, Endpoint, Identity};
+
+use crate::streaming::common::{StreamError, StreamResult, TlsConfig};
+
+/// 按 `TlsConfig` 为 endpoint 配置 TLS，只对 `https` endpoint 生效
+///
+/// 默认使用系统根证书校验服务端证书，`ca_cert_path` 在此基础上追加信任的 CA
+pub(crate) fn apply_tls_config(endpoint: Endpoint, config: &TlsConfig) -> StreamResult<Endpoint> {
+    let mut tls = ClientTlsConfig::new();
+    if let Some(domain_name) = &config.domain_name {
+        tls = tls.domain_name(domain_name.clone());
+    }
+    match (&config.client_cert_path, &config.client_key_path) {
+        (Some(cert), Some(key)) => {
+            tls = tls.identity(Identity::from_pem(read_pem(cert)?, read_pem(key)?))
+        }
+        (None, None) => {}
+        _ => {
+            return Err(StreamError::Config(
+                "TLS client_cert_path and client_key_path must be set together".to_string(),
+            ))
+        }
+    }
+
+    if config.insecure_skip_verify {
+        log::warn!("TLS certificate verification is disabled for {}", endpoint.uri());
+        // 自定义校验器不能与根证书同时配置，ca_cert_path 在此模式下没有意义
+        return endpoint
+            .tls_config_with_verifier(tls, Arc::new(NoCertificateVerification::new()))
+            .map_err(StreamError::Tls);
+    }
+
+    tls = tls.with_native_roots();
+    if let Some(path) = &config.ca_cert_path {
+        tls = tls.ca_certificate(Certificate::from_pem(read_pem(path)?));
+    }
+    endpoint.tls_config(tls).map_err(StreamError::Tls)
+}
+
+fn read_pem(path: &str) -> StreamResult<Vec<u8>> {
+    std::fs::read(path)
+        .map_err(|e| StreamError::Config(format!("failed to read TLS file {path}: {e}")))
+}
+
+/// 接受任意服务端证书的校验器，仅用于 `TlsConfig::insecure_skip_verify`
+///
+/// 握手签名仍然校验，只保证对端持有其出示证书的私钥，不保证证书可信
+#[derive(Debug)]
+struct NoCertificateVerification(CryptoProvider);
+
+impl NoCertificateVerification {
+    fn new() -> Self {
+        Self(rustls::crypto::ring::default_provider())
+    }
+}
+
+impl ServerCertVerifier for NoCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
@@ -16,6 +16,7 @@ pub enum EventPretty {
     BlockMeta(BlockMetaPretty),
     Transaction(TransactionPretty),
     Account(AccountPretty),
+    Entry(EntryPretty),
 }
 
 #[derive(Clone, Default)]
@@ -29,6 +30,8 @@ pub struct AccountPretty {
     pub rent_epoch: u64,
     pub data: Vec<u8>,
     pub recv_us: i64,
+    /// gRPC 流读出该更新时的时间（微秒），由 `process_data_update` 设置
+    pub received_us: i64,
 }
 
 impl fmt::Debug for AccountPretty {
@@ -50,8 +53,16 @@ impl fmt::Debug for AccountPretty {
 pub struct BlockMetaPretty {
     pub slot: u64,
     pub block_hash: String,
+    pub parent_slot: u64,
+    pub parent_block_hash: String,
+    pub block_height: Option<u64>,
+    pub executed_transaction_count: u64,
     pub block_time: Option<Timestamp>,
+    /// 链上记录的出块时间（秒）
+    pub unix_block_time: Option<i64>,
     pub recv_us: i64,
+    /// gRPC 流读出该更新时的时间（微秒），由 `process_data_update` 设置
+    pub received_us: i64,
 }
 
 impl fmt::Debug for BlockMetaPretty {
@@ -59,12 +70,34 @@ impl fmt::Debug for BlockMetaPretty {
         f.debug_struct("BlockMetaPretty")
             .field("slot", &self.slot)
             .field("block_hash", &self.block_hash)
+            .field("parent_slot", &self.parent_slot)
+            .field("parent_block_hash", &self.parent_block_hash)
+            .field("block_height", &self.block_height)
+            .field("executed_transaction_count", &self.executed_transaction_count)
             .field("block_time", &self.block_time)
+            .field("unix_block_time", &self.unix_block_time)
             .field("recv_us", &self.recv_us)
+            .field("received_us", &self.received_us)
             .finish()
     }
 }
 
+/// entry 更新，只在开启 `blocks.subscribe_entries` 时收到
+#[derive(Clone, Debug, Default)]
+pub struct EntryPretty {
+    pub slot: u64,
+    /// entry 在 slot 内的序号
+    pub index: u64,
+    pub num_hashes: u64,
+    pub hash: String,
+    pub executed_transaction_count: u64,
+    /// entry 中第一笔交易在 slot 内的序号
+    pub starting_transaction_index: u64,
+    pub recv_us: i64,
+    /// gRPC 流读出该更新时的时间（微秒），由 `process_data_update` 设置
+    pub received_us: i64,
+}
+
 #[derive(Clone)]
 pub struct TransactionPretty {
     pub slot: u64,
@@ -74,6 +107,8 @@ pub struct TransactionPretty {
     pub signature: Signature,
     pub is_vote: bool,
     pub recv_us: i64,
+    /// gRPC 流读出该更新时的时间（微秒），由 `process_data_update` 设置
+    pub received_us: i64,
     pub grpc_tx: SubscribeUpdateTransactionInfo,
 }
 
@@ -85,6 +120,7 @@ impl fmt::Debug for TransactionPretty {
             .field("signature", &self.signature)
             .field("is_vote", &self.is_vote)
             .field("recv_us", &self.recv_us)
+            .field("received_us", &self.received_us)
             .finish()
     }
 }
@@ -100,6 +136,7 @@ impl Default for TransactionPretty {
             is_vote: false,
             grpc_tx: SubscribeUpdateTransactionInfo::default(),
             recv_us: 0,
+            received_us: 0,
         }
     }
 }
@@ -1,11 +1,19 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod common;
 pub mod event_parser;
 pub mod grpc;
 pub mod shred;
 pub mod shred_stream;
+pub mod testing;
 pub mod yellowstone_grpc;
+pub mod yellowstone_replay;
 pub mod yellowstone_sub_system;
 
+#[cfg(feature = "blocking")]
+pub use blocking::{BlockingStopHandle, BlockingYellowstoneGrpc};
 pub use shred::ShredStreamGrpc;
-pub use yellowstone_grpc::YellowstoneGrpc;
+pub use yellowstone_grpc::{
+    EventStreamHandle, PingInfo, RawUpdateCallback, TransactionEvents, YellowstoneGrpc,
+};
 pub use yellowstone_sub_system::{SystemEvent, TransferInfo};
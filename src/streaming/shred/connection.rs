@@ -2,10 +2,10 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use tonic::transport::Channel;
 
-use crate::common::AnyResult;
 use crate::protos::shredstream::shredstream_proxy_client::ShredstreamProxyClient;
 use crate::streaming::common::{
-    MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
+    MetricsManager, MetricsSnapshot, PerformanceMetrics, StreamClientConfig, StreamError, StreamResult,
+    SubscriptionHandle,
 };
 
 /// ShredStream gRPC 客户端
@@ -18,13 +18,15 @@ pub struct ShredStreamGrpc {
 
 impl ShredStreamGrpc {
     /// 创建客户端，使用默认配置
-    pub async fn new(endpoint: String) -> AnyResult<Self> {
+    pub async fn new(endpoint: String) -> StreamResult<Self> {
         Self::new_with_config(endpoint, StreamClientConfig::default()).await
     }
 
     /// 创建客户端，使用自定义配置
-    pub async fn new_with_config(endpoint: String, config: StreamClientConfig) -> AnyResult<Self> {
-        let shredstream_client = ShredstreamProxyClient::connect(endpoint.clone()).await?;
+    pub async fn new_with_config(endpoint: String, config: StreamClientConfig) -> StreamResult<Self> {
+        let shredstream_client = ShredstreamProxyClient::connect(endpoint.clone())
+            .await
+            .map_err(StreamError::Connection)?;
         MetricsManager::init(config.enable_metrics);
         Ok(Self {
             shredstream_client: Arc::new(shredstream_client),
@@ -48,6 +50,17 @@ impl ShredStreamGrpc {
         MetricsManager::global().get_metrics()
     }
 
+    /// 获取完整指标快照，包括按协议和事件类型的解析指标
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsManager::global().metrics_snapshot()
+    }
+
+    /// 以 Prometheus 文本格式导出当前指标，可直接作为 `/metrics` 的响应体
+    #[cfg(feature = "prometheus")]
+    pub fn encode_prometheus_metrics(&self) -> String {
+        MetricsManager::global().encode_prometheus()
+    }
+
     /// 启用或禁用性能监控
     pub fn set_enable_metrics(&mut self, enabled: bool) {
         self.config.enable_metrics = enabled;
@@ -3,12 +3,12 @@ use std::sync::Arc;
 use futures::StreamExt;
 use solana_sdk::pubkey::Pubkey;
 
-use crate::common::AnyResult;
 use crate::protos::shredstream::SubscribeEntriesRequest;
-use crate::streaming::common::{process_shred_transaction, SubscriptionHandle};
+use crate::streaming::common::callback_guard::catch_callback_panics;
+use crate::streaming::common::{process_shred_transaction, StreamResult, SubscriptionHandle};
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
-use crate::streaming::event_parser::{Protocol, DexEvent};
+use crate::streaming::event_parser::{DexEvent, ParseOptions, Protocol};
 use crate::streaming::grpc::MetricsManager;
 use crate::streaming::shred::pool::factory;
 use log::error;
@@ -24,7 +24,7 @@ impl ShredStreamGrpc {
         bot_wallet: Option<Pubkey>,
         event_type_filter: Option<EventTypeFilter>,
         callback: F,
-    ) -> AnyResult<()>
+    ) -> StreamResult<()>
     where
         F: Fn(DexEvent) + Send + Sync + 'static,
     {
@@ -43,7 +43,23 @@ impl ShredStreamGrpc {
         let mut stream = client.subscribe_entries(request).await?.into_inner();
 
         // Wrap callback once before the async block
-        let callback = Arc::new(callback);
+        let callback: Arc<dyn Fn(DexEvent) + Send + Sync> =
+            if self.config.transaction_meta.is_empty() {
+                Arc::new(callback)
+            } else {
+                let meta_filter = self.config.transaction_meta.clone();
+                Arc::new(move |event: DexEvent| {
+                    if meta_filter.passes(&event) {
+                        callback(event);
+                    }
+                })
+            };
+        // 回调 panic 只跳过当前事件，不中断流
+        let callback = catch_callback_panics(callback);
+
+        // 最小成交额过滤，空表时不传入解析循环
+        let min_quote_amount = (!self.config.min_quote_amount.is_empty())
+            .then(|| self.config.min_quote_amount.clone());
 
         let stream_task = tokio::spawn(async move {
             while let Some(message) = stream.next().await {
@@ -59,12 +75,14 @@ impl ShredStreamGrpc {
                                             get_high_perf_clock(),
                                         );
                                     // Process transaction - clone Arc and Vec for each call
+                                    let options = ParseOptions::new(&protocols)
+                                        .with_event_type_filter(event_type_filter.as_ref())
+                                        .with_min_quote_amount(min_quote_amount.as_ref())
+                                        .with_bot_wallet(bot_wallet);
                                     if let Err(e) = process_shred_transaction(
                                         transaction_with_slot,
-                                        &protocols,
-                                        event_type_filter.as_ref(),
+                                        options,
                                         callback.clone(),
-                                        bot_wallet,
                                     )
                                     .await
                                     {
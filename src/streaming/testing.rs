@@ -0,0 +1,158 @@
+use crate::common::AnyResult;
+use crate::streaming::common::read_recorded_updates;
+use crate::streaming::event_parser::core::event_parser::EventParser;
+use crate::streaming::event_parser::{DexEvent, ParseOptions, Protocol};
+use futures::Stream;
+use parking_lot::Mutex;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+use yellowstone_grpc_proto::tonic::Status;
+
+/// 由预先构造的消息组成的 gRPC 消息流，用于在没有 endpoint 的情况下测试事件处理逻辑
+///
+/// 元素类型与实时订阅的消息流相同，可以交给 `YellowstoneGrpc::subscribe_events_from_stream`
+/// 经过完整的解析、过滤和回调流程。所有消息依次产出后流结束
+///
+/// ```rust,ignore
+/// let stream = MockStream::from_updates(updates);
+/// grpc.subscribe_events_from_stream(stream, Protocol::all(), None, None, |event| {
+///     assert!(event.metadata().succeeded);
+/// })
+/// .await?;
+/// ```
+#[derive(Debug, Default)]
+pub struct MockStream {
+    items: VecDeque<Result<SubscribeUpdate, Status>>,
+}
+
+impl MockStream {
+    /// 依次产出 `updates`
+    pub fn from_updates(updates: impl IntoIterator<Item = SubscribeUpdate>) -> Self {
+        Self { items: updates.into_iter().map(Ok).collect() }
+    }
+
+    /// 依次产出 `items`，可以在中间插入错误以测试断流处理
+    pub fn from_results(items: impl IntoIterator<Item = Result<SubscribeUpdate, Status>>) -> Self {
+        Self { items: items.into_iter().collect() }
+    }
+
+    /// 产出 `StreamClientConfig::record_path` 录制的全部消息
+    pub fn from_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::from_updates(read_recorded_updates(path)?))
+    }
+
+    /// 在末尾追加一条消息
+    pub fn push(&mut self, update: SubscribeUpdate) {
+        self.items.push_back(Ok(update));
+    }
+
+    /// 在末尾追加一个错误
+    pub fn push_error(&mut self, status: Status) {
+        self.items.push_back(Err(status));
+    }
+
+    /// 剩余未产出的消息数
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl Stream for MockStream {
+    type Item = Result<SubscribeUpdate, Status>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().items.pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.items.len(), Some(self.items.len()))
+    }
+}
+
+/// 解析 bincode 编码的 `VersionedTransaction` (即 RPC `base64` 编码的交易解码后的字节)
+///
+/// 只有交易本身没有 meta：inner instructions 和程序日志不可用，依赖 CPI 日志的事件
+/// (如 PumpFun 的交易事件) 只包含指令中的字段；地址查找表中的账户为 `Pubkey::default()`。
+/// 需要完整结果时使用 `parse_transaction_json`
+///
+/// 事件的 `recv_us`、`handle_us` 和 `received_us` 置为 0，便于与期望结果直接比较
+pub async fn parse_transaction_bytes(
+    protocols: &[Protocol],
+    tx_bytes: &[u8],
+) -> AnyResult<Vec<DexEvent>> {
+    let transaction: VersionedTransaction = bincode::deserialize(tx_bytes)?;
+    let signature = transaction.signatures.first().copied().unwrap_or_default();
+    let accounts: Vec<Pubkey> = transaction.message.static_account_keys().to_vec();
+    let (events, callback) = collector();
+    EventParser::parse_instruction_events_from_versioned_transaction(
+        ParseOptions::new(protocols),
+        &transaction,
+        signature,
+        None,
+        None,
+        0,
+        &accounts,
+        &[],
+        None,
+        callback,
+    )
+    .await?;
+    Ok(take_events(&events))
+}
+
+/// 解析 RPC `getTransaction` 的 JSON 响应 (`base64` 或 `base58` 编码)，包含 inner instructions、
+/// 程序日志和交易 meta，适合作为回归测试的固定样本
+///
+/// 事件的 `recv_us`、`handle_us` 和 `received_us` 置为 0，便于与期望结果直接比较
+pub async fn parse_transaction_json(
+    protocols: &[Protocol],
+    json: &str,
+) -> AnyResult<Vec<DexEvent>> {
+    let transaction: EncodedConfirmedTransactionWithStatusMeta = serde_json::from_str(json)?;
+    let signature = transaction
+        .transaction
+        .transaction
+        .decode()
+        .and_then(|transaction| transaction.signatures.first().copied())
+        .unwrap_or_default();
+    let (events, callback) = collector();
+    EventParser::parse_encoded_confirmed_transaction(
+        ParseOptions::new(protocols),
+        transaction,
+        signature,
+        0,
+        callback,
+    )
+    .await?;
+    Ok(take_events(&events))
+}
+
+type EventCollector = Arc<Mutex<Vec<DexEvent>>>;
+
+fn collector() -> (EventCollector, Arc<dyn Fn(DexEvent) + Send + Sync>) {
+    let events = EventCollector::default();
+    let collected = events.clone();
+    (events, Arc::new(move |event: DexEvent| collected.lock().push(event)))
+}
+
+fn take_events(events: &EventCollector) -> Vec<DexEvent> {
+    let mut events = std::mem::take(&mut *events.lock());
+    for event in &mut events {
+        let metadata = event.metadata_mut();
+        metadata.recv_us = 0;
+        metadata.handle_us = 0;
+        metadata.received_us = 0;
+    }
+    events
+}
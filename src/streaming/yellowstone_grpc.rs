@@ -1,32 +1,112 @@
 use crate::common::AnyResult;
+use crate::streaming::common::callback_guard::{
+    await_catching_panics, call_catching_panics, catch_callback_panics,
+};
 use crate::streaming::common::{
-    process_grpc_transaction, MetricsManager, PerformanceMetrics, StreamClientConfig,
-    SubscriptionHandle,
+    enrich_event, process_grpc_transaction, update_slot, AccountLamportsCache, AccountOwnerCache,
+    BoundedQueue, CommitmentTracker, ConnectionState, EndpointHealth,
+    ConnectionStateNotifier, EventDeduplicator, EventReorderBuffer, EventSampler, EventThrottle, ForkDetector, MetricsManager,
+    MetricsSnapshot, ParseConfig, PerformanceMetrics, PriceFeed, ReconnectConfig, SlotLagMonitor,
+    StreamClientConfig, StreamError, StreamResult, SubscriptionHandle, ThrottleAction,
+    TokenRegistry, TransferFeeCache, UpdateRecorder,
+};
+use crate::streaming::event_parser::common::filter::{
+    EventTypeFilter, MinQuoteAmountFilter, MintFilter,
 };
-use crate::streaming::event_parser::common::filter::EventTypeFilter;
-use crate::streaming::event_parser::{DexEvent, Protocol};
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::event_parser::common::{EventCommitment, ParseError, ParseErrorCallback};
+use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
+use crate::streaming::event_parser::core::event_parser::EventParser;
+use crate::streaming::event_parser::{DexEvent, ParseOptions, Protocol};
 use crate::streaming::grpc::pool::factory;
-use crate::streaming::grpc::{EventPretty, SubscriptionManager};
+use crate::streaming::grpc::{validate_subscribe_request, EventPretty, SubscriptionManager};
 use anyhow::anyhow;
-use chrono::Local;
 use futures::channel::mpsc;
-use futures::{SinkExt, StreamExt};
-use log::error;
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use tracing::{debug, error, info, warn, Instrument};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
-use std::sync::atomic::{AtomicBool, Ordering};
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+use futures::future::{self, BoxFuture};
+use futures::stream::{BoxStream, FuturesOrdered};
+use futures::FutureExt;
+use prost_types::Timestamp;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{watch, Mutex, Semaphore};
+use tokio::time::Instant;
 use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
 use yellowstone_grpc_proto::geyser::{
-    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter, SubscribeRequestPing,
+    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter,
+    SubscribeRequestFilterSlots, SubscribeRequestPing, SubscribeUpdate,
 };
+use yellowstone_grpc_proto::tonic::Status;
+
+/// 异步回调模式下，消费缓存事件的函数
+type EventDrain = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// gRPC 读取任务发往解析任务的消息
+///
+/// 绝大多数消息是 `Update`，不为其额外装箱
+#[allow(clippy::large_enum_variant)]
+enum PipelineItem {
+    /// 待解析的更新、其 `created_at` 和从流中读出的时间（微秒）
+    Update(UpdateOneof, Option<Timestamp>, i64),
+    /// 读取任务自身生成的事件（如重连事件）
+    Event(Box<DexEvent>),
+}
+
+/// 订阅包含的过滤器，`subscribe_mixed_commitment` 把交易和账户过滤器拆到两个订阅
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum FilterScope {
+    #[default]
+    All,
+    Transactions,
+    Accounts,
+}
+
+impl FilterScope {
+    /// 只保留本订阅负责的过滤器
+    fn select(
+        self,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+    ) -> (Vec<TransactionFilter>, Vec<AccountFilter>) {
+        match self {
+            FilterScope::All => (transaction_filter, account_filter),
+            FilterScope::Transactions => (transaction_filter, Vec::new()),
+            FilterScope::Accounts => (Vec::new(), account_filter),
+        }
+    }
+}
+
+/// 原始消息回调，在解析前收到每条 `SubscribeUpdate`
+pub type RawUpdateCallback = Arc<dyn Fn(&SubscribeUpdate) + Send + Sync>;
+
+/// 订阅请求发送端，重连后整体替换
+type GrpcSink = Pin<Box<dyn Sink<SubscribeRequest, Error = mpsc::SendError> + Send>>;
+/// 订阅消息流，重连后整体替换
+type GrpcStream = BoxStream<'static, Result<SubscribeUpdate, Status>>;
 
 /// 交易过滤器
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TransactionFilter {
     pub account_include: Vec<String>,
     pub account_exclude: Vec<String>,
     pub account_required: Vec<String>,
+    /// 同时订阅执行失败的交易，事件的 `metadata.succeeded` 为 false (默认: false)
+    pub include_failed: bool,
+    /// 同时订阅投票交易 (默认: false)
+    pub include_votes: bool,
+    /// 只订阅该签名 (base58) 的交易，与账户条件同时满足时才推送，可用于确认自己的交易上链
+    pub signature: Option<String>,
 }
 
 /// 账户过滤器
@@ -37,6 +117,47 @@ pub struct AccountFilter {
     pub filters: Vec<SubscribeRequestFilterAccountsFilter>,
 }
 
+/// `YellowstoneGrpc::ping` 的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PingInfo {
+    /// 响应的 endpoint，配置了多个 endpoint 时为第一个连接成功的
+    pub endpoint: String,
+    /// 服务端 `GetVersion` 返回的版本信息 (JSON 字符串)
+    pub version: String,
+    /// 服务端当前的 processed slot
+    pub slot: u64,
+    /// 建立连接并完成两次请求的总耗时
+    pub latency: Duration,
+}
+
+/// `YellowstoneGrpc::subscribe_events_by_transaction` 推送的单笔交易的全部事件
+///
+/// 不属于交易的事件 (block meta、重连、slot 延迟等) 单独成组，`signature` 为默认值
+#[derive(Debug, Clone, Default)]
+pub struct TransactionEvents {
+    pub signature: Signature,
+    pub slot: u64,
+    /// 按解析顺序排列的事件
+    pub events: Vec<DexEvent>,
+}
+
+impl TransactionEvents {
+    /// 把按顺序排列的事件按连续的 (signature, slot) 分组
+    fn group(events: Vec<DexEvent>) -> Vec<Self> {
+        let mut groups: Vec<Self> = Vec::new();
+        for event in events {
+            let (signature, slot) = (event.metadata().signature, event.metadata().slot);
+            match groups.last_mut() {
+                Some(group) if group.signature == signature && group.slot == slot => {
+                    group.events.push(event)
+                }
+                _ => groups.push(Self { signature, slot, events: vec![event] }),
+            }
+        }
+        groups
+    }
+}
+
 pub struct YellowstoneGrpc {
     pub endpoint: String,
     pub x_token: Option<String>,
@@ -46,14 +167,35 @@ pub struct YellowstoneGrpc {
     // Dynamic subscription management fields
     pub active_subscription: Arc<AtomicBool>,
     pub control_tx: Arc<tokio::sync::Mutex<Option<mpsc::Sender<SubscribeRequest>>>>,
-    pub current_request: Arc<tokio::sync::RwLock<Option<SubscribeRequest>>>,
+    pub(crate) current_request: Arc<tokio::sync::RwLock<Option<SubscribeRequest>>>,
 
     pub event_type_filter: Arc<tokio::sync::RwLock<Option<EventTypeFilter>>>,
+    pub mint_filter: Arc<parking_lot::RwLock<MintFilter>>,
+    /// 当前启用解析的协议，解析循环每条消息读取一次
+    pub active_protocols: Arc<parking_lot::RwLock<Arc<Vec<Protocol>>>>,
+    /// 原始消息回调，未设置时不产生额外开销
+    pub raw_callback: Arc<parking_lot::RwLock<Option<RawUpdateCallback>>>,
+    /// 本客户端订阅的解析错误回调，订阅时读取
+    pub parse_error_callback: Arc<parking_lot::RwLock<Option<ParseErrorCallback>>>,
+    /// 挂在订阅上的价格源，未设置时不产生额外开销
+    pub price_feed: Arc<parking_lot::RwLock<Option<Arc<PriceFeed>>>>,
+    /// 为交易事件计算转账手续费净额的缓存，未设置时不产生额外开销
+    pub transfer_fee_cache: Arc<parking_lot::RwLock<Option<Arc<TransferFeeCache>>>>,
+    /// 为交易事件填充代币符号和界面数量的注册表，未设置时不产生额外开销
+    pub token_registry: Arc<parking_lot::RwLock<Option<Arc<dyn TokenRegistry>>>>,
+    /// `subscribe_multi_commitment` 为其余确认级别开启的订阅，随本客户端一起停止和更新
+    pub commitment_subscriptions: Arc<Mutex<Vec<YellowstoneGrpc>>>,
+    /// 连接状态和状态变化回调
+    pub state_notifier: ConnectionStateNotifier,
+    /// 最后处理完成的数据更新所在的 slot，0 表示还没有处理过
+    pub last_processed_slot: Arc<AtomicU64>,
+    /// 本客户端订阅负责的过滤器，`update_subscription` 只更新这部分
+    filter_scope: Arc<parking_lot::RwLock<FilterScope>>,
 }
 
 impl YellowstoneGrpc {
     /// 创建客户端，使用默认配置
-    pub fn new(endpoint: String, x_token: Option<String>) -> AnyResult<Self> {
+    pub fn new(endpoint: String, x_token: Option<String>) -> StreamResult<Self> {
         Self::new_with_config(endpoint, x_token, StreamClientConfig::default())
     }
 
@@ -62,11 +204,26 @@ impl YellowstoneGrpc {
         endpoint: String,
         x_token: Option<String>,
         config: StreamClientConfig,
-    ) -> AnyResult<Self> {
+    ) -> StreamResult<Self> {
+        Self::new_with_endpoints(vec![(endpoint, x_token)], config)
+    }
+
+    /// 创建客户端，连接失败时按顺序切换到下一个 endpoint
+    ///
+    /// `endpoints` 为 (endpoint, x_token) 列表，按优先级排列；与断线重连配合可在多个服务商之间持续订阅
+    pub fn new_with_endpoints(
+        endpoints: Vec<(String, Option<String>)>,
+        config: StreamClientConfig,
+    ) -> StreamResult<Self> {
+        let (endpoint, x_token) = endpoints
+            .first()
+            .cloned()
+            .ok_or_else(|| StreamError::Config("At least one endpoint is required".into()))?;
         let _ = rustls::crypto::ring::default_provider().install_default().ok();
         let subscription_manager =
-            SubscriptionManager::new(endpoint.clone(), x_token.clone(), config.clone());
+            SubscriptionManager::new_with_endpoints(endpoints, config.clone());
         MetricsManager::init(config.enable_metrics);
+        MetricsManager::global().set_slow_parse_threshold_us(config.slow_parse_threshold_us);
 
         Ok(Self {
             endpoint,
@@ -78,9 +235,32 @@ impl YellowstoneGrpc {
             control_tx: Arc::new(tokio::sync::Mutex::new(None)),
             current_request: Arc::new(tokio::sync::RwLock::new(None)),
             event_type_filter: Arc::new(tokio::sync::RwLock::new(None)),
+            mint_filter: Arc::new(parking_lot::RwLock::new(MintFilter::default())),
+            active_protocols: Arc::default(),
+            raw_callback: Arc::default(),
+            parse_error_callback: Arc::default(),
+            price_feed: Arc::default(),
+            transfer_fee_cache: Arc::default(),
+            token_registry: Arc::default(),
+            commitment_subscriptions: Arc::default(),
+            state_notifier: ConnectionStateNotifier::default(),
+            last_processed_slot: Arc::default(),
+            filter_scope: Arc::default(),
         })
     }
 
+    /// 获取当前使用的 endpoint
+    pub fn active_endpoint(&self) -> &str {
+        self.subscription_manager.active_endpoint()
+    }
+
+    /// 获取本客户端各 endpoint 的连接次数、失败次数和作为当前 endpoint 的时长，按配置顺序排列
+    ///
+    /// 多确认级别订阅的次级连接单独统计，只计入 `MetricsManager::get_endpoint_metrics` 的汇总
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.subscription_manager.endpoint_health()
+    }
+
     /// 获取配置
     pub fn get_config(&self) -> &StreamClientConfig {
         &self.config
@@ -96,6 +276,17 @@ impl YellowstoneGrpc {
         MetricsManager::global().get_metrics()
     }
 
+    /// 获取完整指标快照，包括按协议和事件类型的解析指标
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsManager::global().metrics_snapshot()
+    }
+
+    /// 以 Prometheus 文本格式导出当前指标，可直接作为 `/metrics` 的响应体
+    #[cfg(feature = "prometheus")]
+    pub fn encode_prometheus_metrics(&self) -> String {
+        MetricsManager::global().encode_prometheus()
+    }
+
     /// 打印性能指标
     pub fn print_metrics(&self) {
         MetricsManager::global().print_metrics();
@@ -106,15 +297,150 @@ impl YellowstoneGrpc {
         self.config.enable_metrics = enabled;
     }
 
+    /// 设置 mint 过滤器，只推送涉及这些 mint 的事件，空列表表示不过滤
+    ///
+    /// 在解析后执行，可在订阅前或订阅中随时修改
+    pub fn set_mint_filter(&self, mints: Vec<Pubkey>) {
+        *self.mint_filter.write() = MintFilter::new(mints);
+    }
+
+    /// 设置启用解析的协议，无需重新订阅
+    ///
+    /// 订阅时会重置为订阅传入的协议列表；之后的修改从下一条 gRPC 消息开始生效。
+    /// 未启用协议的指令在解码前跳过，可用于在负载高峰时临时降低解析开销
+    pub fn set_active_protocols(&self, protocols: Vec<Protocol>) {
+        *self.active_protocols.write() = Arc::new(protocols);
+    }
+
+    /// 获取当前启用解析的协议
+    pub fn active_protocols(&self) -> Vec<Protocol> {
+        self.active_protocols.read().as_ref().clone()
+    }
+
+    /// 设置原始消息回调
+    ///
+    /// 每条 gRPC 消息（包括 Ping、Slot、完整 Block 等）在录制和解析之前先以引用传给回调，
+    /// 用于读取事件中没有的字段（entries、rewards 等）。回调在 gRPC 读取任务中同步执行，
+    /// 应尽快返回。可在订阅前或订阅中随时修改，文件回放同样生效
+    pub fn set_raw_callback<F>(&self, callback: F)
+    where
+        F: Fn(&SubscribeUpdate) + Send + Sync + 'static,
+    {
+        *self.raw_callback.write() = Some(Arc::new(callback));
+    }
+
+    /// 移除原始消息回调
+    pub fn clear_raw_callback(&self) {
+        *self.raw_callback.write() = None;
+    }
+
+    /// 设置解析错误回调
+    ///
+    /// 判别器已登记但数据或账户无法解码的指令以 `ParseError` 传给回调。只对本客户端之后开始的
+    /// 订阅和文件回放生效，不影响其他客户端；回调在解析任务中同步执行，应尽快返回
+    pub fn set_parse_error_callback<F>(&self, callback: F)
+    where
+        F: Fn(&ParseError) + Send + Sync + 'static,
+    {
+        *self.parse_error_callback.write() = Some(Arc::new(callback));
+    }
+
+    /// 移除解析错误回调，之后开始的订阅不再上报解析错误
+    pub fn clear_parse_error_callback(&self) {
+        *self.parse_error_callback.write() = None;
+    }
+
+    /// 挂载价格源，之后推送给回调的交易事件都会更新它
+    ///
+    /// 在 mint 过滤和去重之后执行，可在订阅前或订阅中随时修改，文件回放同样生效
+    pub fn set_price_feed(&self, price_feed: Arc<PriceFeed>) {
+        *self.price_feed.write() = Some(price_feed);
+    }
+
+    /// 卸载价格源
+    pub fn clear_price_feed(&self) {
+        *self.price_feed.write() = None;
+    }
+
+    /// 挂载转账手续费缓存，之后推送给回调的交易事件都会填充 `metadata.transfer_fee_amounts`，
+    /// 推送的 Token-2022 mint 账户事件会更新缓存
+    ///
+    /// 在去重之后、价格源之前执行，可在订阅前或订阅中随时修改，文件回放同样生效
+    pub fn set_transfer_fee_cache(&self, cache: Arc<TransferFeeCache>) {
+        *self.transfer_fee_cache.write() = Some(cache);
+    }
+
+    /// 卸载转账手续费缓存
+    pub fn clear_transfer_fee_cache(&self) {
+        *self.transfer_fee_cache.write() = None;
+    }
+
+    /// 挂载代币注册表，之后推送给回调的交易事件都会填充 `metadata.token_info`
+    ///
+    /// 在转账手续费之后、价格源之前执行，可在订阅前或订阅中随时修改，文件回放同样生效
+    pub fn set_token_registry(&self, registry: Arc<dyn TokenRegistry>) {
+        *self.token_registry.write() = Some(registry);
+    }
+
+    /// 卸载代币注册表
+    pub fn clear_token_registry(&self) {
+        *self.token_registry.write() = None;
+    }
+
+    /// 设置连接状态回调，状态变化时调用
+    ///
+    /// 订阅开始时依次收到 `Connecting`、`Connected`；断线后每次重连前收到带尝试次数的
+    /// `Reconnecting`，重连成功后再次收到 `Connected`；调用 `stop()`、首次连接失败或放弃重连
+    /// 时收到 `Stopped`；连续认证失败达到 `reconnect.max_auth_failures` 次时收到终止状态
+    /// `AuthFailed`，不再重连。回调在 gRPC 读取任务中同步执行，应尽快返回。可在订阅前或订阅中随时修改
+    pub fn set_state_change_callback<F>(&self, callback: F)
+    where
+        F: Fn(ConnectionState) + Send + Sync + 'static,
+    {
+        self.state_notifier.set_callback(Arc::new(callback));
+    }
+
+    /// 移除连接状态回调
+    pub fn clear_state_change_callback(&self) {
+        self.state_notifier.clear_callback();
+    }
+
+    /// 获取当前连接状态
+    pub fn connection_state(&self) -> ConnectionState {
+        self.state_notifier.state()
+    }
+
+    /// 获取最后处理完成的 slot，还没有处理过数据更新时返回 None
+    ///
+    /// 该 slot 的数据更新已全部推送给回调 (开启 `ordering` 时可能仍在重排缓存中)；
+    /// 停止订阅后保留，可设置为 `StreamClientConfig::from_slot` 从断点重新订阅
+    pub fn last_processed_slot(&self) -> Option<u64> {
+        Some(self.last_processed_slot.load(Ordering::Relaxed)).filter(|slot| *slot > 0)
+    }
+
     /// 停止当前订阅
+    ///
+    /// 不再读取新的 gRPC 消息，等待已收到的消息处理完成 (包括异步回调) 后返回；
+    /// 超过 `stop_timeout_ms` 仍未完成时强制中止
     pub async fn stop(&self) {
+        let subscriptions = std::mem::take(&mut *self.commitment_subscriptions.lock().await);
+        for subscription in &subscriptions {
+            subscription.stop_own().await;
+        }
+        self.stop_own().await;
+    }
+
+    /// 停止本客户端自身的订阅
+    async fn stop_own(&self) {
         let mut handle_guard = self.subscription_handle.lock().await;
         if let Some(handle) = handle_guard.take() {
-            handle.stop();
+            handle.shutdown(Duration::from_millis(self.config.stop_timeout_ms)).await;
         }
         *self.control_tx.lock().await = None;
         *self.current_request.write().await = None;
         self.active_subscription.store(false, Ordering::Release);
+        *self.filter_scope.write() = FilterScope::All;
+        self.state_notifier.transition(ConnectionState::Stopped);
     }
 
     /// Simplified immediate event subscription (recommended for simple scenarios)
@@ -129,7 +455,7 @@ impl YellowstoneGrpc {
     /// * `callback` - Event callback function that receives parsed unified events
     ///
     /// # Returns
-    /// Returns `AnyResult<()>`, `Ok(())` on success, error information on failure
+    /// Returns `StreamResult<()>`, `Ok(())` on success, error information on failure
     pub async fn subscribe_events_immediate<F>(
         &self,
         protocols: Vec<Protocol>,
@@ -139,18 +465,531 @@ impl YellowstoneGrpc {
         event_type_filter: Option<EventTypeFilter>,
         commitment: Option<CommitmentLevel>,
         callback: F,
-    ) -> AnyResult<()>
+    ) -> StreamResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        self.subscribe_events_inner(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            callback,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Immediate event subscription with an async callback
+    ///
+    /// Same as [`subscribe_events_immediate`](Self::subscribe_events_immediate), but the callback
+    /// returns a future. Events parsed from one gRPC update are buffered and their futures are
+    /// awaited one by one in the processing loop, so events are delivered in order.
+    ///
+    /// Note: the next update is not processed until every future of the current update has
+    /// completed, so slow callbacks (e.g. a database write per event) add latency. Updates wait
+    /// in the internal channel meanwhile, and `StreamClientConfig::channel` decides what happens
+    /// once it is full. Spawn inside the callback if ordering does not matter.
+    ///
+    /// # Parameters
+    /// Same as `subscribe_events_immediate`, except:
+    /// * `callback` - Async event callback, `Fn(DexEvent) -> Future<Output = ()>`
+    ///
+    /// # Returns
+    /// Returns `StreamResult<()>`, `Ok(())` on success, error information on failure
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_events_immediate_async<F, Fut>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+    ) -> StreamResult<()>
+    where
+        F: Fn(DexEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.subscribe_events_async_inner(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            callback,
+            None,
+        )
+        .await
+    }
+
+    /// 异步回调订阅的实现，`parse_error_callback` 不为空时替代客户端的解析错误回调
+    #[allow(clippy::too_many_arguments)]
+    async fn subscribe_events_async_inner<F, Fut>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+        parse_error_callback: Option<ParseErrorCallback>,
+    ) -> StreamResult<()>
+    where
+        F: Fn(DexEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        // 解析是同步回调，先缓存事件，每条消息处理完后再按顺序 await
+        let pending: Arc<parking_lot::Mutex<Vec<DexEvent>>> = Arc::default();
+        let buffer = pending.clone();
+        let buffer_callback = move |event: DexEvent| {
+            buffer.lock().push(event);
+        };
+
+        let callback = Arc::new(callback);
+        let drain: EventDrain = Arc::new(move || {
+            let events = std::mem::take(&mut *pending.lock());
+            let callback = callback.clone();
+            async move {
+                for event in events {
+                    let (signature, slot) = (event.metadata().signature, event.metadata().slot);
+                    await_catching_panics(signature, slot, async { callback(event).await }).await;
+                }
+            }
+            .boxed()
+        });
+
+        self.subscribe_events_inner(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            buffer_callback,
+            Some(drain),
+            parse_error_callback,
+        )
+        .await
+    }
+
+    /// Immediate event subscription delivering the events of each transaction together
+    ///
+    /// The callback receives one `TransactionEvents` per transaction once its update is fully
+    /// parsed, so related events (e.g. the legs of a multi-hop route) can be processed
+    /// atomically. Filters, deduplication and the other callback layers apply per event before
+    /// grouping; a transaction with no remaining events is not delivered. Events not tied to a
+    /// transaction are delivered in groups of their own with the default signature.
+    ///
+    /// # Parameters
+    /// Same as `subscribe_events_immediate`, except:
+    /// * `callback` - Transaction callback, `Fn(TransactionEvents)`
+    ///
+    /// # Returns
+    /// Returns `StreamResult<()>`, `Ok(())` on success, error information on failure
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_events_by_transaction<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+    ) -> StreamResult<()>
+    where
+        F: Fn(TransactionEvents) + Send + Sync + 'static,
+    {
+        // 每条消息处理完后，把缓存的事件按交易分组推送
+        let pending: Arc<parking_lot::Mutex<Vec<DexEvent>>> = Arc::default();
+        let buffer = pending.clone();
+        let buffer_callback = move |event: DexEvent| {
+            buffer.lock().push(event);
+        };
+
+        let drain: EventDrain = Arc::new(move || {
+            let events = std::mem::take(&mut *pending.lock());
+            for group in TransactionEvents::group(events) {
+                let (signature, slot) = (group.signature, group.slot);
+                call_catching_panics(&signature, slot, || callback(group));
+            }
+            future::ready(()).boxed()
+        });
+
+        self.subscribe_events_inner(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            buffer_callback,
+            Some(drain),
+            None,
+        )
+        .await
+    }
+
+    /// Immediate event subscription returning a `Stream` instead of taking a callback
+    ///
+    /// Events are fed into an internal bounded `mpsc` channel (`channel.event_stream_capacity`)
+    /// by the processing loop, in order. When the consumer falls behind, the processing loop
+    /// waits on the channel, so reading from the gRPC stream slows down instead of buffering
+    /// without bound.
+    ///
+    /// The stream ends when `stop()` is called (on this client or the returned handle) or when
+    /// the gRPC connection closes.
+    ///
+    /// # Parameters
+    /// Same as `subscribe_events_immediate`, without the callback
+    ///
+    /// # Returns
+    /// Returns the event stream and a handle to stop the subscription
+    pub async fn subscribe_events_stream(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+    ) -> StreamResult<(impl Stream<Item = DexEvent>, EventStreamHandle)> {
+        let (event_tx, event_rx) =
+            mpsc::channel::<DexEvent>(self.config.channel.event_stream_capacity);
+
+        self.subscribe_events_immediate_async(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            move |event: DexEvent| {
+                let mut event_tx = event_tx.clone();
+                async move {
+                    // 接收端已被丢弃时直接忽略
+                    let _ = event_tx.send(event).await;
+                }
+            },
+        )
+        .await?;
+
+        Ok((event_rx, EventStreamHandle { grpc: self.clone() }))
+    }
+
+    /// Same as `subscribe_events_stream`, with instruction parse failures yielded as `Err`
+    ///
+    /// An `Err(ParseError)` is produced when an instruction's discriminator is registered for an
+    /// event but its data or accounts fail to decode, which usually means a program upgrade
+    /// changed the layout. It carries the signature, program id and raw instruction bytes.
+    ///
+    /// Errors go to this subscription only and replace the client's `set_parse_error_callback`
+    /// for it. They are sent without waiting, may arrive ahead of events of the same update,
+    /// and are dropped when the channel is full; dropped errors are counted in
+    /// `PerformanceMetrics::dropped_parse_errors_count`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_events_stream_with_errors(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+    ) -> StreamResult<(impl Stream<Item = Result<DexEvent, ParseError>>, EventStreamHandle)> {
+        let capacity = self.config.channel.event_stream_capacity;
+        let (event_tx, event_rx) = mpsc::channel::<Result<DexEvent, ParseError>>(capacity);
+
+        // 错误回调随订阅的解析任务一起释放，之后事件流得以结束
+        let error_tx = parking_lot::Mutex::new(event_tx.clone());
+        let parse_error_callback: ParseErrorCallback = Arc::new(move |error: &ParseError| {
+            if let Err(e) = error_tx.lock().try_send(Err(error.clone())) {
+                if e.is_full() {
+                    MetricsManager::global().increment_dropped_parse_errors();
+                }
+            }
+        });
+
+        self.subscribe_events_async_inner(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            move |event: DexEvent| {
+                let mut event_tx = event_tx.clone();
+                async move {
+                    // 接收端已被丢弃时直接忽略
+                    let _ = event_tx.send(Ok(event)).await;
+                }
+            },
+            Some(parse_error_callback),
+        )
+        .await?;
+
+        Ok((event_rx, EventStreamHandle { grpc: self.clone() }))
+    }
+
+    /// Subscribe at several commitment levels at once, sharing this client's configuration
+    ///
+    /// Opens one subscription per level (duplicates are ignored), each on its own connection,
+    /// and delivers the events of all of them to `callback`. Every event carries the level it
+    /// came from in `metadata.commitment`, so a transaction is delivered once per level, e.g.
+    /// first at `Processed` for speed and again at `Confirmed` for correctness.
+    ///
+    /// `stop()` and `update_subscription()` on this client apply to every level. Recording
+    /// (`record_path`) and the periodic metrics printer only run for the first level. If any
+    /// level fails to subscribe, the levels already started are stopped.
+    ///
+    /// # Parameters
+    /// * `levels` - Commitment levels to subscribe to, at least one
+    ///
+    /// Other parameters are the same as `subscribe_events_immediate`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_multi_commitment<F>(
+        &self,
+        levels: Vec<CommitmentLevel>,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> StreamResult<()>
     where
         F: Fn(DexEvent) + Send + Sync + 'static,
     {
+        let mut unique_levels: Vec<CommitmentLevel> = Vec::with_capacity(levels.len());
+        for level in levels {
+            if !unique_levels.contains(&level) {
+                unique_levels.push(level);
+            }
+        }
+        let Some((first, rest)) = unique_levels.split_first() else {
+            return Err(StreamError::Config("At least one commitment level is required".into()));
+        };
+
+        let callback = Arc::new(callback);
+        let first_callback = callback.clone();
+        self.subscribe_events_immediate(
+            protocols.clone(),
+            bot_wallet,
+            transaction_filter.clone(),
+            account_filter.clone(),
+            event_type_filter.clone(),
+            Some(*first),
+            move |event| first_callback(event),
+        )
+        .await?;
+
+        let mut subscriptions: Vec<YellowstoneGrpc> = Vec::with_capacity(rest.len());
+        for level in rest {
+            let subscription = self.commitment_subscription();
+            let level_callback = callback.clone();
+            let result = subscription
+                .subscribe_events_immediate(
+                    protocols.clone(),
+                    bot_wallet,
+                    transaction_filter.clone(),
+                    account_filter.clone(),
+                    event_type_filter.clone(),
+                    Some(*level),
+                    move |event| level_callback(event),
+                )
+                .await;
+            if let Err(e) = result {
+                for subscription in &subscriptions {
+                    subscription.stop_own().await;
+                }
+                self.stop_own().await;
+                return Err(e);
+            }
+            subscriptions.push(subscription);
+        }
+        *self.commitment_subscriptions.lock().await = subscriptions;
+        Ok(())
+    }
+
+    /// Subscribe to transactions and accounts at different commitment levels under one client
+    ///
+    /// `SubscribeRequest` carries a single commitment, so transactions and accounts are
+    /// subscribed on two connections: the transaction filters at `transaction_commitment` on
+    /// this client, the account filters at `account_commitment` on a secondary one, e.g.
+    /// account updates at `Processed` for latency and transactions at `Confirmed` for safety.
+    /// Every event carries the level it came from in `metadata.commitment`.
+    ///
+    /// When both levels are equal, or one of the filter lists is empty, a single subscription
+    /// is opened. `stop()` stops both; `update_subscription()` sends the transaction filters to
+    /// the transaction subscription and the account filters to the account one. Block meta
+    /// events are delivered by both subscriptions. If the account subscription fails, the
+    /// transaction subscription is stopped as well.
+    ///
+    /// # Parameters
+    /// * `transaction_commitment` - Commitment level of the transaction subscription
+    /// * `account_commitment` - Commitment level of the account subscription
+    ///
+    /// Other parameters are the same as `subscribe_events_immediate`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_mixed_commitment<F>(
+        &self,
+        transaction_commitment: CommitmentLevel,
+        account_commitment: CommitmentLevel,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> StreamResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        if transaction_commitment == account_commitment
+            || transaction_filter.is_empty()
+            || account_filter.is_empty()
+        {
+            let commitment = if transaction_filter.is_empty() {
+                account_commitment
+            } else {
+                transaction_commitment
+            };
+            return self
+                .subscribe_events_immediate(
+                    protocols,
+                    bot_wallet,
+                    transaction_filter,
+                    account_filter,
+                    event_type_filter,
+                    Some(commitment),
+                    callback,
+                )
+                .await;
+        }
+
+        let callback = Arc::new(callback);
+        let transaction_callback = callback.clone();
+        self.subscribe_events_immediate(
+            protocols.clone(),
+            bot_wallet,
+            transaction_filter,
+            Vec::new(),
+            event_type_filter.clone(),
+            Some(transaction_commitment),
+            move |event| transaction_callback(event),
+        )
+        .await?;
+        *self.filter_scope.write() = FilterScope::Transactions;
+
+        let subscription = self.commitment_subscription();
+        *subscription.filter_scope.write() = FilterScope::Accounts;
+        let result = subscription
+            .subscribe_events_immediate(
+                protocols,
+                bot_wallet,
+                Vec::new(),
+                account_filter,
+                event_type_filter,
+                Some(account_commitment),
+                move |event| callback(event),
+            )
+            .await;
+        if let Err(e) = result {
+            self.stop_own().await;
+            return Err(e);
+        }
+        *self.commitment_subscriptions.lock().await = vec![subscription];
+        Ok(())
+    }
+
+    /// 为另一个确认级别创建的客户端
+    ///
+    /// 共享连接配置、过滤器和回调设置，订阅状态独立；不录制、不启动指标打印
+    fn commitment_subscription(&self) -> YellowstoneGrpc {
+        let mut config = self.config.clone();
+        config.record_path = None;
+        config.enable_metrics = false;
+        Self {
+            config,
+            subscription_handle: Arc::new(Mutex::new(None)),
+            active_subscription: Arc::new(AtomicBool::new(false)),
+            control_tx: Arc::new(tokio::sync::Mutex::new(None)),
+            current_request: Arc::new(tokio::sync::RwLock::new(None)),
+            commitment_subscriptions: Arc::default(),
+            // 连接状态只反映主订阅
+            state_notifier: ConnectionStateNotifier::default(),
+            last_processed_slot: Arc::default(),
+            filter_scope: Arc::default(),
+            // 次级订阅单独建立连接，当前 endpoint 单独统计
+            subscription_manager: self.subscription_manager.with_own_endpoint_health(),
+            ..self.clone()
+        }
+    }
+
+    /// 订阅的公共实现
+    ///
+    /// `drain` 不为空时，每条 gRPC 消息处理完后调用一次，用于异步回调按顺序消费缓存的事件。
+    /// `parse_error_callback` 为空时使用客户端的解析错误回调
+    #[allow(clippy::too_many_arguments)]
+    async fn subscribe_events_inner<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+        drain: Option<EventDrain>,
+        parse_error_callback: Option<ParseErrorCallback>,
+    ) -> StreamResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        let mut request_builder = self
+            .subscription_manager
+            .request_builder()
+            .transactions(transaction_filter)
+            .accounts(account_filter);
+        if let Some(commitment) = commitment {
+            request_builder = request_builder.commitment(commitment);
+        }
+        if let Some(filter) = &event_type_filter {
+            request_builder = request_builder.event_types(filter.clone());
+        }
+        if let Some(from_slot) = self.config.from_slot {
+            request_builder = request_builder.from_slot(from_slot);
+        }
+        let mut subscribe_request = request_builder.build()?;
+
         *self.event_type_filter.write().await = event_type_filter.clone();
         if self
             .active_subscription
             .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
             .is_err()
         {
-            return Err(anyhow!("Already subscribed. Use update_subscription() to modify filters"));
+            return Err(StreamError::AlreadySubscribed);
         }
+        // 开启录制时，原始消息在解析前写入文件；文件无法创建时在连接和启动监控之前释放订阅
+        let recorder = match &self.config.record_path {
+            Some(path) => match UpdateRecorder::create(path) {
+                Ok(recorder) => Some(recorder),
+                Err(e) => {
+                    self.active_subscription.store(false, Ordering::Release);
+                    return Err(e.into());
+                }
+            },
+            None => None,
+        };
+        *self.active_protocols.write() = Arc::new(protocols);
+        let active_protocols = self.active_protocols.clone();
 
         let mut metrics_handle = None;
         // 启动自动性能监控（如果启用）
@@ -158,120 +997,199 @@ impl YellowstoneGrpc {
             metrics_handle = MetricsManager::global().start_auto_monitoring().await;
         }
 
-        let transactions = self
-            .subscription_manager
-            .get_subscribe_request_filter(transaction_filter, event_type_filter.as_ref());
-        let accounts = self
-            .subscription_manager
-            .subscribe_with_account_request(account_filter, event_type_filter.as_ref());
+        // 开启确认级别跟踪时额外订阅 slot 状态
+        let commitment_tracker = if self.config.track_commitment_upgrades {
+            CommitmentTracker::new(commitment.unwrap_or(CommitmentLevel::Processed)).map(Arc::new)
+        } else {
+            None
+        };
+        if commitment_tracker.is_some() {
+            subscribe_request.slots.insert(
+                "commitment_upgrades".to_string(),
+                SubscribeRequestFilterSlots {
+                    filter_by_commitment: Some(false),
+                    interslot_updates: Some(false),
+                },
+            );
+        }
 
         // 订阅事件
-        let (subscribe_tx, mut stream, subscribe_request) = self
-            .subscription_manager
-            .subscribe_with_request(transactions, accounts, commitment, event_type_filter.as_ref())
-            .await?;
+        self.state_notifier.transition(ConnectionState::Connecting);
+        let (subscribe_tx, stream) =
+            match self.subscription_manager.subscribe(subscribe_request.clone()).await {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    self.state_notifier.transition(if e.is_auth_error() {
+                        ConnectionState::AuthFailed { failures: 1 }
+                    } else {
+                        ConnectionState::Stopped
+                    });
+                    return Err(e);
+                }
+            };
+        self.state_notifier.transition(ConnectionState::Connected);
+        let subscribe_tx: GrpcSink = Box::pin(subscribe_tx);
+        let mut stream: GrpcStream = stream.boxed();
 
         // 用 Arc<Mutex<>> 包装 subscribe_tx 以支持多线程共享
         let subscribe_tx = Arc::new(Mutex::new(subscribe_tx));
+        // 起始 slot 只用于首次连接，重连和更新过滤器时不再重放
+        subscribe_request.from_slot = None;
         *self.current_request.write().await = Some(subscribe_request);
         let (control_tx, mut control_rx) = mpsc::channel(100);
         *self.control_tx.lock().await = Some(control_tx);
 
         // Wrap callback once before the async block
-        let callback = Arc::new(callback);
+        // 回调 panic 只跳过当前事件，不中断流
+        let user_callback = catch_callback_panics(Arc::new(callback));
+        let status_callback = user_callback.clone();
+        // 限速或开启指标时统计推送给回调的事件
+        let throttle = EventThrottle::new(&self.config.throttle).map(Arc::new);
+        let user_callback: Arc<dyn Fn(DexEvent) + Send + Sync> =
+            if throttle.is_some() || self.config.enable_metrics {
+                let throttle = throttle.clone();
+                Arc::new(move |event| {
+                    MetricsManager::global().record_delivered_events(1);
+                    if let Some(throttle) = &throttle {
+                        throttle.record(1);
+                    }
+                    user_callback(event);
+                })
+            } else {
+                user_callback
+            };
+        let callback = self.wrap_callback(
+            user_callback,
+            commitment_tracker.clone(),
+            Some(commitment.unwrap_or(CommitmentLevel::Processed)),
+        );
+        let commitment_tracker_enabled = commitment_tracker.is_some();
+
+        let raw_callback = self.raw_callback.clone();
+
+        // 断线重连使用的状态
+        let subscription_manager = self.subscription_manager.clone();
+        let current_request = self.current_request.clone();
+        let reconnect_config = self.config.reconnect.clone();
+        let state_notifier = self.state_notifier.clone();
+        let last_processed_slot = self.last_processed_slot.clone();
+
+        // 空闲心跳：超过 interval 没有任何更新时发送 ping，超过 timeout 仍无响应视为断线
+        let heartbeat = self.config.heartbeat.clone();
+        let heartbeat_interval = Duration::from_millis(heartbeat.interval_ms);
+        let heartbeat_timeout = Duration::from_millis(heartbeat.timeout_ms);
+        // 空闲超时：超过该时间没有 ping/pong 以外的更新时重连
+        let idle_timeout = self.config.connection.idle_timeout.map(Duration::from_secs);
+
+        // 停止信号：收到后不再读取新消息，已入队的消息处理完后退出
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+
+        // gRPC 读取与解析/回调之间的有界队列，慢回调不会让消息无限堆积
+        let queue = Arc::new(BoundedQueue::new(
+            self.config.channel.capacity,
+            self.config.channel.overflow_policy,
+        ));
 
-        let stream_handle = tokio::spawn(async move {
+        let pipeline = Arc::new(EventPipeline {
+            active_protocols,
+            event_type_filter,
+            min_quote_amount: self.min_quote_amount_filter(),
+            bot_wallet,
+            callback,
+            status_callback,
+            commitment_tracker,
+            slot_lag_monitor: SlotLagMonitor::new(&self.config.slot_lag),
+            reorder: self.config.ordering.enabled.then(|| {
+                parking_lot::Mutex::new(EventReorderBuffer::new(Duration::from_millis(
+                    self.config.ordering.window_ms,
+                )))
+            }),
+            drain,
+            stop_client: throttle
+                .as_ref()
+                .is_some_and(|throttle| throttle.stop_on_breach())
+                .then(|| self.clone()),
+            throttle,
+            last_processed_slot: last_processed_slot.clone(),
+            owner_cache: AccountOwnerCache::new(),
+            lamports_cache: AccountLamportsCache::new(),
+            parse_error_callback: parse_error_callback
+                .or_else(|| self.parse_error_callback.read().clone()),
+        });
+        let span = tracing::info_span!(
+            "grpc_stream",
+            endpoint = %self.subscription_manager.active_endpoint()
+        );
+        let event_handle = tokio::spawn(
+            pipeline.run(queue.clone(), self.config.parse.clone()).instrument(span.clone()),
+        );
+
+        let stream_task = async move {
+            let mut last_update = Instant::now();
+            let mut last_data_update = Instant::now();
+            let mut ping_sent_at: Option<Instant> = None;
+            let mut ping_id = 0;
+            // 连续认证失败次数，收到任何消息后清零
+            let mut auth_failures = 0;
             loop {
+                if *shutdown_rx.borrow() {
+                    break;
+                }
+                let mut disconnected = None;
+                let heartbeat_deadline = match ping_sent_at {
+                    Some(sent_at) => sent_at + heartbeat_timeout,
+                    None => last_update + heartbeat_interval,
+                };
+                let idle_deadline = last_data_update + idle_timeout.unwrap_or_default();
                 tokio::select! {
                                 message = stream.next() => {
                                     match message {
                                         Some(Ok(msg)) => {
+                                            let received_us = get_high_perf_clock();
+                                            last_update = Instant::now();
+                                            ping_sent_at = None;
+                                            if !matches!(
+                                                msg.update_oneof,
+                                                Some(UpdateOneof::Ping(_) | UpdateOneof::Pong(_))
+                                            ) {
+                                                last_data_update = last_update;
+                                            }
+                                            auth_failures = 0;
+                                            let tap = raw_callback.read().clone();
+                                            if let Some(tap) = tap {
+                                                tap(&msg);
+                                            }
+                                            if let Some(recorder) = &recorder {
+                                                if let Err(e) = recorder.record(&msg) {
+                                                    error!(error = %e, "failed to record update");
+                                                }
+                                            }
+                                            if let Some(update) = &msg.update_oneof {
+                                                MetricsManager::global().observe_seen_update(update);
+                                            }
                                             let created_at = msg.created_at;
                                             match msg.update_oneof {
-                                                Some(UpdateOneof::Account(account)) => {
-                                                    let account_pretty = factory::create_account_pretty_pooled(account);
-                                                    log::debug!("Received account: {:?}", account_pretty);
-                                                    if let Err(e) = process_grpc_transaction(
-                                                        EventPretty::Account(account_pretty),
-                                                        &protocols,
-                                                        event_type_filter.as_ref(),
-                                                        callback.clone(),
-                                                        bot_wallet,
-                                                    )
-                                                    .await
-                                                    {
-                                                        error!("Error processing account event: {e:?}");
-                                                    }
-                                                }
-                                                Some(UpdateOneof::BlockMeta(sut)) => {
-                                                    let block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
-                                                    log::debug!("Received block meta: {:?}", block_meta_pretty);
-                                                    if let Err(e) = process_grpc_transaction(
-                                                        EventPretty::BlockMeta(block_meta_pretty),
-                                                        &protocols,
-                                                        event_type_filter.as_ref(),
-                                                        callback.clone(),
-                                                        bot_wallet,
-                                                    )
-                                                    .await
-                                                    {
-                                                        error!("Error processing block meta event: {e:?}");
-                                                    }
+                                                Some(
+                                                    update @ (UpdateOneof::Account(_)
+                                                    | UpdateOneof::BlockMeta(_)
+                                                    | UpdateOneof::Transaction(_)
+                                                    | UpdateOneof::Entry(_)),
+                                                ) => {
+                                                    queue.push(PipelineItem::Update(update, created_at, received_us)).await;
                                                 }
-                                                Some(UpdateOneof::Transaction(sut)) => {
-                                                    let transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
-                                                    log::debug!(
-                                                        "Received transaction: {} at slot {}",
-                                                        transaction_pretty.signature,
-                                                        transaction_pretty.slot
+                                                Some(UpdateOneof::Block(block)) => {
+                                                    // 完整 Block 暂不解析为事件，只在开启 `blocks.subscribe_blocks` 时收到
+                                                    debug!(
+                                                        slot = block.slot,
+                                                        blockhash = %block.blockhash,
+                                                        "received block"
                                                     );
-                                                    if let Err(e) = process_grpc_transaction(
-                                                        EventPretty::Transaction(transaction_pretty),
-                                                        &protocols,
-                                                        event_type_filter.as_ref(),
-                                                        callback.clone(),
-                                                        bot_wallet,
-                                                    )
-                                                    .await
-                                                    {
-                                                        error!("Error processing transaction event: {e:?}");
-                                                    }
                                                 }
-                                                Some(UpdateOneof::Block(block)) => {
-                                                    use std::fs::OpenOptions;
-                                                    use std::io::Write;
-                                                    let block_str = format!("{:#?}\n", block);
-
-                                                    // 以追加模式打开文件（如果不存在则创建）
-                                                    // 注意：由于这是在 async 块里进行同步 IO，高并发下可能会有一点点阻塞，但在调试阶段完全没问题
-                                                    if let Ok(mut file) = OpenOptions::new()
-                                                        .create(true)
-                                                        .append(true)
-                                                        .open("blocks_output.log")
-                                                    {
-                                                        if let Err(e) = file.write_all(block_str.as_bytes()) {
-                                                            error!("Failed to write block to file: {}", e);
-                                                        }
-                                                        std::process::exit(1);
-                                                    } else {
-                                                        error!("Failed to open blocks_output.log");
+                                                Some(update @ UpdateOneof::Slot(_)) => {
+                                                    // 与数据更新走同一队列，保证升级事件在对应交易事件之后
+                                                    if commitment_tracker_enabled {
+                                                        queue.push(PipelineItem::Update(update, created_at, received_us)).await;
                                                     }
-
-                                                    log::info!("Received block and wrote to blocks_output.log");
-                                                    // log::info!("Received block: {:?}", block);
-                                                    // let block_pretty = factory::create_block_pretty_pooled(block, created_at);
-                                                    // log::debug!("Received block: {:?}", block_pretty);
-                                                    // if let Err(e) = process_grpc_transaction(
-                                                    //     EventPretty::Block(block_pretty),
-                                                    //     &protocols,
-                                                    //     event_type_filter.as_ref(),
-                                                    //     callback.clone(),
-                                                    //     bot_wallet,
-                                                    // )
-                                                    // .await
-                                                    // {
-                                                    //     error!("Error processing block event: {e:?}");
-                                                    // }
                                                 }
                                                 Some(UpdateOneof::Ping(_)) => {
                                                     // 只在需要时获取锁，并立即释放
@@ -283,64 +1201,251 @@ impl YellowstoneGrpc {
                                                             })
                                                             .await;
                                                     }
-                                                    log::debug!("service is ping: {}", Local::now());
+                                                    debug!("received ping from server");
                                                 }
                                                 Some(UpdateOneof::Pong(_)) => {
-                                                    log::debug!("service is pong: {}", Local::now());
+                                                    debug!("received pong from server");
                                                 }
                                                 _ => {
-                                                    log::debug!("Received other message type");
+                                                    debug!("received other message type");
                                                 }
                                             }
                                         }
                                         Some(Err(error)) => {
-                                            error!("Stream error: {error:?}");
-                                            break;
+                                            let error = StreamError::Subscribe(error);
+                                            if let Some(size) = error.oversized_message_len() {
+                                                // 超长更新无法跳过 (tonic 随即结束流)，丢弃并重连，
+                                                // 配置了上限时重连使用更大的解码上限
+                                                MetricsManager::global().increment_oversized_messages();
+                                                let limit = subscription_manager.decoding_limit();
+                                                let raised = subscription_manager.raise_decoding_limit(size);
+                                                warn!(
+                                                    size,
+                                                    limit,
+                                                    ?raised,
+                                                    "update exceeds max_decoding_message_size, dropped"
+                                                );
+                                                disconnected = Some(format!(
+                                                    "update of {size} bytes exceeds max_decoding_message_size"
+                                                ));
+                                            } else {
+                                                error!(error = ?error, "gRPC stream error");
+                                                disconnected = Some(format!("{error:?}"));
+                                                if error.is_auth_error() {
+                                                    auth_failures += 1;
+                                                }
+                                            }
+                                        }
+                                        None => {
+                                            disconnected = Some("stream closed".to_string());
                                         }
-                                        None => break,
                                     }
                                 }
+                                _ = shutdown_rx.changed() => {
+                                    debug!("subscription stopping");
+                                    break;
+                                }
                                 Some(update) = control_rx.next() => {
                                     if let Err(e) = subscribe_tx.lock().await.send(update).await {
-                                        error!("Failed to send subscription update: {}", e);
+                                        error!(error = %e, "failed to send subscription update");
                                         break;
                                     }
                                 }
+                                _ = tokio::time::sleep_until(heartbeat_deadline), if heartbeat.enabled => {
+                                    if ping_sent_at.is_some() {
+                                        disconnected = Some("heartbeat timeout".to_string());
+                                    } else {
+                                        ping_id += 1;
+                                        let ping = SubscribeRequest {
+                                            ping: Some(SubscribeRequestPing { id: ping_id }),
+                                            ..Default::default()
+                                        };
+                                        match subscribe_tx.lock().await.send(ping).await {
+                                            Ok(()) => {
+                                                debug!(ping_id, "connection idle, sent heartbeat ping");
+                                                ping_sent_at = Some(Instant::now());
+                                            }
+                                            Err(e) => disconnected = Some(format!("heartbeat ping failed: {e}")),
+                                        }
+                                    }
+                                }
+                                _ = tokio::time::sleep_until(idle_deadline), if idle_timeout.is_some() => {
+                                    warn!(?idle_timeout, "no updates within idle_timeout, reconnecting");
+                                    disconnected = Some("idle timeout".to_string());
+                                }
                             }
+
+                // 连接断开：按退避策略重连，并通过回调通知可能存在的数据缺口
+                if let Some(reason) = disconnected {
+                    let disconnected_us = get_high_perf_clock();
+                    match reconnect(
+                        &subscription_manager,
+                        &current_request,
+                        &reconnect_config,
+                        &state_notifier,
+                        &last_processed_slot,
+                        &mut auth_failures,
+                        &reason,
+                    )
+                    .await
+                    {
+                        Some((new_tx, new_stream, attempts)) => {
+                            *subscribe_tx.lock().await = new_tx;
+                            stream = new_stream;
+                            last_update = Instant::now();
+                            last_data_update = last_update;
+                            ping_sent_at = None;
+                            MetricsManager::global().increment_reconnects();
+                            queue
+                                .push(PipelineItem::Event(Box::new(
+                                    CommonEventParser::generate_reconnect_event(
+                                        attempts,
+                                        reason,
+                                        disconnected_us,
+                                        get_high_perf_clock(),
+                                    ),
+                                )))
+                                .await;
+                        }
+                        None => break,
+                    }
+                }
             }
-        });
+            // 解析任务处理完队列中剩余的消息后结束
+            queue.close();
+            // 认证失败的终止状态保留到下次订阅
+            if !matches!(state_notifier.state(), ConnectionState::AuthFailed { .. }) {
+                state_notifier.transition(ConnectionState::Stopped);
+            }
+        };
+        let stream_handle = tokio::spawn(stream_task.instrument(span));
 
         // 保存订阅句柄
-        let subscription_handle = SubscriptionHandle::new(stream_handle, None, metrics_handle);
+        let subscription_handle =
+            SubscriptionHandle::new(stream_handle, Some(event_handle), metrics_handle)
+                .with_shutdown(shutdown_tx);
         let mut handle_guard = self.subscription_handle.lock().await;
         *handle_guard = Some(subscription_handle);
 
         Ok(())
     }
 
+    /// 在用户回调之前依次应用 mint 过滤、采样、去重、转账手续费、价格源、确认级别跟踪和分叉检测
+    ///
+    /// `commitment` 为 gRPC 订阅的确认级别，写入事件的 `metadata.commitment`
+    pub(crate) fn wrap_callback(
+        &self,
+        user_callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+        tracker: Option<Arc<CommitmentTracker>>,
+        commitment: Option<CommitmentLevel>,
+    ) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+        let commitment = commitment.map(EventCommitment::from);
+        // 开启去重时，重复事件在到达用户回调前被丢弃
+        let deduplicator =
+            self.config.dedup.enabled.then(|| EventDeduplicator::new(self.config.dedup.window_size));
+        let mint_filter = self.mint_filter.clone();
+        let meta_filter = (!self.config.transaction_meta.is_empty())
+            .then(|| self.config.transaction_meta.clone());
+        let price_feed = self.price_feed.clone();
+        let transfer_fee_cache = self.transfer_fee_cache.clone();
+        let token_registry = self.token_registry.clone();
+        let fork_detector = self.config.detect_forks.then(ForkDetector::new);
+        let sampler = EventSampler::new(&self.config.sampling);
+        Arc::new(move |mut event: DexEvent| {
+            if !mint_filter.read().matches(&event) {
+                return;
+            }
+            if meta_filter.as_ref().is_some_and(|filter| !filter.passes(&event)) {
+                return;
+            }
+            if sampler.as_ref().is_some_and(|sampler| !sampler.keep(&event)) {
+                return;
+            }
+            event.metadata_mut().commitment = commitment;
+            if let Some(deduplicator) = &deduplicator {
+                if deduplicator.is_duplicate(&event) {
+                    MetricsManager::global().increment_duplicate_events();
+                    return;
+                }
+            }
+            if let Some(cache) = transfer_fee_cache.read().as_ref() {
+                cache.observe(&event);
+                cache.apply(&mut event);
+            }
+            if let Some(registry) = token_registry.read().as_ref() {
+                enrich_event(registry.as_ref(), &mut event);
+            }
+            if let Some(price_feed) = price_feed.read().as_ref() {
+                price_feed.observe(&event);
+            }
+            if let Some(tracker) = &tracker {
+                tracker.observe(&event);
+            }
+            // 回滚事件在触发回滚的 block meta 之前推送
+            if let (Some(detector), DexEvent::BlockMetaEvent(block)) = (&fork_detector, &event) {
+                for mut rollback in detector.observe(block) {
+                    rollback.metadata_mut().commitment = commitment;
+                    user_callback(rollback);
+                }
+            }
+            user_callback(event);
+        })
+    }
+
+    /// 最小成交额过滤，空表时不传入解析循环
+    pub(crate) fn min_quote_amount_filter(&self) -> Option<MinQuoteAmountFilter> {
+        (!self.config.min_quote_amount.is_empty()).then(|| self.config.min_quote_amount.clone())
+    }
+
+    /// The `SubscribeRequest` currently sent to the server, including filter changes made by
+    /// `update_subscription`; `None` when there is no active subscription
+    pub async fn current_request(&self) -> Option<SubscribeRequest> {
+        self.current_request.read().await.clone()
+    }
+
     /// Update subscription filters at runtime without reconnection
     ///
+    /// The rebuilt request is validated before it is sent; an invalid request returns
+    /// `StreamError::Config` and leaves the running subscription unchanged.
+    ///
     /// # Parameters
     /// * `transaction_filter` - New transaction filter to apply
     /// * `account_filter` - New account filter to apply
     ///
     /// # Returns
-    /// Returns `AnyResult<()>` on success, error on failure
+    /// Returns `StreamResult<()>` on success, error on failure
     pub async fn update_subscription(
         &self,
         transaction_filter: Vec<TransactionFilter>,
         account_filter: Vec<AccountFilter>,
-    ) -> AnyResult<()> {
+    ) -> StreamResult<()> {
+        self.update_own_subscription(transaction_filter.clone(), account_filter.clone()).await?;
+        for subscription in self.commitment_subscriptions.lock().await.iter() {
+            subscription
+                .update_own_subscription(transaction_filter.clone(), account_filter.clone())
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// 更新本客户端自身订阅的过滤器
+    async fn update_own_subscription(
+        &self,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+    ) -> StreamResult<()> {
+        let (transaction_filter, account_filter) =
+            self.filter_scope.read().select(transaction_filter, account_filter);
         let mut control_sender = {
             let control_guard = self.control_tx.lock().await;
 
             if !self.active_subscription.load(Ordering::Acquire) {
-                return Err(anyhow!("No active subscription to update"));
+                return Err(StreamError::NoActiveSubscription);
             }
 
             control_guard
                 .as_ref()
-                .ok_or_else(|| anyhow!("No active subscription to update"))?
+                .ok_or(StreamError::NoActiveSubscription)?
                 .clone()
         };
 
@@ -349,7 +1454,7 @@ impl YellowstoneGrpc {
             .read()
             .await
             .as_ref()
-            .ok_or_else(|| anyhow!("No active subscription"))?
+            .ok_or(StreamError::NoActiveSubscription)?
             .clone();
 
         request.transactions = self
@@ -368,15 +1473,499 @@ impl YellowstoneGrpc {
             )
             .unwrap_or_default();
 
-        control_sender
-            .send(request.clone())
-            .await
-            .map_err(|e| anyhow!("Failed to send update: {}", e))?;
+        validate_subscribe_request(&request)?;
+        control_sender.send(request.clone()).await?;
 
         *self.current_request.write().await = Some(request);
 
         Ok(())
     }
+
+    /// Fetch a historical transaction by signature and parse it with all protocol parsers
+    ///
+    /// The transaction is fetched from `StreamClientConfig::rpc_endpoint` at `confirmed`
+    /// commitment. Useful for verifying a pipeline against known transactions and backfilling
+    /// gaps after a reconnect. Event type and mint filters are not applied.
+    pub async fn parse_signature(&self, signature: &str) -> AnyResult<Vec<DexEvent>> {
+        let rpc_endpoint = self
+            .config
+            .rpc_endpoint
+            .clone()
+            .ok_or_else(|| anyhow!("StreamClientConfig::rpc_endpoint is not configured"))?;
+        let signature = Signature::from_str(signature)?;
+        let client = RpcClient::new(rpc_endpoint);
+        let transaction = client
+            .get_transaction_with_config(
+                &signature,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .await?;
+
+        let events = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let collected = events.clone();
+        EventParser::parse_encoded_confirmed_transaction(
+            ParseOptions::new(&Protocol::all()),
+            transaction,
+            signature,
+            get_high_perf_clock(),
+            Arc::new(move |event: DexEvent| collected.lock().push(event)),
+        )
+        .await?;
+        let events = std::mem::take(&mut *events.lock());
+        Ok(events)
+    }
+
+    /// Check that the endpoint is reachable and accepts the x_token, without subscribing
+    ///
+    /// Connects (failing over between endpoints like a subscription does), calls `GetVersion`
+    /// and `GetSlot`, then drops the connection. An active subscription is not affected, so
+    /// this can be used both as a startup preflight and as a health check.
+    ///
+    /// Transport failures return `StreamError::Connection` (`is_retryable()` is true), while a
+    /// rejected or malformed token returns an error for which `is_auth_error()` is true.
+    pub async fn ping(&self) -> StreamResult<PingInfo> {
+        let started = Instant::now();
+        let mut client = self.subscription_manager.connect().await?;
+        let request_timeout = Duration::from_secs(self.config.connection.request_timeout);
+        let (version, slot) = tokio::time::timeout(request_timeout, async {
+            let version = client.get_version().await?.version;
+            let slot = client.get_slot(Some(CommitmentLevel::Processed)).await?.slot;
+            Ok::<_, StreamError>((version, slot))
+        })
+        .await
+        .map_err(|_| StreamError::Timeout("ping"))??;
+        Ok(PingInfo {
+            endpoint: self.subscription_manager.active_endpoint().to_string(),
+            version,
+            slot,
+            latency: started.elapsed(),
+        })
+    }
+}
+
+/// 按退避策略重新连接，并重新发送当前的 SubscribeRequest（包含 update_subscription 的修改）
+///
+/// 返回新的 sink/stream 和尝试次数；重连被禁用、超过最大次数或订阅已停止时返回 None。
+/// `auth_failures` 为连续认证失败次数，达到 `max_auth_failures` 时切换到 `AuthFailed` 并返回 None
+async fn reconnect(
+    subscription_manager: &SubscriptionManager,
+    current_request: &tokio::sync::RwLock<Option<SubscribeRequest>>,
+    config: &ReconnectConfig,
+    state_notifier: &ConnectionStateNotifier,
+    last_processed_slot: &AtomicU64,
+    auth_failures: &mut u32,
+    reason: &str,
+) -> Option<(GrpcSink, GrpcStream, u32)> {
+    if config.auth_failures_exhausted(*auth_failures) {
+        error!(failures = *auth_failures, reason, "authentication rejected, not reconnecting");
+        state_notifier.transition(ConnectionState::AuthFailed { failures: *auth_failures });
+        return None;
+    }
+    if !config.enabled {
+        return None;
+    }
+    let mut attempt = 1;
+    while config.allows_attempt(attempt) {
+        let delay = config.delay_for_attempt(attempt);
+        warn!(attempt, ?delay, reason, "stream disconnected, reconnecting");
+        state_notifier.transition(ConnectionState::Reconnecting { attempt });
+        tokio::time::sleep(delay).await;
+
+        let mut request = current_request.read().await.clone()?;
+        // 从最后处理完成的 slot (含) 开始重放，该 slot 中已推送的事件会再次推送
+        let last_slot = last_processed_slot.load(Ordering::Relaxed);
+        if config.resume_from_last_slot && last_slot > 0 {
+            request.from_slot = Some(last_slot);
+        }
+        match subscription_manager.subscribe(request).await {
+            Ok((sink, stream)) => {
+                info!(
+                    attempts = attempt,
+                    endpoint = %subscription_manager.active_endpoint(),
+                    "reconnected"
+                );
+                state_notifier.transition(ConnectionState::Connected);
+                return Some((Box::pin(sink), stream.boxed(), attempt));
+            }
+            Err(e) if e.is_auth_error() => {
+                *auth_failures += 1;
+                if config.auth_failures_exhausted(*auth_failures) {
+                    error!(
+                        failures = *auth_failures,
+                        error = %e,
+                        "reconnect aborted, authentication rejected"
+                    );
+                    state_notifier.transition(ConnectionState::AuthFailed {
+                        failures: *auth_failures,
+                    });
+                    return None;
+                }
+                error!(attempt, error = %e, "reconnect attempt failed, authentication rejected");
+            }
+            Err(e) => error!(attempt, error = ?e, "reconnect attempt failed"),
+        }
+        attempt += 1;
+    }
+    error!(attempts = attempt - 1, "giving up reconnecting");
+    None
+}
+
+/// 解析账户、区块元数据和交易更新并推送给回调，实时订阅与文件回放共用
+pub(crate) async fn process_data_update(
+    update: UpdateOneof,
+    created_at: Option<Timestamp>,
+    received_us: i64,
+    options: ParseOptions<'_>,
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+) {
+    let slot = update_slot(&update).unwrap_or_default();
+    let (kind, event_pretty) = match update {
+        UpdateOneof::Account(account) => {
+            let mut account_pretty = factory::create_account_pretty_pooled(account);
+            account_pretty.received_us = received_us;
+            debug!(slot, pubkey = %account_pretty.pubkey, "received account");
+            ("account", EventPretty::Account(account_pretty))
+        }
+        UpdateOneof::BlockMeta(sut) => {
+            let mut block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
+            block_meta_pretty.received_us = received_us;
+            debug!(slot, block_hash = %block_meta_pretty.block_hash, "received block meta");
+            ("block meta", EventPretty::BlockMeta(block_meta_pretty))
+        }
+        UpdateOneof::Transaction(sut) => {
+            let mut transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
+            transaction_pretty.received_us = received_us;
+            debug!(slot, signature = %transaction_pretty.signature, "received transaction");
+            ("transaction", EventPretty::Transaction(transaction_pretty))
+        }
+        UpdateOneof::Entry(entry) => {
+            let mut entry_pretty = factory::create_entry_pretty(entry);
+            entry_pretty.received_us = received_us;
+            debug!(slot, index = entry_pretty.index, "received entry");
+            ("entry", EventPretty::Entry(entry_pretty))
+        }
+        _ => return,
+    };
+    let span = tracing::debug_span!("parse_update", kind, slot);
+    if let Err(e) =
+        process_grpc_transaction(event_pretty, options, callback).instrument(span.clone()).await
+    {
+        span.in_scope(|| error!(error = ?e, "parse error"));
+    }
+}
+
+/// 解析任务：从队列取出消息，解析后按接收顺序推送给回调
+struct EventPipeline {
+    active_protocols: Arc<parking_lot::RwLock<Arc<Vec<Protocol>>>>,
+    event_type_filter: Option<EventTypeFilter>,
+    min_quote_amount: Option<MinQuoteAmountFilter>,
+    bot_wallet: Option<Pubkey>,
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    /// 确认级别升级和 slot 延迟告警直接推送给用户回调，不经过 mint 过滤和去重
+    status_callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    commitment_tracker: Option<Arc<CommitmentTracker>>,
+    slot_lag_monitor: Option<SlotLagMonitor>,
+    /// 开启 `ordering` 时，解析出的事件先在这里按 slot 和交易位置重排
+    reorder: Option<parking_lot::Mutex<EventReorderBuffer>>,
+    drain: Option<EventDrain>,
+    /// 事件速率上限，超出时暂停处理下一条消息
+    throttle: Option<Arc<EventThrottle>>,
+    /// 开启 `stop_on_breach` 时用于在持续超限后停止订阅
+    stop_client: Option<YellowstoneGrpc>,
+    /// 与客户端共享，重连时用作 `from_slot`
+    last_processed_slot: Arc<AtomicU64>,
+    /// 本订阅看到的账户 owner 和 lamports，用于检测 owner 和 SOL 余额变化
+    owner_cache: AccountOwnerCache,
+    lamports_cache: AccountLamportsCache,
+    /// 本订阅的解析错误回调
+    parse_error_callback: Option<ParseErrorCallback>,
+}
+
+/// 并行解析时，按接收顺序等待的单条消息结果
+enum ParsedItem {
+    /// 数据更新解析出的事件及其所在 slot
+    Events { slot: u64, events: Vec<DexEvent> },
+    /// 不需要解析的消息，原样按顺序处理
+    Passthrough(Box<PipelineItem>),
+}
+
+impl EventPipeline {
+    async fn run(self: Arc<Self>, queue: Arc<BoundedQueue<PipelineItem>>, config: ParseConfig) {
+        if config.workers > 1 {
+            self.run_parallel(queue, config.workers, config.reorder_window.max(1)).await;
+        } else {
+            self.run_sequential(queue).await;
+        }
+    }
+
+    /// 单任务解析，每条消息解析完成后才取下一条
+    async fn run_sequential(&self, queue: Arc<BoundedQueue<PipelineItem>>) {
+        loop {
+            let item = tokio::select! {
+                item = queue.pop() => item,
+                _ = self.reorder_deadline() => {
+                    self.release_reordered();
+                    self.drain().await;
+                    continue;
+                }
+            };
+            let Some(item) = item else { break };
+            match item {
+                PipelineItem::Update(
+                    update @ (UpdateOneof::Account(_)
+                    | UpdateOneof::BlockMeta(_)
+                    | UpdateOneof::Transaction(_)
+                    | UpdateOneof::Entry(_)),
+                    created_at,
+                    received_us,
+                ) => {
+                    let slot = update_slot(&update).unwrap_or_default();
+                    if self.reorder.is_some() {
+                        let events = self.parse_collect(update, created_at, received_us).await;
+                        self.deliver(events);
+                    } else {
+                        self.parse(update, created_at, received_us, self.callback.clone()).await;
+                    }
+                    self.record_processed(slot);
+                }
+                item => self.handle_passthrough(item),
+            }
+            self.drain().await;
+            self.throttle().await;
+        }
+        self.flush_reordered().await;
+    }
+
+    /// 多 worker 并行解析
+    ///
+    /// 数据更新分发到 tokio 任务上解析（同时最多 `workers` 个），结果按接收顺序排队，
+    /// 最早的消息解析完成后才推送给回调，因此回调、去重和确认级别跟踪看到的顺序与单任务解析一致。
+    /// 最早未完成的消息之后最多再解析 `reorder_window` 条
+    async fn run_parallel(
+        self: Arc<Self>,
+        queue: Arc<BoundedQueue<PipelineItem>>,
+        workers: usize,
+        reorder_window: usize,
+    ) {
+        let permits = Arc::new(Semaphore::new(workers));
+        let mut in_flight: FuturesOrdered<BoxFuture<'static, ParsedItem>> = FuturesOrdered::new();
+        loop {
+            tokio::select! {
+                biased;
+                Some(parsed) = in_flight.next(), if !in_flight.is_empty() => {
+                    self.emit(parsed).await;
+                }
+                item = queue.pop(), if in_flight.len() < reorder_window => match item {
+                    Some(item) => in_flight.push_back(self.clone().dispatch(item, &permits)),
+                    None => break,
+                },
+                _ = self.reorder_deadline() => {
+                    self.release_reordered();
+                    self.drain().await;
+                }
+            }
+        }
+        while let Some(parsed) = in_flight.next().await {
+            self.emit(parsed).await;
+        }
+        self.flush_reordered().await;
+    }
+
+    /// 数据更新交给 worker 解析，其他消息直接返回
+    fn dispatch(
+        self: Arc<Self>,
+        item: PipelineItem,
+        permits: &Arc<Semaphore>,
+    ) -> BoxFuture<'static, ParsedItem> {
+        match item {
+            PipelineItem::Update(
+                update @ (UpdateOneof::Account(_)
+                | UpdateOneof::BlockMeta(_)
+                | UpdateOneof::Transaction(_)
+                | UpdateOneof::Entry(_)),
+                created_at,
+                received_us,
+            ) => {
+                let slot = update_slot(&update).unwrap_or_default();
+                let permits = permits.clone();
+                let worker = tokio::spawn(async move {
+                    let _permit = permits.acquire_owned().await;
+                    self.parse_collect(update, created_at, received_us).await
+                });
+                async move {
+                    match worker.await {
+                        Ok(events) => ParsedItem::Events { slot, events },
+                        Err(e) => {
+                            error!(slot, error = %e, "parse worker failed");
+                            ParsedItem::Events { slot, events: Vec::new() }
+                        }
+                    }
+                }
+                .boxed()
+            }
+            item => future::ready(ParsedItem::Passthrough(Box::new(item))).boxed(),
+        }
+    }
+
+    async fn emit(&self, parsed: ParsedItem) {
+        match parsed {
+            ParsedItem::Events { slot, events } => {
+                self.deliver(events);
+                self.record_processed(slot);
+            }
+            ParsedItem::Passthrough(item) => self.handle_passthrough(*item),
+        }
+        self.drain().await;
+        self.throttle().await;
+    }
+
+    async fn parse(
+        &self,
+        update: UpdateOneof,
+        created_at: Option<Timestamp>,
+        received_us: i64,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    ) {
+        let protocols = self.active_protocols.read().clone();
+        let options = ParseOptions::new(&protocols)
+            .with_event_type_filter(self.event_type_filter.as_ref())
+            .with_min_quote_amount(self.min_quote_amount.as_ref())
+            .with_bot_wallet(self.bot_wallet)
+            .with_owner_cache(Some(&self.owner_cache))
+            .with_lamports_cache(Some(&self.lamports_cache))
+            .with_parse_error_callback(self.parse_error_callback.as_ref());
+        process_data_update(update, created_at, received_us, options, callback).await;
+    }
+
+    /// 解析一条数据更新并返回其全部事件
+    async fn parse_collect(
+        &self,
+        update: UpdateOneof,
+        created_at: Option<Timestamp>,
+        received_us: i64,
+    ) -> Vec<DexEvent> {
+        let events: Arc<parking_lot::Mutex<Vec<DexEvent>>> = Arc::default();
+        let buffer = events.clone();
+        let collect = Arc::new(move |event: DexEvent| buffer.lock().push(event));
+        self.parse(update, created_at, received_us, collect).await;
+        let events = std::mem::take(&mut *events.lock());
+        events
+    }
+
+    /// 推送解析出的事件，开启排序时先放入重排缓存，只推送已到期的事件
+    fn deliver(&self, events: Vec<DexEvent>) {
+        let Some(reorder) = &self.reorder else {
+            events.into_iter().for_each(|event| (self.callback)(event));
+            return;
+        };
+        let now = std::time::Instant::now();
+        let mut reorder = reorder.lock();
+        for event in events {
+            if let Some(late) = reorder.push(event, now) {
+                (self.callback)(late);
+            }
+        }
+        reorder.pop_ready(now).into_iter().for_each(|event| (self.callback)(event));
+    }
+
+    /// 推送重排缓存中已到期的事件
+    fn release_reordered(&self) {
+        if let Some(reorder) = &self.reorder {
+            let ready = reorder.lock().pop_ready(std::time::Instant::now());
+            ready.into_iter().for_each(|event| (self.callback)(event));
+        }
+    }
+
+    /// 订阅结束时按序推送重排缓存中的全部事件
+    async fn flush_reordered(&self) {
+        if let Some(reorder) = &self.reorder {
+            let events = reorder.lock().drain();
+            events.into_iter().for_each(|event| (self.callback)(event));
+            self.drain().await;
+        }
+    }
+
+    /// 重排缓存中最早的事件到期时返回，没有缓存事件时永不返回
+    async fn reorder_deadline(&self) {
+        let deadline = self.reorder.as_ref().and_then(|reorder| reorder.lock().next_deadline());
+        match deadline {
+            Some(deadline) => tokio::time::sleep_until(Instant::from_std(deadline)).await,
+            None => future::pending().await,
+        }
+    }
+
+    /// 处理 slot 状态和读取任务生成的事件
+    fn handle_passthrough(&self, item: PipelineItem) {
+        match item {
+            PipelineItem::Update(UpdateOneof::Slot(slot_update), ..) => {
+                // 升级事件不经过去重，直接推送给用户回调
+                if let Some(tracker) = &self.commitment_tracker {
+                    for event in tracker.on_slot_status(slot_update.slot, slot_update.status()) {
+                        (self.status_callback)(event);
+                    }
+                }
+            }
+            PipelineItem::Update(..) => {}
+            PipelineItem::Event(event) => (self.callback)(*event),
+        }
+    }
+
+    /// 记录处理进度，延迟超过阈值时推送告警事件
+    fn record_processed(&self, slot: u64) {
+        self.last_processed_slot.fetch_max(slot, Ordering::Relaxed);
+        MetricsManager::global().observe_processed_slot(slot);
+        if let Some(monitor) = &self.slot_lag_monitor {
+            if let Some(event) = monitor.check() {
+                (self.status_callback)(event);
+            }
+        }
+    }
+
+    async fn drain(&self) {
+        if let Some(drain) = &self.drain {
+            drain().await;
+        }
+    }
+
+    /// 超过事件速率上限时暂停，持续超限时报告错误并按配置停止订阅
+    async fn throttle(&self) {
+        let Some(throttle) = &self.throttle else {
+            return;
+        };
+        let pause = match throttle.check() {
+            ThrottleAction::Continue => return,
+            ThrottleAction::Pause(pause) => pause,
+            ThrottleAction::Breach(pause) => {
+                error!(
+                    stopping = self.stop_client.is_some(),
+                    "event rate stayed above the throttle limit"
+                );
+                if let Some(grpc) = self.stop_client.clone() {
+                    // stop() 等待本任务处理完剩余消息，不能在这里直接等待
+                    tokio::spawn(async move { grpc.stop().await });
+                }
+                pause
+            }
+        };
+        throttle.pause(pause).await;
+    }
+}
+
+/// `subscribe_events_stream` 返回的停止句柄
+#[derive(Clone)]
+pub struct EventStreamHandle {
+    grpc: YellowstoneGrpc,
+}
+
+impl EventStreamHandle {
+    /// 停止订阅，事件流随之结束
+    pub async fn stop(&self) {
+        self.grpc.stop().await;
+    }
 }
 
 // 实现 Clone trait 以支持模块间共享
@@ -392,6 +1981,17 @@ impl Clone for YellowstoneGrpc {
             control_tx: self.control_tx.clone(),
             event_type_filter: self.event_type_filter.clone(),
             current_request: self.current_request.clone(),
+            mint_filter: self.mint_filter.clone(),
+            active_protocols: self.active_protocols.clone(),
+            raw_callback: self.raw_callback.clone(),
+            parse_error_callback: self.parse_error_callback.clone(),
+            price_feed: self.price_feed.clone(),
+            transfer_fee_cache: self.transfer_fee_cache.clone(),
+            token_registry: self.token_registry.clone(),
+            commitment_subscriptions: self.commitment_subscriptions.clone(),
+            state_notifier: self.state_notifier.clone(),
+            last_processed_slot: self.last_processed_slot.clone(),
+            filter_scope: self.filter_scope.clone(),
         }
     }
 }
@@ -0,0 +1,97 @@
+use crate::streaming::common::callback_guard::catch_callback_panics;
+use crate::streaming::common::{
+    read_recorded_updates, AccountLamportsCache, AccountOwnerCache, StreamResult,
+};
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::{DexEvent, ParseOptions, Protocol};
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::yellowstone_grpc::{process_data_update, YellowstoneGrpc};
+use futures::{Stream, StreamExt};
+use solana_sdk::pubkey::Pubkey;
+use std::path::Path;
+use std::pin::pin;
+use std::sync::Arc;
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+use yellowstone_grpc_proto::tonic::Status;
+
+impl YellowstoneGrpc {
+    /// 从录制文件回放事件
+    ///
+    /// 读取 `StreamClientConfig::record_path` 录制的 `SubscribeUpdate`，按录制顺序经过与实时订阅
+    /// 相同的解析流程、过滤和回调；slot 与 `created_at` 等时间戳保持录制时的值。
+    /// 全部消息处理完后返回
+    pub async fn subscribe_events_from_file<F>(
+        &self,
+        path: impl AsRef<Path>,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> StreamResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        let updates = read_recorded_updates(path)?;
+        let stream = futures::stream::iter(updates.into_iter().map(Ok));
+        self.subscribe_events_from_stream(
+            stream,
+            protocols,
+            bot_wallet,
+            event_type_filter,
+            callback,
+        )
+        .await
+    }
+
+    /// 解析任意 `SubscribeUpdate` 消息流中的事件
+    ///
+    /// 消息按顺序经过与实时订阅相同的解析流程、过滤和回调，流结束后返回。
+    /// 配合 `MockStream` 可以在没有 endpoint 的情况下测试事件处理逻辑。
+    /// 流中出现错误时停止并返回 `StreamError::Subscribe`
+    pub async fn subscribe_events_from_stream<S, F>(
+        &self,
+        stream: S,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> StreamResult<()>
+    where
+        S: Stream<Item = Result<SubscribeUpdate, Status>>,
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        let callback = self.wrap_callback(catch_callback_panics(Arc::new(callback)), None, None);
+        let min_quote_amount = self.min_quote_amount_filter();
+        // 回放有自己的账户缓存，不受同一客户端其他订阅的影响
+        let owner_cache = AccountOwnerCache::new();
+        let lamports_cache = AccountLamportsCache::new();
+        let parse_error_callback = self.parse_error_callback.read().clone();
+
+        let mut stream = pin!(stream);
+        while let Some(update) = stream.next().await {
+            let update = update?;
+            let received_us = get_high_perf_clock();
+            let raw_callback = self.raw_callback.read().clone();
+            if let Some(raw_callback) = raw_callback {
+                raw_callback(&update);
+            }
+            let Some(update_oneof) = update.update_oneof else { continue };
+            let options = ParseOptions::new(&protocols)
+                .with_event_type_filter(event_type_filter.as_ref())
+                .with_min_quote_amount(min_quote_amount.as_ref())
+                .with_bot_wallet(bot_wallet)
+                .with_owner_cache(Some(&owner_cache))
+                .with_lamports_cache(Some(&lamports_cache))
+                .with_parse_error_callback(parse_error_callback.as_ref());
+            process_data_update(
+                update_oneof,
+                update.created_at,
+                received_us,
+                options,
+                callback.clone(),
+            )
+            .await;
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     common::AnyResult,
     streaming::{
+        common::StreamResult,
         grpc::{pool::factory, EventPretty},
         yellowstone_grpc::{TransactionFilter, YellowstoneGrpc},
     },
@@ -35,15 +36,19 @@ impl YellowstoneGrpc {
         callback: F,
         account_include: Option<Vec<String>>,
         account_exclude: Option<Vec<String>>,
-    ) -> AnyResult<()>
+    ) -> StreamResult<()>
     where
         F: Fn(SystemEvent) + Send + Sync + Clone + 'static,
     {
         let addrs = vec![SYSTEM_PROGRAM_ID.to_string()];
         let account_include = account_include.unwrap_or_default();
         let account_exclude = account_exclude.unwrap_or_default();
-        let tx_filter =
-            vec![TransactionFilter { account_include, account_exclude, account_required: addrs }];
+        let tx_filter = vec![TransactionFilter {
+            account_include,
+            account_exclude,
+            account_required: addrs,
+            ..Default::default()
+        }];
         let transactions = self.subscription_manager.get_subscribe_request_filter(tx_filter, None);
         let (mut subscribe_tx, mut stream, _) = self
             .subscription_manager
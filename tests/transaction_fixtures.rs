@@ -0,0 +1,423 @@
+//! 固定交易样本的解析回归测试
+//!
+//! `tests/fixtures/*.b64` 为 base64 编码的 `VersionedTransaction`，指令使用各协议在主网上的
+//! 程序ID、判别器和账户布局；账户 `key(n)` 为 32 个字节均为 n 的公钥，按指令账户的顺序从 1 开始。
+//! `*.json` 为 `getTransaction` 响应，附带交易 meta (inner instructions、程序日志)
+
+use base64::Engine;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_streamer_sdk::streaming::event_parser::{
+    common::{filter::WSOL_MINT, EventType, ProtocolType},
+    protocols::{openbook_v2::types::OpenBookV2Side, phoenix::types::PhoenixSide},
+    DexEvent, Protocol,
+};
+use solana_streamer_sdk::streaming::testing::{parse_transaction_bytes, parse_transaction_json};
+
+fn key(n: u8) -> Pubkey {
+    Pubkey::new_from_array([n; 32])
+}
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!("{}/tests/fixtures/{name}.b64", env!("CARGO_MANIFEST_DIR"));
+    let encoded = std::fs::read_to_string(&path).unwrap();
+    base64::engine::general_purpose::STANDARD.decode(encoded.trim()).unwrap()
+}
+
+async fn parse_single(protocol: Protocol, name: &str) -> DexEvent {
+    let mut events = parse_transaction_bytes(&[protocol], &fixture(name)).await.unwrap();
+    assert_eq!(events.len(), 1, "{events:?}");
+    events.remove(0)
+}
+
+async fn parse_json(protocol: Protocol, name: &str) -> Vec<DexEvent> {
+    let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+    let json = std::fs::read_to_string(path).unwrap();
+    parse_transaction_json(&[protocol], &json).await.unwrap()
+}
+
+#[tokio::test]
+async fn pumpfun_buy() {
+    let event = parse_single(Protocol::PumpFun, "pumpfun_buy").await;
+    let DexEvent::PumpFunTradeEvent(trade) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(trade.metadata.signature, Signature::from([1; 64]));
+    assert_eq!(trade.metadata.protocol, ProtocolType::PumpFun);
+    assert_eq!(trade.metadata.event_type, EventType::PumpFunBuy);
+    assert!(trade.is_buy);
+    assert_eq!(trade.amount, 1_000_000_000);
+    assert_eq!(trade.max_sol_cost, 50_000_000);
+    assert_eq!(trade.mint, key(3));
+    assert_eq!(trade.bonding_curve, key(4));
+    assert_eq!(trade.user, key(7));
+    // 没有交易日志时无法判断曲线是否完成
+    assert_eq!(trade.bonding_curve_complete, None);
+}
+
+#[tokio::test]
+async fn pumpswap_buy() {
+    let event = parse_single(Protocol::PumpSwap, "pumpswap_buy").await;
+    let DexEvent::PumpSwapBuyEvent(buy) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(buy.metadata.signature, Signature::from([2; 64]));
+    assert_eq!(buy.metadata.event_type, EventType::PumpSwapBuy);
+    assert_eq!(buy.base_amount_out, 2_500_000);
+    assert_eq!(buy.max_quote_amount_in, 12_000_000);
+    assert_eq!(buy.pool, key(1));
+    assert_eq!(buy.user, key(2));
+    assert_eq!(buy.base_mint, key(4));
+    assert_eq!(buy.quote_mint, key(5));
+    assert_eq!(buy.coin_creator_vault_ata, key(18));
+    assert_eq!(buy.coin_creator_vault_authority, key(19));
+}
+
+#[tokio::test]
+async fn raydium_cpmm_swap_base_input() {
+    let event = parse_single(Protocol::RaydiumCpmm, "raydium_cpmm_swap_base_input").await;
+    let DexEvent::RaydiumCpmmSwapEvent(swap) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(swap.metadata.signature, Signature::from([3; 64]));
+    assert_eq!(swap.metadata.event_type, EventType::RaydiumCpmmSwapBaseInput);
+    assert_eq!(swap.amount_in, 100_000_000);
+    assert_eq!(swap.minimum_amount_out, 15_000_000);
+    assert_eq!(swap.pool_state, key(4));
+    assert_eq!(swap.input_vault, key(7));
+    assert_eq!(swap.output_vault, key(8));
+    assert_eq!(swap.input_token_mint, key(11));
+    assert_eq!(swap.output_token_mint, key(12));
+}
+
+#[tokio::test]
+async fn orca_whirlpool_swap() {
+    let event = parse_single(Protocol::OrcaWhirlpool, "orca_whirlpool_swap").await;
+    let DexEvent::OrcaWhirlpoolSwapEvent(swap) = event else {
+        panic!("unexpected event {event:?}")
+    };
+    assert_eq!(swap.metadata.signature, Signature::from([4; 64]));
+    assert_eq!(swap.metadata.protocol, ProtocolType::OrcaWhirlpool);
+    assert_eq!(swap.metadata.event_type, EventType::OrcaWhirlpoolSwap);
+    assert_eq!(swap.amount, 5_000_000);
+    assert_eq!(swap.other_amount_threshold, 4_900_000);
+    assert_eq!(swap.sqrt_price_limit, 79_226_673_515_401_279_992_447_579_055);
+    assert!(swap.amount_specified_is_input);
+    assert!(!swap.a_to_b);
+    assert_eq!(swap.token_authority, key(2));
+    assert_eq!(swap.whirlpool, key(3));
+    assert_eq!(swap.token_vault_a, key(5));
+    assert_eq!(swap.token_vault_b, key(7));
+    assert_eq!(swap.oracle, key(11));
+}
+
+#[tokio::test]
+async fn meteora_dlmm_swap_with_cpi_event() {
+    let mut events = parse_json(Protocol::MeteoraDlmm, "meteora_dlmm_swap").await;
+    assert_eq!(events.len(), 1, "{events:?}");
+    let event = events.remove(0);
+    let DexEvent::MeteoraDlmmSwapEvent(swap) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(swap.metadata.signature, Signature::from([5; 64]));
+    assert_eq!(swap.metadata.event_type, EventType::MeteoraDlmmSwap);
+    // 指令参数与账户
+    assert_eq!(swap.min_amount_out, 950_000);
+    assert_eq!(swap.lb_pair, key(1));
+    assert_eq!(swap.token_x_mint, key(7));
+    assert_eq!(swap.token_y_mint, key(8));
+    assert_eq!(swap.user, key(11));
+    assert_eq!(swap.bin_arrays, vec![key(16), key(17)]);
+    // CPI SwapEvent
+    assert_eq!(swap.amount_in, 1_000_000);
+    assert_eq!(swap.amount_out, 960_000);
+    assert!(swap.swap_for_y);
+    assert_eq!((swap.start_bin_id, swap.end_bin_id, swap.active_bin_id), (100, 102, 102));
+    assert_eq!((swap.fee, swap.protocol_fee), (2_500, 125));
+}
+
+#[tokio::test]
+async fn phoenix_fill_from_log_instruction() {
+    let mut events = parse_json(Protocol::Phoenix, "phoenix_swap_fill").await;
+    assert_eq!(events.len(), 1, "{events:?}");
+    let event = events.remove(0);
+    let DexEvent::PhoenixFillEvent(fill) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(fill.metadata.signature, Signature::from([6; 64]));
+    assert_eq!(fill.metadata.protocol, ProtocolType::Phoenix);
+    assert_eq!(fill.metadata.event_type, EventType::PhoenixFill);
+    assert_eq!(fill.market, key(4));
+    assert_eq!(fill.trader, key(1));
+    assert_eq!(fill.maker, key(30));
+    // maker 的卖单被吃，taker 方向为买
+    assert_eq!(fill.side, PhoenixSide::Bid);
+    assert_eq!(fill.price_in_ticks, 1_500);
+    assert_eq!((fill.base_lots_filled, fill.base_lots_remaining), (20, 5));
+    assert_eq!((fill.order_sequence_number, fill.sequence_number), (12_345, 77));
+    assert_eq!(fill.timestamp, 1_733_000_000);
+}
+
+#[tokio::test]
+async fn raydium_launchpad_buy_exact_in() {
+    let event = parse_single(Protocol::RaydiumLaunchpad, "raydium_launchpad_buy_exact_in").await;
+    let DexEvent::BonkTradeEvent(trade) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(trade.metadata.signature, Signature::from([7; 64]));
+    // LaunchLab 与 Bonk 共用程序，事件按 Bonk 输出
+    assert_eq!(trade.metadata.protocol, ProtocolType::Bonk);
+    assert_eq!(trade.metadata.event_type, EventType::BonkBuyExactIn);
+    assert_eq!(trade.amount_in, 2_000_000_000);
+    assert_eq!(trade.minimum_amount_out, 1_000_000);
+    assert_eq!(trade.payer, key(1));
+    assert_eq!(trade.pool_state, key(5));
+    assert_eq!(trade.base_token_mint, key(10));
+    assert_eq!(trade.quote_token_mint, key(11));
+
+    // 同时选择两个协议时不会重复输出
+    let protocols = [Protocol::Bonk, Protocol::RaydiumLaunchpad];
+    let events = parse_transaction_bytes(&protocols, &fixture("raydium_launchpad_buy_exact_in"))
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1, "{events:?}");
+}
+
+#[tokio::test]
+async fn jupiter_route_collects_every_hop() {
+    let mut events = parse_json(Protocol::Jupiter, "jupiter_route").await;
+    assert_eq!(events.len(), 1, "{events:?}");
+    let event = events.remove(0);
+    let DexEvent::JupiterRoute(route) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(route.metadata.signature, Signature::from([8; 64]));
+    assert_eq!(route.metadata.event_type, EventType::JupiterRoute);
+    assert_eq!(route.in_amount, 10_000_000);
+    assert_eq!(route.quoted_out_amount, 2_000_000);
+    assert_eq!(route.slippage_bps, 50);
+    assert_eq!(route.user_transfer_authority, key(2));
+    assert_eq!(route.destination_mint, key(6));
+    // 两跳按执行顺序收集，source_mint 取第一跳的 input_mint
+    assert_eq!(route.hops.len(), 2);
+    assert_eq!((route.hops[0].amm, route.hops[1].amm), (key(40), key(43)));
+    assert_eq!(route.hops[0].output_mint, route.hops[1].input_mint);
+    assert_eq!(route.source_mint, key(41));
+    assert_eq!(route.total_in_amount(), Some(10_000_000));
+    assert_eq!(route.total_out_amount(), Some(2_010_000));
+    let swap_data = route.metadata.swap_data.unwrap();
+    assert_eq!((swap_data.from_mint, swap_data.to_mint), (key(41), key(6)));
+    assert_eq!((swap_data.from_amount, swap_data.to_amount), (10_000_000, 2_010_000));
+}
+
+#[tokio::test]
+async fn moonshot_buy() {
+    let event = parse_single(Protocol::Moonshot, "moonshot_buy").await;
+    let DexEvent::MoonshotTradeEvent(trade) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(trade.metadata.signature, Signature::from([9; 64]));
+    assert_eq!(trade.metadata.protocol, ProtocolType::Moonshot);
+    assert_eq!(trade.metadata.event_type, EventType::MoonshotBuy);
+    assert!(trade.is_buy);
+    assert_eq!(trade.token_amount, 1_000_000_000_000);
+    assert_eq!(trade.collateral_amount, 50_000_000);
+    assert_eq!(trade.slippage_bps, 100);
+    assert_eq!(trade.sender, key(1));
+    assert_eq!(trade.curve_account, key(3));
+    assert_eq!(trade.mint, key(7));
+}
+
+#[tokio::test]
+async fn pumpfun_migrations_through_cpi_take_their_own_events() {
+    // 调用方程序在一条外层指令中依次 CPI 迁移三条联合曲线，第三次没有迁移完成事件
+    let events = parse_json(Protocol::PumpFun, "pumpfun_migrate_cpi").await;
+    let migrations: Vec<_> = events
+        .iter()
+        .map(|event| match event {
+            DexEvent::PumpFunMigrateEvent(migrate) => migrate,
+            other => panic!("unexpected event {other:?}"),
+        })
+        .collect();
+    assert_eq!(migrations.len(), 2, "{events:?}");
+    let (first, second) = (migrations[0], migrations[1]);
+    assert_eq!(first.metadata.signature, Signature::from([14; 64]));
+    assert_eq!(first.metadata.event_type, EventType::PumpFunMigrate);
+    assert_eq!((first.metadata.outer_index, first.metadata.inner_index), (0, Some(0)));
+    assert_eq!((first.mint, first.bonding_curve, first.pool), (key(3), key(4), key(10)));
+    assert_eq!(first.mint_amount, 206_900_000_000_000);
+    assert_eq!(first.sol_amount, 84_990_359_038);
+    assert_eq!(first.pool_migration_fee, 15_000_001);
+    assert_eq!(first.user, key(6));
+    // 第二次迁移取自己之后的事件，而不是同一外层指令中的第一条
+    assert_eq!(second.metadata.inner_index, Some(2));
+    assert_eq!((second.mint, second.bonding_curve, second.pool), (key(25), key(26), key(31)));
+    assert_eq!(second.sol_amount, 85_000_000_000);
+}
+
+#[tokio::test]
+async fn lifinity_swap() {
+    let event = parse_single(Protocol::Lifinity, "lifinity_swap").await;
+    let DexEvent::LifinitySwapEvent(swap) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(swap.metadata.signature, Signature::from([10; 64]));
+    assert_eq!(swap.metadata.protocol, ProtocolType::Lifinity);
+    assert_eq!(swap.metadata.event_type, EventType::LifinitySwap);
+    assert_eq!(swap.amount_in, 3_000_000);
+    assert_eq!(swap.minimum_amount_out, 2_900_000);
+    assert_eq!(swap.pool, key(2));
+    assert_eq!(swap.user_transfer_authority, key(3));
+    assert_eq!(swap.swap_source, key(6));
+    assert_eq!(swap.swap_destination, key(7));
+    assert_eq!(swap.oracle_pc_account, key(13));
+}
+
+#[tokio::test]
+async fn openbook_v2_fill_from_program_data_log() {
+    let mut events = parse_json(Protocol::OpenBookV2, "openbook_v2_fill").await;
+    assert_eq!(events.len(), 1, "{events:?}");
+    let event = events.remove(0);
+    let DexEvent::OpenBookV2FillEvent(fill) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(fill.metadata.signature, Signature::from([11; 64]));
+    assert_eq!(fill.metadata.protocol, ProtocolType::OpenBookV2);
+    assert_eq!(fill.metadata.event_type, EventType::OpenBookV2Fill);
+    assert_eq!((fill.metadata.outer_index, fill.metadata.inner_index), (0, None));
+    assert_eq!(fill.market, key(3));
+    assert_eq!((fill.maker, fill.taker), (key(30), key(2)));
+    assert_eq!(fill.side, OpenBookV2Side::Bid);
+    assert_eq!((fill.price, fill.quantity), (2_350, 40));
+    assert!(fill.maker_out);
+    assert_eq!(fill.maker_slot, 4);
+    assert_eq!((fill.maker_client_order_id, fill.taker_client_order_id), (55, 66));
+    assert_eq!(fill.taker_fee_ceil, 12);
+    assert_eq!(fill.seq_num, 9_001);
+
+    // 未选择 OpenBook v2 时不解析日志
+    let events = parse_json(Protocol::Phoenix, "openbook_v2_fill").await;
+    assert!(events.is_empty(), "{events:?}");
+}
+
+#[tokio::test]
+async fn raydium_clmm_increase_liquidity_with_log() {
+    let events = parse_json(Protocol::RaydiumClmm, "raydium_clmm_increase_liquidity").await;
+    assert_eq!(events.len(), 2, "{events:?}");
+    let DexEvent::RaydiumClmmIncreaseLiquidityEvent(increase) = &events[0] else {
+        panic!("unexpected event {:?}", events[0])
+    };
+    assert_eq!(increase.metadata.signature, Signature::from([12; 64]));
+    assert_eq!(increase.metadata.event_type, EventType::RaydiumClmmIncreaseLiquidity);
+    assert_eq!(increase.liquidity, 1_000_000_000);
+    assert_eq!((increase.amount0_max, increase.amount1_max), (5_000_000, 6_000_000));
+    assert_eq!(increase.nft_owner, key(1));
+    assert_eq!(increase.pool_state, key(3));
+    assert_eq!(increase.personal_position, key(5));
+
+    // 日志事件在指令事件之后，包含仓位 NFT 和实际存入数量
+    let DexEvent::RaydiumClmmIncreaseLiquidityLogEvent(log) = &events[1] else {
+        panic!("unexpected event {:?}", events[1])
+    };
+    assert_eq!(log.metadata.event_type, EventType::RaydiumClmmIncreaseLiquidityLog);
+    assert_eq!(log.metadata.outer_index, 0);
+    assert_eq!(log.position_nft_mint, key(40));
+    assert_eq!(log.liquidity, 1_000_000_000);
+    assert_eq!((log.amount0, log.amount1), (4_000_000, 5_500_000));
+}
+
+#[tokio::test]
+async fn raydium_amm_v4_initialize2_pool_created() {
+    let event = parse_single(Protocol::RaydiumAmmV4, "raydium_amm_v4_initialize2").await;
+    let DexEvent::RaydiumAmmV4Initialize2Event(init) = &event else {
+        panic!("unexpected event {event:?}")
+    };
+    assert_eq!(init.metadata.signature, Signature::from([15; 64]));
+    assert_eq!(init.metadata.event_type, EventType::RaydiumAmmV4Initialize2);
+    assert_eq!(init.nonce, 254);
+    assert_eq!((init.init_coin_amount, init.init_pc_amount), (79_000_000_000, 2_000_000_000_000));
+    assert_eq!((init.amm, init.coin_mint, init.pc_mint), (key(5), WSOL_MINT, key(10)));
+
+    // coin 一侧为 WSOL，统一视图中作为 quote
+    let created = event.as_pool_created().unwrap();
+    assert_eq!(created.protocol, ProtocolType::RaydiumAmmV4);
+    assert_eq!(created.pool, key(5));
+    assert_eq!((created.base_mint, created.base_reserve), (key(10), 2_000_000_000_000));
+    assert_eq!((created.quote_mint, created.quote_reserve), (WSOL_MINT, 79_000_000_000));
+    assert_eq!((created.lp_mint, created.creator), (key(8), key(18)));
+    assert_eq!(created.open_time, 1_733_000_600);
+}
+
+#[tokio::test]
+async fn pumpfun_buy_that_completes_the_bonding_curve() {
+    let mut events = parse_json(Protocol::PumpFun, "pumpfun_buy_completes_curve").await;
+    assert_eq!(events.len(), 1, "{events:?}");
+    let event = events.remove(0);
+    let DexEvent::PumpFunTradeEvent(trade) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(trade.metadata.signature, Signature::from([16; 64]));
+    assert_eq!((trade.sol_amount, trade.token_amount), (50_000_000, 1_000_000_000));
+    // 交易日志中的储备，真实代币储备耗尽即曲线完成
+    assert_eq!(trade.virtual_sol_reserves, 115_005_359_056);
+    assert_eq!(trade.real_sol_reserves, 85_005_359_056);
+    assert_eq!(trade.real_token_reserves, 0);
+    assert_eq!(trade.bonding_curve_complete, Some(true));
+    // 交易量跟踪字段随日志合并
+    assert!(trade.track_volume);
+    assert_eq!((trade.total_unclaimed_tokens, trade.total_claimed_tokens), (1_000, 2_000));
+    assert_eq!(trade.current_sol_volume, 3_000_000);
+    assert_eq!(trade.last_update_timestamp, 1_733_000_000);
+    assert_eq!((trade.creator, trade.creator_fee), (key(21), 25_000));
+}
+
+#[tokio::test]
+async fn sanctum_swap_exact_in() {
+    let event = parse_single(Protocol::Sanctum, "sanctum_swap_exact_in").await;
+    let DexEvent::SanctumSwapEvent(swap) = event else { panic!("unexpected event {event:?}") };
+    assert_eq!(swap.metadata.signature, Signature::from([13; 64]));
+    assert_eq!(swap.metadata.protocol, ProtocolType::Sanctum);
+    assert_eq!(swap.metadata.event_type, EventType::SanctumSwapExactIn);
+    assert!(!swap.exact_out);
+    assert_eq!((swap.src_lst_index, swap.dst_lst_index), (3, 7));
+    // 交易 meta 不可用时 in/out 为指令中的数量和下限
+    assert_eq!((swap.amount, swap.limit_amount), (1_000_000, 990_000));
+    assert_eq!((swap.in_amount, swap.out_amount), (1_000_000, 990_000));
+    assert_eq!(swap.signer, key(1));
+    assert_eq!((swap.src_lst_mint, swap.dst_lst_mint), (key(2), key(3)));
+    assert_eq!(swap.pool_state, key(9));
+    assert_eq!(swap.dst_pool_reserves, key(12));
+}
+
+#[tokio::test]
+async fn fixtures_are_ignored_by_other_protocols() {
+    let events = parse_transaction_bytes(&[Protocol::Bonk], &fixture("pumpfun_buy")).await.unwrap();
+    assert!(events.is_empty(), "{events:?}");
+}
+
+#[tokio::test]
+async fn pumpswap_deposit_and_withdraw() {
+    let events = parse_json(Protocol::PumpSwap, "pumpswap_deposit_withdraw").await;
+    assert_eq!(events.len(), 2, "{events:?}");
+    let DexEvent::PumpSwapDepositEvent(deposit) = &events[0] else {
+        panic!("unexpected event {:?}", events[0])
+    };
+    assert_eq!(deposit.metadata.signature, Signature::from([17; 64]));
+    assert_eq!(deposit.metadata.event_type, EventType::PumpSwapDeposit);
+    assert_eq!((deposit.pool, deposit.base_mint, deposit.quote_mint), (key(1), key(4), key(5)));
+    // 指令中的最大投入数量，CPI 日志中的实际投入数量和存款后的储备
+    assert_eq!(
+        (deposit.max_base_amount_in, deposit.max_quote_amount_in),
+        (2_000_000_000, 600_000_000)
+    );
+    assert_eq!((deposit.base_amount_in, deposit.quote_amount_in), (1_980_000_000, 590_000_000));
+    assert_eq!(deposit.pool_base_token_reserves, 101_980_000_000);
+    assert_eq!(deposit.lp_mint_supply, 51_000_000);
+    assert_eq!(deposit.lp_token_delta(), 1_000_000);
+
+    let DexEvent::PumpSwapWithdrawEvent(withdraw) = &events[1] else {
+        panic!("unexpected event {:?}", events[1])
+    };
+    assert_eq!(withdraw.metadata.event_type, EventType::PumpSwapWithdraw);
+    assert_eq!(withdraw.metadata.outer_index, 1);
+    assert_eq!((withdraw.base_amount_out, withdraw.quote_amount_out), (792_000_000, 236_000_000));
+    assert_eq!(withdraw.pool_quote_token_reserves, 30_354_000_000);
+    assert_eq!(withdraw.lp_mint_supply, 50_600_000);
+    assert_eq!(withdraw.lp_token_delta(), -400_000);
+}
+
+#[tokio::test]
+async fn raydium_cpmm_swap_base_input_json() {
+    let path =
+        format!("{}/tests/fixtures/raydium_cpmm_swap_base_input.json", env!("CARGO_MANIFEST_DIR"));
+    let json = std::fs::read_to_string(path).unwrap();
+    let events = parse_transaction_json(&[Protocol::RaydiumCpmm], &json).await.unwrap();
+    assert_eq!(events.len(), 1, "{events:?}");
+    let DexEvent::RaydiumCpmmSwapEvent(swap) = &events[0] else {
+        panic!("unexpected event {:?}", events[0])
+    };
+    assert_eq!(swap.metadata.signature, Signature::from([3; 64]));
+    assert_eq!(swap.metadata.slot, 312_000_000);
+    assert_eq!(swap.metadata.block_time, Some(1_733_000_000));
+    assert_eq!(swap.metadata.compute_units_consumed, Some(42_000));
+    assert!(swap.metadata.succeeded);
+    assert_eq!(swap.amount_in, 100_000_000);
+    assert_eq!(swap.input_token_mint, key(11));
+}